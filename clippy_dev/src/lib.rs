@@ -26,7 +26,8 @@ static DEC_CLIPPY_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
     (?:\s+///.*)*
     \s+pub\s+(?P<name>[A-Z_][A-Z_0-9]*)\s*,\s*
     (?P<cat>[a-z_]+)\s*,\s*
-    "(?P<desc>(?:[^"\\]+|\\(?s).(?-s))*)"\s*[})]
+    "(?P<desc>(?:[^"\\]+|\\(?s).(?-s))*)"\s*
+    (?:,\s*applicability:\s*\[[^\]]*\]\s*)?[})]
 "#,
     )
     .unwrap()
@@ -365,6 +366,13 @@ declare_clippy_lint!{
     "single line"
 }
 
+declare_clippy_lint! {
+    pub NEEDLESS_RETURN,
+    style,
+    "with suggestions",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
 /// some doc comment
 declare_deprecated_lint! {
     pub SHOULD_ASSERT_EQ,
@@ -378,6 +386,7 @@ declare_deprecated_lint! {
     let expected = vec![
         Lint::new("ptr_arg", "style", "really long text", None, "module_name"),
         Lint::new("doc_markdown", "pedantic", "single line", None, "module_name"),
+        Lint::new("needless_return", "style", "with suggestions", None, "module_name"),
         Lint::new(
             "should_assert_eq",
             "Deprecated",
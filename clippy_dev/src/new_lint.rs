@@ -165,7 +165,7 @@ fn get_lint_file_contents(
 ) -> String {
     format!(
         "use rustc_lint::{{{type}, {context_import}}};
-use rustc_session::{{declare_lint_pass, declare_tool_lint}};
+use rustc_session::declare_tool_lint;
 {pass_import}
 
 declare_clippy_lint! {{
@@ -0,0 +1,82 @@
+//! Extracts the documentation of the lints and the applicabilities of their suggestions from their
+//! `declare_clippy_lint!` declarations, for `cargo clippy --explain <lint>` and
+//! `--print-lints-json`.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut files = Vec::new();
+    collect_files(Path::new("src"), &mut files);
+    files.sort();
+
+    let mut lints = Vec::new();
+    for file in files {
+        let content = fs::read_to_string(&file).expect("the source files can be read");
+        lints.extend(parse_lints(&content));
+    }
+    lints.sort();
+
+    let mut out = String::from("&[\n");
+    for (name, group, docs, applicability) in lints {
+        writeln!(out, "    ({:?}, {:?}, {:?}, &{:?}),", name, group, docs, applicability).unwrap();
+    }
+    out.push_str("]\n");
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets `OUT_DIR`"));
+    fs::write(out_dir.join("lint_docs.rs"), out).expect("the generated file can be written");
+}
+
+/// Collects the Rust files of the directory, recursively.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("the source directories can be read") {
+        let path = entry.expect("the source directories can be read").path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            println!("cargo:rerun-if-changed={}", path.display());
+            files.push(path);
+        }
+    }
+}
+
+/// Returns the name, group, documentation and applicabilities of the suggestions of the lints
+/// declared in a file, the declarations looking like the documentation of the lint, `pub NAME,`,
+/// `group,`, the description, then `applicability: [MachineApplicable, ..]` if the lint makes
+/// suggestions.
+fn parse_lints(content: &str) -> Vec<(String, String, String, Vec<String>)> {
+    let mut lints = Vec::new();
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() != "declare_clippy_lint! {" {
+            continue;
+        }
+        let mut docs = String::new();
+        for line in &mut lines {
+            let line = line.trim();
+            if let Some(doc) = line.strip_prefix("///") {
+                docs.push_str(doc.strip_prefix(' ').unwrap_or(doc));
+                docs.push('\n');
+                continue;
+            }
+            let name = line.strip_prefix("pub ").and_then(|name| name.strip_suffix(','));
+            let group = lines.next().and_then(|group| group.trim().strip_suffix(','));
+            let applicability = lines
+                .by_ref()
+                .map(str::trim)
+                .take_while(|line| *line != "}")
+                .find_map(|line| line.strip_prefix("applicability: [")?.strip_suffix(']'))
+                .map_or_else(Vec::new, |list| {
+                    list.split(',').map(|app| app.trim().to_string()).collect()
+                });
+            if let (Some(name), Some(group)) = (name, group) {
+                lints.push((name.to_ascii_lowercase(), group.to_string(), docs, applicability));
+            }
+            break;
+        }
+    }
+    lints
+}
@@ -0,0 +1,87 @@
+use crate::utils::{implements_trait, is_type_diagnostic_item, last_path_segment, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeFoldable;
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** This lint warns when you use `Arc` with a type that does not implement
+    /// `Send` or `Sync`.
+    ///
+    /// **Why is this bad?** `Arc<T>` is a thread-safe `Rc<T>` and guarantees that updates to the
+    /// reference counter use atomic operations. To send an `Arc<T>` across thread boundaries and
+    /// share ownership between multiple threads, `T` must be *both* `Send` and `Sync`, so either
+    /// `T` should be made `Send + Sync` or an `Rc` should be used instead of an `Arc`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::cell::RefCell;
+    /// # use std::sync::Arc;
+    ///
+    /// fn main() {
+    ///     // This is fine, as `i32` implements `Send` and `Sync`.
+    ///     let a = Arc::new(42);
+    ///
+    ///     // `RefCell` is `!Sync`, so either the `Arc` should be replaced with an `Rc`
+    ///     // or the `RefCell` replaced with something like a `RwLock`
+    ///     let b = Arc::new(RefCell::new(42));
+    /// }
+    /// ```
+    pub ARC_WITH_NON_SEND_SYNC,
+    style,
+    "using `Arc` with a type that does not implement `Send` and `Sync`"
+}
+
+declare_lint_pass!(ArcWithNonSendSync => [ARC_WITH_NON_SEND_SYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for ArcWithNonSendSync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Call(func, [arg]) = expr.kind;
+            if let ExprKind::Path(ref func_path @ QPath::TypeRelative(..)) = func.kind;
+            if last_path_segment(func_path).ident.name == sym::new;
+            let ty = cx.typeck_results().expr_ty(expr);
+            if is_type_diagnostic_item(cx, ty, sym::Arc);
+            let arg_ty = cx.typeck_results().expr_ty(arg);
+            // generic types may still be `Send` and `Sync` where they are instantiated
+            if !arg_ty.needs_subst();
+            if let (Some(send), Some(sync)) = (
+                cx.tcx.get_diagnostic_item(sym::send_trait),
+                cx.tcx.lang_items().sync_trait(),
+            );
+            let is_send = implements_trait(cx, arg_ty, send, &[]);
+            let is_sync = implements_trait(cx, arg_ty, sync, &[]);
+            if !(is_send && is_sync);
+            then {
+                let reason = match (is_send, is_sync) {
+                    (false, false) => "neither `Send` nor `Sync`",
+                    (false, true) => "not `Send`",
+                    (true, false) => "not `Sync`",
+                    _ => return,
+                };
+                span_lint_and_then(
+                    cx,
+                    ARC_WITH_NON_SEND_SYNC,
+                    expr.span,
+                    "usage of an `Arc` that is not `Send` and `Sync`",
+                    |diag| {
+                        diag.note(&format!(
+                            "`Arc<{}>` is not `Send` and `Sync` as `{}` is {}",
+                            arg_ty, arg_ty, reason
+                        ));
+                        diag.help("if the `Arc` will not be used across threads replace it with an `Rc`");
+                        diag.help(&format!(
+                            "otherwise make `{}` `Send` and `Sync` or consider a wrapper type such as `Mutex`",
+                            arg_ty
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
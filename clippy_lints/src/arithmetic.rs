@@ -2,7 +2,7 @@ use crate::consts::constant_simple;
 use crate::utils::span_lint;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -8,7 +8,7 @@ use rustc_hir as hir;
 use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `a = a op b` or `a = b commutative_op a`
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub ASSIGN_OP_PATTERN,
     style,
-    "assigning the result of an operation on a variable to that same variable"
+    "assigning the result of an operation on a variable to that same variable",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -55,7 +56,8 @@ declare_clippy_lint! {
     /// ```
     pub MISREFACTORED_ASSIGN_OP,
     complexity,
-    "having a variable on both sides of an assign op"
+    "having a variable on both sides of an assign op",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(AssignOps => [ASSIGN_OP_PATTERN, MISREFACTORED_ASSIGN_OP]);
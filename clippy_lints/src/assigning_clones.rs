@@ -0,0 +1,282 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::visitors::LocalUsedVisitor;
+use crate::utils::{in_macro, is_copy, match_def_path, meets_msrv, msrvs, paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{self as hir, Expr, ExprKind, HirId, Node, QPath, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Instance};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::def_id::DefId;
+use rustc_span::symbol::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code like `foo = bar.clone();`
+    ///
+    /// **Why is this bad?** Custom `Clone::clone_from()` or `ToOwned::clone_into` implementations
+    /// allow the objects to share resources and therefore avoid allocations.
+    ///
+    /// **Known problems:** Only types whose `Clone` (or `ToOwned`) implementation overrides
+    /// `clone_from` (or `clone_into`) are linted, since otherwise there is nothing to gain.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Thing;
+    ///
+    /// impl Clone for Thing {
+    ///     fn clone(&self) -> Self { todo!() }
+    ///     fn clone_from(&mut self, other: &Self) { todo!() }
+    /// }
+    ///
+    /// pub fn assign_to_ref(a: &mut Thing, b: Thing) {
+    ///     *a = b.clone();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Thing;
+    ///
+    /// impl Clone for Thing {
+    ///     fn clone(&self) -> Self { todo!() }
+    ///     fn clone_from(&mut self, other: &Self) { todo!() }
+    /// }
+    ///
+    /// pub fn assign_to_ref(a: &mut Thing, b: Thing) {
+    ///     a.clone_from(&b);
+    /// }
+    /// ```
+    pub ASSIGNING_CLONES,
+    perf,
+    "assigning the result of cloning may be inefficient",
+    applicability: [MachineApplicable]
+}
+
+pub struct AssigningClones {
+    msrv: Option<RustcVersion>,
+}
+
+impl AssigningClones {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(AssigningClones => [ASSIGNING_CLONES]);
+
+#[derive(Clone, Copy, PartialEq)]
+enum CloneTrait {
+    Clone,
+    ToOwned,
+}
+
+/// A call to `clone` or `to_owned`, either as a method call or as a function call.
+struct CallCandidate<'tcx> {
+    trait_: CloneTrait,
+    /// The value that is cloned.
+    receiver: &'tcx Expr<'tcx>,
+    /// Whether the receiver already is a reference, i.e. it is the argument of a function call or
+    /// it didn't need to be auto-borrowed in the method call.
+    receiver_is_ref: bool,
+    method_def_id: DefId,
+    call_expr: &'tcx Expr<'tcx>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for AssigningClones {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, assign_expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            // Do not fire the lint in macros
+            if !in_macro(assign_expr.span);
+            if let ExprKind::Assign(lhs, rhs, _) = assign_expr.kind;
+            if let Some(call) = extract_call(cx, rhs);
+            if is_ok_to_suggest(cx, lhs, &call, self.msrv.as_ref());
+            then {
+                suggest(cx, assign_expr, lhs, &call);
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+fn extract_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<CallCandidate<'tcx>> {
+    let (method_def_id, receiver, receiver_is_ref) = match expr.kind {
+        ExprKind::MethodCall(_, _, [receiver], _) => {
+            let method_def_id = cx.typeck_results().type_dependent_def_id(expr.hir_id)?;
+            let receiver_ty = cx.typeck_results().expr_ty(receiver);
+            let receiver_is_ref = receiver_ty == cx.typeck_results().expr_ty_adjusted(receiver);
+            (method_def_id, receiver, receiver_is_ref)
+        },
+        ExprKind::Call(func, [arg]) => {
+            let method_def_id = if let ExprKind::Path(ref qpath) = func.kind {
+                cx.qpath_res(qpath, func.hir_id).opt_def_id()?
+            } else {
+                return None;
+            };
+            (method_def_id, arg, true)
+        },
+        _ => return None,
+    };
+
+    let trait_ = if match_def_path(cx, method_def_id, &paths::CLONE_TRAIT_METHOD) {
+        CloneTrait::Clone
+    } else if match_def_path(cx, method_def_id, &paths::TO_OWNED_METHOD) {
+        CloneTrait::ToOwned
+    } else {
+        return None;
+    };
+
+    Some(CallCandidate {
+        trait_,
+        receiver,
+        receiver_is_ref,
+        method_def_id,
+        call_expr: expr,
+    })
+}
+
+fn is_ok_to_suggest<'tcx>(
+    cx: &LateContext<'tcx>,
+    lhs: &Expr<'tcx>,
+    call: &CallCandidate<'tcx>,
+    msrv: Option<&RustcVersion>,
+) -> bool {
+    if call.trait_ == CloneTrait::ToOwned && !meets_msrv(msrv, &msrvs::CLONE_INTO) {
+        return false;
+    }
+
+    // Copying is just as cheap as cloning
+    let ty = cx.typeck_results().expr_ty(lhs);
+    if is_copy(cx, ty) || ty != cx.typeck_results().expr_ty(call.call_expr) {
+        return false;
+    }
+
+    // Don't suggest this inside of the `clone_from`/`clone_into` implementations themselves, it
+    // would result in infinite recursion
+    let parent_item = cx.tcx.hir().get_parent_item(call.call_expr.hir_id);
+    if let Node::ImplItem(item) = cx.tcx.hir().get(parent_item) {
+        if [sym::clone_from, sym!(clone_into)].contains(&item.ident.name) {
+            return false;
+        }
+    }
+
+    if let Some(local_id) = path_to_local(root_of(lhs)) {
+        // `let x; x = y.clone();` can't be replaced with `x.clone_from(&y)`, as `x` isn't
+        // initialized yet
+        if let ExprKind::Path(_) = lhs.kind {
+            if let Some(Node::Local(local)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(local_id)) {
+                if local.init.is_none() {
+                    return false;
+                }
+            }
+        }
+        // `x = x.field.clone()` would need to borrow `x` both mutably and immutably
+        if LocalUsedVisitor::new(local_id).check_expr(call.receiver) {
+            return false;
+        }
+    }
+
+    let override_name = match call.trait_ {
+        CloneTrait::Clone => sym::clone_from,
+        CloneTrait::ToOwned => sym!(clone_into),
+    };
+    let substs = if let ExprKind::Call(func, _) = call.call_expr.kind {
+        cx.typeck_results().node_substs(func.hir_id)
+    } else {
+        cx.typeck_results().node_substs(call.call_expr.hir_id)
+    };
+    overrides_method(cx, call.method_def_id, substs, override_name)
+}
+
+/// Checks whether the impl that the given trait method resolves to also overrides the method
+/// `name`, e.g. whether the `Clone` impl of the type provides its own `clone_from`.
+fn overrides_method<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_method: DefId,
+    substs: ty::subst::SubstsRef<'tcx>,
+    name: Symbol,
+) -> bool {
+    let instance = match Instance::resolve(cx.tcx, cx.param_env, trait_method, substs) {
+        Ok(Some(instance)) => instance,
+        _ => return false,
+    };
+    let impl_def_id = match cx.tcx.impl_of_method(instance.def_id()) {
+        Some(impl_def_id) => impl_def_id,
+        None => return false,
+    };
+    cx.tcx
+        .associated_items(impl_def_id)
+        .filter_by_name_unhygienic(name)
+        .next()
+        .is_some()
+}
+
+fn path_to_local(expr: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+        if let Res::Local(id) = path.res {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// Peels field accesses, indexing and dereferences from the assigned place, e.g. `a` for
+/// `*a.b[0]`.
+fn root_of<'a, 'tcx>(mut expr: &'a Expr<'tcx>) -> &'a Expr<'tcx> {
+    loop {
+        match expr.kind {
+            ExprKind::Field(base, _) | ExprKind::Index(base, _) | ExprKind::Unary(UnOp::UnDeref, base) => {
+                expr = base;
+            },
+            _ => return expr,
+        }
+    }
+}
+
+fn suggest<'tcx>(cx: &LateContext<'tcx>, assign_expr: &Expr<'tcx>, lhs: &Expr<'tcx>, call: &CallCandidate<'tcx>) {
+    let (method, msg) = match call.trait_ {
+        CloneTrait::Clone => (
+            "clone_from",
+            "assigning the result of `Clone::clone()` may be inefficient",
+        ),
+        CloneTrait::ToOwned => (
+            "clone_into",
+            "assigning the result of `ToOwned::to_owned()` may be inefficient",
+        ),
+    };
+    span_lint_and_then(cx, ASSIGNING_CLONES, assign_expr.span, msg, |diag| {
+        let mut applicability = Applicability::MachineApplicable;
+        // `*lhs = ...` can use `lhs` directly, as it already is a reference
+        let deref_lhs = if let ExprKind::Unary(UnOp::UnDeref, ref_expr) = lhs.kind {
+            if let ty::Ref(_, _, hir::Mutability::Mut) = cx.typeck_results().expr_ty(ref_expr).kind() {
+                Some(ref_expr)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        let receiver = Sugg::hir_with_applicability(cx, call.receiver, "_", &mut applicability);
+        let sugg = match call.trait_ {
+            CloneTrait::Clone => {
+                let dest = Sugg::hir_with_applicability(cx, deref_lhs.unwrap_or(lhs), "_", &mut applicability);
+                let arg = if call.receiver_is_ref {
+                    receiver
+                } else {
+                    receiver.addr()
+                };
+                format!("{}.{}({})", dest.maybe_par(), method, arg)
+            },
+            CloneTrait::ToOwned => {
+                let dest = match deref_lhs {
+                    Some(ref_expr) => Sugg::hir_with_applicability(cx, ref_expr, "_", &mut applicability),
+                    None => Sugg::hir_with_applicability(cx, lhs, "_", &mut applicability).mut_addr(),
+                };
+                format!("{}.{}({})", receiver.maybe_par(), method, dest)
+            },
+        };
+        diag.span_suggestion(assign_expr.span, &format!("use `{}()`", method), sugg, applicability);
+    });
+}
@@ -2,7 +2,7 @@ use crate::utils::{implements_trait, snippet, span_lint_and_then};
 use rustc_errors::Applicability;
 use rustc_hir::{AsyncGeneratorKind, Body, BodyId, ExprKind, GeneratorKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for async blocks that yield values of types
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub ASYNC_YIELDS_ASYNC,
     correctness,
-    "async blocks that return a type that can be awaited"
+    "async blocks that return a type that can be awaited",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(AsyncYieldsAsync => [ASYNC_YIELDS_ASYNC]);
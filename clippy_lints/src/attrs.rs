@@ -1,11 +1,14 @@
 //! checks for attributes
 
 use crate::utils::{
-    first_line_of_span, is_present_in_source, match_panic_def_id, snippet_opt, span_lint, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then, without_block_comments,
+    first_line_of_span, is_present_in_source, match_panic_def_id, snippet_opt, snippet_with_applicability, span_lint,
+    span_lint_and_help, span_lint_and_sugg, span_lint_and_then, without_block_comments,
 };
 use if_chain::if_chain;
-use rustc_ast::{AttrKind, AttrStyle, Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem};
+use rustc_ast::{AttrKind, AttrStyle, Attribute, Lit, LitKind, MacCall, MetaItemKind, NestedMetaItem};
+use rustc_ast_pretty::pprust;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync::Lrc;
 use rustc_errors::Applicability;
 use rustc_hir::{
     Block, Expr, ExprKind, ImplItem, ImplItemKind, Item, ItemKind, StmtKind, TraitFn, TraitItem, TraitItemKind,
@@ -13,7 +16,7 @@ use rustc_hir::{
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 use rustc_span::symbol::{Symbol, SymbolStr};
@@ -98,7 +101,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_ATTRIBUTE,
     correctness,
-    "use of lint attributes on `extern crate` items"
+    "use of lint attributes on `extern crate` items",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -204,7 +208,70 @@ declare_clippy_lint! {
     /// ```
     pub DEPRECATED_CFG_ATTR,
     complexity,
-    "usage of `cfg_attr(rustfmt)` instead of tool attributes"
+    "usage of `cfg_attr(rustfmt)` instead of tool attributes",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[cfg_attr(clippy, allow(clippy::lint))]` (or the
+    /// `feature = "cargo-clippy"` form) and suggests to replace it with
+    /// `#[allow(clippy::lint)]`. It also checks for code that is only compiled when running
+    /// clippy, i.e. `#[cfg(clippy)]` and `cfg!(clippy)`.
+    ///
+    /// **Why is this bad?** The compiler knows about the `clippy` tool namespace, so lint
+    /// attributes for clippy lints work in normal builds without being gated behind a `cfg`.
+    /// Gating code itself behind the `clippy` cfg means clippy checks different code than what
+    /// is actually built, so its results silently diverge from normal builds.
+    ///
+    /// **Known problems:** This lint doesn't detect crate level inner attributes, because they get
+    /// processed before the PreExpansionPass lints get executed.
+    ///
+    /// **Example:**
+    ///
+    /// Bad:
+    /// ```rust
+    /// #[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_return))]
+    /// fn main() { }
+    /// ```
+    ///
+    /// Good:
+    /// ```rust
+    /// #[allow(clippy::needless_return)]
+    /// fn main() { }
+    /// ```
+    pub DEPRECATED_CLIPPY_CFG_ATTR,
+    complexity,
+    "usage of `cfg_attr(clippy)` for lint attributes or `cfg(clippy)` for code",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for items that have the same kind of attributes with mixed styles
+    /// (inner/outer).
+    ///
+    /// **Why is this bad?** Having both style of said attributes makes it more complicated to read
+    /// code. Mixed doc comments are especially confusing, as the inner ones are easily mistaken
+    /// for documentation of the enclosing scope.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[cfg(linux)]
+    /// pub fn foo() {
+    ///     #![cfg(windows)]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[cfg(linux)]
+    /// #[cfg(windows)]
+    /// pub fn foo() {
+    /// }
+    /// ```
+    pub MIXED_ATTRIBUTES_STYLE,
+    style,
+    "item has both inner and outer attributes"
 }
 
 declare_clippy_lint! {
@@ -237,14 +304,59 @@ declare_clippy_lint! {
     /// Check the [Rust Reference](https://doc.rust-lang.org/reference/conditional-compilation.html#target_os) for more details.
     pub MISMATCHED_TARGET_OS,
     correctness,
-    "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`"
+    "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`",
+    applicability: [MaybeIncorrect]
 }
 
-declare_lint_pass!(Attributes => [
+declare_clippy_lint! {
+    /// **What it does:** Checks for `#[allow(clippy::...)]` attributes which neither give a
+    /// `reason = "..."` nor are accompanied by a comment, on the line above or at the end of
+    /// the line, justifying them. This lint only fires when `require-allow-reason = true` is set
+    /// in the configuration.
+    ///
+    /// **Why is this bad?** Without a justification, it's hard to tell later whether a
+    /// suppressed lint is still a false positive or whether the code should be fixed instead.
+    ///
+    /// **Known problems:** Any adjacent comment is accepted as a justification.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// #[allow(clippy::needless_range_loop)]
+    /// fn foo() {}
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[allow(clippy::needless_range_loop, reason = "the index is used for the output")]
+    /// fn foo() {}
+    ///
+    /// // The index is used for the output.
+    /// #[allow(clippy::needless_range_loop)]
+    /// fn bar() {}
+    /// ```
+    pub ALLOW_ATTRIBUTES_WITHOUT_REASON,
+    style,
+    "`#[allow(clippy::...)]` attributes without a reason or a justification comment"
+}
+
+pub struct Attributes {
+    require_allow_reason: bool,
+}
+
+impl Attributes {
+    #[must_use]
+    pub fn new(require_allow_reason: bool) -> Self {
+        Self { require_allow_reason }
+    }
+}
+
+impl_lint_pass!(Attributes => [
     INLINE_ALWAYS,
     DEPRECATED_SEMVER,
     USELESS_ATTRIBUTE,
     BLANKET_CLIPPY_RESTRICTION_LINTS,
+    ALLOW_ATTRIBUTES_WITHOUT_REASON,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Attributes {
@@ -255,6 +367,9 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
                 match ident {
                     "allow" | "warn" | "deny" | "forbid" => {
                         check_clippy_lint_names(cx, ident, items);
+                        if self.require_allow_reason && ident == "allow" {
+                            check_allow_reason(cx, attr, items);
+                        }
                     },
                     _ => {},
                 }
@@ -365,7 +480,7 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
 }
 
 /// Returns the lint name if it is clippy lint.
-fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<SymbolStr> {
+pub(crate) fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<SymbolStr> {
     if_chain! {
         if let Some(meta_item) = lint.meta_item();
         if meta_item.path.segments.len() > 1;
@@ -379,6 +494,44 @@ fn extract_clippy_lint(lint: &NestedMetaItem) -> Option<SymbolStr> {
     None
 }
 
+fn check_allow_reason(cx: &LateContext<'_>, attr: &Attribute, items: &[NestedMetaItem]) {
+    if in_external_macro(cx.sess(), attr.span)
+        || !items.iter().any(|item| extract_clippy_lint(item).is_some())
+        || items.iter().any(|item| item.has_name(sym::reason))
+        || has_justification_comment(cx, attr.span)
+    {
+        return;
+    }
+    span_lint_and_help(
+        cx,
+        ALLOW_ATTRIBUTES_WITHOUT_REASON,
+        attr.span,
+        "allowing a clippy lint without giving a reason",
+        None,
+        "add a `reason = \"...\"` to the attribute or a comment explaining why the lint is allowed",
+    );
+}
+
+/// Checks whether a `//` comment is on the line above `span` or at the end of its last line.
+fn has_justification_comment(cx: &LateContext<'_>, span: Span) -> bool {
+    let is_comment = |line: &str| {
+        let line = line.trim_start();
+        line.starts_with("//") && !line.starts_with("///") && !line.starts_with("//!")
+    };
+    let source_map = cx.sess().source_map();
+    let start = source_map.lookup_char_pos(span.lo());
+    let end = source_map.lookup_char_pos(span.hi());
+    let above = start.line > 1
+        && start
+            .file
+            .get_line(start.line - 2)
+            .map_or(false, |line| is_comment(&line));
+    let trailing = end.file.get_line(end.line - 1).map_or(false, |line| {
+        is_comment(&line.chars().skip(end.col.0).collect::<String>())
+    });
+    above || trailing
+}
+
 fn check_clippy_lint_names(cx: &LateContext<'_>, ident: &str, items: &[NestedMetaItem]) {
     for lint in items {
         if let Some(lint_name) = extract_clippy_lint(lint) {
@@ -503,19 +656,40 @@ fn is_word(nmi: &NestedMetaItem, expected: Symbol) -> bool {
 
 declare_lint_pass!(EarlyAttributes => [
     DEPRECATED_CFG_ATTR,
+    DEPRECATED_CLIPPY_CFG_ATTR,
     MISMATCHED_TARGET_OS,
     EMPTY_LINE_AFTER_OUTER_ATTR,
+    MIXED_ATTRIBUTES_STYLE,
 ]);
 
 impl EarlyLintPass for EarlyAttributes {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
         check_empty_line_after_outer_attr(cx, item);
+        check_mixed_attributes_style(cx, item);
     }
 
     fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
         check_deprecated_cfg_attr(cx, attr);
+        check_deprecated_clippy_cfg_attr(cx, attr);
         check_mismatched_target_os(cx, attr);
     }
+
+    fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &MacCall) {
+        if_chain! {
+            if mac.path == sym::cfg;
+            if is_clippy_cfg_tokens(&pprust::tts_to_string(&mac.args.inner_tokens()));
+            then {
+                span_lint_and_help(
+                    cx,
+                    DEPRECATED_CLIPPY_CFG_ATTR,
+                    mac.span(),
+                    "`cfg!(clippy)` makes the code behave differently when running clippy",
+                    None,
+                    "clippy will check code that diverges from normal builds, consider using lint attributes instead",
+                );
+            }
+        }
+    }
 }
 
 fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
@@ -552,6 +726,59 @@ fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::It
     }
 }
 
+/// The kind of an attribute, as far as `mixed_attributes_style` is concerned.
+#[derive(Hash, PartialEq, Eq)]
+enum SimpleAttrKind {
+    Doc,
+    /// The path of a normal attribute, e.g. `cfg` or `clippy::msrv`.
+    Normal(Vec<Symbol>),
+}
+
+impl From<&Attribute> for SimpleAttrKind {
+    fn from(attr: &Attribute) -> Self {
+        match attr.kind {
+            AttrKind::Normal(ref item, _) if !attr.is_doc_comment() && !attr.has_name(sym::doc) => {
+                Self::Normal(item.path.segments.iter().map(|segment| segment.ident.name).collect())
+            },
+            _ => Self::Doc,
+        }
+    }
+}
+
+fn check_mixed_attributes_style(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
+    let mut inner_attr_kind: FxHashSet<SimpleAttrKind> = FxHashSet::default();
+    let mut outer_attr_kind: FxHashSet<SimpleAttrKind> = FxHashSet::default();
+    let source_map = cx.sess.source_map();
+    let item_file = source_map.lookup_source_file(item.span.lo());
+
+    // only check the attributes written in the same file as the item, inner attributes of
+    // out-of-line modules are in another file
+    let attrs: Vec<&Attribute> = item
+        .attrs
+        .iter()
+        .filter(|attr| {
+            !attr.span.from_expansion() && Lrc::ptr_eq(&source_map.lookup_source_file(attr.span.lo()), &item_file)
+        })
+        .collect();
+    for attr in &attrs {
+        match attr.style {
+            AttrStyle::Inner => inner_attr_kind.insert(SimpleAttrKind::from(*attr)),
+            AttrStyle::Outer => outer_attr_kind.insert(SimpleAttrKind::from(*attr)),
+        };
+    }
+
+    if inner_attr_kind.iter().any(|kind| outer_attr_kind.contains(kind)) {
+        if let (Some(first), Some(last)) = (attrs.first(), attrs.last()) {
+            span_lint(
+                cx,
+                MIXED_ATTRIBUTES_STYLE,
+                first.span.with_hi(last.span.hi()),
+                "item has both inner and outer attributes",
+            );
+        }
+    }
+}
+
 fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute) {
     if_chain! {
         // check cfg_attr
@@ -582,6 +809,93 @@ fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute) {
     }
 }
 
+fn check_deprecated_clippy_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute) {
+    let items = match attr.meta_item_list() {
+        Some(items) if !items.is_empty() => items,
+        _ => return,
+    };
+
+    if attr.has_name(sym::cfg_attr) {
+        if_chain! {
+            if items.len() >= 2;
+            if is_clippy_cfg(&items[0]);
+            if items[1..].iter().all(is_clippy_lint_level_attr);
+            then {
+                let bang = if let AttrStyle::Inner = attr.style { "!" } else { "" };
+                let mut applicability = Applicability::MachineApplicable;
+                let sugg = items[1..]
+                    .iter()
+                    .map(|item| format!("#{}[{}]", bang, snippet_with_applicability(cx, item.span(), "..", &mut applicability)))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                span_lint_and_sugg(
+                    cx,
+                    DEPRECATED_CLIPPY_CFG_ATTR,
+                    attr.span,
+                    "`cfg_attr(clippy)` is unnecessary for clippy lint attributes",
+                    "use the lint attribute directly",
+                    sugg,
+                    applicability,
+                );
+            }
+        }
+    } else if attr.has_name(sym::cfg) && mentions_clippy_cfg(&items[0]) {
+        span_lint_and_help(
+            cx,
+            DEPRECATED_CLIPPY_CFG_ATTR,
+            attr.span,
+            "code gated behind the `clippy` cfg differs between clippy and normal builds",
+            None,
+            "clippy will check code that diverges from normal builds, consider using lint attributes instead",
+        );
+    }
+}
+
+/// Checks whether the `cfg` predicate is `clippy` or `feature = "cargo-clippy"`.
+fn is_clippy_cfg(item: &NestedMetaItem) -> bool {
+    if item.is_word() {
+        item.has_name(sym::clippy)
+    } else {
+        item.has_name(sym::feature) && item.value_str().map_or(false, |value| value.as_str() == "cargo-clippy")
+    }
+}
+
+/// Checks whether the `cfg` predicate depends on the `clippy` cfg, also inside of `all`, `any`
+/// and `not`.
+fn mentions_clippy_cfg(item: &NestedMetaItem) -> bool {
+    if is_clippy_cfg(item) {
+        return true;
+    }
+    item.meta_item_list()
+        .map_or(false, |items| items.iter().any(mentions_clippy_cfg))
+}
+
+/// Checks for `allow`, `warn`, `deny` or `forbid` attributes that only refer to clippy lints.
+fn is_clippy_lint_level_attr(item: &NestedMetaItem) -> bool {
+    if_chain! {
+        if let Some(ident) = item.ident();
+        if matches!(&*ident.as_str(), "allow" | "warn" | "deny" | "forbid");
+        if let Some(lints) = item.meta_item_list();
+        if !lints.is_empty();
+        then {
+            lints.iter().all(|lint| {
+                lint.meta_item().map_or(false, |lint| {
+                    lint.is_word()
+                        && lint.path.segments.len() == 2
+                        && lint.path.segments[0].ident.name == sym::clippy
+                })
+            })
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks whether the tokens of a `cfg!` invocation are `clippy` or `feature = "cargo-clippy"`.
+fn is_clippy_cfg_tokens(tokens: &str) -> bool {
+    tokens == "clippy" || tokens.split_whitespace().collect::<String>() == "feature=\"cargo-clippy\""
+}
+
 fn check_mismatched_target_os(cx: &EarlyContext<'_>, attr: &Attribute) {
     fn find_os(name: &str) -> Option<&'static str> {
         UNIX_SYSTEMS
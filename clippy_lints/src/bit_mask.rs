@@ -6,7 +6,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -91,7 +91,8 @@ declare_clippy_lint! {
     /// ```
     pub VERBOSE_BIT_MASK,
     pedantic,
-    "expressions where a bit mask is less readable than the corresponding method call"
+    "expressions where a bit mask is less readable than the corresponding method call",
+    applicability: [MaybeIncorrect]
 }
 
 #[derive(Copy, Clone)]
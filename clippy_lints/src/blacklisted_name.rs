@@ -1,8 +1,8 @@
+use crate::utils::conf::NameList;
 use crate::utils::span_lint;
-use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::{Pat, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of blacklisted names for variables, such
@@ -24,11 +24,11 @@ declare_clippy_lint! {
 
 #[derive(Clone, Debug)]
 pub struct BlacklistedName {
-    blacklist: FxHashSet<String>,
+    blacklist: NameList,
 }
 
 impl BlacklistedName {
-    pub fn new(blacklist: FxHashSet<String>) -> Self {
+    pub fn new(blacklist: NameList) -> Self {
         Self { blacklist }
     }
 }
@@ -38,7 +38,7 @@ impl_lint_pass!(BlacklistedName => [BLACKLISTED_NAME]);
 impl<'tcx> LateLintPass<'tcx> for BlacklistedName {
     fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
         if let PatKind::Binding(.., ident, _) = pat.kind {
-            if self.blacklist.contains(&ident.name.to_string()) {
+            if self.blacklist.contains(&ident.name.as_str()) {
                 span_lint(
                     cx,
                     BLACKLISTED_NAME,
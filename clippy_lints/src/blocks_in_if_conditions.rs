@@ -5,7 +5,7 @@ use rustc_hir::{BlockCheckMode, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `if` conditions that use blocks containing an
@@ -37,7 +37,8 @@ declare_clippy_lint! {
     /// ```
     pub BLOCKS_IN_IF_CONDITIONS,
     style,
-    "useless or complex blocks that can be eliminated in conditions"
+    "useless or complex blocks that can be eliminated in conditions",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(BlocksInIfConditions => [BLOCKS_IN_IF_CONDITIONS]);
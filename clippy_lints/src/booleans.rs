@@ -9,7 +9,7 @@ use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{BinOpKind, Body, Expr, ExprKind, FnDecl, HirId, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 
@@ -30,7 +30,8 @@ declare_clippy_lint! {
     /// ```
     pub NONMINIMAL_BOOL,
     complexity,
-    "boolean expressions that can be written more concisely"
+    "boolean expressions that can be written more concisely",
+    applicability: [MachineApplicable, Unspecified]
 }
 
 declare_clippy_lint! {
@@ -48,7 +49,8 @@ declare_clippy_lint! {
     /// The `b` is unnecessary, the expression is equivalent to `if a`.
     pub LOGIC_BUG,
     correctness,
-    "boolean expressions that contain terminals which can be eliminated"
+    "boolean expressions that contain terminals which can be eliminated",
+    applicability: [Unspecified]
 }
 
 // For each pairs, both orders are considered.
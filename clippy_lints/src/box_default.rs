@@ -0,0 +1,82 @@
+use crate::utils::{last_path_segment, match_def_path, paths, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Local, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Box::new(T::default())`, which is better written as
+    /// `Box::<T>::default()`.
+    ///
+    /// **Why is this bad?** First, it's more complex, involving two calls instead of one.
+    /// Second, `Box::default()` can be faster
+    /// [in certain cases](https://nnethercote.github.io/perf-book/standard-library-types.html#box).
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: Box<String> = Box::new(Default::default());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x: Box<String> = Box::default();
+    /// ```
+    pub BOX_DEFAULT,
+    style,
+    "Using Box::new(T::default()) instead of Box::default()",
+    applicability: [MachineApplicable]
+}
+
+declare_lint_pass!(BoxDefault => [BOX_DEFAULT]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxDefault {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Call(func, [arg]) = expr.kind;
+            if let ExprKind::Path(ref func_path @ QPath::TypeRelative(..)) = func.kind;
+            if last_path_segment(func_path).ident.name == sym::new;
+            if cx.typeck_results().expr_ty(expr).is_box();
+            if !arg.span.from_expansion();
+            if let ExprKind::Call(arg_func, []) = arg.kind;
+            if let ExprKind::Path(ref arg_path) = arg_func.kind;
+            if let Some(def_id) = cx.qpath_res(arg_path, arg_func.hir_id).opt_def_id();
+            if match_def_path(cx, def_id, &paths::DEFAULT_TRAIT_METHOD)
+                || match_def_path(cx, def_id, &paths::VEC_NEW)
+                || match_def_path(cx, def_id, &paths::STRING_NEW);
+            then {
+                // keep type inference working if the type isn't given elsewhere
+                let sugg = if is_plain_default(arg_path) || has_given_type(cx, expr) {
+                    "Box::default()".to_string()
+                } else {
+                    format!("Box::<{}>::default()", cx.typeck_results().expr_ty(arg))
+                };
+                span_lint_and_sugg(
+                    cx,
+                    BOX_DEFAULT,
+                    expr.span,
+                    "`Box::new(_)` of default value",
+                    "try",
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the path is `Default::default`, which doesn't name the type either.
+fn is_plain_default(path: &QPath<'_>) -> bool {
+    matches!(path, QPath::Resolved(None, _))
+}
+
+/// Checks if the expression initializes a local with an explicit type.
+fn has_given_type(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(
+        cx.tcx.hir().find(cx.tcx.hir().get_parent_node(expr.hir_id)),
+        Some(Node::Local(Local { ty: Some(_), .. }))
+    )
+}
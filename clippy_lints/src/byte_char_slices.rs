@@ -0,0 +1,76 @@
+use crate::utils::{in_macro, span_lint_and_sugg};
+use rustc_ast::ast::{BorrowKind, Expr, ExprKind, LitKind, Mutability};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for hard to read slices of byte characters, that could be more
+    /// efficiently expressed as a bytes string.
+    ///
+    /// **Why is this bad?** Potentially makes the string harder to read. A byte string literal
+    /// is also guaranteed to be contiguous and is shorter to write.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bs = &[b'a', b'b', b'c'];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bs = b"abc";
+    /// ```
+    pub BYTE_CHAR_SLICES,
+    style,
+    "hard to read byte char slice",
+    applicability: [MachineApplicable]
+}
+
+declare_lint_pass!(ByteCharSlice => [BYTE_CHAR_SLICES]);
+
+impl EarlyLintPass for ByteCharSlice {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let Some(slice) = is_byte_char_slices(expr) {
+            span_lint_and_sugg(
+                cx,
+                BYTE_CHAR_SLICES,
+                expr.span,
+                "can be more succinctly written as a byte str",
+                "try",
+                format!("b\"{}\"", slice),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Returns the contents of the byte string literal if the expression is a borrowed array of byte
+/// literals, e.g. `&[b'a', b'b']`.
+fn is_byte_char_slices(expr: &Expr) -> Option<String> {
+    if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, expr) = &expr.kind {
+        if let ExprKind::Array(members) = &expr.kind {
+            if members.is_empty() {
+                return None;
+            }
+            return members
+                .iter()
+                .map(|member| match &member.kind {
+                    ExprKind::Lit(lit) if matches!(lit.kind, LitKind::Byte(_)) => {
+                        // `'` doesn't need to be escaped in a byte string, but `"` does
+                        match &*lit.token.symbol.as_str() {
+                            "\\'" => Some("'".to_string()),
+                            "\"" => Some("\\\"".to_string()),
+                            symbol => Some(symbol.to_string()),
+                        }
+                    },
+                    _ => None,
+                })
+                .collect();
+        }
+    }
+    None
+}
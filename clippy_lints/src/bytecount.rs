@@ -6,7 +6,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, UintTy};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 use rustc_span::Symbol;
 
@@ -29,7 +29,8 @@ declare_clippy_lint! {
     /// ```
     pub NAIVE_BYTECOUNT,
     perf,
-    "use of naive `<slice>.filter(|&x| x == y).count()` to count byte values"
+    "use of naive `<slice>.filter(|&x| x == y).count()` to count byte values",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(ByteCount => [NAIVE_BYTECOUNT]);
@@ -5,11 +5,11 @@ use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::{sym, BytePos};
 
-use crate::utils::{is_type_diagnostic_item, snippet_opt, span_lint_and_help, LimitStack};
+use crate::utils::{is_in_test_context, is_type_diagnostic_item, snippet_opt, span_lint_and_help, LimitStack};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for methods with high cognitive complexity.
@@ -28,13 +28,15 @@ declare_clippy_lint! {
 
 pub struct CognitiveComplexity {
     limit: LimitStack,
+    test_limit: Option<u64>,
 }
 
 impl CognitiveComplexity {
     #[must_use]
-    pub fn new(limit: u64) -> Self {
+    pub fn new(limit: u64, test_limit: Option<u64>) -> Self {
         Self {
             limit: LimitStack::new(limit),
+            test_limit,
         }
     }
 }
@@ -50,11 +52,17 @@ impl CognitiveComplexity {
         decl: &'tcx FnDecl<'_>,
         body: &'tcx Body<'_>,
         body_span: Span,
+        hir_id: HirId,
     ) {
         if body_span.from_expansion() {
             return;
         }
 
+        // the threshold of the `[test-overrides]` table applies to test code
+        let limit = self
+            .limit
+            .limit_or(self.test_limit.filter(|_| is_in_test_context(cx, hir_id)));
+
         let expr = &body.value;
 
         let mut helper = CcHelper { cc: 1, returns: 0 };
@@ -74,7 +82,7 @@ impl CognitiveComplexity {
             rust_cc -= ret_adjust;
         }
 
-        if rust_cc > self.limit.limit() {
+        if rust_cc > limit {
             let fn_span = match kind {
                 FnKind::ItemFn(ident, _, _, _, _) | FnKind::Method(ident, _, _, _) => ident.span,
                 FnKind::Closure(_) => {
@@ -100,11 +108,7 @@ impl CognitiveComplexity {
                 cx,
                 COGNITIVE_COMPLEXITY,
                 fn_span,
-                &format!(
-                    "the function has a cognitive complexity of ({}/{})",
-                    rust_cc,
-                    self.limit.limit()
-                ),
+                &format!("the function has a cognitive complexity of ({}/{})", rust_cc, limit),
                 None,
                 "you could split it up into multiple smaller functions",
             );
@@ -124,7 +128,7 @@ impl<'tcx> LateLintPass<'tcx> for CognitiveComplexity {
     ) {
         let def_id = cx.tcx.hir().local_def_id(hir_id);
         if !cx.tcx.has_attr(def_id.to_def_id(), sym::test) {
-            self.check(cx, kind, decl, body, span);
+            self.check(cx, kind, decl, body, span, hir_id);
         }
     }
 
@@ -15,7 +15,7 @@
 use if_chain::if_chain;
 use rustc_ast::ast;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 use crate::utils::sugg::Sugg;
 use crate::utils::{snippet_block, snippet_block_with_applicability, span_lint_and_sugg, span_lint_and_then};
@@ -49,7 +49,8 @@ declare_clippy_lint! {
     /// ```
     pub COLLAPSIBLE_IF,
     style,
-    "nested `if`s that can be collapsed (e.g., `if x { if y { ... } }`"
+    "nested `if`s that can be collapsed (e.g., `if x { if y { ... } }`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -84,7 +85,8 @@ declare_clippy_lint! {
     /// ```
     pub COLLAPSIBLE_ELSE_IF,
     style,
-    "nested `else`-`if` expressions that can be collapsed (e.g., `else { if x { ... } }`)"
+    "nested `else`-`if` expressions that can be collapsed (e.g., `else { if x { ... } }`)",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(CollapsibleIf => [COLLAPSIBLE_IF, COLLAPSIBLE_ELSE_IF]);
@@ -2,7 +2,7 @@ use crate::utils::{eq_expr_value, in_macro, search_same, SpanlessEq, SpanlessHas
 use crate::utils::{get_parent_expr, if_sequence, span_lint_and_note};
 use rustc_hir::{Block, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for consecutive `if`s with the same condition.
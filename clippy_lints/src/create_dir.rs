@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks usage of `std::fs::create_dir` and suggest using `std::fs::create_dir_all` instead.
@@ -23,7 +23,8 @@ declare_clippy_lint! {
     /// ```
     pub CREATE_DIR,
     restriction,
-    "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`"
+    "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(CreateDir => [CREATE_DIR]);
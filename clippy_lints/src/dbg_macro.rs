@@ -3,7 +3,7 @@ use rustc_ast::ast;
 use rustc_ast::tokenstream::TokenStream;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -24,7 +24,8 @@ declare_clippy_lint! {
     /// ```
     pub DBG_MACRO,
     restriction,
-    "`dbg!` macro is intended as a debugging tool"
+    "`dbg!` macro is intended as a debugging tool",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(DbgMacro => [DBG_MACRO]);
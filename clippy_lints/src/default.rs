@@ -10,7 +10,7 @@ use rustc_hir::{Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::Span;
 
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub DEFAULT_TRAIT_ACCESS,
     pedantic,
-    "checks for literal calls to `Default::default()`"
+    "checks for literal calls to `Default::default()`",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -4,7 +4,7 @@ use rustc_ast::util::parser::{ExprPrecedence, PREC_POSTFIX, PREC_PREFIX};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPLICIT_DEREF_METHODS,
     pedantic,
-    "Explicit use of deref or deref_mut method while not in a method chain."
+    "Explicit use of deref or deref_mut method while not in a method chain.",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(Dereferencing => [
@@ -3,7 +3,7 @@ use crate::utils::span_lint;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::Symbol;
 
 declare_clippy_lint! {
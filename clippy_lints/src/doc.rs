@@ -1,25 +1,29 @@
-use crate::utils::{implements_trait, is_entrypoint_fn, is_type_diagnostic_item, return_ty, span_lint};
+use crate::utils::conf::NameList;
+use crate::utils::{
+    implements_trait, in_macro, is_entrypoint_fn, is_type_diagnostic_item, return_ty, snippet_opt, span_lint,
+    span_lint_and_help, span_lint_and_sugg, span_lint_and_then,
+};
 use if_chain::if_chain;
 use itertools::Itertools;
-use rustc_ast::ast::{Async, AttrKind, Attribute, FnRetTy, ItemKind};
+use rustc_ast::ast::{Async, AttrKind, AttrStyle, Attribute, FnRetTy, ItemKind, MacArgs};
 use rustc_ast::token::CommentKind;
-use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::emitter::EmitterWriter;
-use rustc_errors::Handler;
+use rustc_errors::{Applicability, Handler};
 use rustc_hir as hir;
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_parse::maybe_new_parser_from_source_str;
 use rustc_parse::parser::ForceCollect;
+use rustc_session::declare_tool_lint;
 use rustc_session::parse::ParseSess;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::edition::Edition;
 use rustc_span::source_map::{BytePos, FilePathMapping, MultiSpan, SourceMap, Span};
 use rustc_span::{sym, FileName, Pos};
-use std::io;
 use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
 use url::Url;
 
 declare_clippy_lint! {
@@ -150,15 +154,99 @@ declare_clippy_lint! {
     "presence of `fn main() {` in code examples"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Detects documentation that is empty.
+    ///
+    /// **Why is this bad?** Empty docs clutter code without adding value, and they still
+    /// satisfy `missing_docs` and similar lints without documenting anything.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// ///
+    /// fn returns_true() -> bool {
+    ///     true
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn returns_true() -> bool {
+    ///     true
+    /// }
+    /// ```
+    pub EMPTY_DOCS,
+    style,
+    "docstrings exist but documentation is empty"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** In CommonMark Markdown, the language used to write doc comments, a
+    /// paragraph nested within a list or block quote does not need any line after the first one
+    /// to be indented or marked. The specification calls this a "lazy paragraph continuation."
+    ///
+    /// **Why is this bad?** This is easy to write but hard to read. Lazy continuations make
+    /// unintended markdown easy to write, since a line that was meant to start a new paragraph
+    /// is silently rendered as part of the previous list item or quote.
+    ///
+    /// **Known problems:** List items nested inside block quotes are not checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// This is not lazy continuation
+    /// ///
+    /// /// - this is a list item
+    /// /// this is lazy continuation
+    /// fn foo() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// /// This is not lazy continuation
+    /// ///
+    /// /// - this is a list item
+    /// ///   this is not lazy continuation
+    /// fn foo() {}
+    /// ```
+    pub DOC_LAZY_CONTINUATION,
+    style,
+    "require every line of a paragraph to be indented and marked",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks if included files in doc comments are included only for
+    /// `cfg(doc)`.
+    ///
+    /// **Why is this bad?** These files are not useful for compilation but will still be
+    /// included, and every build of the crate depends on them. This is an issue when the file
+    /// is large or outside of the package, where it may not be available to every build.
+    ///
+    /// **Known problems:** Only files that are larger than 64 KiB or outside of the package
+    /// (`CARGO_MANIFEST_DIR`) are linted.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// #![doc = include_str!("some_file.md")]
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #![cfg_attr(doc, doc = include_str!("some_file.md"))]
+    /// ```
+    pub DOC_INCLUDE_WITHOUT_CFG,
+    restriction,
+    "check if files included in documentation are behind `cfg(doc)`",
+    applicability: [MachineApplicable]
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct DocMarkdown {
-    valid_idents: FxHashSet<String>,
+    valid_idents: NameList,
     in_trait_impl: bool,
 }
 
 impl DocMarkdown {
-    pub fn new(valid_idents: FxHashSet<String>) -> Self {
+    pub fn new(valid_idents: NameList) -> Self {
         Self {
             valid_idents,
             in_trait_impl: false,
@@ -166,7 +254,15 @@ impl DocMarkdown {
     }
 }
 
-impl_lint_pass!(DocMarkdown => [DOC_MARKDOWN, MISSING_SAFETY_DOC, MISSING_ERRORS_DOC, NEEDLESS_DOCTEST_MAIN]);
+impl_lint_pass!(DocMarkdown => [
+    DOC_MARKDOWN,
+    MISSING_SAFETY_DOC,
+    MISSING_ERRORS_DOC,
+    NEEDLESS_DOCTEST_MAIN,
+    EMPTY_DOCS,
+    DOC_LAZY_CONTINUATION,
+    DOC_INCLUDE_WITHOUT_CFG,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
     fn check_crate(&mut self, cx: &LateContext<'tcx>, krate: &'tcx hir::Crate<'_>) {
@@ -214,6 +310,14 @@ impl<'tcx> LateLintPass<'tcx> for DocMarkdown {
             lint_for_missing_headers(cx, item.hir_id, item.span, sig, headers, Some(body_id));
         }
     }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'tcx>, field: &'tcx hir::StructField<'_>) {
+        check_empty_docs(cx, &field.attrs);
+    }
+
+    fn check_variant(&mut self, cx: &LateContext<'tcx>, variant: &'tcx hir::Variant<'_>) {
+        check_empty_docs(cx, &variant.attrs);
+    }
 }
 
 fn lint_for_missing_headers<'tcx>(
@@ -323,7 +427,117 @@ struct DocHeaders {
     errors: bool,
 }
 
-fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &'a [Attribute]) -> DocHeaders {
+/// Lints doc comments and `#[doc = ".."]` attributes that are all empty or whitespace-only.
+/// Returns `true` if it linted.
+fn check_empty_docs(cx: &LateContext<'_>, attrs: &[Attribute]) -> bool {
+    let mut doc_attrs = attrs.iter().filter_map(|attr| {
+        let content = if let AttrKind::DocComment(comment_kind, comment) = attr.kind {
+            strip_doc_comment_decoration(&comment.as_str(), comment_kind, attr.span).0
+        } else if attr.has_name(sym::doc) {
+            // e.g. `#[doc(hidden)]` doesn't contain any documentation
+            attr.value_str()?.as_str().to_string()
+        } else {
+            return None;
+        };
+        Some((attr.span, content))
+    });
+
+    let (first_span, content) = match doc_attrs.next() {
+        Some(first) => first,
+        None => return false,
+    };
+    let mut span = first_span;
+    let mut is_empty = content.trim().is_empty();
+    for (attr_span, content) in doc_attrs {
+        span = span.to(attr_span);
+        is_empty &= content.trim().is_empty();
+    }
+
+    if is_empty && !in_macro(span) {
+        span_lint_and_help(
+            cx,
+            EMPTY_DOCS,
+            span,
+            "empty doc comment",
+            None,
+            "consider removing or filling it",
+        );
+    }
+    is_empty
+}
+
+/// Included files larger than this are linted by `DOC_INCLUDE_WITHOUT_CFG`.
+const LARGE_INCLUDED_DOC_SIZE: u64 = 64 * 1024;
+
+/// Lints `#[doc = include_str!("..")]` attributes which aren't behind `cfg_attr(doc, ..)` if the
+/// included file is large or outside of the package.
+fn check_include_without_cfg(cx: &LateContext<'_>, attrs: &[Attribute]) {
+    for attr in attrs {
+        if_chain! {
+            if let AttrKind::Normal(ref item, _) = attr.kind;
+            if attr.has_name(sym::doc);
+            if let MacArgs::Eq(_, ref token) = item.args;
+            // the value is expanded at this point, so only its span tells it comes from a macro
+            if token.span.from_expansion();
+            // an attribute from `cfg_attr` doesn't start with `#`
+            if let Some(snippet) = snippet_opt(cx, attr.span);
+            if let Some(snippet) = snippet.strip_prefix('#');
+            let snippet = snippet.trim_start_matches('!').trim_start();
+            if let Some(snippet) = snippet.strip_prefix('[');
+            if let Some(snippet) = snippet.trim_start().strip_prefix("doc");
+            if let Some(snippet) = snippet.trim_start().strip_prefix('=');
+            if let Some(snippet) = snippet.trim_start().strip_prefix("include_str!");
+            if let Some(included) = included_path(cx, attr.span, snippet);
+            if is_large_or_outside_package(&included);
+            then {
+                let attr_kind = if attr.style == AttrStyle::Inner { "#!" } else { "#" };
+                let value = snippet.trim_end().trim_end_matches(']').trim_end();
+                span_lint_and_sugg(
+                    cx,
+                    DOC_INCLUDE_WITHOUT_CFG,
+                    attr.span,
+                    "included a file in documentation unconditionally",
+                    "use `cfg_attr(doc, doc = \"...\")`",
+                    format!("{}[cfg_attr(doc, doc = include_str!{})]", attr_kind, value),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Resolves the path in the arguments of `include_str!`, relative to the file containing `span`.
+fn included_path(cx: &LateContext<'_>, span: Span, macro_args: &str) -> Option<PathBuf> {
+    let start = macro_args.find('"')? + 1;
+    let len = macro_args[start..].find('"')?;
+    let path = Path::new(&macro_args[start..start + len]);
+    match cx.sess().source_map().span_to_filename(span) {
+        FileName::Real(name) => Some(name.local_path().parent()?.join(path)),
+        _ => None,
+    }
+}
+
+fn is_large_or_outside_package(path: &Path) -> bool {
+    let path = match path.canonicalize() {
+        Ok(path) => path,
+        Err(_) => return false,
+    };
+    let outside_package = env::var_os("CARGO_MANIFEST_DIR")
+        .and_then(|dir| Path::new(&dir).canonicalize().ok())
+        .map_or(false, |dir| !path.starts_with(dir));
+    outside_package || fs::metadata(&path).map_or(false, |meta| meta.len() > LARGE_INCLUDED_DOC_SIZE)
+}
+
+fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &NameList, attrs: &'a [Attribute]) -> DocHeaders {
+    check_include_without_cfg(cx, attrs);
+
+    if check_empty_docs(cx, attrs) {
+        return DocHeaders {
+            safety: false,
+            errors: false,
+        };
+    }
+
     let mut doc = String::new();
     let mut spans = vec![];
 
@@ -373,14 +587,15 @@ fn check_attrs<'a>(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs
             (previous, current) => Err(((previous, previous_range), (current, current_range))),
         }
     });
-    check_doc(cx, valid_idents, events, &spans)
+    check_doc(cx, valid_idents, &doc, events, &spans)
 }
 
 const RUST_CODE: &[&str] = &["rust", "no_run", "should_panic", "compile_fail"];
 
 fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize>)>>(
     cx: &LateContext<'_>,
-    valid_idents: &FxHashSet<String>,
+    valid_idents: &NameList,
+    doc: &str,
     events: Events,
     spans: &[(usize, Span)],
 ) -> DocHeaders {
@@ -389,7 +604,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     use pulldown_cmark::Event::{
         Code, End, FootnoteReference, HardBreak, Html, Rule, SoftBreak, Start, TaskListMarker, Text,
     };
-    use pulldown_cmark::Tag::{CodeBlock, Heading, Link};
+    use pulldown_cmark::Tag::{BlockQuote, CodeBlock, Heading, Item, Link};
 
     let mut headers = DocHeaders {
         safety: false,
@@ -400,6 +615,9 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     let mut in_heading = false;
     let mut is_rust = false;
     let mut edition = None;
+    // the column where the content of each enclosing list item starts
+    let mut item_indents = Vec::new();
+    let mut quote_depth = 0;
     for (event, range) in events {
         match event {
             Start(CodeBlock(ref kind)) => {
@@ -427,9 +645,18 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
             End(Link(..)) => in_link = None,
             Start(Heading(_)) => in_heading = true,
             End(Heading(_)) => in_heading = false,
+            Start(Item) => item_indents.push(item_content_column(doc, range.start)),
+            End(Item) => {
+                item_indents.pop();
+            },
+            Start(BlockQuote) => quote_depth += 1,
+            End(BlockQuote) => quote_depth -= 1,
             Start(_tag) | End(_tag) => (), // We don't care about other tags
             Html(_html) => (),             // HTML is weird, just ignore it
-            SoftBreak | HardBreak | TaskListMarker(_) | Code(_) | Rule => (),
+            SoftBreak | HardBreak => {
+                check_lazy_continuation(cx, doc, spans, range, item_indents.last().copied(), quote_depth);
+            },
+            TaskListMarker(_) | Code(_) | Rule => (),
             FootnoteReference(text) | Text(text) => {
                 if Some(&text) == in_link.as_ref() {
                     // Probably a link of the form `<http://example.com>`
@@ -461,6 +688,97 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     headers
 }
 
+/// Returns the column at which the content of the list item starting at `start` begins, e.g. 3
+/// for `" - foo"`.
+fn item_content_column(doc: &str, start: usize) -> usize {
+    let line_start = doc[..start].rfind('\n').map_or(0, |i| i + 1);
+    let marker_start = doc[start..].find(|c: char| c != ' ').map_or(doc.len(), |i| start + i);
+    let after_marker = doc[marker_start..]
+        .find(char::is_whitespace)
+        .map_or(doc.len(), |i| marker_start + i);
+    let content_start = doc[after_marker..]
+        .find(|c: char| c != ' ')
+        .map_or(doc.len(), |i| after_marker + i);
+    content_start - line_start
+}
+
+/// Returns the source span of the (empty) position `offset` in the doc string.
+fn doc_offset_span(spans: &[(usize, Span)], offset: usize) -> Span {
+    let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
+        Ok(o) => o,
+        Err(e) => e - 1,
+    };
+    let (begin, span) = spans[index];
+    let pos = span.lo() + BytePos::from_usize(offset - begin);
+    span.with_lo(pos).with_hi(pos)
+}
+
+/// Checks whether the line after the line break at `range` is a lazy continuation of the
+/// enclosing list item or block quote.
+fn check_lazy_continuation(
+    cx: &LateContext<'_>,
+    doc: &str,
+    spans: &[(usize, Span)],
+    range: Range<usize>,
+    item_indent: Option<usize>,
+    quote_depth: usize,
+) {
+    let line_start = match doc[range.start..].find('\n') {
+        Some(i) => range.start + i + 1,
+        None => return,
+    };
+    let line = doc[line_start..].lines().next().unwrap_or("");
+
+    if quote_depth > 0 {
+        // count the `>` markers at the start of the line
+        let mut markers = 0;
+        let mut rest = line.trim_start();
+        while let Some(stripped) = rest.strip_prefix('>') {
+            markers += 1;
+            rest = stripped.trim_start();
+        }
+        if markers < quote_depth {
+            let indent = line.len() - line.trim_start().len();
+            let span = doc_offset_span(spans, line_start + indent);
+            span_lint_and_then(
+                cx,
+                DOC_LAZY_CONTINUATION,
+                span,
+                "doc quote line without `>` marker",
+                |diag| {
+                    diag.span_suggestion(
+                        span,
+                        "add markers to start of line",
+                        "> ".repeat(quote_depth - markers),
+                        Applicability::MachineApplicable,
+                    );
+                    diag.help("if this is not intended to be a quote at all, escape it with `\\>`");
+                },
+            );
+        }
+    } else if let Some(item_indent) = item_indent {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent < item_indent {
+            let span = doc_offset_span(spans, line_start + indent);
+            span_lint_and_then(
+                cx,
+                DOC_LAZY_CONTINUATION,
+                span,
+                "doc list item without indentation",
+                |diag| {
+                    diag.span_suggestion(
+                        span,
+                        "indent this line",
+                        " ".repeat(item_indent - indent),
+                        Applicability::MachineApplicable,
+                    );
+                    diag.help("if this is supposed to be its own paragraph, add a blank line");
+                },
+            );
+        }
+    }
+}
+
 fn check_code(cx: &LateContext<'_>, text: &str, edition: Edition, span: Span) {
     fn has_needless_main(code: &str, edition: Edition) -> bool {
         rustc_driver::catch_fatal_errors(|| {
@@ -532,7 +850,7 @@ fn check_code(cx: &LateContext<'_>, text: &str, edition: Edition, span: Span) {
     }
 }
 
-fn check_text(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, text: &str, span: Span) {
+fn check_text(cx: &LateContext<'_>, valid_idents: &NameList, text: &str, span: Span) {
     for word in text.split(|c: char| c.is_whitespace() || c == '\'') {
         // Trim punctuation as in `some comment (see foo::bar).`
         //                                                   ^^
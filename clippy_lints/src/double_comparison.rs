@@ -3,7 +3,7 @@
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 use crate::utils::{eq_expr_value, snippet_with_applicability, span_lint_and_sugg};
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub DOUBLE_COMPARISONS,
     complexity,
-    "unnecessary double comparisons that can be simplified"
+    "unnecessary double comparisons that can be simplified",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(DoubleComparisons => [DOUBLE_COMPARISONS]);
@@ -2,7 +2,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 
 use crate::consts::{constant, Constant};
@@ -33,7 +33,8 @@ declare_clippy_lint! {
     /// ```
     pub DURATION_SUBSEC,
     complexity,
-    "checks for calculation of subsecond microseconds or milliseconds"
+    "checks for calculation of subsecond microseconds or milliseconds",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(DurationSubsec => [DURATION_SUBSEC]);
@@ -7,7 +7,7 @@ use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_hir::{BorrowKind, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -47,7 +47,8 @@ declare_clippy_lint! {
     /// ```
     pub MAP_ENTRY,
     perf,
-    "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"
+    "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(HashMapPass => [MAP_ENTRY]);
@@ -6,7 +6,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for equal operands to comparison, logical and
@@ -55,7 +55,8 @@ declare_clippy_lint! {
     /// ```
     pub OP_REF,
     style,
-    "taking a reference to satisfy the type constraints on `==`"
+    "taking a reference to satisfy the type constraints on `==`",
+    applicability: [MaybeIncorrect, Unspecified]
 }
 
 declare_lint_pass!(EqOp => [EQ_OP, OP_REF]);
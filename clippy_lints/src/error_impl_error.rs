@@ -0,0 +1,98 @@
+use crate::utils::{get_trait_def_id, implements_trait, paths, span_lint, span_lint_hir_and_then};
+use if_chain::if_chain;
+use rustc_hir::{Item, ItemKind, Node, TraitRef, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for exported types named `Error` that implement
+    /// `std::error::Error`.
+    ///
+    /// **Why is this bad?** It can become confusing when a codebase has 20 types all named
+    /// `Error`, requiring either aliasing them in the `use` statement or qualifying them like
+    /// `my_module::Error`. This hinders comprehension, as it requires you to memorize every
+    /// variation of importing `Error` used across a codebase.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// #[derive(Debug)]
+    /// pub enum Error { ... }
+    ///
+    /// impl std::fmt::Display for Error { ... }
+    ///
+    /// impl std::error::Error for Error { ... }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[derive(Debug)]
+    /// pub enum ParseError { ... }
+    ///
+    /// impl std::fmt::Display for ParseError { ... }
+    ///
+    /// impl std::error::Error for ParseError { ... }
+    /// ```
+    pub ERROR_IMPL_ERROR,
+    restriction,
+    "exported types named `Error` that implement `Error`"
+}
+
+declare_lint_pass!(ErrorImplError => [ERROR_IMPL_ERROR]);
+
+impl<'tcx> LateLintPass<'tcx> for ErrorImplError {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let error_def_id = if let Some(def_id) = get_trait_def_id(cx, &paths::STD_ERROR) {
+            def_id
+        } else {
+            return;
+        };
+
+        match item.kind {
+            ItemKind::TyAlias(_, ref generics) => {
+                if_chain! {
+                    if item.ident.name == sym::Error;
+                    if generics.params.is_empty();
+                    if cx.access_levels.is_exported(item.hir_id);
+                    let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+                    if implements_trait(cx, ty, error_def_id, &[]);
+                    then {
+                        span_lint(
+                            cx,
+                            ERROR_IMPL_ERROR,
+                            item.ident.span,
+                            "exported type alias named `Error` that implements `Error`",
+                        );
+                    }
+                }
+            },
+            ItemKind::Impl(ref imp) => {
+                if_chain! {
+                    if let Some(trait_def_id) = imp.of_trait.as_ref().and_then(TraitRef::trait_def_id);
+                    if trait_def_id == error_def_id;
+                    if let TyKind::Path(ref qpath) = imp.self_ty.kind;
+                    if let Some(def_id) = cx.qpath_res(qpath, imp.self_ty.hir_id).opt_def_id();
+                    if let Some(local_def_id) = def_id.as_local();
+                    let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+                    if let Some(Node::Item(ty_item)) = cx.tcx.hir().find(hir_id);
+                    if ty_item.ident.name == sym::Error;
+                    if cx.access_levels.is_exported(hir_id);
+                    then {
+                        span_lint_hir_and_then(
+                            cx,
+                            ERROR_IMPL_ERROR,
+                            hir_id,
+                            ty_item.ident.span,
+                            "exported type named `Error` that implements `Error`",
+                            |diag| {
+                                diag.span_note(item.span, "`Error` was implemented here");
+                            },
+                        );
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
@@ -4,7 +4,7 @@ use rustc_hir::{def_id, Expr, ExprKind, Param, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 use crate::utils::{
     implements_trait, is_adjusted, iter_input_pats, snippet_opt, span_lint_and_sugg, span_lint_and_then,
@@ -36,7 +36,8 @@ declare_clippy_lint! {
     /// `x`.
     pub REDUNDANT_CLOSURE,
     style,
-    "redundant closures, i.e., `|a| foo(a)` (which can be written as just `foo`)"
+    "redundant closures, i.e., `|a| foo(a)` (which can be written as just `foo`)",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -60,7 +61,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
     pedantic,
-    "redundant closures for method calls"
+    "redundant closures for method calls",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(EtaReduction => [REDUNDANT_CLOSURE, REDUNDANT_CLOSURE_FOR_METHOD_CALLS]);
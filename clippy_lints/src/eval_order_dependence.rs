@@ -5,7 +5,7 @@ use rustc_hir::{def, BinOpKind, Block, Expr, ExprKind, Guard, HirId, Local, Node
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for a read and a write to the same variable where
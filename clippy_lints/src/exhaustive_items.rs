@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -33,7 +33,8 @@ declare_clippy_lint! {
     /// ```
     pub EXHAUSTIVE_ENUMS,
     restriction,
-    "detects exported enums that have not been marked #[non_exhaustive]"
+    "detects exported enums that have not been marked #[non_exhaustive]",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -63,7 +64,8 @@ declare_clippy_lint! {
     /// ```
     pub EXHAUSTIVE_STRUCTS,
     restriction,
-    "detects exported structs that have not been marked #[non_exhaustive]"
+    "detects exported structs that have not been marked #[non_exhaustive]",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(ExhaustiveItems => [EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS]);
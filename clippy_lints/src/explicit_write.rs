@@ -4,7 +4,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BorrowKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -24,7 +24,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPLICIT_WRITE,
     complexity,
-    "using the `write!()` family of functions instead of the `print!()` family of functions, when using the latter would work"
+    "using the `write!()` family of functions instead of the `print!()` family of functions, when using the latter would work",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ExplicitWrite => [EXPLICIT_WRITE]);
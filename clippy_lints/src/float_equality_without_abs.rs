@@ -6,7 +6,7 @@ use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 
 declare_clippy_lint! {
@@ -36,7 +36,8 @@ declare_clippy_lint! {
      /// ```
     pub FLOAT_EQUALITY_WITHOUT_ABS,
     correctness,
-    "float equality check without `.abs()`"
+    "float equality check without `.abs()`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(FloatEqualityWithoutAbs => [FLOAT_EQUALITY_WITHOUT_ABS]);
@@ -5,7 +5,7 @@ use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, FloatTy};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use std::fmt;
 
 declare_clippy_lint! {
@@ -29,7 +29,8 @@ declare_clippy_lint! {
     /// ```
     pub EXCESSIVE_PRECISION,
     style,
-    "excessive precision for float literal"
+    "excessive precision for float literal",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -53,7 +54,8 @@ declare_clippy_lint! {
     /// ```
     pub LOSSY_FLOAT_LITERAL,
     restriction,
-    "lossy whole number float literals"
+    "lossy whole number float literals",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(FloatLiteral => [EXCESSIVE_PRECISION, LOSSY_FLOAT_LITERAL]);
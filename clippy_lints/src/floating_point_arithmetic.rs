@@ -8,7 +8,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind, PathSegment, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 
 use rustc_ast::ast;
@@ -44,7 +44,8 @@ declare_clippy_lint! {
     /// ```
     pub IMPRECISE_FLOPS,
     nursery,
-    "usage of imprecise floating point operations"
+    "usage of imprecise floating point operations",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -101,7 +102,8 @@ declare_clippy_lint! {
     /// ```
     pub SUBOPTIMAL_FLOPS,
     nursery,
-    "usage of sub-optimal floating point operations"
+    "usage of sub-optimal floating point operations",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(FloatingPointArithmetic => [
@@ -1,14 +1,14 @@
 use crate::utils::paths;
 use crate::utils::{
     is_expn_of, is_type_diagnostic_item, last_path_segment, match_def_path, match_function_call, snippet, snippet_opt,
-    span_lint_and_then,
+    span_lint_and_then, LintLevels,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, MatchSource, PatKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 
@@ -36,7 +36,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_FORMAT,
     complexity,
-    "useless use of `format!`"
+    "useless use of `format!`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(UselessFormat => [USELESS_FORMAT]);
@@ -57,7 +58,7 @@ impl<'tcx> LateLintPass<'tcx> for UselessFormat {
     }
 }
 
-fn span_useless_format<T: LintContext>(cx: &T, span: Span, help: &str, mut sugg: String) {
+fn span_useless_format<T: LintContext + LintLevels>(cx: &T, span: Span, help: &str, mut sugg: String) {
     let to_replace = span.source_callsite();
 
     // The callsite span contains the statement semicolon for some reason.
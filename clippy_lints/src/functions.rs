@@ -1,7 +1,9 @@
+use crate::utils::conf::TestOverrides;
 use crate::utils::{
-    attr_by_name, attrs::is_proc_macro, is_must_use_ty, is_trait_impl_item, is_type_diagnostic_item, iter_input_pats,
-    last_path_segment, match_def_path, must_use_attr, return_ty, snippet, snippet_opt, span_lint, span_lint_and_help,
-    span_lint_and_then, trait_ref_of_method, type_is_unsafe_function,
+    attr_by_name, attrs::is_proc_macro, is_in_test_context, is_must_use_ty, is_trait_impl_item,
+    is_type_diagnostic_item, iter_input_pats, last_path_segment, match_def_path, must_use_attr, return_ty, snippet,
+    snippet_opt, span_lint, span_lint_and_help, span_lint_and_then, trait_ref_of_method, type_is_unsafe_function,
+    LimitStack,
 };
 use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
@@ -14,9 +16,10 @@ use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
+use rustc_target::abi::LayoutOf;
 use rustc_target::spec::abi::Abi;
 use rustc_typeck::hir_ty_to_ty;
 
@@ -75,11 +78,11 @@ declare_clippy_lint! {
     /// **Known problems:**
     ///
     /// * It does not check functions recursively so if the pointer is passed to a
-    /// private non-`unsafe` function which does the dereferencing, the lint won't
-    /// trigger.
+    ///   private non-`unsafe` function which does the dereferencing, the lint won't
+    ///   trigger.
     /// * It only checks for arguments whose type are raw pointers, not raw pointers
-    /// got from an argument in some other way (`fn foo(bar: &[*const u8])` or
-    /// `some_argument.get_raw_ptr()`).
+    ///   got from an argument in some other way (`fn foo(bar: &[*const u8])` or
+    ///   `some_argument.get_raw_ptr()`).
     ///
     /// **Example:**
     /// ```rust,ignore
@@ -116,7 +119,8 @@ declare_clippy_lint! {
     /// ```
     pub MUST_USE_UNIT,
     style,
-    "`#[must_use]` attribute on a unit-returning function / method"
+    "`#[must_use]` attribute on a unit-returning function / method",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -169,7 +173,8 @@ declare_clippy_lint! {
     /// ```
     pub MUST_USE_CANDIDATE,
     pedantic,
-    "function or method that could take a `#[must_use]` attribute"
+    "function or method that could take a `#[must_use]` attribute",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -218,15 +223,62 @@ declare_clippy_lint! {
     "public function returning `Result` with an `Err` type of `()`"
 }
 
-#[derive(Copy, Clone)]
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions that return `Result` with an unusually large
+    /// `Err`-variant.
+    ///
+    /// **Why is this bad?** A `Result` is at least as large as the `Err`-variant. While we
+    /// expect that variant to be seldomly used, the compiler needs to reserve and move that
+    /// much memory every single time.
+    ///
+    /// **Known problems:** The size determined by Clippy is platform-dependent.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub enum ParseError {
+    ///     UnparsedBytes([u8; 512]),
+    ///     UnexpectedEof,
+    /// }
+    ///
+    /// // The `Result` has at least 512 bytes, even in the `Ok`-case
+    /// pub fn parse() -> Result<(), ParseError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    /// should be
+    /// ```rust
+    /// pub enum ParseError {
+    ///     UnparsedBytes(Box<[u8; 512]>),
+    ///     UnexpectedEof,
+    /// }
+    ///
+    /// // The `Result` is slightly larger than a pointer
+    /// pub fn parse() -> Result<(), ParseError> {
+    ///     Ok(())
+    /// }
+    /// ```
+    pub RESULT_LARGE_ERR,
+    perf,
+    "function returning `Result` with large `Err` type"
+}
+
 pub struct Functions {
-    threshold: u64,
-    max_lines: u64,
+    threshold: LimitStack,
+    max_lines: LimitStack,
+    large_error_threshold: u64,
+    test_threshold: Option<u64>,
+    test_max_lines: Option<u64>,
 }
 
 impl Functions {
-    pub fn new(threshold: u64, max_lines: u64) -> Self {
-        Self { threshold, max_lines }
+    pub fn new(threshold: u64, max_lines: u64, large_error_threshold: u64, test_overrides: &TestOverrides) -> Self {
+        Self {
+            threshold: LimitStack::new(threshold),
+            max_lines: LimitStack::new(max_lines),
+            large_error_threshold,
+            test_threshold: test_overrides.too_many_arguments_threshold,
+            test_max_lines: test_overrides.too_many_lines_threshold,
+        }
     }
 }
 
@@ -238,6 +290,7 @@ impl_lint_pass!(Functions => [
     DOUBLE_MUST_USE,
     MUST_USE_CANDIDATE,
     RESULT_UNIT_ERR,
+    RESULT_LARGE_ERR,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Functions {
@@ -270,14 +323,14 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
                     _,
                 )
                 | intravisit::FnKind::ItemFn(_, _, hir::FnHeader { abi: Abi::Rust, .. }, _, _) => {
-                    self.check_arg_number(cx, decl, span.with_hi(decl.output.span().hi()))
+                    self.check_arg_number(cx, decl, span.with_hi(decl.output.span().hi()), hir_id)
                 },
                 _ => {},
             }
         }
 
         Self::check_raw_ptr(cx, unsafety, decl, body, hir_id);
-        self.check_line_number(cx, span, body);
+        self.check_line_number(cx, span, body, hir_id);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
@@ -288,6 +341,7 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
             if is_public {
                 check_result_unit_err(cx, &sig.decl, item.span, fn_header_span);
             }
+            self.check_result_large_err(cx, &sig.decl, item.hir_id, item.span);
             if let Some(attr) = attr {
                 check_needless_must_use(cx, &sig.decl, item.hir_id, item.span, fn_header_span, attr);
                 return;
@@ -313,6 +367,9 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
             if is_public && trait_ref_of_method(cx, item.hir_id).is_none() {
                 check_result_unit_err(cx, &sig.decl, item.span, fn_header_span);
             }
+            if trait_ref_of_method(cx, item.hir_id).is_none() {
+                self.check_result_large_err(cx, &sig.decl, item.hir_id, item.span);
+            }
             let attr = must_use_attr(&item.attrs);
             if let Some(attr) = attr {
                 check_needless_must_use(cx, &sig.decl, item.hir_id, item.span, fn_header_span, attr);
@@ -337,13 +394,19 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
         if let hir::TraitItemKind::Fn(ref sig, ref eid) = item.kind {
             // don't lint extern functions decls, it's not their fault
             if sig.header.abi == Abi::Rust {
-                self.check_arg_number(cx, &sig.decl, item.span.with_hi(sig.decl.output.span().hi()));
+                self.check_arg_number(
+                    cx,
+                    &sig.decl,
+                    item.span.with_hi(sig.decl.output.span().hi()),
+                    item.hir_id,
+                );
             }
             let is_public = cx.access_levels.is_exported(item.hir_id);
             let fn_header_span = item.span.with_hi(sig.decl.output.span().hi());
             if is_public {
                 check_result_unit_err(cx, &sig.decl, item.span, fn_header_span);
             }
+            self.check_result_large_err(cx, &sig.decl, item.hir_id, item.span);
 
             let attr = must_use_attr(&item.attrs);
             if let Some(attr) = attr {
@@ -367,22 +430,43 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
             }
         }
     }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.threshold
+            .push_arg_attrs(cx.sess(), attrs, "too_many_arguments", "threshold");
+        self.max_lines
+            .push_arg_attrs(cx.sess(), attrs, "too_many_lines", "threshold");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        self.threshold
+            .pop_arg_attrs(cx.sess(), attrs, "too_many_arguments", "threshold");
+        self.max_lines
+            .pop_arg_attrs(cx.sess(), attrs, "too_many_lines", "threshold");
+    }
+}
+
+/// Returns the limit for the function, which is the one of the `[test-overrides]` table of the
+/// configuration in test code, unless an attribute overrides it.
+fn limit_for(cx: &LateContext<'_>, hir_id: hir::HirId, limit: &LimitStack, test_limit: Option<u64>) -> u64 {
+    limit.limit_or(test_limit.filter(|_| is_in_test_context(cx, hir_id)))
 }
 
 impl<'tcx> Functions {
-    fn check_arg_number(self, cx: &LateContext<'_>, decl: &hir::FnDecl<'_>, fn_span: Span) {
+    fn check_arg_number(&self, cx: &LateContext<'_>, decl: &hir::FnDecl<'_>, fn_span: Span, hir_id: hir::HirId) {
+        let threshold = limit_for(cx, hir_id, &self.threshold, self.test_threshold);
         let args = decl.inputs.len() as u64;
-        if args > self.threshold {
+        if args > threshold {
             span_lint(
                 cx,
                 TOO_MANY_ARGUMENTS,
                 fn_span,
-                &format!("this function has too many arguments ({}/{})", args, self.threshold),
+                &format!("this function has too many arguments ({}/{})", args, threshold),
             );
         }
     }
 
-    fn check_line_number(self, cx: &LateContext<'_>, span: Span, body: &'tcx hir::Body<'_>) {
+    fn check_line_number(&self, cx: &LateContext<'_>, span: Span, body: &'tcx hir::Body<'_>, hir_id: hir::HirId) {
+        let max_lines = limit_for(cx, hir_id, &self.max_lines, self.test_max_lines);
         if in_external_macro(cx.sess(), span) {
             return;
         }
@@ -428,16 +512,55 @@ impl<'tcx> Functions {
             }
         }
 
-        if line_count > self.max_lines {
+        if line_count > max_lines {
             span_lint(
                 cx,
                 TOO_MANY_LINES,
                 span,
-                &format!("this function has too many lines ({}/{})", line_count, self.max_lines),
+                &format!("this function has too many lines ({}/{})", line_count, max_lines),
             )
         }
     }
 
+    fn check_result_large_err(
+        &self,
+        cx: &LateContext<'tcx>,
+        decl: &hir::FnDecl<'_>,
+        hir_id: hir::HirId,
+        item_span: Span,
+    ) {
+        if in_external_macro(cx.sess(), item_span) {
+            return;
+        }
+        if_chain! {
+            if let hir::FnRetTy::Return(ref ret_ty) = decl.output;
+            let ty = return_ty(cx, hir_id);
+            if let ty::Adt(_, substs) = ty.kind();
+            if is_type_diagnostic_item(cx, ty, sym::result_type);
+            let err_ty = substs.type_at(1);
+            if let Ok(layout) = cx.layout_of(err_ty);
+            if layout.size.bytes() > self.large_error_threshold;
+            then {
+                span_lint_and_then(
+                    cx,
+                    RESULT_LARGE_ERR,
+                    ret_ty.span,
+                    "the `Err`-variant returned from this function is very large",
+                    |diag| {
+                        diag.span_label(
+                            ret_ty.span,
+                            format!("the `Err`-variant is at least {} bytes", layout.size.bytes()),
+                        );
+                        diag.help(&format!(
+                            "try reducing the size of `{}`, for example by boxing large elements or replacing it with `Box<{}>`",
+                            err_ty, err_ty
+                        ));
+                    },
+                );
+            }
+        }
+    }
+
     fn check_raw_ptr(
         cx: &LateContext<'tcx>,
         unsafety: hir::Unsafety,
@@ -6,7 +6,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 use rustc_span::sym;
 
@@ -39,7 +39,8 @@ declare_clippy_lint! {
     /// ```
     pub GET_LAST_WITH_LEN,
     complexity,
-    "Using `x.get(x.len() - 1)` when `x.last()` is correct and simpler"
+    "Using `x.get(x.len() - 1)` when `x.last()` is correct and simpler",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(GetLastWithLen => [GET_LAST_WITH_LEN]);
@@ -0,0 +1,130 @@
+use crate::utils::{in_macro, is_type_diagnostic_item, match_type, paths, span_lint_and_then, SpanlessEq};
+use if_chain::if_chain;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `contains` to see if a value is not present
+    /// in a set like `HashSet` or `BTreeSet`, followed by an `insert`.
+    ///
+    /// **Why is this bad?** Using just `insert` and checking the returned `bool` is more
+    /// efficient, as the value is only looked up once.
+    ///
+    /// **Known problems:** In case the value that wants to be inserted is borrowed and also
+    /// expensive or impossible to clone, it can't simply be inserted up front. The map equivalent
+    /// of this pattern is covered by the `map_entry` lint.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// let mut set = HashSet::new();
+    /// let value = 5;
+    /// if !set.contains(&value) {
+    ///     set.insert(value);
+    ///     println!("inserted {:?}", value);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// let mut set = HashSet::new();
+    /// let value = 5;
+    /// if set.insert(value) {
+    ///     println!("inserted {:?}", value);
+    /// }
+    /// ```
+    pub HASHSET_INSERT_AFTER_CONTAINS,
+    nursery,
+    "call to `<set>::contains` followed by `<set>::insert`"
+}
+
+declare_lint_pass!(HashsetInsertAfterContains => [HASHSET_INSERT_AFTER_CONTAINS]);
+
+impl<'tcx> LateLintPass<'tcx> for HashsetInsertAfterContains {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::If(cond, then, else_) = expr.kind {
+            // `if !set.contains(&value) { set.insert(value); }` or
+            // `if set.contains(&value) { .. } else { set.insert(value); }`
+            let (contains, insert_block) = match cond.kind {
+                ExprKind::Unary(UnOp::UnNot, contains) => (contains, Some(then)),
+                _ => (cond, else_),
+            };
+            if_chain! {
+                if let Some(insert_block) = insert_block;
+                if let ExprKind::Block(block, _) = insert_block.kind;
+                if let Some((set_ty, set, value)) = check_contains(cx, contains);
+                if let Some(insert) = find_insert(cx, block, set, value);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        HASHSET_INSERT_AFTER_CONTAINS,
+                        contains.span,
+                        &format!("usage of `{}::insert` after `{}::contains`", set_ty, set_ty),
+                        |diag| {
+                            diag.span_note(insert.span, "`insert` is called here");
+                            diag.help(&format!(
+                                "try using the return value of `{}::insert` instead",
+                                set_ty
+                            ));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks for `set.contains(&value)` on a `HashSet` or `BTreeSet`, returning the name of the
+/// set type, the set and the value.
+fn check_contains<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'static str, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if_chain! {
+        if let ExprKind::MethodCall(path, _, [set, arg], _) = expr.kind;
+        if path.ident.name == sym!(contains);
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, value) = arg.kind;
+        then {
+            let set_ty = cx.typeck_results().expr_ty(set).peel_refs();
+            if is_type_diagnostic_item(cx, set_ty, sym!(hashset_type)) {
+                Some(("HashSet", set, value))
+            } else if match_type(cx, set_ty, &paths::BTREESET) {
+                Some(("BTreeSet", set, value))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds a `set.insert(value)` call among the statements of the block.
+fn find_insert<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    set: &Expr<'_>,
+    value: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            StmtKind::Local(_) | StmtKind::Item(_) => None,
+        })
+        .chain(block.expr)
+        .find(|expr| {
+            if let ExprKind::MethodCall(path, _, [insert_set, insert_value], _) = expr.kind {
+                path.ident.name == sym!(insert)
+                    && SpanlessEq::new(cx).eq_expr(set, insert_set)
+                    && SpanlessEq::new(cx).eq_expr(value, insert_value)
+            } else {
+                false
+            }
+        })
+}
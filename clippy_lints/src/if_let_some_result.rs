@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, MatchSource, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -33,7 +33,8 @@ declare_clippy_lint! {
     /// ```
     pub IF_LET_SOME_RESULT,
     style,
-    "usage of `ok()` in `if let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead"
+    "usage of `ok()` in `if let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(OkIfLet => [IF_LET_SOME_RESULT]);
@@ -4,7 +4,7 @@ use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, MatchSource, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub IMPLICIT_RETURN,
     restriction,
-    "use a return statement like `return expr` instead of an expression"
+    "use a return statement like `return expr` instead of an expression",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ImplicitReturn => [IMPLICIT_RETURN]);
@@ -4,7 +4,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind, QPath, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for implicit saturating subtraction.
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub IMPLICIT_SATURATING_SUB,
     pedantic,
-    "Perform saturating subtraction instead of implicitly checking lower bound of data type"
+    "Perform saturating subtraction instead of implicitly checking lower bound of data type",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ImplicitSaturatingSub => [IMPLICIT_SATURATING_SUB]);
@@ -0,0 +1,210 @@
+use crate::utils::{in_macro, snippet, span_lint_and_then};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    Body, FnDecl, FnRetTy, GenericArgs, GenericBound, HirId, ItemKind, PolyTraitRef, TraitBoundModifier, TyKind,
+    TypeBindingKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{PolyTraitRef as TyPolyTraitRef, Predicate, PredicateKind};
+use rustc_session::declare_tool_lint;
+use rustc_span::{BytePos, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for bounds in `impl Trait` in return position that are implied by
+    /// other bounds. This can happen when a trait is specified that another trait already has as
+    /// a supertrait (e.g. `fn() -> impl Deref + DerefMut<Target = i32>` has an unnecessary
+    /// `Deref` bound, because `Deref` is a supertrait of `DerefMut`).
+    ///
+    /// **Why is this bad?** Specifying more bounds than necessary adds needless complexity for
+    /// the reader.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::ops::{Deref, DerefMut};
+    /// fn f() -> impl Deref<Target = i32> + DerefMut<Target = i32> {
+    /// //             ^^^^^^^^^^^^^^^^^^^ unnecessary bound, already implied by the `DerefMut` trait bound
+    ///     Box::new(123)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ops::{Deref, DerefMut};
+    /// fn f() -> impl DerefMut<Target = i32> {
+    ///     Box::new(123)
+    /// }
+    /// ```
+    pub IMPLIED_BOUNDS_IN_IMPLS,
+    complexity,
+    "specifying bounds that are implied by other bounds in `impl Trait` type",
+    applicability: [MachineApplicable]
+}
+
+declare_lint_pass!(ImpliedBoundsInImpls => [IMPLIED_BOUNDS_IN_IMPLS]);
+
+/// A trait bound of the `impl Trait` type, together with its lowered form.
+struct ImplTraitBound<'a, 'tcx> {
+    /// The index of the bound in the list of bounds.
+    index: usize,
+    poly_trait: &'a PolyTraitRef<'a>,
+    trait_ref: TyPolyTraitRef<'tcx>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ImpliedBoundsInImpls {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        _: Span,
+        _: HirId,
+    ) {
+        let ret_ty = if let FnRetTy::Return(ty) = decl.output {
+            ty
+        } else {
+            return;
+        };
+        if in_macro(ret_ty.span) {
+            return;
+        }
+        if let TyKind::OpaqueDef(item_id, _) = ret_ty.kind {
+            let item = cx.tcx.hir().item(item_id.id);
+            if let ItemKind::OpaqueTy(ref opaque) = item.kind {
+                if opaque.bounds.len() < 2 {
+                    return;
+                }
+                let predicates = cx.tcx.explicit_item_bounds(cx.tcx.hir().local_def_id(item.hir_id));
+                check_bounds(cx, opaque.bounds, predicates);
+            }
+        }
+    }
+}
+
+fn check_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    bounds: &'tcx [GenericBound<'tcx>],
+    predicates: &[(Predicate<'tcx>, Span)],
+) {
+    let trait_bounds: Vec<ImplTraitBound<'_, 'tcx>> = bounds
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bound)| {
+            if let GenericBound::Trait(poly_trait, TraitBoundModifier::None) = bound {
+                predicates
+                    .iter()
+                    .filter(|(_, span)| *span == poly_trait.span)
+                    .find_map(|(predicate, _)| predicate.to_opt_poly_trait_ref())
+                    .map(|trait_ref| ImplTraitBound {
+                        index,
+                        poly_trait,
+                        trait_ref: trait_ref.value,
+                    })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    for implied in &trait_bounds {
+        let implied_by = trait_bounds.iter().find(|bound| {
+            bound.index != implied.index
+                && cx
+                    .tcx
+                    .super_predicates_of(bound.trait_ref.def_id())
+                    .predicates
+                    .iter()
+                    .filter_map(|(predicate, _)| {
+                        predicate
+                            .subst_supertrait(cx.tcx, &bound.trait_ref)
+                            .to_opt_poly_trait_ref()
+                    })
+                    .any(|super_trait| super_trait.value.skip_binder() == implied.trait_ref.skip_binder())
+        });
+
+        if let Some(implied_by) = implied_by {
+            emit_lint(cx, bounds, predicates, implied, implied_by);
+        }
+    }
+}
+
+fn emit_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    bounds: &[GenericBound<'_>],
+    predicates: &[(Predicate<'tcx>, Span)],
+    implied: &ImplTraitBound<'_, 'tcx>,
+    implied_by: &ImplTraitBound<'_, 'tcx>,
+) {
+    let implied_args = implied
+        .poly_trait
+        .trait_ref
+        .path
+        .segments
+        .last()
+        .and_then(|seg| seg.args);
+    let implied_by_path = &implied_by.poly_trait.trait_ref.path;
+    let implied_by_args = implied_by_path.segments.last().and_then(|seg| seg.args);
+
+    // The associated type bindings of the removed bound that are not repeated on the bound that
+    // implies it need to be moved over, e.g. `impl Deref<Target = u8> + DerefMut` becomes
+    // `impl DerefMut<Target = u8>`.
+    let mut missing_bindings = Vec::new();
+    for binding in implied_args.map_or(&[][..], |args| args.bindings) {
+        if let TypeBindingKind::Constraint { .. } = binding.kind {
+            return;
+        }
+        let projection = predicates
+            .iter()
+            .find(|(predicate, span)| *span == binding.span && is_projection(*predicate))
+            .map(|(predicate, _)| predicate.kind().skip_binder());
+        let already_bound = predicates.iter().any(|(predicate, span)| {
+            implied_by.poly_trait.span.contains(*span) && Some(predicate.kind().skip_binder()) == projection
+        });
+        if !already_bound {
+            missing_bindings.push(snippet(cx, binding.span, "..").into_owned());
+        }
+    }
+
+    let index = implied.index;
+    let removal_span = bounds.get(index + 1).map_or_else(
+        || bounds[index - 1].span().shrink_to_hi().to(bounds[index].span()),
+        |next| bounds[index].span().until(next.span()),
+    );
+    let mut sugg = vec![(removal_span, String::new())];
+    if !missing_bindings.is_empty() {
+        let bindings = missing_bindings.join(", ");
+        if implied_by_args.map_or(true, |args| is_empty_args(args)) {
+            sugg.push((implied_by_path.span.shrink_to_hi(), format!("<{}>", bindings)));
+        } else {
+            // insert the bindings right before the closing `>`
+            let closing = implied_by_path.span.hi() - BytePos(1);
+            sugg.push((
+                implied_by_path.span.with_lo(closing).with_hi(closing),
+                format!(", {}", bindings),
+            ));
+        }
+    }
+
+    span_lint_and_then(
+        cx,
+        IMPLIED_BOUNDS_IN_IMPLS,
+        implied.poly_trait.span,
+        &format!(
+            "this bound is already specified as the supertrait of `{}`",
+            snippet(cx, implied_by.poly_trait.span, "..")
+        ),
+        |diag| {
+            diag.multipart_suggestion("try removing this bound", sugg, Applicability::MachineApplicable);
+        },
+    );
+}
+
+fn is_projection(predicate: Predicate<'_>) -> bool {
+    matches!(predicate.kind().skip_binder(), PredicateKind::Projection(_))
+}
+
+fn is_empty_args(args: &GenericArgs<'_>) -> bool {
+    args.args.is_empty() && args.bindings.is_empty()
+}
@@ -0,0 +1,128 @@
+use crate::utils::span_lint;
+use rustc_attr::StabilityLevel;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{DefIdTree, TyCtxt};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::def_id::DefId;
+use rustc_span::{ExpnKind, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** This lint checks that no function newer than the defined MSRV (minimum
+    /// supported rust version) is used in the crate.
+    ///
+    /// **Why is this bad?** It would prevent the crate to be actually used with the specified MSRV.
+    ///
+    /// **Known problems:** Only calls to functions and methods are checked, and only when an MSRV
+    /// is configured.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // MSRV of 1.3.0
+    /// use std::thread::sleep;
+    /// use std::time::Duration;
+    ///
+    /// // Sleep was defined in `1.4.0`.
+    /// sleep(Duration::new(1, 0));
+    /// ```
+    ///
+    /// To fix this problem, either increase your MSRV or use another item
+    /// available in your current MSRV.
+    pub INCOMPATIBLE_MSRV,
+    style,
+    "ensures that all items used in the crate are available for the current MSRV"
+}
+
+pub struct IncompatibleMsrv {
+    msrv: Option<RustcVersion>,
+    is_above_msrv: FxHashMap<DefId, RustcVersion>,
+}
+
+impl_lint_pass!(IncompatibleMsrv => [INCOMPATIBLE_MSRV]);
+
+impl IncompatibleMsrv {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv,
+            is_above_msrv: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the version in which the item was stabilized, falling back to the stability of
+    /// its parents (e.g. for methods in impl blocks without their own stability attribute).
+    fn get_def_id_version(&mut self, tcx: TyCtxt<'_>, def_id: DefId) -> RustcVersion {
+        if let Some(version) = self.is_above_msrv.get(&def_id) {
+            return *version;
+        }
+        let stable_since = tcx
+            .lookup_stability(def_id)
+            .and_then(|stability| match stability.level {
+                StabilityLevel::Stable { since } => RustcVersion::parse(&since.as_str()).ok(),
+                StabilityLevel::Unstable { .. } => None,
+            });
+        let version = match (stable_since, tcx.parent(def_id)) {
+            (Some(version), _) => version,
+            (None, Some(parent_def_id)) => self.get_def_id_version(tcx, parent_def_id),
+            (None, None) => RustcVersion::new(1, 0, 0),
+        };
+        self.is_above_msrv.insert(def_id, version);
+        version
+    }
+
+    fn emit_lint_if_under_msrv(&mut self, cx: &LateContext<'_>, def_id: DefId, span: Span) {
+        let msrv = match self.msrv {
+            Some(msrv) => msrv,
+            None => return,
+        };
+        if def_id.is_local() {
+            // We don't check local items since their MSRV is supposed to always be valid.
+            return;
+        }
+        let version = self.get_def_id_version(cx.tcx, def_id);
+        if msrv.meets(version) {
+            return;
+        }
+        if let ExpnKind::AstPass(_) | ExpnKind::Desugaring(_) = span.ctxt().outer_expn_data().kind {
+            // Desugared expressions get to cheat and stability is ignored.
+            return;
+        }
+        span_lint(
+            cx,
+            INCOMPATIBLE_MSRV,
+            span,
+            &format!(
+                "current MSRV (Minimum Supported Rust Version) is `{}` but this item is stable since `{}`",
+                msrv, version
+            ),
+        );
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for IncompatibleMsrv {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if self.msrv.is_none() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(_, span, ..) => {
+                if let Some(method_did) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.emit_lint_if_under_msrv(cx, method_did, span);
+                }
+            },
+            ExprKind::Call(call, _) => {
+                if let ExprKind::Path(ref qpath) = call.kind {
+                    if let Some(path_def_id) = cx.qpath_res(qpath, call.hir_id).opt_def_id() {
+                        self.emit_lint_if_under_msrv(cx, path_def_id, call.span);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
@@ -6,7 +6,7 @@ use rustc_ast::ast::Attribute;
 use rustc_errors::Applicability;
 use rustc_hir::{TraitFn, TraitItem, TraitItemKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::{sym, Symbol};
 
 declare_clippy_lint! {
@@ -26,7 +26,8 @@ declare_clippy_lint! {
     /// ```
     pub INLINE_FN_WITHOUT_BODY,
     correctness,
-    "use of `#[inline]` on trait methods without bodies"
+    "use of `#[inline]` on trait methods without bodies",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(InlineFnWithoutBody => [INLINE_FN_WITHOUT_BODY]);
@@ -3,7 +3,7 @@
 use rustc_ast::ast::{BinOpKind, Expr, ExprKind, Lit, LitKind};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 use crate::utils::{snippet_opt, span_lint_and_sugg};
 
@@ -30,7 +30,8 @@ declare_clippy_lint! {
     /// ```
     pub INT_PLUS_ONE,
     complexity,
-    "instead of using `x >= y + 1`, use `x > y`"
+    "instead of using `x >= y + 1`, use `x > y`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(IntPlusOne => [INT_PLUS_ONE]);
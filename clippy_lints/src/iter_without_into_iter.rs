@@ -0,0 +1,249 @@
+use crate::utils::{get_trait_def_id, implements_trait, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{FnRetTy, ImplItem, ImplItemKind, Item, ItemKind, MutTy, Mutability, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, AssocKind, Ty, TypeAndMut};
+use rustc_session::declare_tool_lint;
+use rustc_span::def_id::DefId;
+use rustc_span::symbol::Symbol;
+use rustc_typeck::hir_ty_to_ty;
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for `iter` and `iter_mut` methods without an associated
+    /// `IntoIterator for (&|&mut) Type` implementation.
+    ///
+    /// **Why is this bad?** It's not bad, but having them is idiomatic and allows the type to be
+    /// used in for loops directly (`for val in &iter {}`), without having to first call `iter()`
+    /// or `iter_mut()`.
+    ///
+    /// **Known problems:** The suggested implementation uses the return type of the method as
+    /// the `IntoIter` type, which may need lifetime annotations to compile.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct MySlice<'a>(&'a [u8]);
+    /// impl<'a> MySlice<'a> {
+    ///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+    ///         self.0.iter()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct MySlice<'a>(&'a [u8]);
+    /// impl<'a> MySlice<'a> {
+    ///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+    ///         self.0.iter()
+    ///     }
+    /// }
+    /// impl<'a> IntoIterator for &MySlice<'a> {
+    ///     type Item = &'a u8;
+    ///     type IntoIter = std::slice::Iter<'a, u8>;
+    ///     fn into_iter(self) -> Self::IntoIter {
+    ///         self.iter()
+    ///     }
+    /// }
+    /// ```
+    pub ITER_WITHOUT_INTO_ITER,
+    pedantic,
+    "implementing `iter(_mut)` without an associated `IntoIterator for (&|&mut) Type` impl",
+    applicability: [Unspecified]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** This is the opposite of the `iter_without_into_iter` lint.
+    /// It looks for `IntoIterator for (&|&mut) Type` implementations without an inherent `iter`
+    /// or `iter_mut` method on the type.
+    ///
+    /// **Why is this bad?** It's not bad, but having them is idiomatic and allows the type to be
+    /// used in iterator chains by just calling `.iter()`, instead of the more awkward
+    /// `<&Type>::into_iter` or `(&val).into_iter()` syntax in case of ambiguity with another
+    /// `IntoIterator` impl.
+    ///
+    /// **Known problems:** Types implementing `Deref` are ignored, as they may already get an
+    /// `iter` method from their target.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct MySlice<'a>(&'a [u8]);
+    /// impl<'a> IntoIterator for &MySlice<'a> {
+    ///     type Item = &'a u8;
+    ///     type IntoIter = std::slice::Iter<'a, u8>;
+    ///     fn into_iter(self) -> Self::IntoIter {
+    ///         self.0.iter()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct MySlice<'a>(&'a [u8]);
+    /// impl<'a> MySlice<'a> {
+    ///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+    ///         self.into_iter()
+    ///     }
+    /// }
+    /// impl<'a> IntoIterator for &MySlice<'a> {
+    ///     type Item = &'a u8;
+    ///     type IntoIter = std::slice::Iter<'a, u8>;
+    ///     fn into_iter(self) -> Self::IntoIter {
+    ///         self.0.iter()
+    ///     }
+    /// }
+    /// ```
+    pub INTO_ITER_WITHOUT_ITER,
+    pedantic,
+    "implementing `IntoIterator for (&|&mut) Type` without an inherent `iter(_mut)` method",
+    applicability: [Unspecified]
+}
+
+declare_lint_pass!(IterWithoutIntoIter => [ITER_WITHOUT_INTO_ITER, INTO_ITER_WITHOUT_ITER]);
+
+impl<'tcx> LateLintPass<'tcx> for IterWithoutIntoIter {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if_chain! {
+            if !item.span.from_expansion();
+            if let ItemKind::Impl(ref imp) = item.kind;
+            if let Some(trait_ref) = &imp.of_trait;
+            if let Some(into_iter_did) = get_trait_def_id(cx, &paths::INTO_ITERATOR);
+            if trait_ref.trait_def_id() == Some(into_iter_did);
+            if let TyKind::Rptr(_, MutTy { ty: self_ty, mutbl }) = imp.self_ty.kind;
+            let ty = hir_ty_to_ty(cx.tcx, self_ty);
+            if let ty::Adt(adt, _) = ty.kind();
+            if let Some(local_did) = adt.did.as_local();
+            if cx.access_levels.is_exported(cx.tcx.hir().local_def_id_to_hir_id(local_did));
+            if !cx.tcx.lang_items().deref_trait().map_or(false, |id| implements_trait(cx, ty, id, &[]));
+            let expected_method_name = method_name(mutbl);
+            if !has_inherent_method(cx, adt.did, expected_method_name);
+            then {
+                let ref_self = if mutbl == Mutability::Mut { "mut " } else { "" };
+                span_lint_and_then(
+                    cx,
+                    INTO_ITER_WITHOUT_ITER,
+                    item.span,
+                    &format!(
+                        "`IntoIterator` implemented for a reference type without an `{}` method",
+                        expected_method_name
+                    ),
+                    |diag| {
+                        // The lifetime of the impl header is not needed for the method, but
+                        // keeping the generics as they are is simpler and still valid.
+                        let sugg = format!(
+                            "\n\nimpl{} {} {{\n    \
+                                pub fn {}(&{}self) -> <&{}Self as IntoIterator>::IntoIter {{\n        \
+                                    <&{}Self as IntoIterator>::into_iter(self)\n    \
+                                }}\n\
+                            }}",
+                            snippet(cx, imp.generics.span, ""),
+                            snippet(cx, self_ty.span, ".."),
+                            expected_method_name,
+                            ref_self,
+                            ref_self,
+                            ref_self,
+                        );
+                        diag.span_suggestion_verbose(
+                            item.span.shrink_to_hi(),
+                            &format!("consider implementing `{}`", expected_method_name),
+                            sugg,
+                            // Just like iter_without_into_iter, this suggestion is on a best effort
+                            // basis and requires potentially adding lifetimes or moving them
+                            // around.
+                            Applicability::Unspecified,
+                        );
+                    },
+                );
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        let name = item.ident.name.as_str();
+        let mutbl = match &*name {
+            "iter" => Mutability::Not,
+            "iter_mut" => Mutability::Mut,
+            _ => return,
+        };
+
+        let parent = cx.tcx.hir().expect_item(cx.tcx.hir().get_parent_item(item.hir_id));
+        if_chain! {
+            if !item.span.from_expansion();
+            if let ImplItemKind::Fn(ref sig, _) = item.kind;
+            if let ItemKind::Impl(ref imp) = parent.kind;
+            if imp.of_trait.is_none();
+            if cx.access_levels.is_exported(item.hir_id);
+            if sig.decl.inputs.len() == 1 && sig.decl.implicit_self.has_implicit_self();
+            if let FnRetTy::Return(ret_ty) = sig.decl.output;
+            let fn_sig = cx.tcx.erase_late_bound_regions(cx.tcx.fn_sig(cx.tcx.hir().local_def_id(item.hir_id)));
+            if let ty::Ref(_, self_ty, self_mutbl) = *fn_sig.inputs()[0].kind();
+            if self_mutbl == mutbl;
+            if let Some(iter_did) = get_trait_def_id(cx, &paths::ITERATOR);
+            if implements_trait(cx, fn_sig.output(), iter_did, &[]);
+            if let Some(into_iter_did) = get_trait_def_id(cx, &paths::INTO_ITERATOR);
+            if !implements_ref_into_iter(cx, self_ty, mutbl, into_iter_did);
+            then {
+                let ref_self = if mutbl == Mutability::Mut { "&mut " } else { "&" };
+                let self_ty_snippet = format!("{}{}", ref_self, snippet(cx, imp.self_ty.span, ".."));
+                span_lint_and_then(
+                    cx,
+                    ITER_WITHOUT_INTO_ITER,
+                    item.span,
+                    &format!("`{}` method without an `IntoIterator` impl for `{}`", name, self_ty_snippet),
+                    |diag| {
+                        // `impl Trait` can't be used as the `IntoIter` type
+                        if let TyKind::OpaqueDef(..) = ret_ty.kind {
+                            return;
+                        }
+                        let sugg = format!(
+                            "\n\nimpl{} IntoIterator for {} {{\n    \
+                                type IntoIter = {};\n    \
+                                type Item = <Self::IntoIter as Iterator>::Item;\n    \
+                                fn into_iter(self) -> Self::IntoIter {{\n        \
+                                    self.{}()\n    \
+                                }}\n\
+                            }}",
+                            snippet(cx, imp.generics.span, ""),
+                            self_ty_snippet,
+                            snippet(cx, ret_ty.span, ".."),
+                            name,
+                        );
+                        diag.span_suggestion_verbose(
+                            parent.span.shrink_to_hi(),
+                            &format!("consider implementing `IntoIterator` for `{}`", self_ty_snippet),
+                            sugg,
+                            // Suggestion may require adding lifetimes to the impl header.
+                            Applicability::Unspecified,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn method_name(mutbl: Mutability) -> &'static str {
+    match mutbl {
+        Mutability::Not => "iter",
+        Mutability::Mut => "iter_mut",
+    }
+}
+
+/// Checks if the type has an inherent method with the given name in any of its impl blocks.
+fn has_inherent_method(cx: &LateContext<'_>, did: DefId, name: &str) -> bool {
+    let name = Symbol::intern(name);
+    cx.tcx.inherent_impls(did).iter().any(|&imp| {
+        cx.tcx
+            .associated_items(imp)
+            .filter_by_name_unhygienic(name)
+            .any(|item| item.kind == AssocKind::Fn)
+    })
+}
+
+fn implements_ref_into_iter<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    mutbl: Mutability,
+    into_iter_did: DefId,
+) -> bool {
+    let ref_ty = cx.tcx.mk_ref(cx.tcx.lifetimes.re_erased, TypeAndMut { ty, mutbl });
+    implements_trait(cx, ref_ty, into_iter_did, &[])
+}
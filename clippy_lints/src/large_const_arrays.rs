@@ -6,7 +6,7 @@ use rustc_hir::{Item, ItemKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::interpret::ConstValue;
 use rustc_middle::ty::{self, ConstKind};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::{BytePos, Pos, Span};
 use rustc_typeck::hir_ty_to_ty;
 
@@ -29,7 +29,8 @@ declare_clippy_lint! {
     /// ```
     pub LARGE_CONST_ARRAYS,
     perf,
-    "large non-scalar const array may cause performance overhead"
+    "large non-scalar const array may cause performance overhead",
+    applicability: [MachineApplicable]
 }
 
 pub struct LargeConstArrays {
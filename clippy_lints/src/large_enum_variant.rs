@@ -5,7 +5,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind, VariantData};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_target::abi::LayoutOf;
 
 declare_clippy_lint! {
@@ -38,7 +38,8 @@ declare_clippy_lint! {
     /// ```
     pub LARGE_ENUM_VARIANT,
     perf,
-    "large size difference between variants on an enum"
+    "large size difference between variants on an enum",
+    applicability: [MaybeIncorrect]
 }
 
 #[derive(Copy, Clone)]
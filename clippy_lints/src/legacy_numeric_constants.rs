@@ -0,0 +1,182 @@
+use crate::utils::{in_macro, meets_msrv, msrvs, span_lint_and_help, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, Item, ItemKind, PrimTy, QPath, TyKind, UseKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::def_id::DefId;
+use rustc_span::symbol::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `<integer>::max_value()`, `std::<integer>::MAX`,
+    /// `std::<float>::EPSILON`, etc.
+    ///
+    /// **Why is this bad?** All of these have been superseded by the associated constants on
+    /// their respective types, such as `i128::MAX`. These legacy items may be deprecated in a
+    /// future version of rust.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let eps = std::f32::EPSILON;
+    /// let max = u32::max_value();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let eps = f32::EPSILON;
+    /// let max = u32::MAX;
+    /// ```
+    pub LEGACY_NUMERIC_CONSTANTS,
+    style,
+    "checks for usage of legacy std numeric constants and methods",
+    applicability: [MachineApplicable]
+}
+
+pub struct LegacyNumericConstants {
+    msrv: Option<RustcVersion>,
+}
+
+impl LegacyNumericConstants {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(LegacyNumericConstants => [LEGACY_NUMERIC_CONSTANTS]);
+
+const INTEGER_MODULES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+const FLOAT_MODULES: &[&str] = &["f32", "f64"];
+
+impl<'tcx> LateLintPass<'tcx> for LegacyNumericConstants {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::ASSOC_INT_CONSTS) || in_macro(item.span) {
+            return;
+        }
+
+        // Integer modules are "TBD" deprecated, and the contents are too,
+        // so lint on the `use` statement directly.
+        if let ItemKind::Use(path, kind @ (UseKind::Single | UseKind::Glob)) = item.kind {
+            let (msg, help) = match path.res {
+                Res::Def(DefKind::Mod, def_id) if is_integer_module(cx, def_id) => {
+                    let msg = if kind == UseKind::Glob {
+                        "importing legacy numeric constants"
+                    } else {
+                        "importing a legacy numeric constant module"
+                    };
+                    (
+                        msg,
+                        "remove this import and use the associated constants instead".to_string(),
+                    )
+                },
+                Res::Def(DefKind::Const, def_id) => {
+                    if let Some((module, name)) = legacy_constant(cx, def_id) {
+                        (
+                            "importing legacy numeric constants",
+                            format!(
+                                "remove this import and use the associated constant `{}::{}` instead",
+                                module, name
+                            ),
+                        )
+                    } else {
+                        return;
+                    }
+                },
+                _ => return,
+            };
+            span_lint_and_help(cx, LEGACY_NUMERIC_CONSTANTS, item.span, msg, None, &help);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::ASSOC_INT_CONSTS)
+            || in_macro(expr.span)
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+
+        let (span, sugg, msg) = match expr.kind {
+            // `std::<integer>::<CONST>` or `std::<float>::<CONST>`
+            ExprKind::Path(QPath::Resolved(None, path)) => {
+                if_chain! {
+                    // `u32::MAX` after `use std::u32;` is linted at the import instead, and a
+                    // single ident comes from importing the constant itself
+                    if path.segments.len() > 2;
+                    if let Res::Def(DefKind::Const, def_id) = path.res;
+                    if let Some((module, name)) = legacy_constant(cx, def_id);
+                    then {
+                        (
+                            expr.span,
+                            format!("{}::{}", module, name),
+                            "usage of a legacy numeric constant",
+                        )
+                    } else {
+                        return;
+                    }
+                }
+            },
+            // `<integer>::max_value()` or `<integer>::min_value()`
+            ExprKind::Call(func, []) => {
+                if_chain! {
+                    if let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind;
+                    if let TyKind::Path(QPath::Resolved(None, ty_path)) = ty.kind;
+                    if let Res::PrimTy(PrimTy::Int(_) | PrimTy::Uint(_)) = ty_path.res;
+                    let name = segment.ident.as_str();
+                    if name == "max_value" || name == "min_value";
+                    if let [.., ty_segment] = ty_path.segments;
+                    then {
+                        let constant = if name == "max_value" { "MAX" } else { "MIN" };
+                        (
+                            expr.span,
+                            format!("{}::{}", ty_segment.ident, constant),
+                            "usage of a legacy numeric method",
+                        )
+                    } else {
+                        return;
+                    }
+                }
+            },
+            _ => return,
+        };
+
+        span_lint_and_sugg(
+            cx,
+            LEGACY_NUMERIC_CONSTANTS,
+            span,
+            msg,
+            "use the associated constant instead",
+            sugg,
+            Applicability::MachineApplicable,
+        );
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks whether the `DefId` is one of the legacy integer modules, e.g. `core::u32`. The float
+/// modules are still needed for `f32::consts`.
+fn is_integer_module(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    match &*cx.get_def_path(def_id) {
+        [krate, module] => krate.as_str() == "core" && INTEGER_MODULES.contains(&&*module.as_str()),
+        _ => false,
+    }
+}
+
+/// If the `DefId` is a constant in one of the legacy numeric modules (e.g. `core::u32::MAX`),
+/// returns the module and constant names. Constants in `core::f32::consts` are not legacy.
+fn legacy_constant(cx: &LateContext<'_>, def_id: DefId) -> Option<(Symbol, Symbol)> {
+    match &*cx.get_def_path(def_id) {
+        [krate, module, name] if krate.as_str() == "core" => {
+            let module_str = module.as_str();
+            (INTEGER_MODULES.contains(&&*module_str) || FLOAT_MODULES.contains(&&*module_str)).then(|| (*module, *name))
+        },
+        _ => None,
+    }
+}
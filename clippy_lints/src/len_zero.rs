@@ -6,7 +6,7 @@ use rustc_hir::def_id::DefId;
 use rustc_hir::{AssocItemKind, BinOpKind, Expr, ExprKind, Impl, ImplItemRef, Item, ItemKind, TraitItemRef};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{Span, Spanned, Symbol};
 
 declare_clippy_lint! {
@@ -40,7 +40,8 @@ declare_clippy_lint! {
     /// ```
     pub LEN_ZERO,
     style,
-    "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead"
+    "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -102,7 +103,8 @@ declare_clippy_lint! {
     /// ```
     pub COMPARISON_TO_EMPTY,
     style,
-    "checking `x == \"\"` or `x == []` (or similar) when `.is_empty()` could be used instead"
+    "checking `x == \"\"` or `x == []` (or similar) when `.is_empty()` could be used instead",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(LenZero => [LEN_ZERO, LEN_WITHOUT_IS_EMPTY, COMPARISON_TO_EMPTY]);
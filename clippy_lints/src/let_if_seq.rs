@@ -5,7 +5,7 @@ use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::BindingAnnotation;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for variable declarations immediately followed by a
@@ -49,7 +49,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_LET_IF_SEQ,
     nursery,
-    "unidiomatic `let mut` declaration followed by initialization in `if`"
+    "unidiomatic `let mut` declaration followed by initialization in `if`",
+    applicability: [HasPlaceholders]
 }
 
 declare_lint_pass!(LetIfSeq => [USELESS_LET_IF_SEQ]);
@@ -35,6 +35,7 @@ extern crate rustc_index;
 extern crate rustc_infer;
 extern crate rustc_lexer;
 extern crate rustc_lint;
+extern crate rustc_metadata;
 extern crate rustc_middle;
 extern crate rustc_mir;
 extern crate rustc_parse;
@@ -47,8 +48,12 @@ extern crate rustc_typeck;
 
 use crate::utils::parse_msrv;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::LintId;
+use rustc_data_structures::sync;
+use rustc_lint::{EarlyLintPass, LateLintPass, Level, LintId};
 use rustc_session::Session;
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -60,6 +65,9 @@ use rustc_session::Session;
 ///    `Deny`. The lint level here has nothing to do with what lint groups the lint is a part of.
 /// 4. The `description` that contains a short explanation on what's wrong with code where the
 ///    lint is triggered.
+/// 5. If the lint makes suggestions, the `applicability` of the suggestions, listing the variants
+///    of `rustc_errors::Applicability` they use, for `--print-lints-json`. It is checked when the
+///    suggestions are made by the tests of Clippy.
 ///
 /// Currently the categories `style`, `correctness`, `complexity` and `perf` are enabled by default.
 /// As said in the README.md of this repository, if the lint level mapping changes, please update
@@ -97,6 +105,10 @@ use rustc_session::Session;
 /// [lint_naming]: https://rust-lang.github.io/rfcs/0344-conventions-galore.html#lints
 #[macro_export]
 macro_rules! declare_clippy_lint {
+    { $(#[$attr:meta])* pub $name:tt, $group:ident, $description:tt, applicability: [$($applicability:ident),+] } => {
+        const _: &[::rustc_errors::Applicability] = &[$(::rustc_errors::Applicability::$applicability),+];
+        $crate::declare_clippy_lint! { $(#[$attr])* pub $name, $group, $description }
+    };
     { $(#[$attr:meta])* pub $name:tt, style, $description:tt } => {
         declare_tool_lint! {
             $(#[$attr])* pub clippy::$name, Warn, $description, report_in_external_macro: true
@@ -149,17 +161,47 @@ macro_rules! declare_clippy_lint {
     };
 }
 
+/// Declares a lint pass like `rustc_session::declare_lint_pass!`, also implementing `PassLints`
+/// for the pass.
+macro_rules! declare_lint_pass {
+    ($(#[$m:meta])* $name:ident => [$($lint:expr),* $(,)?]) => {
+        $(#[$m])* #[derive(Copy, Clone)] pub struct $name;
+        impl_lint_pass!($name => [$($lint),*]);
+    };
+}
+
+/// Implements `LintPass` for a lint pass like `rustc_session::impl_lint_pass!`, also implementing
+/// `PassLints` for the pass.
+macro_rules! impl_lint_pass {
+    ($ty:ty => [$($lint:expr),* $(,)?]) => {
+        ::rustc_session::impl_lint_pass!($ty => [$($lint),*]);
+        impl crate::PassLints for $ty {
+            fn lints() -> ::rustc_session::lint::LintArray {
+                <$ty>::get_lints()
+            }
+        }
+    };
+}
+
+/// The lints emitted by a lint pass, implemented by `declare_lint_pass!` and `impl_lint_pass!`
+/// for `register_plugins` to only register the passes of the lints given to `--only`.
+trait PassLints {
+    fn lints() -> rustc_session::lint::LintArray;
+}
+
 mod consts;
 #[macro_use]
 mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod approx_const;
+mod arc_with_non_send_sync;
 mod arithmetic;
 mod as_conversions;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assign_ops;
+mod assigning_clones;
 mod async_yields_async;
 mod atomic_ordering;
 mod attrs;
@@ -168,6 +210,8 @@ mod bit_mask;
 mod blacklisted_name;
 mod blocks_in_if_conditions;
 mod booleans;
+mod box_default;
+mod byte_char_slices;
 mod bytecount;
 mod cargo_common_metadata;
 mod case_sensitive_file_extension_comparisons;
@@ -196,6 +240,7 @@ mod enum_clike;
 mod enum_variants;
 mod eq_op;
 mod erasing_op;
+mod error_impl_error;
 mod escape;
 mod eta_reduction;
 mod eval_order_dependence;
@@ -213,12 +258,15 @@ mod from_over_into;
 mod functions;
 mod future_not_send;
 mod get_last_with_len;
+mod hashset_insert_after_contains;
 mod identity_op;
 mod if_let_mutex;
 mod if_let_some_result;
 mod if_not_else;
 mod implicit_return;
 mod implicit_saturating_sub;
+mod implied_bounds_in_impls;
+mod incompatible_msrv;
 mod indexing_slicing;
 mod infinite_iter;
 mod inherent_impl;
@@ -227,9 +275,11 @@ mod inline_fn_without_body;
 mod int_plus_one;
 mod integer_division;
 mod items_after_statements;
+mod iter_without_into_iter;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_stack_arrays;
+mod legacy_numeric_constants;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
@@ -239,8 +289,14 @@ mod loops;
 mod macro_use;
 mod main_recursion;
 mod manual_async_fn;
+mod manual_clamp;
+mod manual_div_ceil;
+mod manual_hash_one;
+mod manual_ignore_case_cmp;
 mod manual_non_exhaustive;
 mod manual_ok_or;
+mod manual_retain;
+mod manual_rotate;
 mod manual_strip;
 mod manual_unwrap_or;
 mod map_clone;
@@ -260,6 +316,7 @@ mod missing_const_for_fn;
 mod missing_doc;
 mod missing_inline;
 mod modulo_arithmetic;
+mod multiple_bound_locations;
 mod multiple_crate_versions;
 mod mut_key;
 mod mut_mut;
@@ -281,6 +338,8 @@ mod new_without_default;
 mod no_effect;
 mod non_copy_const;
 mod non_expressive_names;
+mod non_zero_suggestions;
+mod not_using_associated_type;
 mod open_options;
 mod option_env_unwrap;
 mod option_if_let_else;
@@ -288,6 +347,7 @@ mod overflow_check_conditional;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod partialeq_ne_impl;
+mod partialeq_to_none;
 mod pass_by_ref_or_value;
 mod path_buf_push_overwrite;
 mod pattern_type_mismatch;
@@ -308,20 +368,24 @@ mod ref_option_ref;
 mod reference;
 mod regex;
 mod repeat_once;
+mod repeat_vec_with_capacity;
 mod returns;
 mod self_assignment;
 mod serde_api;
 mod shadow;
 mod single_component_path_imports;
+mod single_range_in_vec_init;
 mod size_of_in_element_count;
 mod slow_vector_initialization;
 mod stable_sort_primitive;
 mod strings;
+mod struct_field_names;
 mod suspicious_operation_groupings;
 mod suspicious_trait_impl;
 mod swap;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
+mod thread_local_initializer_can_be_made_const;
 mod to_digit_is_some;
 mod to_string_in_display;
 mod trait_bounds;
@@ -329,15 +393,20 @@ mod transmute;
 mod transmuting_null;
 mod try_err;
 mod types;
+mod unconditional_recursion;
 mod undropped_manually_drops;
 mod unicode;
 mod unit_return_expecting_ord;
 mod unnamed_address;
+mod unnecessary_debug_formatting;
+mod unnecessary_literal_bound;
 mod unnecessary_sort_by;
 mod unnecessary_wraps;
 mod unnested_or_patterns;
 mod unsafe_removed_from_name;
 mod unused_io_amount;
+mod unused_lint_allows;
+mod unused_result_ok;
 mod unused_self;
 mod unused_unit;
 mod unwrap;
@@ -349,11 +418,13 @@ mod vec;
 mod vec_init_then_push;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
+mod visibility;
 mod wildcard_dependencies;
 mod wildcard_imports;
 mod write;
 mod zero_div_zero;
 mod zero_sized_map_values;
+mod zombie_processes;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 pub use crate::utils::conf::Conf;
@@ -366,73 +437,336 @@ pub use crate::utils::conf::Conf;
 /// level (i.e `#![cfg_attr(...)]`) will still be expanded even when using a pre-expansion pass.
 ///
 /// Used in `./src/driver.rs`.
-pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore) {
+pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, only_lints: Option<&[String]>) {
+    let store = &mut ClippyLintStore::new(store, only_lints);
     store.register_pre_expansion_pass(|| box write::Write::default());
     store.register_pre_expansion_pass(|| box attrs::EarlyAttributes);
     store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro);
 }
 
-#[doc(hidden)]
-pub fn read_conf(args: &[rustc_ast::NestedMetaItem], sess: &Session) -> Conf {
-    use std::path::Path;
-    match utils::conf::file_from_args(args) {
-        Ok(file_name) => {
-            // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
-            // do not require the file to exist
-            let file_name = match file_name {
-                Some(file_name) => file_name,
-                None => match utils::conf::lookup_conf_file() {
-                    Ok(Some(path)) => path,
-                    Ok(None) => return Conf::default(),
-                    Err(error) => {
-                        sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
-                            .emit();
-                        return Conf::default();
-                    },
-                },
-            };
+/// The kinds of targets which are only linted if the `lint-tests`, `lint-examples` and
+/// `lint-benches` options are set, as they are by default.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TargetKind {
+    Test,
+    Example,
+    Bench,
+}
+
+/// The configuration of Clippy, read once per compilation from the configuration files and the
+/// `CLIPPY_CONF_<KEY>` environment variables, before the session is created. The errors found
+/// reading it are reported by `report_errors` once it is.
+pub struct LoadedConf {
+    /// The configuration files read, each followed by the files it extends
+    files: Vec<PathBuf>,
+    conf: Conf,
+    /// The error finding the configuration files, if any
+    lookup_error: Option<std::io::Error>,
+    /// The errors following the `extends` keys of the configuration files
+    extends_errors: Vec<(PathBuf, utils::conf::Error)>,
+    /// The errors of the configuration values, by source
+    errors: Vec<(utils::conf::Source, utils::conf::Error)>,
+    /// The source of each configured value, noted with `cargo clippy --verbose`
+    sources: BTreeMap<String, utils::conf::Source>,
+}
 
-            let file_name = if file_name.is_relative() {
-                sess.local_crate_source_file
-                    .as_deref()
-                    .and_then(Path::parent)
-                    .unwrap_or_else(|| Path::new(""))
-                    .join(file_name)
-            } else {
-                file_name
-            };
+impl LoadedConf {
+    /// Reads the configuration file given, which must exist, or the `clippy.toml` files of the
+    /// workspace, which are optional. Relative paths are relative to the directory of the root
+    /// file of the crate.
+    #[must_use]
+    pub fn load(file_name: Option<PathBuf>, crate_dir: &Path) -> Self {
+        let (file_names, lookup_error) = match file_name {
+            Some(file_name) => (vec![file_name], None),
+            None => match utils::conf::lookup_conf_files() {
+                Ok(file_names) => (file_names, None),
+                Err(error) => (Vec::new(), Some(error)),
+            },
+        };
+        let file_names: Vec<_> = file_names.iter().map(|file_name| crate_dir.join(file_name)).collect();
+        let (files, extends_errors) = utils::conf::with_extended_files(&file_names);
+        let (conf, errors, sources) = utils::conf::read_files(&files);
+        Self {
+            files,
+            conf,
+            lookup_error,
+            extends_errors,
+            errors,
+            sources,
+        }
+    }
+
+    #[must_use]
+    pub fn conf(&self) -> &Conf {
+        &self.conf
+    }
+
+    /// Returns the configuration files read, to cache the lints of the crate.
+    #[must_use]
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
 
-            let (conf, errors) = utils::conf::read(&file_name);
+    /// Returns the lint levels of the `[levels]` table, to be passed to the compiler before the
+    /// levels given on the command line. When compiling with `--test`, they are followed by the
+    /// ones of the `[test-overrides.levels]` table.
+    #[must_use]
+    pub fn levels(&self, test: bool) -> Vec<(String, Level)> {
+        let mut levels = utils::conf::lint_levels(&self.conf.levels);
+        if test {
+            levels.extend(utils::conf::lint_levels(&self.conf.test_overrides.levels));
+        }
+        levels
+            .into_iter()
+            .filter_map(|(name, level)| Some((name, Level::from_str(level)?)))
+            .collect()
+    }
 
-            // all conf errors are non-fatal, we just use the default conf in case of error
-            for error in errors {
-                sess.struct_err(&format!(
-                    "error reading Clippy's configuration file `{}`: {}",
-                    file_name.display(),
-                    error
-                ))
+    /// Checks whether the targets of the kind are linted according to the configuration.
+    #[must_use]
+    pub fn is_target_linted(&self, kind: TargetKind) -> bool {
+        match kind {
+            TargetKind::Test => self.conf.lint_tests,
+            TargetKind::Example => self.conf.lint_examples,
+            TargetKind::Bench => self.conf.lint_benches,
+        }
+    }
+
+    /// Reports the errors found reading the configuration. They are all non-fatal: the invalid
+    /// values are left to their defaults.
+    pub fn report_errors(&self, sess: &Session) {
+        if let Some(error) = &self.lookup_error {
+            sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
                 .emit();
+        }
+
+        for (file_name, error) in &self.extends_errors {
+            sess.struct_err(&format!(
+                "error reading Clippy's configuration {}: {}",
+                utils::conf::Source::File(file_name.clone()),
+                error
+            ))
+            .emit();
+        }
+
+        for (source, error) in &self.errors {
+            if error.is_warning() {
+                let mut diag = sess.struct_warn(&format!("in Clippy's configuration {}: {}", source, error));
+                if let utils::conf::Source::Env(_) = source {
+                    diag.note(
+                        "`CLIPPY_CONF_<KEY>` environment variables override the option `<key>` of the \
+                         configuration files",
+                    );
+                }
+                diag.emit();
+            } else {
+                sess.struct_err(&format!("error reading Clippy's configuration {}: {}", source, error))
+                    .emit();
             }
+        }
 
-            conf
-        },
-        Err((err, span)) => {
-            sess.struct_span_err(span, err)
-                .span_note(span, "Clippy will use default configuration")
-                .emit();
-            Conf::default()
-        },
+        let levels = self.conf.levels.iter().chain(&self.conf.test_overrides.levels);
+        for (name, level) in levels.filter_map(|(name, level)| Some((name, level.level()?))) {
+            if Level::from_str(level).is_none() {
+                sess.err(&format!(
+                    "error reading Clippy's configuration: unknown lint level `{}` for `{}`, expected one of \
+                     `allow`, `warn`, `deny` or `forbid`",
+                    level, name
+                ));
+            }
+        }
+
+        // `cargo clippy --verbose` shows where the configuration comes from
+        if std::env::var_os("CLIPPY_VERBOSE").is_some() {
+            for (key, source) in &self.sources {
+                sess.note_without_error(&format!(
+                    "Clippy's configuration value `{}` is read from the {}",
+                    key, source
+                ));
+            }
+        }
+    }
+}
+
+/// Allows the lints of Clippy and of the plugins in the files filtered out by the `exclude` and
+/// `lint-generated-code` options, once the crate is expanded and before it is linted.
+///
+/// Used in `./src/driver.rs`.
+pub fn allow_filtered_lints(sess: &Session, krate: &mut rustc_ast::ast::Crate, store: &rustc_lint::LintStore) {
+    utils::file_filter::allow_filtered_lints(sess, krate, store);
+}
+
+/// Returns the description of the configuration options as JSON, printed by
+/// `clippy-driver --print-config-schema`.
+#[must_use]
+pub fn config_schema() -> String {
+    utils::conf::schema()
+}
+
+/// Returns the documentation of a lint, printed by `clippy-driver --explain <lint>`.
+#[must_use]
+pub fn explain(lint: &str) -> Option<String> {
+    utils::lint_docs::explain(lint)
+}
+
+/// Returns the name, group, default level, description, applicabilities of the suggestions and
+/// documentation of the registered Clippy lints as JSON, printed by `clippy-driver
+/// --print-lints-json`.
+#[must_use]
+pub fn lint_metadata(store: &rustc_lint::LintStore) -> String {
+    let groups = store.get_lint_groups();
+    let lints: Vec<_> = store
+        .get_lints()
+        .iter()
+        .filter_map(|lint| {
+            let name = lint.name_lower();
+            let name = name.strip_prefix("clippy::")?.to_string();
+            let group = groups
+                .iter()
+                .filter(|(group, _, _)| group.starts_with("clippy::") && *group != "clippy::all")
+                .find(|(_, lints, _)| lints.contains(&LintId::of(lint)))
+                .map_or("internal", |(group, _, _)| &group["clippy::".len()..]);
+            let (docs, applicability) = utils::lint_docs::docs(&name).unwrap_or_default();
+            Some(serde_json::json!({
+                "name": name,
+                "group": group,
+                "level": lint.default_level.as_str(),
+                "description": lint.desc,
+                "applicability": applicability,
+                "docs": docs,
+            }))
+        })
+        .collect();
+    serde_json::to_string_pretty(&lints).expect("the lints can be serialized")
+}
+
+/// Returns the lints given to `--only` which aren't Clippy lints, once the lints are registered.
+#[must_use]
+pub fn unknown_only_lints(store: &rustc_lint::LintStore, lints: &[String]) -> Vec<String> {
+    lints
+        .iter()
+        .map(|lint| only_lint_name(lint))
+        .filter(|name| !store.get_lints().iter().any(|lint| lint.name_lower() == *name))
+        .collect()
+}
+
+/// Makes the registered lint passes measure the time they take, for `clippy-driver
+/// --profile-lints`, see `lint_pass_profile`.
+pub fn profile_lint_passes(store: &mut rustc_lint::LintStore) {
+    utils::profile::measure_lint_passes(store);
+}
+
+/// Returns the lint passes which took the most time linting the crate, once the passes profiled by
+/// `profile_lint_passes` are done, with the time they took.
+#[must_use]
+pub fn lint_pass_profile(crate_name: &str, count: usize) -> String {
+    utils::profile::report(crate_name, count)
+}
+
+/// Builds a list of names from a list-valued configuration option, reporting the invalid
+/// regular expressions.
+fn read_name_list(sess: &Session, option: &str, entries: &[String]) -> utils::conf::NameList {
+    let (list, errors) = utils::conf::NameList::new(entries);
+    for error in errors {
+        sess.err(&format!(
+            "error reading Clippy's configuration: `{}` contains an {}",
+            option, error
+        ));
     }
+    list
+}
+
+/// The lint store the lint passes of Clippy are registered in, which leaves out the passes
+/// emitting none of the lints given to `--only`.
+struct ClippyLintStore<'a> {
+    store: &'a mut rustc_lint::LintStore,
+    only_lints: Option<Vec<String>>,
+}
+
+impl<'a> ClippyLintStore<'a> {
+    fn new(store: &'a mut rustc_lint::LintStore, only_lints: Option<&[String]>) -> Self {
+        Self {
+            store,
+            only_lints: only_lints.map(|lints| lints.iter().map(|lint| only_lint_name(lint)).collect()),
+        }
+    }
+
+    fn is_registered<P: PassLints>(&self) -> bool {
+        self.only_lints.as_ref().map_or(true, |only_lints| {
+            P::lints()
+                .iter()
+                .any(|lint| only_lints.iter().any(|name| lint.name_lower() == *name))
+        })
+    }
+
+    fn register_pre_expansion_pass<P>(&mut self, pass: impl Fn() -> Box<P> + sync::Send + sync::Sync + 'static)
+    where
+        P: PassLints + EarlyLintPass + sync::Send + sync::Sync + 'static,
+    {
+        if self.is_registered::<P>() {
+            self.store.register_pre_expansion_pass(move || pass());
+        }
+    }
+
+    fn register_early_pass<P>(&mut self, pass: impl Fn() -> Box<P> + sync::Send + sync::Sync + 'static)
+    where
+        P: PassLints + EarlyLintPass + sync::Send + sync::Sync + 'static,
+    {
+        if self.is_registered::<P>() {
+            self.store.register_early_pass(move || pass());
+        }
+    }
+
+    fn register_late_pass<P>(&mut self, pass: impl Fn() -> Box<P> + sync::Send + sync::Sync + 'static)
+    where
+        P: PassLints + for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync + 'static,
+    {
+        if self.is_registered::<P>() {
+            self.store.register_late_pass(move || pass());
+        }
+    }
+}
+
+impl Deref for ClippyLintStore<'_> {
+    type Target = rustc_lint::LintStore;
+
+    fn deref(&self) -> &Self::Target {
+        self.store
+    }
+}
+
+impl DerefMut for ClippyLintStore<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.store
+    }
+}
+
+/// Returns the name of a Clippy lint given to `--only` like `clippy::needless_return`,
+/// `needless_return` or `needless-return`, as it is registered.
+fn only_lint_name(lint: &str) -> String {
+    let lint = lint.strip_prefix("clippy::").unwrap_or(lint);
+    format!("clippy::{}", lint.replace('-', "_").to_ascii_lowercase())
 }
 
 /// Register all lints and lint groups with the rustc plugin registry
 ///
+/// The lint passes emitting none of the `only_lints` aren't registered, for `cargo clippy --
+/// --only <lints>`. The other lints of the passes registered are still emitted.
+///
 /// Used in `./src/driver.rs`.
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
-pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+pub fn register_plugins(
+    store: &mut rustc_lint::LintStore,
+    sess: &Session,
+    conf: &Conf,
+    only_lints: Option<&[String]>,
+) {
+    let store = &mut ClippyLintStore::new(store, only_lints);
     register_removed_non_tool_lints(store);
 
+    utils::file_filter::filter_files(&conf.exclude, conf.lint_generated_code);
+
     // begin deprecated lints, do not remove this comment, it’s used in `update_lints`
     store.register_removed(
         "clippy::should_assert_eq",
@@ -541,6 +875,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         #[cfg(feature = "internal-lints")]
         &utils::internal_lints::UNNECESSARY_SYMBOL_STR,
         &approx_const::APPROX_CONSTANT,
+        &arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC,
         &arithmetic::FLOAT_ARITHMETIC,
         &arithmetic::INTEGER_ARITHMETIC,
         &as_conversions::AS_CONVERSIONS,
@@ -549,14 +884,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &assertions_on_constants::ASSERTIONS_ON_CONSTANTS,
         &assign_ops::ASSIGN_OP_PATTERN,
         &assign_ops::MISREFACTORED_ASSIGN_OP,
+        &assigning_clones::ASSIGNING_CLONES,
         &async_yields_async::ASYNC_YIELDS_ASYNC,
         &atomic_ordering::INVALID_ATOMIC_ORDERING,
+        &attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON,
         &attrs::BLANKET_CLIPPY_RESTRICTION_LINTS,
         &attrs::DEPRECATED_CFG_ATTR,
+        &attrs::DEPRECATED_CLIPPY_CFG_ATTR,
         &attrs::DEPRECATED_SEMVER,
         &attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
         &attrs::INLINE_ALWAYS,
         &attrs::MISMATCHED_TARGET_OS,
+        &attrs::MIXED_ATTRIBUTES_STYLE,
         &attrs::USELESS_ATTRIBUTE,
         &await_holding_invalid::AWAIT_HOLDING_LOCK,
         &await_holding_invalid::AWAIT_HOLDING_REFCELL_REF,
@@ -567,6 +906,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS,
         &booleans::LOGIC_BUG,
         &booleans::NONMINIMAL_BOOL,
+        &box_default::BOX_DEFAULT,
+        &byte_char_slices::BYTE_CHAR_SLICES,
         &bytecount::NAIVE_BYTECOUNT,
         &cargo_common_metadata::CARGO_COMMON_METADATA,
         &case_sensitive_file_extension_comparisons::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
@@ -590,7 +931,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &derive::EXPL_IMPL_CLONE_ON_COPY,
         &derive::UNSAFE_DERIVE_DESERIALIZE,
         &disallowed_method::DISALLOWED_METHOD,
+        &doc::DOC_INCLUDE_WITHOUT_CFG,
+        &doc::DOC_LAZY_CONTINUATION,
         &doc::DOC_MARKDOWN,
+        &doc::EMPTY_DOCS,
         &doc::MISSING_ERRORS_DOC,
         &doc::MISSING_SAFETY_DOC,
         &doc::NEEDLESS_DOCTEST_MAIN,
@@ -612,6 +956,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &eq_op::EQ_OP,
         &eq_op::OP_REF,
         &erasing_op::ERASING_OP,
+        &error_impl_error::ERROR_IMPL_ERROR,
         &escape::BOXED_LOCAL,
         &eta_reduction::REDUNDANT_CLOSURE,
         &eta_reduction::REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
@@ -639,17 +984,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &functions::MUST_USE_CANDIDATE,
         &functions::MUST_USE_UNIT,
         &functions::NOT_UNSAFE_PTR_ARG_DEREF,
+        &functions::RESULT_LARGE_ERR,
         &functions::RESULT_UNIT_ERR,
         &functions::TOO_MANY_ARGUMENTS,
         &functions::TOO_MANY_LINES,
         &future_not_send::FUTURE_NOT_SEND,
         &get_last_with_len::GET_LAST_WITH_LEN,
+        &hashset_insert_after_contains::HASHSET_INSERT_AFTER_CONTAINS,
         &identity_op::IDENTITY_OP,
         &if_let_mutex::IF_LET_MUTEX,
         &if_let_some_result::IF_LET_SOME_RESULT,
         &if_not_else::IF_NOT_ELSE,
         &implicit_return::IMPLICIT_RETURN,
         &implicit_saturating_sub::IMPLICIT_SATURATING_SUB,
+        &implied_bounds_in_impls::IMPLIED_BOUNDS_IN_IMPLS,
+        &incompatible_msrv::INCOMPATIBLE_MSRV,
         &indexing_slicing::INDEXING_SLICING,
         &indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         &infinite_iter::INFINITE_ITER,
@@ -661,9 +1010,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &int_plus_one::INT_PLUS_ONE,
         &integer_division::INTEGER_DIVISION,
         &items_after_statements::ITEMS_AFTER_STATEMENTS,
+        &iter_without_into_iter::INTO_ITER_WITHOUT_ITER,
+        &iter_without_into_iter::ITER_WITHOUT_INTO_ITER,
         &large_const_arrays::LARGE_CONST_ARRAYS,
         &large_enum_variant::LARGE_ENUM_VARIANT,
         &large_stack_arrays::LARGE_STACK_ARRAYS,
+        &legacy_numeric_constants::LEGACY_NUMERIC_CONSTANTS,
         &len_zero::COMPARISON_TO_EMPTY,
         &len_zero::LEN_WITHOUT_IS_EMPTY,
         &len_zero::LEN_ZERO,
@@ -699,8 +1051,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &macro_use::MACRO_USE_IMPORTS,
         &main_recursion::MAIN_RECURSION,
         &manual_async_fn::MANUAL_ASYNC_FN,
+        &manual_clamp::MANUAL_CLAMP,
+        &manual_div_ceil::MANUAL_DIV_CEIL,
+        &manual_hash_one::MANUAL_HASH_ONE,
+        &manual_ignore_case_cmp::MANUAL_IGNORE_CASE_CMP,
         &manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         &manual_ok_or::MANUAL_OK_OR,
+        &manual_retain::MANUAL_RETAIN,
+        &manual_rotate::MANUAL_ROTATE,
         &manual_strip::MANUAL_STRIP,
         &manual_unwrap_or::MANUAL_UNWRAP_OR,
         &map_clone::MAP_CLONE,
@@ -719,6 +1077,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &matches::MATCH_SINGLE_BINDING,
         &matches::MATCH_WILDCARD_FOR_SINGLE_VARIANTS,
         &matches::MATCH_WILD_ERR_ARM,
+        &matches::REDUNDANT_GUARDS,
         &matches::REDUNDANT_PATTERN_MATCHING,
         &matches::REST_PAT_IN_FULLY_BOUND_STRUCTS,
         &matches::SINGLE_MATCH,
@@ -736,6 +1095,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &methods::CLONE_DOUBLE_REF,
         &methods::CLONE_ON_COPY,
         &methods::CLONE_ON_REF_PTR,
+        &methods::DRAIN_COLLECT,
         &methods::EXPECT_FUN_CALL,
         &methods::EXPECT_USED,
         &methods::FILETYPE_IS_FILE,
@@ -753,26 +1113,37 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &methods::ITER_NEXT_SLICE,
         &methods::ITER_NTH,
         &methods::ITER_NTH_ZERO,
+        &methods::ITER_ON_EMPTY_COLLECTIONS,
+        &methods::ITER_ON_SINGLE_ITEMS,
         &methods::ITER_SKIP_NEXT,
+        &methods::ITER_SKIP_ZERO,
         &methods::MANUAL_FILTER_MAP,
         &methods::MANUAL_FIND_MAP,
         &methods::MANUAL_SATURATING_ARITHMETIC,
+        &methods::MAP_ALL_ANY_IDENTITY,
         &methods::MAP_COLLECT_RESULT_UNIT,
         &methods::MAP_FLATTEN,
         &methods::MAP_UNWRAP_OR,
+        &methods::NEEDLESS_AS_BYTES,
+        &methods::NEEDLESS_CHARACTER_ITERATION,
         &methods::NEW_RET_NO_SELF,
         &methods::OK_EXPECT,
         &methods::OPTION_AS_REF_DEREF,
+        &methods::OPTION_MAP_OR_ERR_OK,
         &methods::OPTION_MAP_OR_NONE,
         &methods::OR_FUN_CALL,
         &methods::RESULT_MAP_OR_INTO_OPTION,
         &methods::SEARCH_IS_SOME,
+        &methods::SEEK_FROM_CURRENT,
+        &methods::SEEK_TO_START_INSTEAD_OF_REWIND,
         &methods::SHOULD_IMPLEMENT_TRAIT,
         &methods::SINGLE_CHAR_ADD_STR,
         &methods::SINGLE_CHAR_PATTERN,
         &methods::SKIP_WHILE_NEXT,
+        &methods::SLICED_STRING_AS_BYTES,
         &methods::STRING_EXTEND_CHARS,
         &methods::SUSPICIOUS_MAP,
+        &methods::UNBUFFERED_BYTES,
         &methods::UNINIT_ASSUMED_INIT,
         &methods::UNNECESSARY_FILTER_MAP,
         &methods::UNNECESSARY_FOLD,
@@ -805,6 +1176,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         &missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
         &modulo_arithmetic::MODULO_ARITHMETIC,
+        &multiple_bound_locations::MULTIPLE_BOUND_LOCATIONS,
         &multiple_crate_versions::MULTIPLE_CRATE_VERSIONS,
         &mut_key::MUTABLE_KEY_TYPE,
         &mut_mut::MUT_MUT,
@@ -832,6 +1204,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         &non_expressive_names::MANY_SINGLE_CHAR_NAMES,
         &non_expressive_names::SIMILAR_NAMES,
+        &non_zero_suggestions::NON_ZERO_SUGGESTIONS,
+        &not_using_associated_type::NOT_USING_ASSOCIATED_CONST,
+        &not_using_associated_type::NOT_USING_ASSOCIATED_TYPE,
         &open_options::NONSENSICAL_OPEN_OPTIONS,
         &option_env_unwrap::OPTION_ENV_UNWRAP,
         &option_if_let_else::OPTION_IF_LET_ELSE,
@@ -842,6 +1217,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &panic_unimplemented::UNIMPLEMENTED,
         &panic_unimplemented::UNREACHABLE,
         &partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        &partialeq_to_none::PARTIALEQ_TO_NONE,
         &pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
         &pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         &path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
@@ -871,14 +1247,17 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &regex::INVALID_REGEX,
         &regex::TRIVIAL_REGEX,
         &repeat_once::REPEAT_ONCE,
+        &repeat_vec_with_capacity::REPEAT_VEC_WITH_CAPACITY,
         &returns::LET_AND_RETURN,
         &returns::NEEDLESS_RETURN,
+        &returns::NEEDLESS_RETURN_WITH_QUESTION_MARK,
         &self_assignment::SELF_ASSIGNMENT,
         &serde_api::SERDE_API_MISUSE,
         &shadow::SHADOW_REUSE,
         &shadow::SHADOW_SAME,
         &shadow::SHADOW_UNRELATED,
         &single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
+        &single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT,
         &size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         &slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         &stable_sort_primitive::STABLE_SORT_PRIMITIVE,
@@ -888,6 +1267,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &strings::STRING_LIT_AS_BYTES,
         &strings::STRING_TO_STRING,
         &strings::STR_TO_STRING,
+        &struct_field_names::STRUCT_FIELD_NAMES,
         &suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS,
         &suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         &suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
@@ -895,6 +1275,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &swap::MANUAL_SWAP,
         &tabs_in_doc_comments::TABS_IN_DOC_COMMENTS,
         &temporary_assignment::TEMPORARY_ASSIGNMENT,
+        &thread_local_initializer_can_be_made_const::THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
         &to_digit_is_some::TO_DIGIT_IS_SOME,
         &to_string_in_display::TO_STRING_IN_DISPLAY,
         &trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS,
@@ -917,6 +1298,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::BORROWED_BOX,
         &types::BOX_VEC,
         &types::CAST_LOSSLESS,
+        &types::CAST_NAN_TO_INT,
         &types::CAST_POSSIBLE_TRUNCATION,
         &types::CAST_POSSIBLE_WRAP,
         &types::CAST_PRECISION_LOSS,
@@ -939,6 +1321,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &types::UNIT_CMP,
         &types::UNNECESSARY_CAST,
         &types::VEC_BOX,
+        &unconditional_recursion::UNCONDITIONAL_RECURSION,
         &undropped_manually_drops::UNDROPPED_MANUALLY_DROPS,
         &unicode::INVISIBLE_CHARACTERS,
         &unicode::NON_ASCII_LITERAL,
@@ -946,11 +1329,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD,
         &unnamed_address::FN_ADDRESS_COMPARISONS,
         &unnamed_address::VTABLE_ADDRESS_COMPARISONS,
+        &unnecessary_debug_formatting::UNNECESSARY_DEBUG_FORMATTING,
+        &unnecessary_literal_bound::UNNECESSARY_LITERAL_BOUND,
         &unnecessary_sort_by::UNNECESSARY_SORT_BY,
         &unnecessary_wraps::UNNECESSARY_WRAPS,
         &unnested_or_patterns::UNNESTED_OR_PATTERNS,
         &unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         &unused_io_amount::UNUSED_IO_AMOUNT,
+        &unused_lint_allows::UNUSED_LINT_ALLOWS,
+        &unused_result_ok::UNUSED_RESULT_OK,
         &unused_self::UNUSED_SELF,
         &unused_unit::UNUSED_UNIT,
         &unwrap::PANICKING_UNWRAP,
@@ -963,6 +1350,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &vec_init_then_push::VEC_INIT_THEN_PUSH,
         &vec_resize_to_zero::VEC_RESIZE_TO_ZERO,
         &verbose_file_reads::VERBOSE_FILE_READS,
+        &visibility::NEEDLESS_PUB_SELF,
+        &visibility::PUB_WITHOUT_SHORTHAND,
+        &visibility::PUB_WITH_SHORTHAND,
         &wildcard_dependencies::WILDCARD_DEPENDENCIES,
         &wildcard_imports::ENUM_GLOB_USE,
         &wildcard_imports::WILDCARD_IMPORTS,
@@ -977,6 +1367,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         &write::WRITE_WITH_NEWLINE,
         &zero_div_zero::ZERO_DIVIDED_BY_ZERO,
         &zero_sized_map_values::ZERO_SIZED_MAP_VALUES,
+        &zombie_processes::ZOMBIE_PROCESSES,
     ]);
     // end register lints, do not remove this comment, it’s used in `update_lints`
 
@@ -1017,7 +1408,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box mut_mut::MutMut);
     store.register_late_pass(|| box mut_reference::UnnecessaryMutPassed);
     store.register_late_pass(|| box len_zero::LenZero);
-    store.register_late_pass(|| box attrs::Attributes);
+    let require_allow_reason = conf.require_allow_reason;
+    store.register_late_pass(move || box attrs::Attributes::new(require_allow_reason));
     store.register_late_pass(|| box blocks_in_if_conditions::BlocksInIfConditions);
     store.register_late_pass(|| box collapsible_match::CollapsibleMatch);
     store.register_late_pass(|| box unicode::Unicode);
@@ -1046,6 +1438,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box use_self::UseSelf::new(msrv));
     store.register_late_pass(move || box missing_const_for_fn::MissingConstForFn::new(msrv));
     store.register_late_pass(move || box needless_question_mark::NeedlessQuestionMark::new(msrv));
+    store.register_late_pass(move || box manual_hash_one::ManualHashOne::new(msrv));
+    store.register_late_pass(move || box thread_local_initializer_can_be_made_const::ThreadLocalInitializerCanBeMadeConst::new(msrv));
+    store.register_late_pass(move || box incompatible_msrv::IncompatibleMsrv::new(msrv));
+    store.register_late_pass(move || box assigning_clones::AssigningClones::new(msrv));
+    store.register_late_pass(move || box legacy_numeric_constants::LegacyNumericConstants::new(msrv));
+    store.register_late_pass(move || box manual_div_ceil::ManualDivCeil::new(msrv));
+    store.register_late_pass(move || box manual_retain::ManualRetain::new(msrv));
+    store.register_late_pass(move || box manual_clamp::ManualClamp::new(msrv));
 
     store.register_late_pass(|| box size_of_in_element_count::SizeOfInElementCount);
     store.register_late_pass(|| box map_clone::MapClone);
@@ -1071,7 +1471,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box temporary_assignment::TemporaryAssignment);
     store.register_late_pass(|| box transmute::Transmute);
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
-    store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
+    let test_cognitive_complexity_threshold = conf.test_overrides.cognitive_complexity_threshold;
+    store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold, test_cognitive_complexity_threshold));
     let too_large_for_stack = conf.too_large_for_stack;
     store.register_late_pass(move || box escape::BoxedLocal{too_large_for_stack});
     store.register_late_pass(move || box vec::UselessVec{too_large_for_stack});
@@ -1091,12 +1492,21 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| box swap::Swap);
     store.register_late_pass(|| box overflow_check_conditional::OverflowCheckConditional);
     store.register_late_pass(|| box new_without_default::NewWithoutDefault::default());
-    let blacklisted_names = conf.blacklisted_names.iter().cloned().collect::<FxHashSet<_>>();
+    let blacklisted_names = read_name_list(sess, "blacklisted-names", &conf.blacklisted_names);
     store.register_late_pass(move || box blacklisted_name::BlacklistedName::new(blacklisted_names.clone()));
     let too_many_arguments_threshold1 = conf.too_many_arguments_threshold;
     let too_many_lines_threshold2 = conf.too_many_lines_threshold;
-    store.register_late_pass(move || box functions::Functions::new(too_many_arguments_threshold1, too_many_lines_threshold2));
-    let doc_valid_idents = conf.doc_valid_idents.iter().cloned().collect::<FxHashSet<_>>();
+    let large_error_threshold = conf.large_error_threshold;
+    let test_overrides = conf.test_overrides.clone();
+    store.register_late_pass(move || {
+        box functions::Functions::new(
+            too_many_arguments_threshold1,
+            too_many_lines_threshold2,
+            large_error_threshold,
+            &test_overrides,
+        )
+    });
+    let doc_valid_idents = read_name_list(sess, "doc-valid-idents", &conf.doc_valid_idents);
     store.register_late_pass(move || box doc::DocMarkdown::new(doc_valid_idents.clone()));
     store.register_late_pass(|| box neg_multiply::NegMultiply);
     store.register_late_pass(|| box mem_discriminant::MemDiscriminant);
@@ -1187,6 +1597,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(move || box literal_representation::DecimalLiteralRepresentation::new(literal_representation_threshold));
     let enum_variant_name_threshold = conf.enum_variant_name_threshold;
     store.register_early_pass(move || box enum_variants::EnumVariantNames::new(enum_variant_name_threshold));
+    let struct_field_name_threshold = conf.struct_field_name_threshold;
+    store.register_early_pass(move || box struct_field_names::StructFieldNames::new(struct_field_name_threshold));
     store.register_early_pass(|| box tabs_in_doc_comments::TabsInDocComments);
     store.register_early_pass(|| box upper_case_acronyms::UpperCaseAcronyms);
     store.register_late_pass(|| box default::Default::default());
@@ -1248,7 +1660,39 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move || box types::PtrAsPtr::new(msrv));
     store.register_late_pass(|| box case_sensitive_file_extension_comparisons::CaseSensitiveFileExtensionComparisons);
     store.register_late_pass(|| box redundant_slicing::RedundantSlicing);
-
+    store.register_late_pass(|| box implied_bounds_in_impls::ImpliedBoundsInImpls);
+    store.register_early_pass(|| box visibility::Visibility);
+    store.register_late_pass(|| box error_impl_error::ErrorImplError);
+    store.register_late_pass(|| box iter_without_into_iter::IterWithoutIntoIter);
+    store.register_late_pass(|| box repeat_vec_with_capacity::RepeatVecWithCapacity);
+    store.register_early_pass(|| box multiple_bound_locations::MultipleBoundLocations);
+    store.register_late_pass(|| box unconditional_recursion::UnconditionalRecursion);
+    store.register_early_pass(|| box byte_char_slices::ByteCharSlice);
+    store.register_late_pass(|| box hashset_insert_after_contains::HashsetInsertAfterContains);
+    store.register_late_pass(|| box manual_rotate::ManualRotate);
+    store.register_late_pass(|| box non_zero_suggestions::NonZeroSuggestions);
+    store.register_late_pass(|| box unused_result_ok::UnusedResultOk);
+    store.register_late_pass(|| box zombie_processes::ZombieProcesses);
+    let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
+    store.register_late_pass(move || box unnecessary_literal_bound::UnnecessaryLiteralBound::new(avoid_breaking_exported_api));
+    store.register_late_pass(|| box unnecessary_debug_formatting::UnnecessaryDebugFormatting);
+    store.register_late_pass(|| box manual_ignore_case_cmp::ManualIgnoreCaseCmp);
+    store.register_late_pass(|| box arc_with_non_send_sync::ArcWithNonSendSync);
+    store.register_late_pass(|| box single_range_in_vec_init::SingleRangeInVecInit);
+    store.register_late_pass(|| box box_default::BoxDefault);
+    store.register_late_pass(|| box partialeq_to_none::PartialeqToNone);
+    let not_using_associated_type_foreign_only = conf.not_using_associated_type_foreign_only;
+    let not_using_associated_type_ignored_types = conf
+        .not_using_associated_type_ignored_types
+        .iter()
+        .cloned()
+        .collect::<FxHashSet<_>>();
+    store.register_late_pass(move || {
+        box not_using_associated_type::NotUsingAssociatedType::new(
+            not_using_associated_type_foreign_only,
+            &not_using_associated_type_ignored_types,
+        )
+    });
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
         LintId::of(&arithmetic::FLOAT_ARITHMETIC),
         LintId::of(&arithmetic::INTEGER_ARITHMETIC),
@@ -1257,7 +1701,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&asm_syntax::INLINE_ASM_X86_INTEL_SYNTAX),
         LintId::of(&create_dir::CREATE_DIR),
         LintId::of(&dbg_macro::DBG_MACRO),
+        LintId::of(&doc::DOC_INCLUDE_WITHOUT_CFG),
         LintId::of(&else_if_without_else::ELSE_IF_WITHOUT_ELSE),
+        LintId::of(&error_impl_error::ERROR_IMPL_ERROR),
         LintId::of(&exhaustive_items::EXHAUSTIVE_ENUMS),
         LintId::of(&exhaustive_items::EXHAUSTIVE_STRUCTS),
         LintId::of(&exit::EXIT),
@@ -1283,6 +1729,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS),
         LintId::of(&missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS),
         LintId::of(&modulo_arithmetic::MODULO_ARITHMETIC),
+        LintId::of(&non_zero_suggestions::NON_ZERO_SUGGESTIONS),
         LintId::of(&panic_in_result_fn::PANIC_IN_RESULT_FN),
         LintId::of(&panic_unimplemented::PANIC),
         LintId::of(&panic_unimplemented::TODO),
@@ -1295,8 +1742,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&strings::STRING_TO_STRING),
         LintId::of(&strings::STR_TO_STRING),
         LintId::of(&types::RC_BUFFER),
+        LintId::of(&unused_lint_allows::UNUSED_LINT_ALLOWS),
+        LintId::of(&unused_result_ok::UNUSED_RESULT_OK),
         LintId::of(&unwrap_in_result::UNWRAP_IN_RESULT),
         LintId::of(&verbose_file_reads::VERBOSE_FILE_READS),
+        LintId::of(&visibility::PUB_WITHOUT_SHORTHAND),
+        LintId::of(&visibility::PUB_WITH_SHORTHAND),
         LintId::of(&write::PRINT_STDERR),
         LintId::of(&write::PRINT_STDOUT),
         LintId::of(&write::USE_DEBUG),
@@ -1329,6 +1780,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&implicit_saturating_sub::IMPLICIT_SATURATING_SUB),
         LintId::of(&infinite_iter::MAYBE_INFINITE_ITER),
         LintId::of(&items_after_statements::ITEMS_AFTER_STATEMENTS),
+        LintId::of(&iter_without_into_iter::INTO_ITER_WITHOUT_ITER),
+        LintId::of(&iter_without_into_iter::ITER_WITHOUT_INTO_ITER),
         LintId::of(&large_stack_arrays::LARGE_STACK_ARRAYS),
         LintId::of(&let_underscore::LET_UNDERSCORE_DROP),
         LintId::of(&literal_representation::LARGE_DIGIT_GROUPS),
@@ -1363,6 +1816,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&ref_option_ref::REF_OPTION_REF),
         LintId::of(&shadow::SHADOW_UNRELATED),
         LintId::of(&strings::STRING_ADD_ASSIGN),
+        LintId::of(&struct_field_names::STRUCT_FIELD_NAMES),
+        LintId::of(&thread_local_initializer_can_be_made_const::THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST),
         LintId::of(&trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS),
         LintId::of(&trait_bounds::TYPE_REPETITION_IN_BOUNDS),
         LintId::of(&types::CAST_LOSSLESS),
@@ -1379,6 +1834,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::PTR_AS_PTR),
         LintId::of(&unicode::NON_ASCII_LITERAL),
         LintId::of(&unicode::UNICODE_NOT_NFC),
+        LintId::of(&unnecessary_debug_formatting::UNNECESSARY_DEBUG_FORMATTING),
+        LintId::of(&unnecessary_literal_bound::UNNECESSARY_LITERAL_BOUND),
         LintId::of(&unnested_or_patterns::UNNESTED_OR_PATTERNS),
         LintId::of(&unused_self::UNUSED_SELF),
         LintId::of(&wildcard_imports::ENUM_GLOB_USE),
@@ -1403,15 +1860,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     store.register_group(true, "clippy::all", Some("clippy"), vec![
         LintId::of(&approx_const::APPROX_CONSTANT),
+        LintId::of(&arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC),
         LintId::of(&assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
         LintId::of(&assign_ops::ASSIGN_OP_PATTERN),
         LintId::of(&assign_ops::MISREFACTORED_ASSIGN_OP),
+        LintId::of(&assigning_clones::ASSIGNING_CLONES),
         LintId::of(&async_yields_async::ASYNC_YIELDS_ASYNC),
         LintId::of(&atomic_ordering::INVALID_ATOMIC_ORDERING),
+        LintId::of(&attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON),
         LintId::of(&attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
         LintId::of(&attrs::DEPRECATED_CFG_ATTR),
+        LintId::of(&attrs::DEPRECATED_CLIPPY_CFG_ATTR),
         LintId::of(&attrs::DEPRECATED_SEMVER),
         LintId::of(&attrs::MISMATCHED_TARGET_OS),
+        LintId::of(&attrs::MIXED_ATTRIBUTES_STYLE),
         LintId::of(&attrs::USELESS_ATTRIBUTE),
         LintId::of(&bit_mask::BAD_BIT_MASK),
         LintId::of(&bit_mask::INEFFECTIVE_BIT_MASK),
@@ -1419,6 +1881,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS),
         LintId::of(&booleans::LOGIC_BUG),
         LintId::of(&booleans::NONMINIMAL_BOOL),
+        LintId::of(&box_default::BOX_DEFAULT),
+        LintId::of(&byte_char_slices::BYTE_CHAR_SLICES),
         LintId::of(&bytecount::NAIVE_BYTECOUNT),
         LintId::of(&collapsible_if::COLLAPSIBLE_ELSE_IF),
         LintId::of(&collapsible_if::COLLAPSIBLE_IF),
@@ -1429,6 +1893,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
         LintId::of(&derive::DERIVE_ORD_XOR_PARTIAL_ORD),
+        LintId::of(&doc::DOC_LAZY_CONTINUATION),
+        LintId::of(&doc::EMPTY_DOCS),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
@@ -1461,12 +1927,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&functions::DOUBLE_MUST_USE),
         LintId::of(&functions::MUST_USE_UNIT),
         LintId::of(&functions::NOT_UNSAFE_PTR_ARG_DEREF),
+        LintId::of(&functions::RESULT_LARGE_ERR),
         LintId::of(&functions::RESULT_UNIT_ERR),
         LintId::of(&functions::TOO_MANY_ARGUMENTS),
         LintId::of(&get_last_with_len::GET_LAST_WITH_LEN),
         LintId::of(&identity_op::IDENTITY_OP),
         LintId::of(&if_let_mutex::IF_LET_MUTEX),
         LintId::of(&if_let_some_result::IF_LET_SOME_RESULT),
+        LintId::of(&implied_bounds_in_impls::IMPLIED_BOUNDS_IN_IMPLS),
+        LintId::of(&incompatible_msrv::INCOMPATIBLE_MSRV),
         LintId::of(&indexing_slicing::OUT_OF_BOUNDS_INDEXING),
         LintId::of(&infinite_iter::INFINITE_ITER),
         LintId::of(&inherent_to_string::INHERENT_TO_STRING),
@@ -1475,6 +1944,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&int_plus_one::INT_PLUS_ONE),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(&large_enum_variant::LARGE_ENUM_VARIANT),
+        LintId::of(&legacy_numeric_constants::LEGACY_NUMERIC_CONSTANTS),
         LintId::of(&len_zero::COMPARISON_TO_EMPTY),
         LintId::of(&len_zero::LEN_WITHOUT_IS_EMPTY),
         LintId::of(&len_zero::LEN_ZERO),
@@ -1501,7 +1971,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::WHILE_LET_ON_ITERATOR),
         LintId::of(&main_recursion::MAIN_RECURSION),
         LintId::of(&manual_async_fn::MANUAL_ASYNC_FN),
+        LintId::of(&manual_clamp::MANUAL_CLAMP),
+        LintId::of(&manual_div_ceil::MANUAL_DIV_CEIL),
+        LintId::of(&manual_hash_one::MANUAL_HASH_ONE),
+        LintId::of(&manual_ignore_case_cmp::MANUAL_IGNORE_CASE_CMP),
         LintId::of(&manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
+        LintId::of(&manual_retain::MANUAL_RETAIN),
+        LintId::of(&manual_rotate::MANUAL_ROTATE),
         LintId::of(&manual_strip::MANUAL_STRIP),
         LintId::of(&manual_unwrap_or::MANUAL_UNWRAP_OR),
         LintId::of(&map_clone::MAP_CLONE),
@@ -1514,6 +1990,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&matches::MATCH_OVERLAPPING_ARM),
         LintId::of(&matches::MATCH_REF_PATS),
         LintId::of(&matches::MATCH_SINGLE_BINDING),
+        LintId::of(&matches::REDUNDANT_GUARDS),
         LintId::of(&matches::REDUNDANT_PATTERN_MATCHING),
         LintId::of(&matches::SINGLE_MATCH),
         LintId::of(&matches::WILDCARD_IN_OR_PATTERNS),
@@ -1526,6 +2003,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::CHARS_NEXT_CMP),
         LintId::of(&methods::CLONE_DOUBLE_REF),
         LintId::of(&methods::CLONE_ON_COPY),
+        LintId::of(&methods::DRAIN_COLLECT),
         LintId::of(&methods::EXPECT_FUN_CALL),
         LintId::of(&methods::FILTER_NEXT),
         LintId::of(&methods::FLAT_MAP_IDENTITY),
@@ -1538,23 +2016,32 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::ITER_NTH),
         LintId::of(&methods::ITER_NTH_ZERO),
         LintId::of(&methods::ITER_SKIP_NEXT),
+        LintId::of(&methods::ITER_SKIP_ZERO),
         LintId::of(&methods::MANUAL_FILTER_MAP),
         LintId::of(&methods::MANUAL_FIND_MAP),
         LintId::of(&methods::MANUAL_SATURATING_ARITHMETIC),
+        LintId::of(&methods::MAP_ALL_ANY_IDENTITY),
         LintId::of(&methods::MAP_COLLECT_RESULT_UNIT),
+        LintId::of(&methods::NEEDLESS_AS_BYTES),
+        LintId::of(&methods::NEEDLESS_CHARACTER_ITERATION),
         LintId::of(&methods::NEW_RET_NO_SELF),
         LintId::of(&methods::OK_EXPECT),
         LintId::of(&methods::OPTION_AS_REF_DEREF),
+        LintId::of(&methods::OPTION_MAP_OR_ERR_OK),
         LintId::of(&methods::OPTION_MAP_OR_NONE),
         LintId::of(&methods::OR_FUN_CALL),
         LintId::of(&methods::RESULT_MAP_OR_INTO_OPTION),
         LintId::of(&methods::SEARCH_IS_SOME),
+        LintId::of(&methods::SEEK_FROM_CURRENT),
+        LintId::of(&methods::SEEK_TO_START_INSTEAD_OF_REWIND),
         LintId::of(&methods::SHOULD_IMPLEMENT_TRAIT),
         LintId::of(&methods::SINGLE_CHAR_ADD_STR),
         LintId::of(&methods::SINGLE_CHAR_PATTERN),
         LintId::of(&methods::SKIP_WHILE_NEXT),
+        LintId::of(&methods::SLICED_STRING_AS_BYTES),
         LintId::of(&methods::STRING_EXTEND_CHARS),
         LintId::of(&methods::SUSPICIOUS_MAP),
+        LintId::of(&methods::UNBUFFERED_BYTES),
         LintId::of(&methods::UNINIT_ASSUMED_INIT),
         LintId::of(&methods::UNNECESSARY_FILTER_MAP),
         LintId::of(&methods::UNNECESSARY_FOLD),
@@ -1577,6 +2064,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&misc_early::REDUNDANT_PATTERN),
         LintId::of(&misc_early::UNNEEDED_WILDCARD_PATTERN),
         LintId::of(&misc_early::ZERO_PREFIXED_LITERAL),
+        LintId::of(&multiple_bound_locations::MULTIPLE_BOUND_LOCATIONS),
         LintId::of(&mut_key::MUTABLE_KEY_TYPE),
         LintId::of(&mut_mutex_lock::MUT_MUTEX_LOCK),
         LintId::of(&mut_reference::UNNECESSARY_MUT_PASSED),
@@ -1600,6 +2088,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(&overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
         LintId::of(&partialeq_ne_impl::PARTIALEQ_NE_IMPL),
+        LintId::of(&partialeq_to_none::PARTIALEQ_TO_NONE),
         LintId::of(&precedence::PRECEDENCE),
         LintId::of(&ptr::CMP_NULL),
         LintId::of(&ptr::MUT_FROM_REF),
@@ -1620,11 +2109,14 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&regex::INVALID_REGEX),
         LintId::of(&regex::TRIVIAL_REGEX),
         LintId::of(&repeat_once::REPEAT_ONCE),
+        LintId::of(&repeat_vec_with_capacity::REPEAT_VEC_WITH_CAPACITY),
         LintId::of(&returns::LET_AND_RETURN),
         LintId::of(&returns::NEEDLESS_RETURN),
+        LintId::of(&returns::NEEDLESS_RETURN_WITH_QUESTION_MARK),
         LintId::of(&self_assignment::SELF_ASSIGNMENT),
         LintId::of(&serde_api::SERDE_API_MISUSE),
         LintId::of(&single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS),
+        LintId::of(&single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT),
         LintId::of(&size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT),
         LintId::of(&slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(&stable_sort_primitive::STABLE_SORT_PRIMITIVE),
@@ -1654,6 +2146,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::ABSURD_EXTREME_COMPARISONS),
         LintId::of(&types::BORROWED_BOX),
         LintId::of(&types::BOX_VEC),
+        LintId::of(&types::CAST_NAN_TO_INT),
         LintId::of(&types::CAST_REF_TO_MUT),
         LintId::of(&types::CHAR_LIT_AS_U8),
         LintId::of(&types::FN_TO_NUMERIC_CAST),
@@ -1664,6 +2157,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&types::UNIT_CMP),
         LintId::of(&types::UNNECESSARY_CAST),
         LintId::of(&types::VEC_BOX),
+        LintId::of(&unconditional_recursion::UNCONDITIONAL_RECURSION),
         LintId::of(&undropped_manually_drops::UNDROPPED_MANUALLY_DROPS),
         LintId::of(&unicode::INVISIBLE_CHARACTERS),
         LintId::of(&unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD),
@@ -1681,6 +2175,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&vec::USELESS_VEC),
         LintId::of(&vec_init_then_push::VEC_INIT_THEN_PUSH),
         LintId::of(&vec_resize_to_zero::VEC_RESIZE_TO_ZERO),
+        LintId::of(&visibility::NEEDLESS_PUB_SELF),
         LintId::of(&write::PRINTLN_EMPTY_STRING),
         LintId::of(&write::PRINT_LITERAL),
         LintId::of(&write::PRINT_WITH_NEWLINE),
@@ -1688,19 +2183,27 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&write::WRITE_LITERAL),
         LintId::of(&write::WRITE_WITH_NEWLINE),
         LintId::of(&zero_div_zero::ZERO_DIVIDED_BY_ZERO),
+        LintId::of(&zombie_processes::ZOMBIE_PROCESSES),
     ]);
 
     store.register_group(true, "clippy::style", Some("clippy_style"), vec![
+        LintId::of(&arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC),
         LintId::of(&assertions_on_constants::ASSERTIONS_ON_CONSTANTS),
         LintId::of(&assign_ops::ASSIGN_OP_PATTERN),
+        LintId::of(&attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON),
         LintId::of(&attrs::BLANKET_CLIPPY_RESTRICTION_LINTS),
+        LintId::of(&attrs::MIXED_ATTRIBUTES_STYLE),
         LintId::of(&blacklisted_name::BLACKLISTED_NAME),
         LintId::of(&blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS),
+        LintId::of(&box_default::BOX_DEFAULT),
+        LintId::of(&byte_char_slices::BYTE_CHAR_SLICES),
         LintId::of(&collapsible_if::COLLAPSIBLE_ELSE_IF),
         LintId::of(&collapsible_if::COLLAPSIBLE_IF),
         LintId::of(&collapsible_match::COLLAPSIBLE_MATCH),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
         LintId::of(&default::FIELD_REASSIGN_WITH_DEFAULT),
+        LintId::of(&doc::DOC_LAZY_CONTINUATION),
+        LintId::of(&doc::EMPTY_DOCS),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&enum_variants::ENUM_VARIANT_NAMES),
@@ -1716,7 +2219,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&functions::MUST_USE_UNIT),
         LintId::of(&functions::RESULT_UNIT_ERR),
         LintId::of(&if_let_some_result::IF_LET_SOME_RESULT),
+        LintId::of(&incompatible_msrv::INCOMPATIBLE_MSRV),
         LintId::of(&inherent_to_string::INHERENT_TO_STRING),
+        LintId::of(&legacy_numeric_constants::LEGACY_NUMERIC_CONSTANTS),
         LintId::of(&len_zero::COMPARISON_TO_EMPTY),
         LintId::of(&len_zero::LEN_WITHOUT_IS_EMPTY),
         LintId::of(&len_zero::LEN_ZERO),
@@ -1730,6 +2235,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&main_recursion::MAIN_RECURSION),
         LintId::of(&manual_async_fn::MANUAL_ASYNC_FN),
         LintId::of(&manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE),
+        LintId::of(&manual_rotate::MANUAL_ROTATE),
         LintId::of(&map_clone::MAP_CLONE),
         LintId::of(&matches::INFALLIBLE_DESTRUCTURING_MATCH),
         LintId::of(&matches::MATCH_LIKE_MATCHES_MACRO),
@@ -1749,8 +2255,10 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::ITER_SKIP_NEXT),
         LintId::of(&methods::MANUAL_SATURATING_ARITHMETIC),
         LintId::of(&methods::MAP_COLLECT_RESULT_UNIT),
+        LintId::of(&methods::NEEDLESS_CHARACTER_ITERATION),
         LintId::of(&methods::NEW_RET_NO_SELF),
         LintId::of(&methods::OK_EXPECT),
+        LintId::of(&methods::OPTION_MAP_OR_ERR_OK),
         LintId::of(&methods::OPTION_MAP_OR_NONE),
         LintId::of(&methods::RESULT_MAP_OR_INTO_OPTION),
         LintId::of(&methods::SHOULD_IMPLEMENT_TRAIT),
@@ -1766,6 +2274,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&misc_early::DUPLICATE_UNDERSCORE_ARGUMENT),
         LintId::of(&misc_early::MIXED_CASE_HEX_LITERALS),
         LintId::of(&misc_early::REDUNDANT_PATTERN),
+        LintId::of(&multiple_bound_locations::MULTIPLE_BOUND_LOCATIONS),
         LintId::of(&mut_mutex_lock::MUT_MUTEX_LOCK),
         LintId::of(&mut_reference::UNNECESSARY_MUT_PASSED),
         LintId::of(&neg_multiply::NEG_MULTIPLY),
@@ -1774,6 +2283,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST),
         LintId::of(&non_expressive_names::JUST_UNDERSCORES_AND_DIGITS),
         LintId::of(&non_expressive_names::MANY_SINGLE_CHAR_NAMES),
+        LintId::of(&partialeq_to_none::PARTIALEQ_TO_NONE),
         LintId::of(&ptr::CMP_NULL),
         LintId::of(&ptr::PTR_ARG),
         LintId::of(&ptr_eq::PTR_EQ),
@@ -1782,29 +2292,37 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&redundant_field_names::REDUNDANT_FIELD_NAMES),
         LintId::of(&redundant_static_lifetimes::REDUNDANT_STATIC_LIFETIMES),
         LintId::of(&regex::TRIVIAL_REGEX),
+        LintId::of(&repeat_vec_with_capacity::REPEAT_VEC_WITH_CAPACITY),
         LintId::of(&returns::LET_AND_RETURN),
         LintId::of(&returns::NEEDLESS_RETURN),
+        LintId::of(&returns::NEEDLESS_RETURN_WITH_QUESTION_MARK),
         LintId::of(&single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS),
+        LintId::of(&single_range_in_vec_init::SINGLE_RANGE_IN_VEC_INIT),
         LintId::of(&suspicious_operation_groupings::SUSPICIOUS_OPERATION_GROUPINGS),
         LintId::of(&tabs_in_doc_comments::TABS_IN_DOC_COMMENTS),
         LintId::of(&to_digit_is_some::TO_DIGIT_IS_SOME),
         LintId::of(&try_err::TRY_ERR),
+        LintId::of(&types::CAST_NAN_TO_INT),
         LintId::of(&types::FN_TO_NUMERIC_CAST),
         LintId::of(&types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION),
+        LintId::of(&unconditional_recursion::UNCONDITIONAL_RECURSION),
         LintId::of(&unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME),
         LintId::of(&unused_unit::UNUSED_UNIT),
         LintId::of(&upper_case_acronyms::UPPER_CASE_ACRONYMS),
+        LintId::of(&visibility::NEEDLESS_PUB_SELF),
         LintId::of(&write::PRINTLN_EMPTY_STRING),
         LintId::of(&write::PRINT_LITERAL),
         LintId::of(&write::PRINT_WITH_NEWLINE),
         LintId::of(&write::WRITELN_EMPTY_STRING),
         LintId::of(&write::WRITE_LITERAL),
         LintId::of(&write::WRITE_WITH_NEWLINE),
+        LintId::of(&zombie_processes::ZOMBIE_PROCESSES),
     ]);
 
     store.register_group(true, "clippy::complexity", Some("clippy_complexity"), vec![
         LintId::of(&assign_ops::MISREFACTORED_ASSIGN_OP),
         LintId::of(&attrs::DEPRECATED_CFG_ATTR),
+        LintId::of(&attrs::DEPRECATED_CLIPPY_CFG_ATTR),
         LintId::of(&booleans::NONMINIMAL_BOOL),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
         LintId::of(&double_parens::DOUBLE_PARENS),
@@ -1816,6 +2334,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&functions::TOO_MANY_ARGUMENTS),
         LintId::of(&get_last_with_len::GET_LAST_WITH_LEN),
         LintId::of(&identity_op::IDENTITY_OP),
+        LintId::of(&implied_bounds_in_impls::IMPLIED_BOUNDS_IN_IMPLS),
         LintId::of(&int_plus_one::INT_PLUS_ONE),
         LintId::of(&lifetimes::EXTRA_UNUSED_LIFETIMES),
         LintId::of(&lifetimes::NEEDLESS_LIFETIMES),
@@ -1823,6 +2342,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&loops::MUT_RANGE_BOUND),
         LintId::of(&loops::SINGLE_ELEMENT_LOOP),
         LintId::of(&loops::WHILE_LET_LOOP),
+        LintId::of(&manual_clamp::MANUAL_CLAMP),
+        LintId::of(&manual_div_ceil::MANUAL_DIV_CEIL),
+        LintId::of(&manual_hash_one::MANUAL_HASH_ONE),
         LintId::of(&manual_strip::MANUAL_STRIP),
         LintId::of(&manual_unwrap_or::MANUAL_UNWRAP_OR),
         LintId::of(&map_identity::MAP_IDENTITY),
@@ -1830,6 +2352,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&map_unit_fn::RESULT_MAP_UNIT_FN),
         LintId::of(&matches::MATCH_AS_REF),
         LintId::of(&matches::MATCH_SINGLE_BINDING),
+        LintId::of(&matches::REDUNDANT_GUARDS),
         LintId::of(&matches::WILDCARD_IN_OR_PATTERNS),
         LintId::of(&methods::BIND_INSTEAD_OF_MAP),
         LintId::of(&methods::CLONE_ON_COPY),
@@ -1838,8 +2361,12 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&methods::INSPECT_FOR_EACH),
         LintId::of(&methods::MANUAL_FILTER_MAP),
         LintId::of(&methods::MANUAL_FIND_MAP),
+        LintId::of(&methods::MAP_ALL_ANY_IDENTITY),
+        LintId::of(&methods::NEEDLESS_AS_BYTES),
         LintId::of(&methods::OPTION_AS_REF_DEREF),
         LintId::of(&methods::SEARCH_IS_SOME),
+        LintId::of(&methods::SEEK_FROM_CURRENT),
+        LintId::of(&methods::SEEK_TO_START_INSTEAD_OF_REWIND),
         LintId::of(&methods::SKIP_WHILE_NEXT),
         LintId::of(&methods::SUSPICIOUS_MAP),
         LintId::of(&methods::UNNECESSARY_FILTER_MAP),
@@ -1930,6 +2457,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&mem_replace::MEM_REPLACE_WITH_UNINIT),
         LintId::of(&methods::CLONE_DOUBLE_REF),
         LintId::of(&methods::ITERATOR_STEP_BY_ZERO),
+        LintId::of(&methods::ITER_SKIP_ZERO),
         LintId::of(&methods::UNINIT_ASSUMED_INIT),
         LintId::of(&methods::ZST_OFFSET),
         LintId::of(&minmax::MIN_MAX),
@@ -1966,17 +2494,24 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     ]);
 
     store.register_group(true, "clippy::perf", Some("clippy_perf"), vec![
+        LintId::of(&assigning_clones::ASSIGNING_CLONES),
         LintId::of(&bytecount::NAIVE_BYTECOUNT),
         LintId::of(&entry::MAP_ENTRY),
         LintId::of(&escape::BOXED_LOCAL),
+        LintId::of(&functions::RESULT_LARGE_ERR),
         LintId::of(&large_const_arrays::LARGE_CONST_ARRAYS),
         LintId::of(&large_enum_variant::LARGE_ENUM_VARIANT),
         LintId::of(&loops::MANUAL_MEMCPY),
         LintId::of(&loops::NEEDLESS_COLLECT),
+        LintId::of(&manual_ignore_case_cmp::MANUAL_IGNORE_CASE_CMP),
+        LintId::of(&manual_retain::MANUAL_RETAIN),
+        LintId::of(&methods::DRAIN_COLLECT),
         LintId::of(&methods::EXPECT_FUN_CALL),
         LintId::of(&methods::ITER_NTH),
         LintId::of(&methods::OR_FUN_CALL),
         LintId::of(&methods::SINGLE_CHAR_PATTERN),
+        LintId::of(&methods::SLICED_STRING_AS_BYTES),
+        LintId::of(&methods::UNBUFFERED_BYTES),
         LintId::of(&misc::CMP_OWNED),
         LintId::of(&mutex_atomic::MUTEX_ATOMIC),
         LintId::of(&redundant_clone::REDUNDANT_CLONE),
@@ -2002,17 +2537,28 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(&floating_point_arithmetic::IMPRECISE_FLOPS),
         LintId::of(&floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(&future_not_send::FUTURE_NOT_SEND),
+        LintId::of(&hashset_insert_after_contains::HASHSET_INSERT_AFTER_CONTAINS),
         LintId::of(&let_if_seq::USELESS_LET_IF_SEQ),
+        LintId::of(&methods::ITER_ON_EMPTY_COLLECTIONS),
+        LintId::of(&methods::ITER_ON_SINGLE_ITEMS),
         LintId::of(&missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(&mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
         LintId::of(&mutex_atomic::MUTEX_INTEGER),
         LintId::of(&needless_borrow::NEEDLESS_BORROW),
+        LintId::of(&not_using_associated_type::NOT_USING_ASSOCIATED_CONST),
+        LintId::of(&not_using_associated_type::NOT_USING_ASSOCIATED_TYPE),
         LintId::of(&path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE),
         LintId::of(&redundant_pub_crate::REDUNDANT_PUB_CRATE),
         LintId::of(&strings::STRING_LIT_AS_BYTES),
         LintId::of(&transmute::USELESS_TRANSMUTE),
         LintId::of(&use_self::USE_SELF),
     ]);
+
+    // the lints of the plugins are registered after the ones of Clippy
+    utils::plugins::register_lints(sess, store, &conf.plugins);
+    // reports the allow attributes which allowed none of the lints emitted by the passes above,
+    // including the ones of the plugins
+    store.register_late_pass(|| box unused_lint_allows::UnusedLintAllows::default());
 }
 
 #[rustfmt::skip]
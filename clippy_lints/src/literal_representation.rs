@@ -11,7 +11,7 @@ use rustc_ast::ast::{Expr, ExprKind, Lit, LitKind};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Warns if a long integral or floating-point constant does
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub UNREADABLE_LITERAL,
     pedantic,
-    "long literal without underscores"
+    "long literal without underscores",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -56,7 +57,8 @@ declare_clippy_lint! {
     /// ```
     pub MISTYPED_LITERAL_SUFFIXES,
     correctness,
-    "mistyped literal suffix"
+    "mistyped literal suffix",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -79,7 +81,8 @@ declare_clippy_lint! {
     /// ```
     pub INCONSISTENT_DIGIT_GROUPING,
     style,
-    "integer literals with digits grouped inconsistently"
+    "integer literals with digits grouped inconsistently",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -98,7 +101,8 @@ declare_clippy_lint! {
     /// ```
     pub UNUSUAL_BYTE_GROUPINGS,
     style,
-    "binary or hex literals that aren't grouped by four"
+    "binary or hex literals that aren't grouped by four",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -117,7 +121,8 @@ declare_clippy_lint! {
     /// ```
     pub LARGE_DIGIT_GROUPS,
     pedantic,
-    "grouping digits into groups that are too large"
+    "grouping digits into groups that are too large",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -135,7 +140,8 @@ declare_clippy_lint! {
     /// `4_042_322_160` => `0xF0F0_F0F0`
     pub DECIMAL_LITERAL_REPRESENTATION,
     restriction,
-    "using decimal representation when hexadecimal would be better"
+    "using decimal representation when hexadecimal would be better",
+    applicability: [MachineApplicable]
 }
 
 enum WarningType {
@@ -26,7 +26,7 @@ use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::middle::region;
 use rustc_middle::ty::{self, Ty, TyS};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_typeck::expr_use_visitor::{ConsumeMode, Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
@@ -57,7 +57,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_MEMCPY,
     perf,
-    "manually copying items between slices"
+    "manually copying items between slices",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -85,7 +86,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_RANGE_LOOP,
     style,
-    "for-looping over a range of indices where an iterator over items would do"
+    "for-looping over a range of indices where an iterator over items would do",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -114,7 +116,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPLICIT_ITER_LOOP,
     pedantic,
-    "for-looping over `_.iter()` or `_.iter_mut()` when `&_` or `&mut _` would do"
+    "for-looping over `_.iter()` or `_.iter_mut()` when `&_` or `&mut _` would do",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -142,7 +145,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPLICIT_INTO_ITER_LOOP,
     pedantic,
-    "for-looping over `_.into_iter()` when `_` would do"
+    "for-looping over `_.into_iter()` when `_` would do",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -237,7 +241,8 @@ declare_clippy_lint! {
     /// ```
     pub WHILE_LET_LOOP,
     complexity,
-    "`loop { if let { ... } else break }`, which can be written as a `while let` loop"
+    "`loop { if let { ... } else break }`, which can be written as a `while let` loop",
+    applicability: [HasPlaceholders]
 }
 
 declare_clippy_lint! {
@@ -259,7 +264,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_COLLECT,
     perf,
-    "collecting an iterator when collect is not needed"
+    "collecting an iterator when collect is not needed",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -289,7 +295,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPLICIT_COUNTER_LOOP,
     complexity,
-    "for-looping with an explicit counter when `_.enumerate()` would do"
+    "for-looping with an explicit counter when `_.enumerate()` would do",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -341,7 +348,8 @@ declare_clippy_lint! {
     /// ```
     pub WHILE_LET_ON_ITERATOR,
     style,
-    "using a while-let loop instead of a for loop on an iterator"
+    "using a while-let loop instead of a for loop on an iterator",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -369,7 +377,8 @@ declare_clippy_lint! {
     /// ```
     pub FOR_KV_MAP,
     style,
-    "looping on a map using `iter` when `keys` or `values` would do"
+    "looping on a map using `iter` when `keys` or `values` would do",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -491,7 +500,8 @@ declare_clippy_lint! {
     /// ```
     pub SINGLE_ELEMENT_LOOP,
     complexity,
-    "there is no reason to have a single element loop"
+    "there is no reason to have a single element loop",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(Loops => [
@@ -6,7 +6,7 @@ use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::{edition::Edition, Span};
 
 declare_clippy_lint! {
@@ -24,7 +24,8 @@ declare_clippy_lint! {
     /// ```
     pub MACRO_USE_IMPORTS,
     pedantic,
-    "#[macro_use] is no longer needed"
+    "#[macro_use] is no longer needed",
+    applicability: [MaybeIncorrect]
 }
 
 const BRACKETS: &[char] = &['<', '>'];
@@ -8,7 +8,7 @@ use rustc_hir::{
     IsAsync, ItemKind, LifetimeName, TraitRef, Ty, TyKind, TypeBindingKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_ASYNC_FN,
     style,
-    "manual implementations of `async` functions can be simplified using the dedicated syntax"
+    "manual implementations of `async` functions can be simplified using the dedicated syntax",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ManualAsyncFn => [MANUAL_ASYNC_FN]);
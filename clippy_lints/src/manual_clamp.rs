@@ -0,0 +1,284 @@
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    in_macro, match_def_path, match_trait_method, meets_msrv, msrvs, paths, snippet_with_applicability,
+    span_lint_and_then, SpanlessEq,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use std::cmp::Ordering;
+
+declare_clippy_lint! {
+    /// **What it does:** Identifies good opportunities for a clamp function from std or core,
+    /// and suggests using it.
+    ///
+    /// **Why is this bad?** `clamp` is much shorter, easier to read, and doesn't use any
+    /// control flow.
+    ///
+    /// **Known problems:** Only bounds which are constants are checked, so that the suggestion
+    /// can't panic. For floats, `clamp` returns `NaN` if the input is `NaN`, while the `min`
+    /// and `max` functions would return one of the bounds.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let input: i32 = 5;
+    /// let x = input.max(-7).min(7);
+    /// let y = if input > 7 {
+    ///     7
+    /// } else if input < -7 {
+    ///     -7
+    /// } else {
+    ///     input
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let input: i32 = 5;
+    /// let x = input.clamp(-7, 7);
+    /// let y = input.clamp(-7, 7);
+    /// ```
+    pub MANUAL_CLAMP,
+    complexity,
+    "using a clamp pattern instead of the clamp function",
+    applicability: [MaybeIncorrect]
+}
+
+pub struct ManualClamp {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualClamp {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualClamp => [MANUAL_CLAMP]);
+
+/// A clamp pattern with its input and bounds.
+struct ClampSuggestion<'tcx> {
+    input: &'tcx Expr<'tcx>,
+    min: &'tcx Expr<'tcx>,
+    max: &'tcx Expr<'tcx>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualClamp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) || !meets_msrv(self.msrv.as_ref(), &msrvs::CLAMP) {
+            return;
+        }
+        let suggestion = match_min_max_chain(cx, expr).or_else(|| match_if_else_ladder(cx, expr));
+        if let Some(suggestion) = suggestion {
+            if bounds_are_ordered(cx, &suggestion) {
+                emit_suggestion(cx, expr, &suggestion);
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MinMax {
+    Min,
+    Max,
+}
+
+/// Checks for `max(min(x, max), min)` and `x.max(min).min(max)`, and the variants with `min` and
+/// `max` switched.
+fn match_min_max_chain<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<ClampSuggestion<'tcx>> {
+    let (outer_kind, outer_args) = min_max_call(cx, expr)?;
+    let (inner_kind, outer_bound, inner_args) = match outer_args {
+        [first, second] => {
+            if let Some((kind, args)) = min_max_call(cx, first) {
+                (kind, second, args)
+            } else {
+                let (kind, args) = min_max_call(cx, second)?;
+                (kind, first, args)
+            }
+        },
+        _ => return None,
+    };
+    if inner_kind == outer_kind {
+        return None;
+    }
+    let (input, inner_bound) = match inner_args {
+        [first, second] if is_constant(cx, second) && !is_constant(cx, first) => (first, second),
+        [first, second] if is_constant(cx, first) && !is_constant(cx, second) => (second, first),
+        _ => return None,
+    };
+    if !is_constant(cx, outer_bound) {
+        return None;
+    }
+    // `max(min(x, hi), lo)` and `min(max(x, lo), hi)`
+    let (min, max) = if outer_kind == MinMax::Max {
+        (outer_bound, inner_bound)
+    } else {
+        (inner_bound, outer_bound)
+    };
+    Some(ClampSuggestion { input, min, max })
+}
+
+/// Returns the kind and arguments of a call to `cmp::min`/`cmp::max` or the `min`/`max`
+/// methods of `Ord` and the floating point types.
+fn min_max_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(MinMax, &'tcx [Expr<'tcx>])> {
+    match expr.kind {
+        ExprKind::Call(func, args @ [_, _]) => {
+            if let ExprKind::Path(ref qpath) = func.kind {
+                let def_id = cx.qpath_res(qpath, func.hir_id).opt_def_id()?;
+                if match_def_path(cx, def_id, &paths::CMP_MIN) {
+                    return Some((MinMax::Min, args));
+                } else if match_def_path(cx, def_id, &paths::CMP_MAX) {
+                    return Some((MinMax::Max, args));
+                }
+            }
+            None
+        },
+        ExprKind::MethodCall(path, _, args @ [recv, _], _) => {
+            if !cx.typeck_results().expr_ty(recv).is_floating_point() && !match_trait_method(cx, expr, &paths::ORD) {
+                return None;
+            }
+            match &*path.ident.as_str() {
+                "min" => Some((MinMax::Min, args)),
+                "max" => Some((MinMax::Max, args)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks for `if x > max { max } else if x < min { min } else { x }`, with any order of the
+/// two comparisons and any orientation of the comparison operators.
+fn match_if_else_ladder<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<ClampSuggestion<'tcx>> {
+    let (first_cond, first_then, else_expr) = match expr.kind {
+        ExprKind::If(cond, then, Some(else_expr)) => (cond, then, else_expr),
+        _ => return None,
+    };
+    let (second_cond, second_then, input_block) = match else_expr.kind {
+        ExprKind::If(cond, then, Some(else_expr)) => (cond, then, else_expr),
+        _ => return None,
+    };
+    let first = clamp_branch(cx, first_cond, block_value(first_then)?)?;
+    let second = clamp_branch(cx, second_cond, block_value(second_then)?)?;
+    let input = block_value(input_block)?;
+
+    let mut eq = SpanlessEq::new(cx);
+    if !eq.eq_expr(first.input, input) || !eq.eq_expr(second.input, input) {
+        return None;
+    }
+    let (min, max) = match (first.kind, second.kind) {
+        (MinMax::Min, MinMax::Max) => (first.bound, second.bound),
+        (MinMax::Max, MinMax::Min) => (second.bound, first.bound),
+        _ => return None,
+    };
+    Some(ClampSuggestion { input, min, max })
+}
+
+/// A branch of an `if` which replaces the input with a bound.
+struct ClampBranch<'tcx> {
+    /// `Min` if the bound is the lower one, `Max` if it's the upper one
+    kind: MinMax,
+    input: &'tcx Expr<'tcx>,
+    bound: &'tcx Expr<'tcx>,
+}
+
+/// Checks for a condition like `x < min` followed by the value `min`.
+fn clamp_branch<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    value: &'tcx Expr<'tcx>,
+) -> Option<ClampBranch<'tcx>> {
+    let cond = match cond.kind {
+        ExprKind::DropTemps(inner) => inner,
+        _ => cond,
+    };
+    let (op, left, right) = match cond.kind {
+        ExprKind::Binary(op, left, right) => (op.node, left, right),
+        _ => return None,
+    };
+    // normalize to `input <op> bound`
+    let (op, input, bound) = match op {
+        BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge if is_constant(cx, right) => (op, left, right),
+        BinOpKind::Lt => (BinOpKind::Gt, right, left),
+        BinOpKind::Le => (BinOpKind::Ge, right, left),
+        BinOpKind::Gt => (BinOpKind::Lt, right, left),
+        BinOpKind::Ge => (BinOpKind::Le, right, left),
+        _ => return None,
+    };
+    if !is_constant(cx, bound) || !SpanlessEq::new(cx).eq_expr(bound, value) {
+        return None;
+    }
+    let kind = if matches!(op, BinOpKind::Lt | BinOpKind::Le) {
+        MinMax::Min
+    } else {
+        MinMax::Max
+    };
+    Some(ClampBranch { kind, input, bound })
+}
+
+/// Returns the value of a block without statements.
+fn block_value<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::Block(
+            Block {
+                stmts: [],
+                expr: Some(value),
+                ..
+            },
+            _,
+        ) => Some(value),
+        _ => None,
+    }
+}
+
+fn is_constant(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    constant(cx, cx.typeck_results(), expr).is_some()
+}
+
+/// Checks that `min <= max`, since `clamp` panics otherwise.
+fn bounds_are_ordered(cx: &LateContext<'_>, suggestion: &ClampSuggestion<'_>) -> bool {
+    let typeck_results = cx.typeck_results();
+    match (
+        constant(cx, typeck_results, suggestion.min),
+        constant(cx, typeck_results, suggestion.max),
+    ) {
+        (Some((min, _)), Some((max, _))) => matches!(
+            Constant::partial_cmp(cx.tcx, typeck_results.expr_ty(suggestion.input), &min, &max),
+            Some(Ordering::Less | Ordering::Equal)
+        ),
+        _ => false,
+    }
+}
+
+fn emit_suggestion(cx: &LateContext<'_>, expr: &Expr<'_>, suggestion: &ClampSuggestion<'_>) {
+    let mut applicability = Applicability::MaybeIncorrect;
+    let input = Sugg::hir_with_applicability(cx, suggestion.input, "..", &mut applicability).maybe_par();
+    let min = snippet_with_applicability(cx, suggestion.min.span, "..", &mut applicability);
+    let max = snippet_with_applicability(cx, suggestion.max.span, "..", &mut applicability);
+    let is_float = cx.typeck_results().expr_ty(suggestion.input).is_floating_point();
+    span_lint_and_then(
+        cx,
+        MANUAL_CLAMP,
+        expr.span,
+        "clamp-like pattern without using clamp function",
+        |diag| {
+            diag.span_suggestion(
+                expr.span,
+                "replace with clamp",
+                format!("{}.clamp({}, {})", input, min, max),
+                applicability,
+            );
+            if is_float {
+                diag.note("clamp will panic if max < min, min.is_nan(), or max.is_nan()");
+                diag.note("clamp returns NaN if the input is NaN");
+            } else {
+                diag.note("clamp will panic if max < min");
+            }
+        },
+    );
+}
@@ -0,0 +1,146 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, meets_msrv, msrvs, span_lint_and_sugg, SpanlessEq};
+use rustc_ast::ast::{LitIntType, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for an expression like `(x + (y - 1)) / y` which is a common
+    /// manual reimplementation of `x.div_ceil(y)`.
+    ///
+    /// **Why is this bad?** It's simpler, clearer and more readable, and the manual version can
+    /// overflow when `x` is close to the maximum value of its type.
+    ///
+    /// **Known problems:** Only unsigned integers are checked, since `div_ceil` isn't stable for
+    /// signed integers. `div_ceil` is stabilized in Rust 1.73, so the suggestion doesn't compile with
+    /// older toolchains. It isn't made when the `msrv` option is older.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let x: u32 = 7;
+    /// let y: u32 = 4;
+    /// let div = (x + (y - 1)) / y;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let x: u32 = 7;
+    /// let y: u32 = 4;
+    /// let div = x.div_ceil(y);
+    /// ```
+    pub MANUAL_DIV_CEIL,
+    complexity,
+    "manually reimplementing `div_ceil`",
+    applicability: [MachineApplicable]
+}
+
+pub struct ManualDivCeil {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualDivCeil {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualDivCeil => [MANUAL_DIV_CEIL]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualDivCeil {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::DIV_CEIL) || in_macro(expr.span) {
+            return;
+        }
+
+        if let ExprKind::Binary(op, dividend, divisor) = expr.kind {
+            if op.node != BinOpKind::Div {
+                return;
+            }
+            let ty = cx.typeck_results().expr_ty(expr);
+            if !matches!(ty.kind(), ty::Uint(_)) {
+                return;
+            }
+            if let Some(x) = check_dividend(cx, dividend, divisor) {
+                build_suggestion(cx, expr, ty, x, divisor);
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks whether the dividend is `x + (y - 1)`, `(y - 1) + x`, `x + y - 1` or `x + C` with
+/// `C == y - 1` for constants, and returns `x`.
+fn check_dividend<'tcx>(cx: &LateContext<'_>, dividend: &'tcx Expr<'tcx>, y: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    let (lhs, rhs) = match dividend.kind {
+        // `x + y - 1`
+        ExprKind::Binary(op, inner, one) if op.node == BinOpKind::Sub && is_one(cx, one) => {
+            if let ExprKind::Binary(inner_op, x, inner_y) = inner.kind {
+                if inner_op.node == BinOpKind::Add && SpanlessEq::new(cx).eq_expr(inner_y, y) {
+                    return Some(x);
+                }
+            }
+            return None;
+        },
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Add => (lhs, rhs),
+        _ => return None,
+    };
+
+    // `x + (y - 1)` or `(y - 1) + x`
+    if is_minus_one(cx, rhs, y) {
+        return Some(lhs);
+    }
+    if is_minus_one(cx, lhs, y) {
+        return Some(rhs);
+    }
+
+    // `x + 7` where `y` is `8`
+    let typeck = cx.typeck_results();
+    match (constant_simple(cx, typeck, rhs), constant_simple(cx, typeck, y)) {
+        (Some(Constant::Int(c)), Some(Constant::Int(y))) if y > 0 && c == y - 1 => Some(lhs),
+        _ => None,
+    }
+}
+
+/// Checks whether the expression is `y - 1`.
+fn is_minus_one(cx: &LateContext<'_>, expr: &Expr<'_>, y: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, lhs, one) = expr.kind {
+        op.node == BinOpKind::Sub && is_one(cx, one) && SpanlessEq::new(cx).eq_expr(lhs, y)
+    } else {
+        false
+    }
+}
+
+fn is_one(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    constant_simple(cx, cx.typeck_results(), expr) == Some(Constant::Int(1))
+}
+
+fn build_suggestion(cx: &LateContext<'_>, expr: &Expr<'_>, ty: Ty<'_>, x: &Expr<'_>, y: &Expr<'_>) {
+    let mut applicability = Applicability::MachineApplicable;
+    // an unsuffixed literal needs a suffix, since the method can't be called on `{integer}`
+    let x_sugg = match x.kind {
+        ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Int(_, LitIntType::Unsuffixed)) => {
+            let snippet = Sugg::hir_with_applicability(cx, x, "..", &mut applicability);
+            format!("{}_{}", snippet, ty)
+        },
+        _ => Sugg::hir_with_applicability(cx, x, "..", &mut applicability)
+            .maybe_par()
+            .to_string(),
+    };
+    let y_sugg = Sugg::hir_with_applicability(cx, y, "..", &mut applicability);
+
+    span_lint_and_sugg(
+        cx,
+        MANUAL_DIV_CEIL,
+        expr.span,
+        "manually reimplementing `div_ceil`",
+        "consider using `.div_ceil()`",
+        format!("{}.div_ceil({})", x_sugg, y_sugg),
+        applicability,
+    );
+}
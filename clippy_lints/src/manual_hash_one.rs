@@ -0,0 +1,193 @@
+use crate::utils::{match_trait_method, meets_msrv, msrvs, paths, snippet_with_applicability, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BindingAnnotation, Block, BorrowKind, Expr, ExprKind, HirId, Mutability, PatKind, QPath, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::map::Map;
+use rustc_middle::lint::in_external_macro;
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for cases where [`BuildHasher::hash_one`] can be used.
+    ///
+    /// [`BuildHasher::hash_one`]: https://doc.rust-lang.org/std/hash/trait.BuildHasher.html#method.hash_one
+    ///
+    /// **Why is this bad?** It is more concise to use the `hash_one` method.
+    ///
+    /// **Known problems:** `hash_one` is stabilized in Rust 1.71, so the suggestion doesn't
+    /// compile with older toolchains. It isn't made when the `msrv` option is older.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let s = RandomState::new();
+    /// let value = vec![1, 2, 3];
+    ///
+    /// let mut hasher = s.build_hasher();
+    /// value.hash(&mut hasher);
+    /// let hash = hasher.finish();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::hash::BuildHasher;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let s = RandomState::new();
+    /// let value = vec![1, 2, 3];
+    ///
+    /// let hash = s.hash_one(&value);
+    /// ```
+    pub MANUAL_HASH_ONE,
+    complexity,
+    "manual implementations of `BuildHasher::hash_one`",
+    applicability: [MachineApplicable]
+}
+
+pub struct ManualHashOne {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualHashOne {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualHashOne => [MANUAL_HASH_ONE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualHashOne {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::BUILD_HASHER_HASH_ONE) {
+            return;
+        }
+
+        for (i, stmts) in block.stmts.windows(2).enumerate() {
+            if_chain! {
+                // let mut hasher = build_hasher.build_hasher();
+                if let StmtKind::Local(local) = stmts[0].kind;
+                if let PatKind::Binding(BindingAnnotation::Mutable, hasher, _, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if !in_external_macro(cx.sess(), init.span) && !init.span.from_expansion();
+                if let ExprKind::MethodCall(path, _, [build_hasher], _) = init.kind;
+                if path.ident.name.as_str() == "build_hasher";
+                if match_trait_method(cx, init, &paths::BUILD_HASHER);
+
+                // value.hash(&mut hasher);
+                if let StmtKind::Semi(hash_expr) = stmts[1].kind;
+                if !hash_expr.span.from_expansion();
+                if let ExprKind::MethodCall(path, _, [value, hasher_arg], _) = hash_expr.kind;
+                if path.ident.name == sym::hash;
+                if match_trait_method(cx, hash_expr, &paths::HASH);
+                if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, hasher_ref) = hasher_arg.kind;
+                if path_to_local(hasher_ref) == Some(hasher);
+
+                // hasher.finish()
+                let rest = &block.stmts[i + 2..];
+                if let Some(next_span) = rest.first().map(|s| s.span).or_else(|| block.expr.map(|e| e.span));
+                if let Some(finish) = find_single_finish(cx, hasher, rest, block.expr);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        MANUAL_HASH_ONE,
+                        finish.span,
+                        "manual implementation of `BuildHasher::hash_one`",
+                        |diag| {
+                            let mut applicability = Applicability::MachineApplicable;
+                            let build_hasher = snippet_with_applicability(cx, build_hasher.span, "..", &mut applicability);
+                            let value = snippet_with_applicability(cx, value.span, "..", &mut applicability);
+                            diag.multipart_suggestion(
+                                "try",
+                                vec![
+                                    (stmts[0].span.until(next_span), String::new()),
+                                    (finish.span, format!("{}.hash_one(&{})", build_hasher, value)),
+                                ],
+                                applicability,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+fn path_to_local(expr: &Expr<'_>) -> Option<HirId> {
+    if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+        if let Res::Local(id) = path.res {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// Finds the `hasher.finish()` call, if it is the only remaining use of `hasher`.
+fn find_single_finish<'tcx>(
+    cx: &LateContext<'tcx>,
+    hasher: HirId,
+    stmts: &'tcx [rustc_hir::Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let mut visitor = FinishVisitor {
+        cx,
+        hasher,
+        finish: None,
+        other_uses: 0,
+    };
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+    if let Some(expr) = expr {
+        visitor.visit_expr(expr);
+    }
+    match visitor.finish {
+        Some(finish) if visitor.other_uses == 0 => Some(finish),
+        _ => None,
+    }
+}
+
+struct FinishVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    hasher: HirId,
+    finish: Option<&'tcx Expr<'tcx>>,
+    other_uses: usize,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FinishVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [receiver], _) = expr.kind;
+            if path.ident.name == sym::finish;
+            if path_to_local(receiver) == Some(self.hasher);
+            if !expr.span.from_expansion();
+            if match_trait_method(self.cx, expr, &paths::HASHER);
+            then {
+                if self.finish.is_some() {
+                    self.other_uses += 1;
+                } else {
+                    self.finish = Some(expr);
+                }
+                return;
+            }
+        }
+        if path_to_local(expr) == Some(self.hasher) {
+            self.other_uses += 1;
+        } else {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,119 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, is_type_diagnostic_item, snippet_with_applicability, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual case-insensitive ASCII comparison.
+    ///
+    /// **Why is this bad?** The `eq_ignore_ascii_case` method is faster because it does not
+    /// allocate memory for the new strings, and it is more readable.
+    ///
+    /// **Known problems:** `to_lowercase` and `to_uppercase` also change the case of non-ASCII
+    /// characters, so the suggestion changes the behavior for strings which aren't ASCII.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn compare(a: &str, b: &str) -> bool {
+    ///     a.to_ascii_lowercase() == b.to_ascii_lowercase()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn compare(a: &str, b: &str) -> bool {
+    ///     a.eq_ignore_ascii_case(b)
+    /// }
+    /// ```
+    pub MANUAL_IGNORE_CASE_CMP,
+    perf,
+    "manual case-insensitive ASCII comparison",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
+declare_lint_pass!(ManualIgnoreCaseCmp => [MANUAL_IGNORE_CASE_CMP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualIgnoreCaseCmp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Binary(op, left, right) = expr.kind {
+            if !matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) {
+                return;
+            }
+            if let (Some((left_method, left_recv)), Some((right_method, right_recv))) =
+                (case_conversion(cx, left), case_conversion(cx, right))
+            {
+                if left_method != right_method {
+                    return;
+                }
+                let mut applicability = if left_method.starts_with("to_ascii_") {
+                    Applicability::MachineApplicable
+                } else {
+                    Applicability::MaybeIncorrect
+                };
+                let recv_sugg = Sugg::hir_with_applicability(cx, left_recv, "..", &mut applicability).maybe_par();
+                let arg_snippet = snippet_with_applicability(cx, right_recv.span, "..", &mut applicability);
+                let deref = if cx.typeck_results().expr_ty(right_recv).is_ref() {
+                    ""
+                } else {
+                    "&"
+                };
+                let neg = if op.node == BinOpKind::Ne { "!" } else { "" };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_IGNORE_CASE_CMP,
+                    expr.span,
+                    "manual case-insensitive ASCII comparison",
+                    "consider using `.eq_ignore_ascii_case()` instead",
+                    format!("{}{}.eq_ignore_ascii_case({}{})", neg, recv_sugg, deref, arg_snippet),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Checks for a case conversion of an ASCII-compatible value, and returns the name of the
+/// conversion method and its receiver.
+fn case_conversion<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<(&'static str, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(path, _, [recv], _) = expr.kind {
+        let method = match &*path.ident.as_str() {
+            "to_ascii_lowercase" => "to_ascii_lowercase",
+            "to_ascii_uppercase" => "to_ascii_uppercase",
+            "to_lowercase" => "to_lowercase",
+            "to_uppercase" => "to_uppercase",
+            _ => return None,
+        };
+        let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+        // `char::to_lowercase` returns an iterator, so only strings are checked for those
+        let is_ascii_compatible = if method.starts_with("to_ascii_") {
+            is_ascii_compatible_type(cx, recv_ty)
+        } else {
+            is_string_type(cx, recv_ty)
+        };
+        if is_ascii_compatible {
+            return Some((method, recv));
+        }
+    }
+    None
+}
+
+fn is_string_type(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    *ty.kind() == ty::Str || is_type_diagnostic_item(cx, ty, sym::string_type)
+}
+
+fn is_ascii_compatible_type(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Char | ty::Uint(UintTy::U8) => true,
+        ty::Slice(inner) => *inner.kind() == ty::Uint(UintTy::U8),
+        ty::Adt(_, substs) if is_type_diagnostic_item(cx, ty, sym::vec_type) => {
+            *substs.type_at(0).kind() == ty::Uint(UintTy::U8)
+        },
+        _ => is_string_type(cx, ty),
+    }
+}
@@ -1,15 +1,13 @@
-use crate::utils::{meets_msrv, snippet_opt, span_lint_and_then};
+use crate::utils::{meets_msrv, msrvs, snippet_opt, span_lint_and_then};
 use if_chain::if_chain;
 use rustc_ast::ast::{Attribute, Item, ItemKind, StructField, Variant, VariantData, VisibilityKind};
 use rustc_attr as attr;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::{sym, Span};
 
-const MANUAL_NON_EXHAUSTIVE_MSRV: RustcVersion = RustcVersion::new(1, 40, 0);
-
 declare_clippy_lint! {
     /// **What it does:** Checks for manual implementations of the non-exhaustive pattern.
     ///
@@ -55,7 +53,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_NON_EXHAUSTIVE,
     style,
-    "manual implementations of the non-exhaustive pattern can be simplified using #[non_exhaustive]"
+    "manual implementations of the non-exhaustive pattern can be simplified using #[non_exhaustive]",
+    applicability: [Unspecified]
 }
 
 #[derive(Clone)]
@@ -74,7 +73,7 @@ impl_lint_pass!(ManualNonExhaustive => [MANUAL_NON_EXHAUSTIVE]);
 
 impl EarlyLintPass for ManualNonExhaustive {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
-        if !meets_msrv(self.msrv.as_ref(), &MANUAL_NON_EXHAUSTIVE_MSRV) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::NON_EXHAUSTIVE) {
             return;
         }
 
@@ -7,7 +7,7 @@ use rustc_hir::{def, Expr, ExprKind, PatKind, QPath};
 use rustc_lint::LintContext;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::sym;
 
 declare_clippy_lint! {
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_OK_OR,
     pedantic,
-    "finds patterns that can be encoded more concisely with `Option::ok_or`"
+    "finds patterns that can be encoded more concisely with `Option::ok_or`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ManualOkOr => [MANUAL_OK_OR]);
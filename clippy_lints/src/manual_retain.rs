@@ -0,0 +1,210 @@
+use crate::utils::{
+    higher, in_macro, is_type_diagnostic_item, match_trait_method, meets_msrv, msrvs, paths, snippet_opt,
+    span_lint_and_sugg, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code to be replaced by `.retain()`.
+    ///
+    /// **Why is this bad?** `.retain()` is simpler and avoids needless allocation.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec = vec.iter().filter(|&x| x % 2 == 0).copied().collect();
+    /// vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec.retain(|x| x % 2 == 0);
+    /// vec.retain(|x| x % 2 == 0);
+    /// ```
+    pub MANUAL_RETAIN,
+    perf,
+    "`retain()` is simpler and avoids needless allocation",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
+pub struct ManualRetain {
+    msrv: Option<RustcVersion>,
+}
+
+impl ManualRetain {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualRetain => [MANUAL_RETAIN]);
+
+/// The kinds of collections which have a `retain` method.
+#[derive(Clone, Copy, PartialEq)]
+enum Collection {
+    /// `Vec`, `VecDeque` and `HashSet`, which call the predicate with a reference to the element
+    Sequence,
+    /// `HashMap`, which calls the predicate with a reference to the key and the value
+    Map,
+    /// `String`, which calls the predicate with a `char`
+    String,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Assign(target, collect_expr, _) = expr.kind;
+            if let ExprKind::MethodCall(collect_path, _, [collect_recv], _) = collect_expr.kind;
+            if collect_path.ident.name == sym!(collect);
+            if match_trait_method(cx, collect_expr, &paths::ITERATOR);
+            if let Some(collection) = self.collection_kind(cx, target);
+            if let Some((source, predicate)) = check_filter(cx, collect_recv, collection);
+            if SpanlessEq::new(cx).eq_expr(target, source);
+            if let Some(target_snippet) = snippet_opt(cx, target.span);
+            then {
+                let applicability = if collection == Collection::Map {
+                    // the value is passed by mutable reference to `retain`
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    "consider calling `.retain()` instead",
+                    format!("{}.retain({})", target_snippet, predicate),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+impl ManualRetain {
+    fn collection_kind(&self, cx: &LateContext<'_>, target: &Expr<'_>) -> Option<Collection> {
+        let ty = cx.typeck_results().expr_ty(target);
+        if is_type_diagnostic_item(cx, ty, sym::vec_type)
+            || is_type_diagnostic_item(cx, ty, sym!(vecdeque_type))
+            || (is_type_diagnostic_item(cx, ty, sym!(hashset_type))
+                && meets_msrv(self.msrv.as_ref(), &msrvs::HASH_MAP_RETAIN))
+        {
+            Some(Collection::Sequence)
+        } else if is_type_diagnostic_item(cx, ty, sym!(hashmap_type))
+            && meets_msrv(self.msrv.as_ref(), &msrvs::HASH_MAP_RETAIN)
+        {
+            Some(Collection::Map)
+        } else if is_type_diagnostic_item(cx, ty, sym::string_type)
+            && meets_msrv(self.msrv.as_ref(), &msrvs::STRING_RETAIN)
+        {
+            Some(Collection::String)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks for `x.into_iter().filter(f)`, `x.drain(..).filter(f)`, `x.iter().filter(f).cloned()`
+/// and `s.chars().filter(f)`, and returns `x` and the predicate to pass to `retain`.
+fn check_filter<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'tcx>,
+    collection: Collection,
+) -> Option<(&'tcx Expr<'tcx>, String)> {
+    if let ExprKind::MethodCall(path, _, args, _) = expr.kind {
+        match (&*path.ident.as_str(), args) {
+            ("filter", [iter_expr, predicate]) => {
+                if let ExprKind::MethodCall(iter_path, _, iter_args, _) = iter_expr.kind {
+                    match (&*iter_path.ident.as_str(), iter_args, collection) {
+                        // the predicate of `filter` gets a reference to the element, like the
+                        // one of `retain`
+                        ("into_iter" | "drain", [source], Collection::Sequence | Collection::Map) => {
+                            if collection == Collection::Map {
+                                return Some((source, map_predicate(cx, predicate)?));
+                            }
+                            return Some((source, snippet_opt(cx, predicate.span)?));
+                        },
+                        // `Vec` and `VecDeque` have to be drained completely
+                        ("drain", [source, range], Collection::Sequence) if is_full_range(range) => {
+                            return Some((source, snippet_opt(cx, predicate.span)?));
+                        },
+                        // the predicate of `filter` gets a reference to the `char`
+                        ("chars", [source], Collection::String) => {
+                            return Some((source, strip_ref_predicate(cx, predicate)?));
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            ("cloned" | "copied", [filter_expr]) => {
+                // the predicate of `filter` gets a reference to a reference to the element
+                if_chain! {
+                    if collection == Collection::Sequence;
+                    if let ExprKind::MethodCall(filter_path, _, [iter_expr, predicate], _) = filter_expr.kind;
+                    if filter_path.ident.name == sym!(filter);
+                    if let ExprKind::MethodCall(iter_path, _, [source], _) = iter_expr.kind;
+                    if iter_path.ident.name == sym::iter;
+                    then {
+                        return Some((source, strip_ref_predicate(cx, predicate)?));
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    None
+}
+
+fn is_full_range(expr: &Expr<'_>) -> bool {
+    higher::range(expr).map_or(false, |range| range.start.is_none() && range.end.is_none())
+}
+
+/// Turns `|&x| ..` into `|x| ..`, since `retain` passes the element by one reference less than
+/// `filter` does.
+fn strip_ref_predicate(cx: &LateContext<'_>, predicate: &Expr<'_>) -> Option<String> {
+    if let ExprKind::Closure(_, _, body_id, _, _) = predicate.kind {
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params {
+            if let PatKind::Ref(pat, _) = param.pat.kind {
+                return Some(format!(
+                    "|{}| {}",
+                    snippet_opt(cx, pat.span)?,
+                    snippet_opt(cx, body.value.span)?
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// Turns `|(k, v)| ..` into `|k, v| ..`, since `retain` passes the key and the value of a map
+/// separately.
+fn map_predicate(cx: &LateContext<'_>, predicate: &Expr<'_>) -> Option<String> {
+    if let ExprKind::Closure(_, _, body_id, _, _) = predicate.kind {
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params {
+            if let PatKind::Tuple([key, value], None) = param.pat.kind {
+                return Some(format!(
+                    "|{}, {}| {}",
+                    snippet_opt(cx, key.span)?,
+                    snippet_opt(cx, value.span)?,
+                    snippet_opt(cx, body.value.span)?
+                ));
+            }
+        }
+    }
+    None
+}
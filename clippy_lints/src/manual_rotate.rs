@@ -0,0 +1,171 @@
+use crate::consts::{constant_simple, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_sugg, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, layout::IntegerExt};
+use rustc_session::declare_tool_lint;
+use rustc_target::abi::Integer;
+use std::fmt;
+
+declare_clippy_lint! {
+    /// **What it does:** It detects manual bit rotations that could be rewritten using standard
+    /// functions `rotate_left` or `rotate_right`.
+    ///
+    /// **Why is this bad?** Calling the function better conveys the intent. A manual rotation is
+    /// also easy to get wrong, since shifting by the full bit width of the type overflows.
+    ///
+    /// **Known problems:** Only unsigned integers are checked, since the right shift of signed
+    /// integers is an arithmetic shift.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = 12345678_u32;
+    /// let _ = (x >> 8) | (x << 24);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = 12345678_u32;
+    /// let _ = x.rotate_right(8);
+    /// ```
+    pub MANUAL_ROTATE,
+    style,
+    "using bit shifts to rotate integers",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
+declare_lint_pass!(ManualRotate => [MANUAL_ROTATE]);
+
+#[derive(Clone, Copy, PartialEq)]
+enum ShiftDirection {
+    Left,
+    Right,
+}
+
+impl fmt::Display for ShiftDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Left => "rotate_left",
+            Self::Right => "rotate_right",
+        })
+    }
+}
+
+/// Returns the direction, the shifted value and the shift amount of a shift expression.
+fn parse_shift<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(ShiftDirection, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Binary(op, value, amount) = expr.kind {
+        let dir = match op.node {
+            BinOpKind::Shl => ShiftDirection::Left,
+            BinOpKind::Shr => ShiftDirection::Right,
+            _ => return None,
+        };
+        return Some((dir, value, amount));
+    }
+    None
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualRotate {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Binary(op, l, r) = expr.kind {
+            if op.node != BinOpKind::BitOr {
+                return;
+            }
+            let (l_dir, l_value, l_amount) = match parse_shift(l) {
+                Some(shift) => shift,
+                None => return,
+            };
+            let (r_dir, r_value, r_amount) = match parse_shift(r) {
+                Some(shift) => shift,
+                None => return,
+            };
+            if l_dir == r_dir || !SpanlessEq::new(cx).eq_expr(l_value, r_value) {
+                return;
+            }
+            let bit_width = match cx.typeck_results().expr_ty(expr).kind() {
+                ty::Uint(uty) => u128::from(Integer::from_uint_ty(&cx.tcx, *uty).size().bits()),
+                _ => return,
+            };
+
+            let mut applicability = Applicability::MachineApplicable;
+            let typeck = cx.typeck_results();
+            let (dir, amount) = if let (Some(Constant::Int(l_const)), Some(Constant::Int(r_const))) = (
+                constant_simple(cx, typeck, l_amount),
+                constant_simple(cx, typeck, r_amount),
+            ) {
+                if l_const + r_const != bit_width {
+                    return;
+                }
+                if l_const < r_const {
+                    (l_dir, l_const.to_string())
+                } else {
+                    (r_dir, r_const.to_string())
+                }
+            } else {
+                // `(x << n) | (x >> (BITS - n))`
+                let (dir, amount) = if is_width_minus(cx, r_amount, l_amount, bit_width) {
+                    (l_dir, l_amount)
+                } else if is_width_minus(cx, l_amount, r_amount, bit_width) {
+                    (r_dir, r_amount)
+                } else {
+                    return;
+                };
+                // `rotate_left` and `rotate_right` take a `u32`, while shifts accept any integer
+                if !matches!(typeck.expr_ty(amount).kind(), ty::Uint(ty::UintTy::U32)) {
+                    applicability = Applicability::MaybeIncorrect;
+                }
+                (
+                    dir,
+                    snippet_with_applicability(cx, amount.span, "_", &mut applicability).to_string(),
+                )
+            };
+
+            let value = Sugg::hir_with_applicability(cx, l_value, "_", &mut applicability);
+            // the shifted value may already be parenthesized, e.g. `((x + 1) << 8) | ((x + 1) >> 24)`
+            let value = if has_enclosing_paren(&value.to_string()) {
+                value
+            } else {
+                value.maybe_par()
+            };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_ROTATE,
+                expr.span,
+                "there is no need to manually implement bit rotation",
+                "this expression can be rewritten as",
+                format!("{}.{}({})", value, dir, amount),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks whether `expr` is `bit_width - amount`.
+fn is_width_minus(cx: &LateContext<'_>, expr: &Expr<'_>, amount: &Expr<'_>, bit_width: u128) -> bool {
+    if let ExprKind::Binary(op, width, rhs) = expr.kind {
+        op.node == BinOpKind::Sub
+            && constant_simple(cx, cx.typeck_results(), width) == Some(Constant::Int(bit_width))
+            && SpanlessEq::new(cx).eq_expr(rhs, amount)
+    } else {
+        false
+    }
+}
+
+/// Checks whether the whole snippet is enclosed in a pair of parentheses.
+fn has_enclosing_paren(snippet: &str) -> bool {
+    let mut depth = 0;
+    for (i, c) in snippet.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {},
+        }
+        if depth == 0 {
+            return i == snippet.len() - 1 && i > 0;
+        }
+    }
+    false
+}
@@ -7,7 +7,7 @@ use rustc_hir::{def, Arm, Expr, ExprKind, Pat, PatKind, QPath};
 use rustc_lint::LintContext;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_UNWRAP_OR,
     complexity,
-    "finds patterns that can be encoded more concisely with `Option::unwrap_or` or `Result::unwrap_or`"
+    "finds patterns that can be encoded more concisely with `Option::unwrap_or` or `Result::unwrap_or`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ManualUnwrapOr => [MANUAL_UNWRAP_OR]);
@@ -56,7 +57,7 @@ enum Case {
 }
 
 impl Case {
-    fn unwrap_fn_path(&self) -> &str {
+    fn unwrap_fn_path(self) -> &'static str {
         match self {
             Case::Option => "Option::unwrap_or",
             Case::Result => "Result::unwrap_or",
@@ -9,7 +9,7 @@ use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::Mutability;
 use rustc_middle::ty;
 use rustc_middle::ty::adjustment::Adjust;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span};
 
@@ -39,7 +39,8 @@ declare_clippy_lint! {
     /// ```
     pub MAP_CLONE,
     style,
-    "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"
+    "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(MapClone => [MAP_CLONE]);
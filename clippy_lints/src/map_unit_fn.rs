@@ -4,7 +4,7 @@ use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 
@@ -47,7 +47,8 @@ declare_clippy_lint! {
     /// ```
     pub OPTION_MAP_UNIT_FN,
     complexity,
-    "using `option.map(f)`, where `f` is a function or closure that returns `()`"
+    "using `option.map(f)`, where `f` is a function or closure that returns `()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -88,7 +89,8 @@ declare_clippy_lint! {
     /// ```
     pub RESULT_MAP_UNIT_FN,
     complexity,
-    "using `result.map(f)`, where `f` is a function or closure that returns `()`"
+    "using `result.map(f)`, where `f` is a function or closure that returns `()`",
+    applicability: [HasPlaceholders, MachineApplicable]
 }
 
 declare_lint_pass!(MapUnit => [OPTION_MAP_UNIT_FN, RESULT_MAP_UNIT_FN]);
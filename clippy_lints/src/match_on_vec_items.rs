@@ -4,7 +4,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, LangItem, MatchSource};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -40,7 +40,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_ON_VEC_ITEMS,
     pedantic,
-    "matching on vector elements can panic"
+    "matching on vector elements can panic",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(MatchOnVecItems => [MATCH_ON_VEC_ITEMS]);
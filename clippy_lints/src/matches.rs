@@ -3,9 +3,10 @@ use crate::utils::sugg::Sugg;
 use crate::utils::usage::is_unused;
 use crate::utils::{
     expr_block, get_arg_name, get_parent_expr, implements_trait, in_macro, indent_of, is_allowed, is_expn_of,
-    is_refutable, is_type_diagnostic_item, is_wild, match_qpath, match_type, match_var, meets_msrv, multispan_sugg,
-    peel_hir_pat_refs, peel_mid_ty_refs, peel_n_hir_expr_refs, remove_blocks, snippet, snippet_block, snippet_opt,
-    snippet_with_applicability, span_lint_and_help, span_lint_and_note, span_lint_and_sugg, span_lint_and_then,
+    is_refutable, is_type_diagnostic_item, is_wild, match_qpath, match_type, match_var, meets_msrv, msrvs,
+    multispan_sugg, peel_hir_pat_refs, peel_mid_ty_refs, peel_n_hir_expr_refs, remove_blocks, snippet, snippet_block,
+    snippet_opt, snippet_with_applicability, span_lint_and_help, span_lint_and_note, span_lint_and_sugg,
+    span_lint_and_then,
 };
 use crate::utils::{paths, search_same, SpanlessEq, SpanlessHash};
 use if_chain::if_chain;
@@ -21,7 +22,7 @@ use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty, TyS};
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{Span, Spanned};
 use rustc_span::{sym, Symbol};
 use std::cmp::Ordering;
@@ -53,7 +54,8 @@ declare_clippy_lint! {
     /// ```
     pub SINGLE_MATCH,
     style,
-    "a `match` statement with a single nontrivial arm (i.e., where the other arm is `_ => {}`) instead of `if let`"
+    "a `match` statement with a single nontrivial arm (i.e., where the other arm is `_ => {}`) instead of `if let`",
+    applicability: [HasPlaceholders]
 }
 
 declare_clippy_lint! {
@@ -92,7 +94,8 @@ declare_clippy_lint! {
     /// ```
     pub SINGLE_MATCH_ELSE,
     pedantic,
-    "a `match` statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern"
+    "a `match` statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern",
+    applicability: [HasPlaceholders]
 }
 
 declare_clippy_lint! {
@@ -123,7 +126,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_REF_PATS,
     style,
-    "a `match` or `if let` with all arms prefixed with `&` instead of deref-ing the match expression"
+    "a `match` or `if let` with all arms prefixed with `&` instead of deref-ing the match expression",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -157,7 +161,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_BOOL,
     pedantic,
-    "a `match` on a boolean expression instead of an `if..else` block"
+    "a `match` on a boolean expression instead of an `if..else` block",
+    applicability: [HasPlaceholders]
 }
 
 declare_clippy_lint! {
@@ -227,7 +232,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_AS_REF,
     complexity,
-    "a `match` on an Option value instead of using `as_ref()` or `as_mut`"
+    "a `match` on an Option value instead of using `as_ref()` or `as_mut`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -256,7 +262,8 @@ declare_clippy_lint! {
     /// ```
     pub WILDCARD_ENUM_MATCH_ARM,
     restriction,
-    "a wildcard enum match arm using `_`"
+    "a wildcard enum match arm using `_`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -288,7 +295,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_WILDCARD_FOR_SINGLE_VARIANTS,
     pedantic,
-    "a wildcard enum match for a single variant"
+    "a wildcard enum match for a single variant",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -350,7 +358,8 @@ declare_clippy_lint! {
     /// ```
     pub INFALLIBLE_DESTRUCTURING_MATCH,
     style,
-    "a `match` statement with a single infallible arm instead of a `let`"
+    "a `match` statement with a single infallible arm instead of a `let`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -378,7 +387,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_SINGLE_BINDING,
     complexity,
-    "a match with a single binding instead of using `let` statement"
+    "a match with a single binding instead of using `let` statement",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -456,7 +466,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_PATTERN_MATCHING,
     style,
-    "use the proper utility function avoiding an `if let`"
+    "use the proper utility function avoiding an `if let`",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -489,7 +500,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_LIKE_MATCHES_MACRO,
     style,
-    "a match that could be written with the matches! macro"
+    "a match that could be written with the matches! macro",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -533,6 +545,38 @@ declare_clippy_lint! {
     "`match` with identical arm bodies"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for unnecessary guards in match expressions.
+    ///
+    /// **Why is this bad?** It's more complex and much less readable. Making it part of the
+    /// pattern can improve exhaustiveness checking as well.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// match x {
+    ///     Some(x) if x == 2 => ..,
+    ///     Some(x) if x == MAX => ..,
+    ///     Some(x) if (3..=5).contains(&x) => ..,
+    ///     _ => todo!(),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// match x {
+    ///     Some(2) => ..,
+    ///     Some(MAX) => ..,
+    ///     Some(3..=5) => ..,
+    ///     _ => todo!(),
+    /// }
+    /// ```
+    pub REDUNDANT_GUARDS,
+    complexity,
+    "checks for unnecessary guards in match expressions",
+    applicability: [MachineApplicable]
+}
+
 #[derive(Default)]
 pub struct Matches {
     msrv: Option<RustcVersion>,
@@ -566,10 +610,9 @@ impl_lint_pass!(Matches => [
     REDUNDANT_PATTERN_MATCHING,
     MATCH_LIKE_MATCHES_MACRO,
     MATCH_SAME_ARMS,
+    REDUNDANT_GUARDS,
 ]);
 
-const MATCH_LIKE_MATCHES_MACRO_MSRV: RustcVersion = RustcVersion::new(1, 42, 0);
-
 impl<'tcx> LateLintPass<'tcx> for Matches {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if in_external_macro(cx.sess(), expr.span) || in_macro(expr.span) {
@@ -578,7 +621,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
 
         redundant_pattern_match::check(cx, expr);
 
-        if meets_msrv(self.msrv.as_ref(), &MATCH_LIKE_MATCHES_MACRO_MSRV) {
+        if meets_msrv(self.msrv.as_ref(), &msrvs::MATCHES_MACRO) {
             if !check_match_like_matches(cx, expr) {
                 lint_match_arms(cx, expr);
             }
@@ -594,6 +637,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
             check_wild_enum_match(cx, ex, arms);
             check_match_as_ref(cx, ex, arms, expr);
             check_wild_in_or_pats(cx, arms);
+            redundant_guards::check(cx, arms);
 
             if self.infallible_destructuring_match_linted {
                 self.infallible_destructuring_match_linted = false;
@@ -1821,6 +1865,207 @@ mod redundant_pattern_match {
     }
 }
 
+mod redundant_guards {
+    use super::REDUNDANT_GUARDS;
+    use crate::utils::{higher, in_macro, snippet_with_applicability, span_lint_and_then};
+    use if_chain::if_chain;
+    use rustc_ast::ast::RangeLimits;
+    use rustc_errors::Applicability;
+    use rustc_hir::def::{DefKind, Res};
+    use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+    use rustc_hir::{
+        Arm, BinOpKind, BindingAnnotation, BorrowKind, Expr, ExprKind, Guard, HirId, Node, Pat, PatKind, QPath, UnOp,
+    };
+    use rustc_lint::LateContext;
+    use rustc_middle::hir::map::Map;
+    use rustc_middle::ty::{self, TypeFoldable};
+    use rustc_span::Span;
+
+    pub fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'tcx>]) {
+        for arm in arms {
+            if_chain! {
+                if let Some(Guard::If(guard)) = arm.guard;
+                if !in_macro(guard.span);
+                if let Some((local_id, pat_spans)) = guard_as_pattern(cx, guard);
+                if let Some((binding, shorthand_field)) = find_binding(cx, arm.pat, local_id);
+                if !is_local_used(cx, arm.body, local_id);
+                then {
+                    span_lint_and_then(cx, REDUNDANT_GUARDS, guard.span, "redundant guard", |diag| {
+                        let mut applicability = Applicability::MachineApplicable;
+                        let pat_snip = match pat_spans {
+                            PatSpans::Single(span) => snippet_with_applicability(cx, span, "..", &mut applicability)
+                                .into_owned(),
+                            PatSpans::Range(start, end) => format!(
+                                "{}..={}",
+                                snippet_with_applicability(cx, start, "..", &mut applicability),
+                                snippet_with_applicability(cx, end, "..", &mut applicability),
+                            ),
+                        };
+                        let replacement = match shorthand_field {
+                            Some(field) => format!("{}: {}", field, pat_snip),
+                            None => pat_snip,
+                        };
+                        diag.multipart_suggestion(
+                            "try",
+                            vec![
+                                (binding.span, replacement),
+                                (arm.pat.span.shrink_to_hi().with_hi(guard.span.hi()), String::new()),
+                            ],
+                            applicability,
+                        );
+                    });
+                }
+            }
+        }
+    }
+
+    enum PatSpans {
+        /// A literal or a constant
+        Single(Span),
+        /// The bounds of an inclusive range
+        Range(Span, Span),
+    }
+
+    /// Checks if the guard is `x == <pat>`, `<pat> == x` or `(<pat>..=<pat>).contains(&x)`,
+    /// returning the binding of `x` and the spans of the pattern parts.
+    fn guard_as_pattern<'tcx>(cx: &LateContext<'tcx>, guard: &'tcx Expr<'tcx>) -> Option<(HirId, PatSpans)> {
+        match guard.kind {
+            ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Eq => {
+                if let (Some(id), true) = (path_to_local(lhs), is_pat_expressible(cx, rhs, lhs)) {
+                    Some((id, PatSpans::Single(rhs.span)))
+                } else if let (Some(id), true) = (path_to_local(rhs), is_pat_expressible(cx, lhs, rhs)) {
+                    Some((id, PatSpans::Single(lhs.span)))
+                } else {
+                    None
+                }
+            },
+            ExprKind::MethodCall(path, _, [receiver, arg], _) if path.ident.as_str() == "contains" => {
+                if_chain! {
+                    if let Some(higher::Range { start: Some(start), end: Some(end), limits: RangeLimits::Closed }) =
+                        higher::range(receiver);
+                    if let ExprKind::AddrOf(BorrowKind::Ref, _, inner) = arg.kind;
+                    if let Some(id) = path_to_local(inner);
+                    if is_pat_expressible(cx, start, inner) && is_pat_expressible(cx, end, inner);
+                    then {
+                        Some((id, PatSpans::Range(start.span, end.span)))
+                    } else {
+                        None
+                    }
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn path_to_local(expr: &Expr<'_>) -> Option<HirId> {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+            if let Res::Local(id) = path.res {
+                return Some(id);
+            }
+        }
+        None
+    }
+
+    /// Checks if the expression can be written as a pattern of a primitive type, i.e. it is a
+    /// (negated) literal or a constant, that can be matched against the binding.
+    fn is_pat_expressible(cx: &LateContext<'_>, expr: &Expr<'_>, binding: &Expr<'_>) -> bool {
+        let ty = cx.typeck_results().expr_ty(expr);
+        if expr.span.from_expansion() || ty != cx.typeck_results().expr_ty(binding) {
+            return false;
+        }
+        let is_primitive = match ty.kind() {
+            ty::Int(_) | ty::Uint(_) | ty::Char | ty::Bool => true,
+            ty::Ref(_, inner, _) => inner.is_str(),
+            _ => false,
+        };
+        if !is_primitive {
+            return false;
+        }
+        match expr.kind {
+            ExprKind::Lit(_) => true,
+            ExprKind::Unary(UnOp::UnNeg, inner) => matches!(inner.kind, ExprKind::Lit(_)),
+            ExprKind::Path(ref qpath) => {
+                matches!(
+                    cx.qpath_res(qpath, expr.hir_id),
+                    Res::Def(DefKind::Const | DefKind::AssocConst, _)
+                ) && !cx.typeck_results().node_substs(expr.hir_id).needs_subst()
+            },
+            _ => false,
+        }
+    }
+
+    /// Finds the by-value binding `local_id` in the pattern. If it is the shorthand of a struct
+    /// field, the name of the field is returned as well.
+    fn find_binding<'tcx>(
+        cx: &LateContext<'tcx>,
+        pat: &'tcx Pat<'tcx>,
+        local_id: HirId,
+    ) -> Option<(&'tcx Pat<'tcx>, Option<String>)> {
+        let binding = if let Some(Node::Binding(binding)) = cx.tcx.hir().find(local_id) {
+            binding
+        } else {
+            return None;
+        };
+        if !matches!(
+            binding.kind,
+            PatKind::Binding(BindingAnnotation::Unannotated, _, _, None)
+        ) || !pat.span.contains(binding.span)
+        {
+            return None;
+        }
+
+        // bindings in or-patterns would have to be replaced in every alternative
+        let mut has_or_pat = false;
+        let mut shorthand_field = None;
+        pat.walk_always(|p| match p.kind {
+            PatKind::Or(_) => has_or_pat = true,
+            PatKind::Struct(_, fields, _) => {
+                if let Some(field) = fields.iter().find(|f| f.is_shorthand && f.pat.hir_id == local_id) {
+                    shorthand_field = Some(field.ident.to_string());
+                }
+            },
+            _ => {},
+        });
+        if has_or_pat {
+            None
+        } else {
+            Some((binding, shorthand_field))
+        }
+    }
+
+    fn is_local_used<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, local_id: HirId) -> bool {
+        let mut visitor = UsedVisitor {
+            cx,
+            local_id,
+            used: false,
+        };
+        visitor.visit_expr(expr);
+        visitor.used
+    }
+
+    struct UsedVisitor<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        local_id: HirId,
+        used: bool,
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
+        type Map = Map<'tcx>;
+
+        fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+            if path_to_local(expr) == Some(self.local_id) {
+                self.used = true;
+            } else {
+                walk_expr(self, expr);
+            }
+        }
+
+        fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+            NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+        }
+    }
+}
+
 #[test]
 fn test_overlapping() {
     use rustc_span::source_map::DUMMY_SP;
@@ -1,6 +1,6 @@
 use crate::utils::{
-    in_macro, match_def_path, match_qpath, meets_msrv, paths, snippet, snippet_with_applicability, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then,
+    in_macro, match_def_path, match_qpath, meets_msrv, msrvs, paths, snippet, snippet_with_applicability,
+    span_lint_and_help, span_lint_and_sugg, span_lint_and_then,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -8,7 +8,7 @@ use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
 
@@ -36,7 +36,8 @@ declare_clippy_lint! {
     /// ```
     pub MEM_REPLACE_OPTION_WITH_NONE,
     style,
-    "replacing an `Option` with `None` instead of `take()`"
+    "replacing an `Option` with `None` instead of `take()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -68,7 +69,8 @@ declare_clippy_lint! {
     /// on panic, to ensure that the uninitialized value cannot be observed.
     pub MEM_REPLACE_WITH_UNINIT,
     correctness,
-    "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`"
+    "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -92,7 +94,8 @@ declare_clippy_lint! {
     /// ```
     pub MEM_REPLACE_WITH_DEFAULT,
     style,
-    "replacing a value of type `T` with `T::default()` instead of using `std::mem::take`"
+    "replacing a value of type `T` with `T::default()` instead of using `std::mem::take`",
+    applicability: [MachineApplicable]
 }
 
 impl_lint_pass!(MemReplace =>
@@ -225,8 +228,6 @@ fn check_replace_with_default(cx: &LateContext<'_>, src: &Expr<'_>, dest: &Expr<
     }
 }
 
-const MEM_REPLACE_WITH_DEFAULT_MSRV: RustcVersion = RustcVersion::new(1, 40, 0);
-
 pub struct MemReplace {
     msrv: Option<RustcVersion>,
 }
@@ -250,7 +251,7 @@ impl<'tcx> LateLintPass<'tcx> for MemReplace {
             then {
                 check_replace_option_with_none(cx, src, dest, expr.span);
                 check_replace_with_uninit(cx, src, dest, expr.span);
-                if meets_msrv(self.msrv.as_ref(), &MEM_REPLACE_WITH_DEFAULT_MSRV) {
+                if meets_msrv(self.msrv.as_ref(), &msrvs::MEM_TAKE) {
                     check_replace_with_default(cx, src, dest, expr.span);
                 }
             }
@@ -0,0 +1,58 @@
+use crate::utils::{
+    higher, is_type_diagnostic_item, match_type, paths, snippet_with_applicability, span_lint_and_sugg,
+};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::DRAIN_COLLECT;
+
+/// lint use of `v.drain(..).collect()` into a container of the same type as `v`
+pub(super) fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, drain_args: &'tcx [hir::Expr<'_>]) {
+    let recv = &drain_args[0];
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let (recv_ty, is_ref) = match recv_ty.kind() {
+        ty::Ref(_, inner, hir::Mutability::Mut) => (*inner, true),
+        _ => (recv_ty, false),
+    };
+
+    let drains_everything = match drain_args {
+        // `Vec`, `VecDeque` and `String` take a range
+        [_, range] => {
+            (is_type_diagnostic_item(cx, recv_ty, sym::vec_type)
+                || is_type_diagnostic_item(cx, recv_ty, sym!(vecdeque_type))
+                || is_type_diagnostic_item(cx, recv_ty, sym::string_type))
+                && is_full_range(range)
+        },
+        [_] => is_drainable_map_or_set(cx, recv_ty),
+        _ => false,
+    };
+    if !drains_everything || cx.typeck_results().expr_ty(expr) != recv_ty {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    let recv_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+    let borrow = if is_ref { "" } else { "&mut " };
+    span_lint_and_sugg(
+        cx,
+        DRAIN_COLLECT,
+        expr.span,
+        "you seem to be trying to move all elements into a new collection",
+        "consider using `mem::take`",
+        format!("std::mem::take({}{})", borrow, recv_snippet),
+        applicability,
+    );
+}
+
+fn is_full_range(expr: &hir::Expr<'_>) -> bool {
+    higher::range(expr).map_or(false, |range| range.start.is_none() && range.end.is_none())
+}
+
+fn is_drainable_map_or_set(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    is_type_diagnostic_item(cx, ty, sym!(hashmap_type))
+        || is_type_diagnostic_item(cx, ty, sym!(hashset_type))
+        || match_type(cx, ty, &paths::BINARY_HEAP)
+}
@@ -0,0 +1,86 @@
+use crate::utils::higher::{vec_macro, VecArgs};
+use crate::utils::{is_type_diagnostic_item, match_qpath, paths, snippet_with_applicability, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::{ITER_ON_EMPTY_COLLECTIONS, ITER_ON_SINGLE_ITEMS};
+
+/// The contents of a collection literal the iterator is created from
+enum Contents<'tcx> {
+    Empty,
+    Single(&'tcx hir::Expr<'tcx>),
+}
+
+/// lint use of `[x].iter()`, `Some(x).into_iter()`, `vec![].iter()` and similar
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    iter_args: &'tcx [hir::Expr<'_>],
+    method: &str,
+) {
+    let recv = &iter_args[0];
+    let (contents, is_array) = if let Some(VecArgs::Vec(elems)) = vec_macro(cx, recv) {
+        match elems {
+            [] => (Contents::Empty, false),
+            [elem] => (Contents::Single(elem), false),
+            _ => return,
+        }
+    } else {
+        match recv.kind {
+            ExprKind::Array([]) => (Contents::Empty, true),
+            ExprKind::Array([elem]) => (Contents::Single(elem), true),
+            ExprKind::Call(func, [elem]) if is_option_ctor(cx, recv, func, &paths::OPTION_SOME) => {
+                (Contents::Single(elem), false)
+            },
+            ExprKind::Path(ref qpath)
+                if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::option_type)
+                    && match_qpath(qpath, &paths::OPTION_NONE) =>
+            {
+                (Contents::Empty, false)
+            },
+            _ => return,
+        }
+    };
+
+    match contents {
+        Contents::Single(elem) => {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let elem_snippet = snippet_with_applicability(cx, elem.span, "..", &mut applicability);
+            // `into_iter` on an array resolves to the slice's one, which yields references
+            let borrow = match method {
+                "iter" => "&",
+                "iter_mut" => "&mut ",
+                _ if is_array => "&",
+                _ => "",
+            };
+            span_lint_and_sugg(
+                cx,
+                ITER_ON_SINGLE_ITEMS,
+                expr.span,
+                &format!("`{}` call on a collection with only one item", method),
+                "try",
+                format!("std::iter::once({}{})", borrow, elem_snippet),
+                applicability,
+            );
+        },
+        Contents::Empty => {
+            span_lint_and_sugg(
+                cx,
+                ITER_ON_EMPTY_COLLECTIONS,
+                expr.span,
+                &format!("`{}` call on an empty collection", method),
+                "try",
+                "std::iter::empty()".to_string(),
+                Applicability::MaybeIncorrect,
+            );
+        },
+    }
+}
+
+fn is_option_ctor(cx: &LateContext<'_>, expr: &hir::Expr<'_>, func: &hir::Expr<'_>, path: &[&str]) -> bool {
+    matches!(func.kind, ExprKind::Path(ref qpath) if match_qpath(qpath, path))
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::option_type)
+}
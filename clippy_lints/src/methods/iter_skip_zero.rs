@@ -0,0 +1,33 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{match_trait_method, paths, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::ITER_SKIP_ZERO;
+
+/// lint use of `skip(0)` for `Iterators`
+pub(super) fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, skip_args: &'tcx [hir::Expr<'_>]) {
+    if_chain! {
+        if match_trait_method(cx, expr, &paths::ITERATOR);
+        if let Some((Constant::Int(0), _)) = constant(cx, cx.typeck_results(), &skip_args[1]);
+        then {
+            span_lint_and_then(
+                cx,
+                ITER_SKIP_ZERO,
+                skip_args[1].span,
+                "called `.skip(0)` on an `Iterator`, which does nothing",
+                |diag| {
+                    diag.span_suggestion(
+                        skip_args[1].span,
+                        "if you meant to skip the first element, use",
+                        "1".to_string(),
+                        Applicability::MaybeIncorrect,
+                    );
+                    diag.note("otherwise this call to `skip` is useless and can be removed");
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::utils::{is_expr_identity_function, match_trait_method, paths, snippet_opt, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+
+use super::MAP_ALL_ANY_IDENTITY;
+
+/// lint use of `iter.map(f).all(|x| x)` and `iter.map(f).any(|x| x)`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    map_args: &'tcx [hir::Expr<'_>],
+    args: &'tcx [hir::Expr<'_>],
+    map_span: Span,
+    method: &str,
+) {
+    if_chain! {
+        if let [map_recv, map_fn] = map_args;
+        if let [_, identity] = args;
+        if match_trait_method(cx, expr, &paths::ITERATOR);
+        if is_expr_identity_function(cx, identity);
+        if let Some(map_fn_snippet) = snippet_opt(cx, map_fn.span);
+        then {
+            let span = map_span.with_hi(expr.span.hi());
+            // `all` and `any` take the iterator by mutable reference, so a binding used as the
+            // receiver may have to be made mutable
+            let applicability = if let hir::ExprKind::Path(_) = map_recv.kind {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            span_lint_and_then(
+                cx,
+                MAP_ALL_ANY_IDENTITY,
+                span,
+                &format!("usage of `.map(...).{}(identity)`", method),
+                |diag| {
+                    diag.span_suggestion_verbose(
+                        span,
+                        &format!("use `.{}(...)` instead", method),
+                        format!("{}({})", method, map_fn_snippet),
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+}
@@ -1,8 +1,19 @@
 mod bind_instead_of_map;
+mod drain_collect;
 mod inefficient_to_string;
 mod inspect_for_each;
+mod iter_on_single_or_empty_collections;
+mod iter_skip_zero;
 mod manual_saturating_arithmetic;
+mod map_all_any_identity;
+mod needless_as_bytes;
+mod needless_character_iteration;
+mod option_map_or_err_ok;
 mod option_map_unwrap_or;
+mod seek_from_current;
+mod seek_to_start_instead_of_rewind;
+mod sliced_string_as_bytes;
+mod unbuffered_bytes;
 mod unnecessary_filter_map;
 mod unnecessary_lazy_eval;
 
@@ -21,7 +32,7 @@ use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, TraitRef, Ty, TyS};
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{sym, SymbolStr};
 use rustc_typeck::hir_ty_to_ty;
@@ -33,9 +44,9 @@ use crate::utils::{
     contains_return, contains_ty, get_arg_name, get_parent_expr, get_trait_def_id, has_iter_method, higher,
     implements_trait, in_macro, is_copy, is_expn_of, is_type_diagnostic_item, iter_input_pats, last_path_segment,
     match_def_path, match_qpath, match_trait_method, match_type, match_var, meets_msrv, method_calls,
-    method_chain_args, paths, remove_blocks, return_ty, single_segment_path, snippet, snippet_with_applicability,
-    snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, sugg,
-    walk_ptrs_ty_depth, SpanlessEq,
+    method_chain_args, msrvs, paths, remove_blocks, return_ty, single_segment_path, snippet,
+    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg,
+    span_lint_and_then, sugg, walk_ptrs_ty_depth, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -268,7 +279,8 @@ declare_clippy_lint! {
     /// ```
     pub MAP_UNWRAP_OR,
     pedantic,
-    "using `.map(f).unwrap_or(a)` or `.map(f).unwrap_or_else(func)`, which are more succinctly expressed as `map_or(a, f)` or `map_or_else(a, f)`"
+    "using `.map(f).unwrap_or(a)` or `.map(f).unwrap_or_else(func)`, which are more succinctly expressed as `map_or(a, f)` or `map_or_else(a, f)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -291,7 +303,8 @@ declare_clippy_lint! {
     /// ```
     pub OPTION_MAP_OR_NONE,
     style,
-    "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`"
+    "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -317,7 +330,8 @@ declare_clippy_lint! {
     /// ```
     pub RESULT_MAP_OR_INTO_OPTION,
     style,
-    "using `Result.map_or(None, Some)`, which is more succinctly expressed as `ok()`"
+    "using `Result.map_or(None, Some)`, which is more succinctly expressed as `ok()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -350,7 +364,8 @@ declare_clippy_lint! {
     /// ```
     pub BIND_INSTEAD_OF_MAP,
     complexity,
-    "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`"
+    "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -373,7 +388,8 @@ declare_clippy_lint! {
     /// ```
     pub FILTER_NEXT,
     complexity,
-    "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`"
+    "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -419,7 +435,8 @@ declare_clippy_lint! {
     /// ```
     pub MAP_FLATTEN,
     pedantic,
-    "using combinations of `flatten` and `map` which can usually be written as a single method call"
+    "using combinations of `flatten` and `map` which can usually be written as a single method call",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -474,7 +491,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_FILTER_MAP,
     complexity,
-    "using `_.filter(_).map(_)` in a way that can be written more simply as `filter_map(_)`"
+    "using `_.filter(_).map(_)` in a way that can be written more simply as `filter_map(_)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -500,7 +518,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_FIND_MAP,
     complexity,
-    "using `_.find(_).map(_)` in a way that can be written more simply as `find_map(_)`"
+    "using `_.find(_).map(_)` in a way that can be written more simply as `find_map(_)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -522,7 +541,8 @@ declare_clippy_lint! {
     /// ```
     pub FILTER_MAP_NEXT,
     pedantic,
-    "using combination of `filter_map` and `next` which can usually be written as a single method call"
+    "using combination of `filter_map` and `next` which can usually be written as a single method call",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -544,7 +564,8 @@ declare_clippy_lint! {
     /// ```
     pub FLAT_MAP_IDENTITY,
     complexity,
-    "call to `flat_map` where `flatten` is sufficient"
+    "call to `flat_map` where `flatten` is sufficient",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -568,7 +589,8 @@ declare_clippy_lint! {
     /// ```
     pub SEARCH_IS_SOME,
     complexity,
-    "using an iterator or string search followed by `is_some()`, which is more succinctly expressed as a call to `any()` or `contains()`"
+    "using an iterator or string search followed by `is_some()`, which is more succinctly expressed as a call to `any()` or `contains()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -592,7 +614,8 @@ declare_clippy_lint! {
     /// ```
     pub CHARS_NEXT_CMP,
     style,
-    "using `.chars().next()` to check if a string starts with a char"
+    "using `.chars().next()` to check if a string starts with a char",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -623,7 +646,8 @@ declare_clippy_lint! {
     /// ```
     pub OR_FUN_CALL,
     perf,
-    "using any `*or` method with a function call, which suggests `*or_else`"
+    "using any `*or` method with a function call, which suggests `*or_else`",
+    applicability: [HasPlaceholders, MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -658,7 +682,8 @@ declare_clippy_lint! {
     /// ```
     pub EXPECT_FUN_CALL,
     perf,
-    "using any `expect` method with a function call"
+    "using any `expect` method with a function call",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -675,7 +700,8 @@ declare_clippy_lint! {
     /// ```
     pub CLONE_ON_COPY,
     complexity,
-    "using `clone` on a `Copy` type"
+    "using `clone` on a `Copy` type",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -700,7 +726,8 @@ declare_clippy_lint! {
     /// ```
     pub CLONE_ON_REF_PTR,
     restriction,
-    "using 'clone' on a ref-counted pointer"
+    "using 'clone' on a ref-counted pointer",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -722,7 +749,8 @@ declare_clippy_lint! {
     /// ```
     pub CLONE_DOUBLE_REF,
     correctness,
-    "using `clone` on `&&T`"
+    "using `clone` on `&&T`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -745,7 +773,8 @@ declare_clippy_lint! {
     /// ```
     pub INEFFICIENT_TO_STRING,
     pedantic,
-    "using `to_string` on `&&T` where `T: ToString`"
+    "using `to_string` on `&&T` where `T: ToString`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -827,7 +856,8 @@ declare_clippy_lint! {
     /// _.split('x');
     pub SINGLE_CHAR_PATTERN,
     perf,
-    "using a single-character str where a char could be used, e.g., `_.split(\"x\")`"
+    "using a single-character str where a char could be used, e.g., `_.split(\"x\")`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -874,7 +904,8 @@ declare_clippy_lint! {
     /// ```
     pub ITER_NTH_ZERO,
     style,
-    "replace `iter.nth(0)` with `iter.next()`"
+    "replace `iter.nth(0)` with `iter.next()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -924,7 +955,8 @@ declare_clippy_lint! {
     /// ```
     pub ITER_SKIP_NEXT,
     style,
-    "using `.skip(x).next()` on an iterator"
+    "using `.skip(x).next()` on an iterator",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -957,7 +989,8 @@ declare_clippy_lint! {
     /// ```
     pub GET_UNWRAP,
     restriction,
-    "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"
+    "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -986,7 +1019,8 @@ declare_clippy_lint! {
     /// ```
     pub STRING_EXTEND_CHARS,
     style,
-    "using `x.extend(s.chars())` where s is a `&str` or `String`"
+    "using `x.extend(s.chars())` where s is a `&str` or `String`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1009,7 +1043,8 @@ declare_clippy_lint! {
     /// ```
     pub ITER_CLONED_COLLECT,
     style,
-    "using `.cloned().collect()` on slice to create a `Vec`"
+    "using `.cloned().collect()` on slice to create a `Vec`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1033,7 +1068,8 @@ declare_clippy_lint! {
     /// ```
     pub CHARS_LAST_CMP,
     style,
-    "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char"
+    "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1058,7 +1094,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_ASREF,
     complexity,
-    "using `as_ref` where the types before and after the call are the same"
+    "using `as_ref` where the types before and after the call are the same",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1080,7 +1117,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_FOLD,
     style,
-    "using `fold` when a more succinct alternative exists"
+    "using `fold` when a more succinct alternative exists",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1133,7 +1171,8 @@ declare_clippy_lint! {
     /// ```
     pub INTO_ITER_ON_REF,
     style,
-    "using `.into_iter()` on a reference"
+    "using `.into_iter()` on a reference",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1211,7 +1250,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_SATURATING_ARITHMETIC,
     style,
-    "`.chcked_add/sub(x).unwrap_or(MAX/MIN)`"
+    "`.chcked_add/sub(x).unwrap_or(MAX/MIN)`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1293,7 +1333,8 @@ declare_clippy_lint! {
     /// ```
     pub OPTION_AS_REF_DEREF,
     complexity,
-    "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`"
+    "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1319,7 +1360,8 @@ declare_clippy_lint! {
     /// ```
     pub ITER_NEXT_SLICE,
     style,
-    "using `.iter().next()` on a sliced array, which can be shortened to just `.get()`"
+    "using `.iter().next()` on a sliced array, which can be shortened to just `.get()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1344,7 +1386,8 @@ declare_clippy_lint! {
     /// ```
     pub SINGLE_CHAR_ADD_STR,
     style,
-    "`push_str()` or `insert_str()` used with a single-character string literal as parameter"
+    "`push_str()` or `insert_str()` used with a single-character string literal as parameter",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1380,7 +1423,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_LAZY_EVALUATIONS,
     style,
-    "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation"
+    "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -1401,7 +1445,8 @@ declare_clippy_lint! {
     /// ```
     pub MAP_COLLECT_RESULT_UNIT,
     style,
-    "using `.map(_).collect::<Result<(),_>()`, which can be replaced with `try_for_each`"
+    "using `.map(_).collect::<Result<(),_>()`, which can be replaced with `try_for_each`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1434,7 +1479,8 @@ declare_clippy_lint! {
     /// ```
     pub FROM_ITER_INSTEAD_OF_COLLECT,
     style,
-    "use `.collect()` instead of `::from_iter()`"
+    "use `.collect()` instead of `::from_iter()`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -1467,6 +1513,340 @@ declare_clippy_lint! {
     "using `.inspect().for_each()`, which can be replaced with `.for_each()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for the use of `iter.skip(0)`.
+    ///
+    /// **Why is this bad?** Skipping zero elements does nothing, so the call is either
+    /// left over from an edit or a typo for `.skip(1)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// // Bad
+    /// let x = v.iter().skip(0).collect::<Vec<_>>();
+    ///
+    /// // Good
+    /// let x = v.iter().collect::<Vec<_>>();
+    /// ```
+    pub ITER_SKIP_ZERO,
+    correctness,
+    "using `.skip(0)` on an iterator, which is a no-op",
+    applicability: [MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `_.map_or(Err(_), Ok)` and
+    /// `_.map_or_else(|| Err(_), Ok)` on `Option` values.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `_.ok_or(_)` or `_.ok_or_else(|| _)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let opt = Some(1);
+    /// // Bad
+    /// let res: Result<i32, &str> = opt.map_or(Err("error"), Ok);
+    ///
+    /// // Good
+    /// let res: Result<i32, &str> = opt.ok_or("error");
+    /// ```
+    pub OPTION_MAP_OR_ERR_OK,
+    style,
+    "using `Option.map_or(Err(_), Ok)`, which is more succinctly expressed as `Option.ok_or(_)`",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks if an iterator over the `char`s of a string is used to check an
+    /// ASCII property of the string, like `is_ascii` or `is_ascii_digit`.
+    ///
+    /// **Why is this bad?** `str::is_ascii` checks the whole string at once, and the other ASCII
+    /// predicates can be checked on the bytes of the string without decoding its `char`s.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let s = "hello";
+    /// // Bad
+    /// let is_ascii = s.chars().all(|c| c.is_ascii());
+    /// let is_number = s.chars().all(|c| c.is_ascii_digit());
+    ///
+    /// // Good
+    /// let is_ascii = s.is_ascii();
+    /// let is_number = s.bytes().all(|c| c.is_ascii_digit());
+    /// ```
+    pub NEEDLESS_CHARACTER_ITERATION,
+    style,
+    "checking an ASCII property of a string by iterating over its `char`s",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing a string and then calling `as_bytes` or `bytes` on
+    /// the slice.
+    ///
+    /// **Why is this bad?** Slicing a string panics if the range doesn't fall on `char`
+    /// boundaries, which is an unnecessary check when only the bytes are needed. Slicing the
+    /// bytes of the string instead only panics if the range is out of bounds.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let s = "Lorem ipsum";
+    /// // Bad
+    /// let bytes = s[1..5].as_bytes();
+    ///
+    /// // Good
+    /// let bytes = &s.as_bytes()[1..5];
+    /// ```
+    pub SLICED_STRING_AS_BYTES,
+    perf,
+    "slicing a string and immediately calling `as_bytes` or `bytes` on it",
+    applicability: [MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `.map(...)`, followed by `.all(identity)` or
+    /// `.any(identity)`.
+    ///
+    /// **Why is this bad?** The `.all(...)` or `.any(...)` methods can be called directly in place
+    /// of `.map(...)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let mut v = [""];
+    /// // Bad
+    /// let e1 = v.iter().map(|s| s.is_empty()).all(|a| a);
+    /// let e2 = v.iter().map(|s| s.is_empty()).any(std::convert::identity);
+    ///
+    /// // Good
+    /// let e1 = v.iter().all(|s| s.is_empty());
+    /// let e2 = v.iter().any(|s| s.is_empty());
+    /// ```
+    pub MAP_ALL_ANY_IDENTITY,
+    complexity,
+    "combine `.map(_)` followed by `.all(identity)`/`.any(identity)` into a single call",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `as_bytes()` on a string which are immediately
+    /// followed by a call to `len()` or `is_empty()`.
+    ///
+    /// **Why is this bad?** `str::len()` and `str::is_empty()` already work on the bytes of the
+    /// string, so the call to `as_bytes()` is unnecessary.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let s = "Lorem ipsum";
+    /// // Bad
+    /// let len = s.as_bytes().len();
+    ///
+    /// // Good
+    /// let len = s.len();
+    /// ```
+    pub NEEDLESS_AS_BYTES,
+    complexity,
+    "detect useless calls to `as_bytes()`",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Read::bytes` on types which don't implement
+    /// `BufRead`.
+    ///
+    /// **Why is this bad?** The default implementation calls `read` for each byte, which can be
+    /// very inefficient for data that's not in memory, such as `File` or `TcpStream`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// use std::io::Read;
+    /// use std::fs::File;
+    /// // Bad
+    /// let file = File::open("./bytes.txt").unwrap();
+    /// file.bytes();
+    ///
+    /// // Good
+    /// use std::io::BufReader;
+    /// let file = BufReader::new(File::open("./bytes.txt").unwrap());
+    /// file.bytes();
+    /// ```
+    pub UNBUFFERED_BYTES,
+    perf,
+    "calling `.bytes()` is very inefficient when data is not in memory"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `.drain()` that clear the collection, immediately
+    /// followed by a call to `.collect()` into a collection of the same type.
+    ///
+    /// **Why is this bad?** `std::mem::take` moves the allocation over to the new collection
+    /// instead of moving every element into a newly allocated one.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
+    ///     v.drain(..).collect()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::mem;
+    /// fn remove_all(v: &mut Vec<i32>) -> Vec<i32> {
+    ///     mem::take(v)
+    /// }
+    /// ```
+    pub DRAIN_COLLECT,
+    perf,
+    "calling `.drain(..).collect()` to move all elements into a new collection",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `iter`, `iter_mut` or `into_iter` on collections
+    /// containing a single item.
+    ///
+    /// **Why is this bad?** It is simpler to use the `once` function from the standard library,
+    /// which also doesn't construct a collection just to iterate over it.
+    ///
+    /// **Known problems:** The type of the resulting iterator might become incompatible with its
+    /// usage, e.g. when it is returned from different branches of an `if`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let x = [5];
+    /// // Bad
+    /// let iter = [x].iter();
+    ///
+    /// // Good
+    /// let iter = std::iter::once(&x);
+    /// ```
+    pub ITER_ON_SINGLE_ITEMS,
+    nursery,
+    "iterator on a collection with a single item",
+    applicability: [MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `iter`, `iter_mut` or `into_iter` on empty
+    /// collections.
+    ///
+    /// **Why is this bad?** It is simpler to use the `empty` function from the standard library,
+    /// which also doesn't construct a collection just to iterate over it.
+    ///
+    /// **Known problems:** The type of the resulting iterator might become incompatible with its
+    /// usage, e.g. when it is returned from different branches of an `if`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // Bad
+    /// let iter = None::<u32>.into_iter();
+    ///
+    /// // Good
+    /// let iter = std::iter::empty::<u32>();
+    /// ```
+    pub ITER_ON_EMPTY_COLLECTIONS,
+    nursery,
+    "iterator on an empty collection",
+    applicability: [MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks if the `seek` method of the `Seek` trait is called with
+    /// `SeekFrom::Current(0)`, and if it is, suggests using `stream_position` instead.
+    ///
+    /// **Why is this bad?** Readability. Use dedicated method.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::{self, Write, Seek, SeekFrom};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut f = File::create("foo.txt")?;
+    ///     f.write_all(b"Hello")?;
+    ///     eprintln!("Written {} bytes", f.seek(SeekFrom::Current(0))?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::{self, Write, Seek, SeekFrom};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut f = File::create("foo.txt")?;
+    ///     f.write_all(b"Hello")?;
+    ///     eprintln!("Written {} bytes", f.stream_position()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub SEEK_FROM_CURRENT,
+    complexity,
+    "use dedicated method for seek from current position",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for jumps to the start of a stream that implements `Seek`
+    /// and uses the `seek` method providing `Start` as parameter.
+    ///
+    /// **Why is this bad?** Readability. There is a specific method that was implemented for
+    /// this exact scenario.
+    ///
+    /// **Known problems:** `rewind` doesn't return the new position, so the suggestion only
+    /// applies directly if the result of `seek` is ignored.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::io::{Seek, SeekFrom};
+    /// fn rewind<T: Seek>(t: &mut T) {
+    ///     t.seek(SeekFrom::Start(0));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # use std::io::Seek;
+    /// fn rewind<T: Seek>(t: &mut T) {
+    ///     t.rewind();
+    /// }
+    /// ```
+    pub SEEK_TO_START_INSTEAD_OF_REWIND,
+    complexity,
+    "jumping to the start of stream using `seek` method",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
 pub struct Methods {
     msrv: Option<RustcVersion>,
 }
@@ -1531,6 +1911,18 @@ impl_lint_pass!(Methods => [
     MAP_COLLECT_RESULT_UNIT,
     FROM_ITER_INSTEAD_OF_COLLECT,
     INSPECT_FOR_EACH,
+    ITER_SKIP_ZERO,
+    OPTION_MAP_OR_ERR_OK,
+    NEEDLESS_CHARACTER_ITERATION,
+    SLICED_STRING_AS_BYTES,
+    MAP_ALL_ANY_IDENTITY,
+    NEEDLESS_AS_BYTES,
+    UNBUFFERED_BYTES,
+    DRAIN_COLLECT,
+    ITER_ON_SINGLE_ITEMS,
+    ITER_ON_EMPTY_COLLECTIONS,
+    SEEK_FROM_CURRENT,
+    SEEK_TO_START_INSTEAD_OF_REWIND,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Methods {
@@ -1556,7 +1948,11 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                     unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "unwrap_or");
                 }
             },
-            ["map_or", ..] => lint_map_or_none(cx, expr, arg_lists[0]),
+            ["map_or", ..] => {
+                lint_map_or_none(cx, expr, arg_lists[0]);
+                option_map_or_err_ok::lint(cx, expr, arg_lists[0], false);
+            },
+            ["map_or_else", ..] => option_map_or_err_ok::lint(cx, expr, arg_lists[0], true),
             ["and_then", ..] => {
                 let biom_option_linted = bind_instead_of_map::OptionAndThenSome::lint(cx, expr, arg_lists[0]);
                 let biom_result_linted = bind_instead_of_map::ResultAndThenOk::lint(cx, expr, arg_lists[0]);
@@ -1591,6 +1987,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["nth", "iter"] => lint_iter_nth(cx, expr, &arg_lists, false),
             ["nth", "iter_mut"] => lint_iter_nth(cx, expr, &arg_lists, true),
             ["nth", ..] => lint_iter_nth_zero(cx, expr, arg_lists[0]),
+            ["skip", ..] => iter_skip_zero::lint(cx, expr, arg_lists[0]),
             ["step_by", ..] => lint_step_by(cx, expr, arg_lists[0]),
             ["next", "skip"] => lint_iter_skip_next(cx, expr, arg_lists[1]),
             ["collect", "cloned"] => lint_iter_cloned_collect(cx, expr, arg_lists[1]),
@@ -1618,6 +2015,22 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["ok_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], "ok_or"),
             ["collect", "map"] => lint_map_collect(cx, expr, arg_lists[1], arg_lists[0]),
             ["for_each", "inspect"] => inspect_for_each::lint(cx, expr, method_spans[1]),
+            ["all", "chars"] => needless_character_iteration::lint(cx, expr, arg_lists[1], arg_lists[0], true),
+            ["any", "chars"] => needless_character_iteration::lint(cx, expr, arg_lists[1], arg_lists[0], false),
+            ["as_bytes", ..] => sliced_string_as_bytes::lint(cx, expr, arg_lists[0], "as_bytes"),
+            ["bytes", ..] => {
+                sliced_string_as_bytes::lint(cx, expr, arg_lists[0], "bytes");
+                unbuffered_bytes::lint(cx, expr, arg_lists[0]);
+            },
+            [method @ ("len" | "is_empty"), "as_bytes"] => needless_as_bytes::lint(cx, expr, arg_lists[1], method),
+            [method @ ("all" | "any"), "map"] => {
+                map_all_any_identity::lint(cx, expr, arg_lists[1], arg_lists[0], method_spans[1], method)
+            },
+            ["collect", "drain"] => drain_collect::lint(cx, expr, arg_lists[1]),
+            ["seek", ..] => {
+                seek_from_current::lint(cx, expr, arg_lists[0], self.msrv.as_ref());
+                seek_to_start_instead_of_rewind::lint(cx, expr, arg_lists[0], method_spans[0], self.msrv.as_ref());
+            },
             _ => {},
         }
 
@@ -1641,6 +2054,11 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 if args.len() == 1 && method_call.ident.name == sym!(to_string) {
                     inefficient_to_string::lint(cx, expr, &args[0], self_ty);
                 }
+                // checked here since `method_calls` stops at receivers like `vec![x]`
+                let method = &*method_call.ident.as_str();
+                if args.len() == 1 && matches!(method, "iter" | "iter_mut" | "into_iter") {
+                    iter_on_single_or_empty_collections::lint(cx, expr, args, method);
+                }
 
                 if let Some(fn_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
                     if match_def_path(cx, fn_def_id, &paths::PUSH_STR) {
@@ -2839,8 +3257,6 @@ fn lint_map_flatten<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, map
     }
 }
 
-const MAP_UNWRAP_OR_MSRV: RustcVersion = RustcVersion::new(1, 41, 0);
-
 /// lint use of `map().unwrap_or_else()` for `Option`s and `Result`s
 /// Return true if lint triggered
 fn lint_map_unwrap_or_else<'tcx>(
@@ -2850,7 +3266,7 @@ fn lint_map_unwrap_or_else<'tcx>(
     unwrap_args: &'tcx [hir::Expr<'_>],
     msrv: Option<&RustcVersion>,
 ) -> bool {
-    if !meets_msrv(msrv, &MAP_UNWRAP_OR_MSRV) {
+    if !meets_msrv(msrv, &msrvs::RESULT_MAP_OR_ELSE) {
         return false;
     }
     // lint if the caller of `map()` is an `Option`
@@ -3096,8 +3512,6 @@ fn lint_filter_map<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, is_f
     }
 }
 
-const FILTER_MAP_NEXT_MSRV: RustcVersion = RustcVersion::new(1, 30, 0);
-
 /// lint use of `filter_map().next()` for `Iterators`
 fn lint_filter_map_next<'tcx>(
     cx: &LateContext<'tcx>,
@@ -3106,7 +3520,7 @@ fn lint_filter_map_next<'tcx>(
     msrv: Option<&RustcVersion>,
 ) {
     if match_trait_method(cx, expr, &paths::ITERATOR) {
-        if !meets_msrv(msrv, &FILTER_MAP_NEXT_MSRV) {
+        if !meets_msrv(msrv, &msrvs::ITERATOR_FIND_MAP) {
             return;
         }
 
@@ -3639,8 +4053,6 @@ fn lint_suspicious_map(cx: &LateContext<'_>, expr: &hir::Expr<'_>) {
     );
 }
 
-const OPTION_AS_REF_DEREF_MSRV: RustcVersion = RustcVersion::new(1, 40, 0);
-
 /// lint use of `_.as_ref().map(Deref::deref)` for `Option`s
 fn lint_option_as_ref_deref<'tcx>(
     cx: &LateContext<'tcx>,
@@ -3650,7 +4062,7 @@ fn lint_option_as_ref_deref<'tcx>(
     is_mut: bool,
     msrv: Option<&RustcVersion>,
 ) {
-    if !meets_msrv(msrv, &OPTION_AS_REF_DEREF_MSRV) {
+    if !meets_msrv(msrv, &msrvs::OPTION_AS_DEREF) {
         return;
     }
 
@@ -4113,3 +4525,20 @@ fn fn_header_equals(expected: hir::FnHeader, actual: hir::FnHeader) -> bool {
         && expected.unsafety == actual.unsafety
         && expected.asyncness == actual.asyncness
 }
+
+/// Checks if the expression is `SeekFrom::<variant>(0)`, where `path` is the path of the variant.
+fn is_seek_from(cx: &LateContext<'_>, expr: &hir::Expr<'_>, path: &[&str]) -> bool {
+    if_chain! {
+        if let hir::ExprKind::Call(func, [arg]) = expr.kind;
+        if let hir::ExprKind::Path(ref qpath) = func.kind;
+        if let Some(ctor_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id();
+        if match_def_path(cx, ctor_id, path);
+        if let hir::ExprKind::Lit(ref lit) = arg.kind;
+        if let ast::LitKind::Int(0, _) = lit.node;
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
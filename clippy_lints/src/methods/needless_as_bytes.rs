@@ -0,0 +1,37 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{is_type_diagnostic_item, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::NEEDLESS_AS_BYTES;
+
+/// lint use of `s.as_bytes().len()` and `s.as_bytes().is_empty()`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    as_bytes_args: &'tcx [hir::Expr<'_>],
+    method: &str,
+) {
+    if_chain! {
+        if let [recv] = as_bytes_args;
+        let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+        if *recv_ty.kind() == ty::Str || is_type_diagnostic_item(cx, recv_ty, sym::string_type);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let sugg = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_AS_BYTES,
+                expr.span,
+                "needless call to `as_bytes()`",
+                &format!("`{}()` can be called directly on strings", method),
+                format!("{}.{}()", sugg, method),
+                applicability,
+            );
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::utils::{is_type_diagnostic_item, remove_blocks, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, PatKind, QPath, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::symbol::{sym, Symbol};
+
+use super::NEEDLESS_CHARACTER_ITERATION;
+
+/// The `char` predicates which are also available on `u8` and which are false for all non-ASCII
+/// characters, so checking the bytes of a string gives the same result as checking its `char`s.
+const ASCII_PREDICATES: [&str; 11] = [
+    "is_ascii",
+    "is_ascii_alphabetic",
+    "is_ascii_alphanumeric",
+    "is_ascii_control",
+    "is_ascii_digit",
+    "is_ascii_graphic",
+    "is_ascii_hexdigit",
+    "is_ascii_lowercase",
+    "is_ascii_punctuation",
+    "is_ascii_uppercase",
+    "is_ascii_whitespace",
+];
+
+/// lint use of `s.chars().all(|c| c.is_ascii())` and similar ASCII checks
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    chars_args: &'tcx [hir::Expr<'_>],
+    args: &'tcx [hir::Expr<'_>],
+    is_all: bool,
+) {
+    if_chain! {
+        if let [str_expr] = chars_args;
+        if let [_, closure] = args;
+        let str_ty = cx.typeck_results().expr_ty_adjusted(str_expr).peel_refs();
+        if *str_ty.kind() == ty::Str || is_type_diagnostic_item(cx, str_ty, sym::string_type);
+        if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        if let PatKind::Binding(_, param_id, param_ident, None) = param.pat.kind;
+        if let Some((method, negated)) = check_predicate(cx, remove_blocks(&body.value), param_id);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let str_snippet = snippet_with_applicability(cx, str_expr.span, "..", &mut applicability);
+            let neg = if negated { "!" } else { "" };
+            let sugg = if &*method.as_str() == "is_ascii" && is_all != negated {
+                // `all(|c| c.is_ascii())` and `any(|c| !c.is_ascii())`
+                format!("{}{}.is_ascii()", neg, str_snippet)
+            } else {
+                format!(
+                    "{}.bytes().{}(|{}| {}{}.{}())",
+                    str_snippet,
+                    if is_all { "all" } else { "any" },
+                    param_ident,
+                    neg,
+                    param_ident,
+                    method
+                )
+            };
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_CHARACTER_ITERATION,
+                expr.span,
+                "checking an ASCII property of a string by iterating over its `char`s",
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks for `c.is_ascii_*()` or `char::is_ascii_*(&c)`, possibly negated, and returns the name
+/// of the predicate and whether it is negated.
+fn check_predicate(cx: &LateContext<'_>, expr: &Expr<'_>, param_id: hir::HirId) -> Option<(Symbol, bool)> {
+    match expr.kind {
+        ExprKind::Unary(UnOp::UnNot, inner) => {
+            let (method, negated) = check_predicate(cx, inner, param_id)?;
+            Some((method, !negated))
+        },
+        ExprKind::MethodCall(path, _, [recv], _) if is_param(recv, param_id) => {
+            is_ascii_predicate(path.ident.name).then(|| (path.ident.name, false))
+        },
+        ExprKind::Call(func, [arg]) => {
+            if_chain! {
+                if let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind;
+                if let hir::TyKind::Path(QPath::Resolved(None, ty_path)) = ty.kind;
+                if let Res::PrimTy(hir::PrimTy::Char) = ty_path.res;
+                if is_ascii_predicate(segment.ident.name);
+                if let ExprKind::AddrOf(BorrowKind::Ref, _, arg) = arg.kind;
+                if is_param(arg, param_id);
+                then {
+                    Some((segment.ident.name, false))
+                } else {
+                    None
+                }
+            }
+        },
+        _ => None,
+    }
+}
+
+fn is_ascii_predicate(name: Symbol) -> bool {
+    ASCII_PREDICATES.contains(&&*name.as_str())
+}
+
+fn is_param(expr: &Expr<'_>, param_id: hir::HirId) -> bool {
+    matches!(expr.kind, ExprKind::Path(QPath::Resolved(None, path)) if path.res == Res::Local(param_id))
+}
@@ -0,0 +1,66 @@
+use crate::utils::{
+    eager_or_lazy, is_type_diagnostic_item, match_qpath, paths, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::symbol::sym;
+
+use super::OPTION_MAP_OR_ERR_OK;
+
+/// lint use of `opt.map_or(Err(e), Ok)` and `opt.map_or_else(|| Err(e), Ok)` for `Option`s
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    args: &'tcx [hir::Expr<'_>],
+    is_map_or_else: bool,
+) {
+    if_chain! {
+        if let [recv, or_expr, map_expr] = args;
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::option_type);
+        if let ExprKind::Path(ref ok_path) = map_expr.kind;
+        if match_qpath(ok_path, &paths::RESULT_OK);
+        if let Some(err_expr) = if is_map_or_else { closure_body(cx, or_expr) } else { Some(or_expr) };
+        if let ExprKind::Call(Expr { kind: ExprKind::Path(err_path), .. }, [err_arg]) = err_expr.kind;
+        if match_qpath(err_path, &paths::RESULT_ERR);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let recv_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+            let err_snippet = snippet_with_applicability(cx, err_arg.span, "..", &mut applicability);
+            // The error is only computed on demand in `map_or_else`, which has to stay that way if
+            // computing it may have side effects.
+            let sugg = if !is_map_or_else || eager_or_lazy::is_eagerness_candidate(cx, err_arg) {
+                format!("{}.ok_or({})", recv_snippet, err_snippet)
+            } else {
+                format!("{}.ok_or_else(|| {})", recv_snippet, err_snippet)
+            };
+            let (method, or_arg) = if is_map_or_else {
+                ("map_or_else", "|| Err(_)")
+            } else {
+                ("map_or", "Err(_)")
+            };
+            span_lint_and_sugg(
+                cx,
+                OPTION_MAP_OR_ERR_OK,
+                expr.span,
+                &format!("called `{}({}, Ok)` on an `Option` value", method, or_arg),
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Returns the body of a closure without parameters.
+fn closure_body<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Closure(_, _, body_id, ..) = expr.kind {
+        let body = cx.tcx.hir().body(body_id);
+        if body.params.is_empty() {
+            return Some(&body.value);
+        }
+    }
+    None
+}
@@ -0,0 +1,34 @@
+use crate::utils::{match_trait_method, meets_msrv, msrvs, paths, snippet_with_applicability, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_semver::RustcVersion;
+
+use super::{is_seek_from, SEEK_FROM_CURRENT};
+
+/// lint use of `Seek::seek(SeekFrom::Current(0))`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    seek_args: &'tcx [hir::Expr<'_>],
+    msrv: Option<&RustcVersion>,
+) {
+    if !meets_msrv(msrv, &msrvs::SEEK_STREAM_POSITION) || !match_trait_method(cx, expr, &paths::IO_SEEK) {
+        return;
+    }
+    if let [recv, arg] = seek_args {
+        if is_seek_from(cx, arg, &paths::IO_SEEK_FROM_CURRENT) {
+            let mut applicability = Applicability::MachineApplicable;
+            let recv_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                SEEK_FROM_CURRENT,
+                expr.span,
+                "using `SeekFrom::Current` to start from current position",
+                "replace with",
+                format!("{}.stream_position()", recv_snippet),
+                applicability,
+            );
+        }
+    }
+}
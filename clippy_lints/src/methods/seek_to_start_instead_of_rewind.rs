@@ -0,0 +1,50 @@
+use crate::utils::{match_trait_method, meets_msrv, msrvs, paths, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_semver::RustcVersion;
+use rustc_span::Span;
+
+use super::{is_seek_from, SEEK_TO_START_INSTEAD_OF_REWIND};
+
+/// lint use of `Seek::seek(SeekFrom::Start(0))`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    seek_args: &'tcx [hir::Expr<'_>],
+    method_span: Span,
+    msrv: Option<&RustcVersion>,
+) {
+    if !meets_msrv(msrv, &msrvs::SEEK_REWIND) || !match_trait_method(cx, expr, &paths::IO_SEEK) {
+        return;
+    }
+    if let [_, arg] = seek_args {
+        if is_seek_from(cx, arg, &paths::IO_SEEK_FROM_START) {
+            // `rewind` returns `()` instead of the new position, which is only fine if it's unused
+            let applicability = if is_statement(cx, expr) {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            span_lint_and_sugg(
+                cx,
+                SEEK_TO_START_INSTEAD_OF_REWIND,
+                method_span.with_hi(expr.span.hi()),
+                "used `seek` to go to the start of the stream",
+                "replace with",
+                "rewind()".to_string(),
+                applicability,
+            );
+        }
+    }
+}
+
+fn is_statement(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    matches!(
+        cx.tcx.hir().find(cx.tcx.hir().get_parent_node(expr.hir_id)),
+        Some(hir::Node::Stmt(hir::Stmt {
+            kind: hir::StmtKind::Semi(_),
+            ..
+        }))
+    )
+}
@@ -0,0 +1,43 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{higher, is_type_diagnostic_item, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::SLICED_STRING_AS_BYTES;
+
+/// lint use of `s[a..b].as_bytes()` and `s[a..b].bytes()`
+pub(super) fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, args: &'tcx [hir::Expr<'_>], method: &str) {
+    if_chain! {
+        if let [recv] = args;
+        if let ExprKind::Index(string, index) = recv.kind;
+        // a full range can't panic
+        if let Some(range) = higher::range(index);
+        if range.start.is_some() || range.end.is_some();
+        let string_ty = cx.typeck_results().expr_ty(string).peel_refs();
+        if *string_ty.kind() == ty::Str || is_type_diagnostic_item(cx, string_ty, sym::string_type);
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let string_sugg = Sugg::hir_with_applicability(cx, string, "..", &mut applicability).maybe_par();
+            let range = snippet_with_applicability(cx, index.span, "..", &mut applicability);
+            let sugg = if method == "as_bytes" {
+                format!("&{}.as_bytes()[{}]", string_sugg, range)
+            } else {
+                format!("{}.as_bytes()[{}].iter().copied()", string_sugg, range)
+            };
+            span_lint_and_sugg(
+                cx,
+                SLICED_STRING_AS_BYTES,
+                expr.span,
+                &format!("calling `{}` after slicing a string", method),
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
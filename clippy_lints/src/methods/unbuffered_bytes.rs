@@ -0,0 +1,27 @@
+use crate::utils::{get_trait_def_id, implements_trait, match_trait_method, paths, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::UNBUFFERED_BYTES;
+
+/// lint use of `Read::bytes()` on readers which don't implement `BufRead`
+pub(super) fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>, args: &'tcx [hir::Expr<'_>]) {
+    if_chain! {
+        if let [recv] = args;
+        if match_trait_method(cx, expr, &paths::IO_READ);
+        if let Some(buf_read) = get_trait_def_id(cx, &paths::IO_BUF_READ);
+        let ty = cx.typeck_results().expr_ty_adjusted(recv);
+        if !implements_trait(cx, ty, buf_read, &[]);
+        then {
+            span_lint_and_help(
+                cx,
+                UNBUFFERED_BYTES,
+                expr.span,
+                "calling `.bytes()` is very inefficient when data is not in memory",
+                None,
+                "consider using `BufReader`",
+            );
+        }
+    }
+}
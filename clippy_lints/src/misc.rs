@@ -9,7 +9,7 @@ use rustc_hir::{
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::hygiene::DesugaringKind;
 use rustc_span::source_map::{ExpnKind, Span};
 
@@ -52,7 +52,8 @@ declare_clippy_lint! {
     /// ```
     pub TOPLEVEL_REF_ARG,
     style,
-    "an entire binding declared as `ref`, in a function argument or a `let` statement"
+    "an entire binding declared as `ref`, in a function argument or a `let` statement",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -108,7 +109,8 @@ declare_clippy_lint! {
     /// ```
     pub FLOAT_CMP,
     correctness,
-    "using `==` or `!=` on float values instead of comparing difference with an epsilon"
+    "using `==` or `!=` on float values instead of comparing difference with an epsilon",
+    applicability: [HasPlaceholders]
 }
 
 declare_clippy_lint! {
@@ -135,7 +137,8 @@ declare_clippy_lint! {
     /// ```
     pub CMP_OWNED,
     perf,
-    "creating owned instances for comparing with others, e.g., `x == \"foo\".to_string()`"
+    "creating owned instances for comparing with others, e.g., `x == \"foo\".to_string()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -200,7 +203,8 @@ declare_clippy_lint! {
     /// ```
     pub SHORT_CIRCUIT_STATEMENT,
     complexity,
-    "using a short circuit boolean condition as a statement"
+    "using a short circuit boolean condition as a statement",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -222,7 +226,8 @@ declare_clippy_lint! {
     /// ```
     pub ZERO_PTR,
     style,
-    "using `0 as *{const, mut} T`"
+    "using `0 as *{const, mut} T`",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -253,7 +258,8 @@ declare_clippy_lint! {
     /// ```
     pub FLOAT_CMP_CONST,
     restriction,
-    "using `==` or `!=` on float constants instead of comparing difference with an epsilon"
+    "using `==` or `!=` on float constants instead of comparing difference with an epsilon",
+    applicability: [HasPlaceholders]
 }
 
 declare_lint_pass!(MiscLints => [
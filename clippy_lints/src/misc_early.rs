@@ -8,7 +8,7 @@ use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 declare_clippy_lint! {
@@ -123,7 +123,8 @@ declare_clippy_lint! {
     /// ```
     pub UNSEPARATED_LITERAL_SUFFIX,
     pedantic,
-    "literals whose suffix is not separated by an underscore"
+    "literals whose suffix is not separated by an underscore",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -161,7 +162,8 @@ declare_clippy_lint! {
     /// prints `83` (as `83 == 0o123` while `123 == 0o173`).
     pub ZERO_PREFIXED_LITERAL,
     complexity,
-    "integer literals starting with `0`"
+    "integer literals starting with `0`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -211,7 +213,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_PATTERN,
     style,
-    "using `name @ _` in a pattern"
+    "using `name @ _` in a pattern",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -245,7 +248,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNEEDED_WILDCARD_PATTERN,
     complexity,
-    "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)"
+    "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(MiscEarlyLints => [
@@ -0,0 +1,91 @@
+use crate::utils::{in_macro, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_ast::ast::{Generics, TyKind, WherePredicate};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// **What it does:** Check if a generic is defined both in the bound predicate and in the
+    /// `where` clause.
+    ///
+    /// **Why is this bad?** It can be confusing for developers when seeing bounds for a generic in
+    /// multiple places.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn ty<F: std::fmt::Debug>(a: F)
+    /// where
+    ///     F: Sized,
+    /// {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn ty<F>(a: F)
+    /// where
+    ///     F: Sized + std::fmt::Debug,
+    /// {}
+    /// ```
+    pub MULTIPLE_BOUND_LOCATIONS,
+    style,
+    "defining generic bounds in multiple locations"
+}
+
+declare_lint_pass!(MultipleBoundLocations => [MULTIPLE_BOUND_LOCATIONS]);
+
+impl EarlyLintPass for MultipleBoundLocations {
+    fn check_generics(&mut self, cx: &EarlyContext<'_>, generics: &Generics) {
+        if generics.params.is_empty() || generics.where_clause.predicates.is_empty() || in_macro(generics.span) {
+            return;
+        }
+
+        let generic_params_with_bounds: FxHashMap<Symbol, Span> = generics
+            .params
+            .iter()
+            .filter(|param| !param.bounds.is_empty())
+            .map(|param| (param.ident.name, param.ident.span))
+            .collect();
+        if generic_params_with_bounds.is_empty() {
+            return;
+        }
+
+        for clause in &generics.where_clause.predicates {
+            match clause {
+                WherePredicate::BoundPredicate(pred) => {
+                    if_chain! {
+                        if !pred.bounds.is_empty();
+                        if let TyKind::Path(None, path) = &pred.bounded_ty.kind;
+                        if let [segment] = &*path.segments;
+                        if segment.args.is_none();
+                        if let Some(bound_span) = generic_params_with_bounds.get(&segment.ident.name);
+                        then {
+                            emit_lint(cx, *bound_span, pred.bounded_ty.span, segment.ident.name);
+                        }
+                    }
+                },
+                WherePredicate::RegionPredicate(pred) => {
+                    if let Some(bound_span) = generic_params_with_bounds.get(&pred.lifetime.ident.name) {
+                        if !pred.bounds.is_empty() {
+                            emit_lint(cx, *bound_span, pred.lifetime.ident.span, pred.lifetime.ident.name);
+                        }
+                    }
+                },
+                WherePredicate::EqPredicate(_) => {},
+            }
+        }
+    }
+}
+
+fn emit_lint(cx: &EarlyContext<'_>, bound_span: Span, where_span: Span, name: Symbol) {
+    span_lint_and_help(
+        cx,
+        MULTIPLE_BOUND_LOCATIONS,
+        where_span,
+        "bound is defined in more than one place",
+        Some(bound_span),
+        &format!("consider moving all bounds of `{}` into one place", name),
+    );
+}
@@ -4,7 +4,7 @@ use crate::utils::{run_lints, span_lint};
 use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::{Crate, CRATE_HIR_ID};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::DUMMY_SP;
 
 use cargo_metadata::{DependencyKind, Node, Package, PackageId};
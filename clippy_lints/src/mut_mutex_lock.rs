@@ -4,7 +4,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, Mutability};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `&mut Mutex::lock` calls
@@ -39,7 +39,8 @@ declare_clippy_lint! {
     /// ```
     pub MUT_MUTEX_LOCK,
     style,
-    "`&mut Mutex::lock` does unnecessary locking"
+    "`&mut Mutex::lock` does unnecessary locking",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(MutMutexLock => [MUT_MUTEX_LOCK]);
@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_ast::ast::{BindingMode, Lifetime, Mutability, Param, PatKind, Path, TyKind};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::kw;
 use rustc_span::Span;
 
@@ -54,7 +54,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_ARBITRARY_SELF_TYPE,
     complexity,
-    "type of `self` parameter is already by default `Self`"
+    "type of `self` parameter is already by default `Self`",
+    applicability: [HasPlaceholders, MachineApplicable]
 }
 
 declare_lint_pass!(NeedlessArbitrarySelfType => [NEEDLESS_ARBITRARY_SELF_TYPE]);
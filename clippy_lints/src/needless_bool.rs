@@ -8,7 +8,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Block, Expr, ExprKind, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 use rustc_span::Span;
 
@@ -37,7 +37,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_BOOL,
     complexity,
-    "if-statements with plain booleans in the then- and else-clause, e.g., `if p { true } else { false }`"
+    "if-statements with plain booleans in the then- and else-clause, e.g., `if p { true } else { false }`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -61,7 +62,8 @@ declare_clippy_lint! {
     /// ```
     pub BOOL_COMPARISON,
     complexity,
-    "comparing a variable to a boolean, e.g., `if x == true` or `if x != true`"
+    "comparing a variable to a boolean, e.g., `if x == true` or `if x != true`",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(NeedlessBool => [NEEDLESS_BOOL]);
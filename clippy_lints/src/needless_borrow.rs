@@ -9,7 +9,7 @@ use rustc_hir::{BindingAnnotation, BorrowKind, Expr, ExprKind, HirId, Item, Muta
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_middle::ty::adjustment::{Adjust, Adjustment};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for address of operations (`&`) that are going to
@@ -30,7 +30,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_BORROW,
     nursery,
-    "taking a reference that is going to be automatically dereferenced"
+    "taking a reference that is going to be automatically dereferenced",
+    applicability: [MachineApplicable]
 }
 
 #[derive(Default)]
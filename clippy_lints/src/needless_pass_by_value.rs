@@ -12,7 +12,7 @@ use rustc_hir::{BindingAnnotation, Body, FnDecl, GenericArg, HirId, Impl, ItemKi
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, TypeFoldable};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::kw;
 use rustc_span::{sym, Span};
 use rustc_target::spec::abi::Abi;
@@ -32,8 +32,8 @@ declare_clippy_lint! {
     ///
     /// **Known problems:**
     /// * This lint suggests taking an argument by reference,
-    /// however sometimes it is better to let users decide the argument type
-    /// (by using `Borrow` trait, for example), depending on how the function is used.
+    ///   however sometimes it is better to let users decide the argument type
+    ///   (by using `Borrow` trait, for example), depending on how the function is used.
     ///
     /// **Example:**
     /// ```rust
@@ -49,7 +49,8 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_PASS_BY_VALUE,
     pedantic,
-    "functions taking arguments by value, but not consuming them in its body"
+    "functions taking arguments by value, but not consuming them in its body",
+    applicability: [Unspecified]
 }
 
 declare_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
@@ -8,7 +8,7 @@ use rustc_hir::HirIdSet;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{Ty, TyS};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -47,7 +47,8 @@ declare_clippy_lint! {
     /// ```
     pub NEW_WITHOUT_DEFAULT,
     style,
-    "`fn new() -> Self` method without `Default` implementation"
+    "`fn new() -> Self` method without `Default` implementation",
+    applicability: [MaybeIncorrect]
 }
 
 #[derive(Clone, Default)]
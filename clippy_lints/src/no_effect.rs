@@ -3,7 +3,7 @@ use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, BlockCheckMode, Expr, ExprKind, Stmt, StmtKind, UnsafeSource};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use std::ops::Deref;
 
 declare_clippy_lint! {
@@ -39,7 +39,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_OPERATION,
     complexity,
-    "outer expressions with no effect"
+    "outer expressions with no effect",
+    applicability: [MachineApplicable]
 }
 
 fn has_no_effect(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
@@ -3,7 +3,7 @@ use rustc_ast::ast::{Arm, AssocItem, AssocItemKind, Attribute, Block, FnDecl, It
 use rustc_ast::visit::{walk_block, walk_expr, walk_pat, Visitor};
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 use rustc_span::symbol::{Ident, Symbol};
@@ -0,0 +1,113 @@
+use crate::utils::{get_parent_expr, in_macro, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for conversions from `NonZero` types to regular integer types,
+    /// and suggests using `NonZero` types for the target as well.
+    ///
+    /// **Why is this bad?** Converting from `NonZero` types to regular integer types and then
+    /// back to `NonZero` types is less efficient and loses the type-safety guarantees provided
+    /// by `NonZero` types. Using `NonZero` types consistently can lead to more optimized code
+    /// and prevent certain classes of errors related to zero values. For example, dividing by
+    /// a `NonZero` type can't panic.
+    ///
+    /// **Known problems:** Outside of divisions, the suggested conversion changes the type of
+    /// the expression, so the surrounding code may have to be adapted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::num::{NonZeroU32, NonZeroU64};
+    ///
+    /// fn example(x: u64, y: NonZeroU32) {
+    ///     let r1 = x / u64::from(y.get());
+    ///     let r2 = x % u64::from(y.get());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::num::{NonZeroU32, NonZeroU64};
+    ///
+    /// fn example(x: u64, y: NonZeroU32) {
+    ///     let r1 = x / NonZeroU64::from(y);
+    ///     let r2 = x % NonZeroU64::from(y);
+    /// }
+    /// ```
+    pub NON_ZERO_SUGGESTIONS,
+    restriction,
+    "suggests using `NonZero*` type conversions instead of converting to a plain integer",
+    applicability: [MachineApplicable, MaybeIncorrect]
+}
+
+declare_lint_pass!(NonZeroSuggestions => [NON_ZERO_SUGGESTIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for NonZeroSuggestions {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Binary(op, _, rhs) = expr.kind {
+            // only unsigned integers can be divided by `NonZero` types
+            if matches!(op.node, BinOpKind::Div | BinOpKind::Rem)
+                && matches!(cx.typeck_results().expr_ty(rhs).kind(), ty::Uint(_))
+            {
+                check_non_zero_conversion(cx, rhs, Applicability::MachineApplicable);
+            }
+        } else if !is_binary_operand(cx, expr) {
+            check_non_zero_conversion(cx, expr, Applicability::MaybeIncorrect);
+        }
+    }
+}
+
+/// Checks whether the expression is an operand of a binary operation. Divisions are handled
+/// when checking the operation itself, while in other operations the suggested `NonZero` type
+/// can't be used.
+fn is_binary_operand(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |parent| matches!(parent.kind, ExprKind::Binary(..)))
+}
+
+/// Checks for `<int>::from(non_zero.get())`.
+fn check_non_zero_conversion(cx: &LateContext<'_>, expr: &Expr<'_>, mut applicability: Applicability) {
+    if_chain! {
+        if let ExprKind::Call(func, [arg]) = expr.kind;
+        if let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind;
+        if segment.ident.name == sym::from;
+        if let ExprKind::MethodCall(method, _, [receiver], _) = arg.kind;
+        if method.ident.name == sym!(get);
+        if is_non_zero_type(cx, cx.typeck_results().expr_ty(receiver));
+        let target_ty = cx.typeck_results().expr_ty(expr);
+        if let ty::Int(_) | ty::Uint(_) = target_ty.kind();
+        then {
+            let target_name = target_ty.to_string();
+            let non_zero_name = format!("NonZero{}{}", target_name[..1].to_uppercase(), &target_name[1..]);
+            let receiver_snippet = snippet_with_applicability(cx, receiver.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                NON_ZERO_SUGGESTIONS,
+                expr.span,
+                &format!(
+                    "consider using `{}::from()` for more efficient and type-safe conversion",
+                    non_zero_name
+                ),
+                "replace with",
+                format!("{}::from({})", non_zero_name, receiver_snippet),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks whether the type is one of the `core::num::NonZero*` types.
+fn is_non_zero_type(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    if let ty::Adt(adt, _) = ty.kind() {
+        if let [krate, num, .., name] = &*cx.get_def_path(adt.did) {
+            return krate.as_str() == "core" && num.as_str() == "num" && name.as_str().starts_with("NonZero");
+        }
+    }
+    false
+}
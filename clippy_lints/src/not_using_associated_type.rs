@@ -0,0 +1,530 @@
+use crate::utils::{snippet_opt, span_lint_and_help, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_qpath, walk_ty, NestedVisitorMap, Visitor};
+use rustc_hir::{ExprKind, GenericArg, HirId, Impl, ImplItemKind, Item, ItemKind, Lifetime, Path, QPath, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass, Lint};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::{self, DefIdTree};
+use rustc_session::declare_tool_lint;
+use rustc_span::{Span, Symbol};
+use rustc_typeck::hir_ty_to_ty;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths in the methods of trait implementations which name a
+    /// type that is also the value of one of the implementation's associated types.
+    ///
+    /// **Why is this bad?** Using the associated type makes the connection between the code and
+    /// the trait implementation explicit, and only one place has to be changed if the associated
+    /// type changes.
+    ///
+    /// Associated types set to `Self`, or to one of the types listed in the
+    /// `not-using-associated-type-ignored-types` configuration option (by default common types
+    /// of the standard library like `String` and `Option`), are not checked. The lint can be
+    /// restricted to types defined in other crates with the
+    /// `not-using-associated-type-foreign-only` configuration option.
+    ///
+    /// **Known problems:** Associated types which are set to generic types, including generic
+    /// associated types like `type Member<T> = Pair<T, u8>;`, are only checked in method
+    /// signatures. If several associated types are set to the same type, no suggestion is made.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # trait Machine { type State; fn start() -> Self::State; }
+    /// enum State { A, B }
+    /// struct Switch;
+    ///
+    /// impl Machine for Switch {
+    ///     type State = State;
+    ///
+    ///     fn start() -> Self::State {
+    ///         State::A
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # trait Machine { type State; fn start() -> Self::State; }
+    /// # enum State { A, B }
+    /// # struct Switch;
+    /// impl Machine for Switch {
+    ///     type State = State;
+    ///
+    ///     fn start() -> Self::State {
+    ///         Self::State::A
+    ///     }
+    /// }
+    /// ```
+    pub NOT_USING_ASSOCIATED_TYPE,
+    nursery,
+    "naming a type in a trait implementation instead of the associated type it is assigned to",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for paths in the methods of trait implementations which name a
+    /// constant that is also the value of one of the implementation's associated constants.
+    ///
+    /// **Why is this bad?** Using the associated constant makes the connection between the code
+    /// and the trait implementation explicit, and only one place has to be changed if the
+    /// associated constant changes.
+    ///
+    /// **Known problems:** Only associated constants which are set to a path to a constant are
+    /// checked.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # trait Machine { const MAX: usize; fn is_full(count: usize) -> bool; }
+    /// const MAX_STATES: usize = 4;
+    /// struct Switch;
+    ///
+    /// impl Machine for Switch {
+    ///     const MAX: usize = MAX_STATES;
+    ///
+    ///     fn is_full(count: usize) -> bool {
+    ///         count == MAX_STATES
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # trait Machine { const MAX: usize; fn is_full(count: usize) -> bool; }
+    /// # const MAX_STATES: usize = 4;
+    /// # struct Switch;
+    /// impl Machine for Switch {
+    ///     const MAX: usize = MAX_STATES;
+    ///
+    ///     fn is_full(count: usize) -> bool {
+    ///         count == Self::MAX
+    ///     }
+    /// }
+    /// ```
+    pub NOT_USING_ASSOCIATED_CONST,
+    nursery,
+    "naming a constant in a trait implementation instead of the associated constant it is assigned to",
+    applicability: [MachineApplicable]
+}
+
+pub struct NotUsingAssociatedType {
+    foreign_only: bool,
+    ignored_types: FxHashSet<Vec<Symbol>>,
+}
+
+impl NotUsingAssociatedType {
+    #[must_use]
+    pub fn new(foreign_only: bool, ignored_types: &FxHashSet<String>) -> Self {
+        Self {
+            foreign_only,
+            ignored_types: ignored_types
+                .iter()
+                .map(|s| s.split("::").map(Symbol::intern).collect::<Vec<_>>())
+                .collect(),
+        }
+    }
+
+    /// Checks whether naming the type through an associated type would make the code less
+    /// readable: for `type Output = Self;`, and for common types of the standard library.
+    fn is_ignored(&self, cx: &LateContext<'_>, impl_def_id: DefId, ty: ty::Ty<'_>, def_id: DefId) -> bool {
+        ty == cx.tcx.type_of(impl_def_id)
+            || (self.foreign_only && def_id.is_local())
+            || self.ignored_types.contains(&cx.get_def_path(def_id))
+    }
+}
+
+impl_lint_pass!(NotUsingAssociatedType => [NOT_USING_ASSOCIATED_TYPE, NOT_USING_ASSOCIATED_CONST]);
+
+/// An associated type of a trait implementation, like `type State = State;`.
+struct AssocType<'tcx> {
+    name: Symbol,
+    /// The type the associated type is set to
+    ty: ty::Ty<'tcx>,
+    /// The `DefId` of the type the associated type is set to
+    def_id: DefId,
+    /// The number of generic parameters of the implementation, which come before the ones of the
+    /// associated type
+    parent_count: usize,
+    /// The number of generic parameters of the associated type, which is a generic associated
+    /// type if there are some
+    own_count: usize,
+}
+
+impl AssocType<'_> {
+    /// Whether the type has generic arguments, in which case the `DefId` of a path isn't enough
+    /// to tell whether it names the same type.
+    fn is_generic(&self) -> bool {
+        self.own_count > 0 || matches!(self.ty.kind(), ty::Adt(_, substs) if !substs.is_empty())
+    }
+
+    /// Returns the index among the parameters of the associated type of a parameter, or `None`
+    /// for the parameters of the implementation.
+    fn own_index(&self, index: u32) -> Option<usize> {
+        (index as usize).checked_sub(self.parent_count)
+    }
+
+    /// Checks whether the type refers to the parameters of the associated type.
+    fn has_own_params(&self, ty: ty::Ty<'_>) -> bool {
+        ty.walk().any(|arg| match arg.unpack() {
+            GenericArgKind::Type(ty) => matches!(ty.kind(), ty::Param(param) if self.own_index(param.index).is_some()),
+            GenericArgKind::Lifetime(region) => {
+                matches!(region, ty::ReEarlyBound(region) if self.own_index(region.index).is_some())
+            },
+            GenericArgKind::Const(_) => false,
+        })
+    }
+}
+
+/// An associated constant of a trait implementation, like `const MAX: usize = MAX_STATES;`.
+struct AssocConst {
+    name: Symbol,
+    /// The `DefId` of the constant the associated constant is set to
+    def_id: DefId,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NotUsingAssociatedType {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if item.span.from_expansion() {
+            return;
+        }
+        if let ItemKind::Impl(Impl {
+            of_trait: Some(_),
+            items,
+            ..
+        }) = item.kind
+        {
+            let impl_def_id = cx.tcx.hir().local_def_id(item.hir_id).to_def_id();
+
+            // first pass: collect the associated types and constants
+            let assoc_types: Vec<_> = items
+                .iter()
+                .filter_map(|impl_item_ref| {
+                    let impl_item = cx.tcx.hir().impl_item(impl_item_ref.id);
+                    if let ImplItemKind::TyAlias(_) = impl_item.kind {
+                        let def_id = cx.tcx.hir().local_def_id(impl_item.hir_id);
+                        let generics = cx.tcx.generics_of(def_id);
+                        let ty = cx.tcx.type_of(def_id);
+                        match ty.kind() {
+                            ty::Adt(adt, _) if !self.is_ignored(cx, impl_def_id, ty, adt.did) => Some(AssocType {
+                                name: impl_item.ident.name,
+                                ty,
+                                def_id: adt.did,
+                                parent_count: generics.parent_count,
+                                own_count: generics.params.len(),
+                            }),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            let assoc_consts: Vec<_> = items
+                .iter()
+                .filter_map(|impl_item_ref| {
+                    let impl_item = cx.tcx.hir().impl_item(impl_item_ref.id);
+                    if_chain! {
+                        if let ImplItemKind::Const(_, body_id) = impl_item.kind;
+                        let value = &cx.tcx.hir().body(body_id).value;
+                        if let ExprKind::Path(QPath::Resolved(None, path)) = value.kind;
+                        if let Res::Def(DefKind::Const, def_id) = path.res;
+                        then {
+                            Some(AssocConst {
+                                name: impl_item.ident.name,
+                                def_id,
+                            })
+                        } else {
+                            None
+                        }
+                    }
+                })
+                .collect();
+            if assoc_types.is_empty() && assoc_consts.is_empty() {
+                return;
+            }
+
+            // second pass: look for paths to the same types in the signatures and bodies of the
+            // methods
+            let mut visitor = MatchingPathVisitor {
+                cx,
+                assoc_types: &assoc_types,
+                assoc_consts: &assoc_consts,
+                in_signature: false,
+            };
+            for impl_item_ref in items {
+                let impl_item = cx.tcx.hir().impl_item(impl_item_ref.id);
+                if let ImplItemKind::Fn(ref sig, body_id) = impl_item.kind {
+                    visitor.in_signature = true;
+                    visitor.visit_fn_decl(sig.decl);
+                    visitor.in_signature = false;
+                    visitor.visit_body(cx.tcx.hir().body(body_id));
+                }
+            }
+        }
+    }
+}
+
+struct MatchingPathVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    assoc_types: &'a [AssocType<'tcx>],
+    assoc_consts: &'a [AssocConst],
+    /// Whether a method signature is visited, where types can be lowered without a type check
+    in_signature: bool,
+}
+
+impl<'a, 'tcx> MatchingPathVisitor<'a, 'tcx> {
+    /// Checks whether the path names one of the associated types, or one of its variants or
+    /// constructors. Returns the names of the matching associated types and the span of the part
+    /// naming the type.
+    fn compare_path(&self, path: &Path<'_>) -> Option<(Vec<String>, Span)> {
+        let (type_def_id, type_segment) = match path.res {
+            Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias, _) => {
+                (self.resolve_type(path.res)?, path.segments.last()?)
+            },
+            Res::Def(DefKind::Variant, def_id) => (self.cx.tcx.parent(def_id)?, path.segments.iter().nth_back(1)?),
+            Res::Def(DefKind::Ctor(CtorOf::Variant, _), def_id) => {
+                let variant_def_id = self.cx.tcx.parent(def_id)?;
+                (self.cx.tcx.parent(variant_def_id)?, path.segments.iter().nth_back(1)?)
+            },
+            // tuple and unit struct constructors can't be named through an associated type
+            _ => return None,
+        };
+        let span = path.span.with_hi(type_segment.ident.span.hi());
+        if span.from_expansion() {
+            return None;
+        }
+        let names: Vec<_> = self
+            .assoc_types
+            .iter()
+            .filter(|assoc_type| !assoc_type.is_generic() && assoc_type.def_id == type_def_id)
+            .map(|assoc_type| assoc_type.name.to_string())
+            .collect();
+        if names.is_empty() {
+            None
+        } else {
+            Some((names, span))
+        }
+    }
+
+    /// Checks whether the path names a constant one of the associated constants is set to, and
+    /// returns the names of the matching associated constants.
+    fn compare_const(&self, path: &Path<'_>) -> Option<Vec<String>> {
+        if let Res::Def(DefKind::Const, def_id) = path.res {
+            let names: Vec<_> = self
+                .assoc_consts
+                .iter()
+                .filter(|assoc_const| assoc_const.def_id == def_id)
+                .map(|assoc_const| assoc_const.name.to_string())
+                .collect();
+            if !names.is_empty() && !path.span.from_expansion() {
+                return Some(names);
+            }
+        }
+        None
+    }
+
+    /// Checks whether the self type of a type relative path, like the `State` of
+    /// `State::new()`, names one of the associated types.
+    fn compare_self_ty(&self, self_ty: &Ty<'_>) -> Option<(Vec<String>, Span)> {
+        if let TyKind::Path(QPath::Resolved(None, path)) = self_ty.kind {
+            if let Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias, _) = path.res {
+                return self.compare_path(path);
+            }
+        }
+        None
+    }
+
+    /// Checks whether a type of a method signature is the type of a generic associated type with
+    /// some arguments, and returns the arguments, like `U` for `Pair<U, u8>` and
+    /// `type Member<T> = Pair<T, u8>;`.
+    fn match_generic_assoc_type(&self, assoc_type: &AssocType<'tcx>, hir_ty: &Ty<'_>) -> Option<Vec<String>> {
+        let mut args = vec![None; assoc_type.own_count];
+        self.bind_ty(assoc_type, assoc_type.ty, hir_ty, &mut args)?;
+        // the parameters which don't appear in the type can't be inferred
+        args.into_iter().collect()
+    }
+
+    /// Matches a type of a method signature against the type of a generic associated type,
+    /// binding the parameters of the associated type to the code of the arguments they match.
+    fn bind_ty(
+        &self,
+        assoc_type: &AssocType<'tcx>,
+        pattern: ty::Ty<'tcx>,
+        hir_ty: &Ty<'_>,
+        args: &mut [Option<String>],
+    ) -> Option<()> {
+        match pattern.kind() {
+            ty::Param(param) if assoc_type.own_index(param.index).is_some() => bind(
+                &mut args[assoc_type.own_index(param.index)?],
+                snippet_opt(self.cx, hir_ty.span)?,
+            ),
+            _ if !assoc_type.has_own_params(pattern) => (hir_ty_to_ty(self.cx.tcx, hir_ty) == pattern).then(|| ()),
+            ty::Adt(adt, substs) => {
+                // the arguments written in the path are matched with the ones of the type, type
+                // aliases having parameters of their own
+                let path = match hir_ty.kind {
+                    TyKind::Path(QPath::Resolved(None, path)) => path,
+                    _ => return None,
+                };
+                if path.res.opt_def_id() != Some(adt.did) {
+                    return None;
+                }
+                let hir_args = path.segments.last()?.args.map_or(&[][..], |args| args.args);
+                let mut lifetimes = hir_args.iter().filter_map(|arg| match arg {
+                    GenericArg::Lifetime(lifetime) => Some(lifetime),
+                    _ => None,
+                });
+                let mut types = hir_args.iter().filter_map(|arg| match arg {
+                    GenericArg::Type(ty) => Some(ty),
+                    _ => None,
+                });
+                for subst in substs.iter() {
+                    match subst.unpack() {
+                        GenericArgKind::Lifetime(region) => {
+                            bind_region(assoc_type, region, lifetimes.next(), args)?;
+                        },
+                        GenericArgKind::Type(ty) => self.bind_ty(assoc_type, ty, types.next()?, args)?,
+                        GenericArgKind::Const(_) => return None,
+                    }
+                }
+                Some(())
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the `DefId` of the type named by a path resolving to a type, looking through type
+    /// aliases.
+    fn resolve_type(&self, res: Res) -> Option<DefId> {
+        match res {
+            Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union, def_id) => Some(def_id),
+            Res::Def(DefKind::TyAlias, def_id) => match self.cx.tcx.type_of(def_id).kind() {
+                ty::Adt(adt, _) => Some(adt.did),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for MatchingPathVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_ty(&mut self, hir_ty: &'tcx Ty<'tcx>) {
+        // generic types have to match with all of their arguments
+        if_chain! {
+            if self.in_signature && !hir_ty.span.from_expansion();
+            if let TyKind::Path(QPath::Resolved(None, path)) = hir_ty.kind;
+            if let Some(def_id) = self.resolve_type(path.res);
+            if self.assoc_types.iter().any(|assoc_type| assoc_type.is_generic() && assoc_type.def_id == def_id);
+            then {
+                let ty = hir_ty_to_ty(self.cx.tcx, hir_ty);
+                let names: Vec<_> = self
+                    .assoc_types
+                    .iter()
+                    .filter(|assoc_type| assoc_type.is_generic() && assoc_type.def_id == def_id)
+                    .filter_map(|assoc_type| {
+                        if assoc_type.own_count == 0 {
+                            (assoc_type.ty == ty).then(|| assoc_type.name.to_string())
+                        } else {
+                            let args = self.match_generic_assoc_type(assoc_type, hir_ty)?;
+                            Some(format!("{}<{}>", assoc_type.name, args.join(", ")))
+                        }
+                    })
+                    .collect();
+                if !names.is_empty() {
+                    emit_lint(self.cx, NOT_USING_ASSOCIATED_TYPE, hir_ty.span, &names, "type");
+                    return;
+                }
+            }
+        }
+        walk_ty(self, hir_ty);
+    }
+
+    // This also sees the paths of patterns, including the ones of parameters, since `walk_pat`
+    // visits them as `QPath`s
+    fn visit_qpath(&mut self, qpath: &'tcx QPath<'tcx>, id: HirId, span: Span) {
+        if let QPath::Resolved(None, path) = qpath {
+            if let Some(names) = self.compare_const(path) {
+                emit_lint(self.cx, NOT_USING_ASSOCIATED_CONST, path.span, &names, "constant");
+                return;
+            }
+        }
+        let matching = match qpath {
+            QPath::Resolved(None, path) => self.compare_path(path),
+            QPath::TypeRelative(self_ty, _) => self.compare_self_ty(self_ty),
+            // the self type of `<State as Trait>::item` is checked when walking the path
+            _ => None,
+        };
+        if let Some((names, span)) = matching {
+            emit_lint(self.cx, NOT_USING_ASSOCIATED_TYPE, span, &names, "type");
+        } else {
+            walk_qpath(self, qpath, id, span);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+/// Binds a parameter of a generic associated type to the code of an argument, checking that the
+/// parameter isn't bound to another argument, or to several elided lifetimes.
+fn bind(arg: &mut Option<String>, code: String) -> Option<()> {
+    match arg {
+        Some(bound) => (*bound == code && code != "'_").then(|| ()),
+        None => {
+            *arg = Some(code);
+            Some(())
+        },
+    }
+}
+
+/// Matches a lifetime of a method signature against a lifetime of the type of a generic
+/// associated type, the elided lifetimes being written `'_`.
+fn bind_region(
+    assoc_type: &AssocType<'_>,
+    region: ty::Region<'_>,
+    lifetime: Option<&Lifetime>,
+    args: &mut [Option<String>],
+) -> Option<()> {
+    let code = match lifetime {
+        Some(lifetime) if !lifetime.is_elided() => lifetime.to_string(),
+        _ => "'_".to_string(),
+    };
+    match region {
+        ty::ReEarlyBound(region) => match assoc_type.own_index(region.index) {
+            Some(index) => bind(&mut args[index], code),
+            None => (*region.name.as_str() == code).then(|| ()),
+        },
+        ty::ReStatic => lifetime.map_or(false, Lifetime::is_static).then(|| ()),
+        _ => None,
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, lint: &'static Lint, span: Span, names: &[String], kind: &str) {
+    if let [name] = names {
+        span_lint_and_sugg(
+            cx,
+            lint,
+            span,
+            &format!("this path names the same {0} as the associated {0} `{1}`", kind, name),
+            &format!("use the associated {}", kind),
+            format!("Self::{}", name),
+            Applicability::MachineApplicable,
+        );
+    } else {
+        // it's up to the user to pick the associated item which fits the context
+        let candidates: Vec<_> = names.iter().map(|name| format!("`Self::{}`", name)).collect();
+        span_lint_and_help(
+            cx,
+            lint,
+            span,
+            &format!("this path names the same {0} as several associated {0}s", kind),
+            None,
+            &format!("consider using one of {}", candidates.join(", ")),
+        );
+    }
+}
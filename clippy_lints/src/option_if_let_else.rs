@@ -7,7 +7,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Arm, BindingAnnotation, Block, Expr, ExprKind, MatchSource, Mutability, PatKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -58,7 +58,8 @@ declare_clippy_lint! {
     /// ```
     pub OPTION_IF_LET_ELSE,
     pedantic,
-    "reimplementation of Option::map_or"
+    "reimplementation of Option::map_or",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(OptionIfLetElse => [OPTION_IF_LET_ELSE]);
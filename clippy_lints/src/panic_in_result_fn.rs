@@ -2,7 +2,7 @@ use crate::utils::{find_macro_calls, is_type_diagnostic_item, return_ty, span_li
 use rustc_hir as hir;
 use rustc_hir::intravisit::FnKind;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
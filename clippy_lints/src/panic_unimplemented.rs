@@ -2,7 +2,7 @@ use crate::utils::{is_expn_of, match_panic_call, span_lint};
 use if_chain::if_chain;
 use rustc_hir::Expr;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::Span;
 
 declare_clippy_lint! {
@@ -0,0 +1,96 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, is_type_diagnostic_item, match_qpath, paths, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for binary comparisons to a literal `Option::None`.
+    ///
+    /// **Why is this bad?** A programmer checking if some `foo` is `None` via a comparison
+    /// `foo == None` is usually inspired from other programming languages (e.g. `foo is None`
+    /// in Python). Checking if a value of type `Option<T>` is (not) equal to `None` in that way
+    /// relies on `T: PartialEq` to do the comparison, which is unneeded.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(f: Option<u32>) -> &'static str {
+    ///     if f != None { "yay" } else { "nay" }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn foo(f: Option<u32>) -> &'static str {
+    ///     if f.is_some() { "yay" } else { "nay" }
+    /// }
+    /// ```
+    pub PARTIALEQ_TO_NONE,
+    style,
+    "binary comparison to `None` via `PartialEq` instead of calling `is_none()` or `is_some()`",
+    applicability: [MachineApplicable]
+}
+
+declare_lint_pass!(PartialeqToNone => [PARTIALEQ_TO_NONE]);
+
+impl<'tcx> LateLintPass<'tcx> for PartialeqToNone {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (op, left, right) = match expr.kind {
+            ExprKind::Binary(op, left, right) if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) => (op, left, right),
+            _ => return,
+        };
+
+        // `&x == &None` compiles as well, so the borrows are peeled off first
+        let (left, right) = (peel_borrows_and_derefs(left), peel_borrows_and_derefs(right));
+        if left.span.from_expansion() || right.span.from_expansion() {
+            return;
+        }
+        let other = if is_none(cx, right) {
+            left
+        } else if is_none(cx, left) {
+            right
+        } else {
+            return;
+        };
+
+        // the `Option` may be behind references, which method calls dereference automatically
+        if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(other).peel_refs(), sym::option_type) {
+            return;
+        }
+
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_applicability(cx, other, "..", &mut applicability).maybe_par();
+        let (method, help) = if op.node == BinOpKind::Eq {
+            ("is_none", "use `Option::is_none()` instead")
+        } else {
+            ("is_some", "use `Option::is_some()` instead")
+        };
+        span_lint_and_sugg(
+            cx,
+            PARTIALEQ_TO_NONE,
+            expr.span,
+            "binary comparison to literal `Option::None`",
+            help,
+            format!("{}.{}()", recv, method),
+            applicability,
+        );
+    }
+}
+
+fn peel_borrows_and_derefs<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::UnDeref, inner) = expr.kind {
+        expr = inner;
+    }
+    expr
+}
+
+fn is_none(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Path(ref qpath) if match_qpath(qpath, &paths::OPTION_NONE))
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::option_type)
+}
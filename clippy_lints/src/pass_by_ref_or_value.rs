@@ -9,7 +9,7 @@ use rustc_hir::intravisit::FnKind;
 use rustc_hir::{BindingAnnotation, Body, FnDecl, HirId, Impl, ItemKind, MutTy, Mutability, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::{sym, Span};
 use rustc_target::abi::LayoutOf;
 use rustc_target::spec::abi::Abi;
@@ -51,7 +51,8 @@ declare_clippy_lint! {
     /// ```
     pub TRIVIALLY_COPY_PASS_BY_REF,
     pedantic,
-    "functions taking small copyable arguments by reference"
+    "functions taking small copyable arguments by reference",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -83,7 +84,8 @@ declare_clippy_lint! {
     /// ```
     pub LARGE_TYPES_PASSED_BY_VALUE,
     pedantic,
-    "functions taking large arguments by value"
+    "functions taking large arguments by value",
+    applicability: [MaybeIncorrect]
 }
 
 #[derive(Copy, Clone)]
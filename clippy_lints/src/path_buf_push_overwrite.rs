@@ -4,7 +4,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use std::path::{Component, Path};
 
 declare_clippy_lint! {
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub PATH_BUF_PUSH_OVERWRITE,
     nursery,
-    "calling `push` with file system root on `PathBuf` can overwrite it"
+    "calling `push` with file system root on `PathBuf` can overwrite it",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(PathBufPushOverwrite => [PATH_BUF_PUSH_OVERWRITE]);
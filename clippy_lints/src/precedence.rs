@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_ast::ast::{BinOpKind, Expr, ExprKind, LitKind, UnOp};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 
 const ALLOWED_ODD_FUNCTIONS: [&str; 14] = [
@@ -27,9 +27,9 @@ declare_clippy_lint! {
     /// **What it does:** Checks for operations where precedence may be unclear
     /// and suggests to add parentheses. Currently it catches the following:
     /// * mixed usage of arithmetic and bit shifting/combining operators without
-    /// parentheses
+    ///   parentheses
     /// * a "negative" numeric literal (which is really a unary `-` followed by a
-    /// numeric literal)
+    ///   numeric literal)
     ///   followed by a method call
     ///
     /// **Why is this bad?** Not everyone knows the precedence of those operators by
@@ -43,7 +43,8 @@ declare_clippy_lint! {
     /// * `-1i32.abs()` equals -1, while `(-1i32).abs()` equals 1
     pub PRECEDENCE,
     complexity,
-    "operations where precedence may be unclear"
+    "operations where precedence may be unclear",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(Precedence => [PRECEDENCE]);
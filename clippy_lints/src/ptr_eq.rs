@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Use `std::ptr::eq` when applicable
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub PTR_EQ,
     style,
-    "use `std::ptr::eq` when comparing raw pointers"
+    "use `std::ptr::eq` when comparing raw pointers",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(PtrEq => [PTR_EQ]);
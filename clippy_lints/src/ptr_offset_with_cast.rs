@@ -2,7 +2,7 @@ use crate::utils::{snippet_opt, span_lint, span_lint_and_sugg};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 use std::fmt;
 
@@ -39,7 +39,8 @@ declare_clippy_lint! {
     /// ```
     pub PTR_OFFSET_WITH_CAST,
     complexity,
-    "unneeded pointer offset cast"
+    "unneeded pointer offset cast",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(PtrOffsetWithCast => [PTR_OFFSET_WITH_CAST]);
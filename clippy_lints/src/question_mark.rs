@@ -3,7 +3,7 @@ use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{def, BindingAnnotation, Block, Expr, ExprKind, MatchSource, PatKind, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 use crate::utils::sugg::Sugg;
@@ -33,7 +33,8 @@ declare_clippy_lint! {
     /// ```
     pub QUESTION_MARK,
     style,
-    "checks for expressions that could be replaced by the question mark operator"
+    "checks for expressions that could be replaced by the question mark operator",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(QuestionMark => [QUESTION_MARK]);
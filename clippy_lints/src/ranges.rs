@@ -6,7 +6,7 @@ use rustc_hir::{BinOpKind, Expr, ExprKind, PathSegment, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::ty;
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{Span, Spanned};
 use rustc_span::sym;
 use rustc_span::symbol::Ident;
@@ -14,7 +14,7 @@ use std::cmp::Ordering;
 
 use crate::utils::sugg::Sugg;
 use crate::utils::{
-    get_parent_expr, in_constant, is_integer_const, meets_msrv, single_segment_path, snippet, snippet_opt,
+    get_parent_expr, in_constant, is_integer_const, meets_msrv, msrvs, single_segment_path, snippet, snippet_opt,
     snippet_with_applicability, span_lint, span_lint_and_sugg, span_lint_and_then,
 };
 use crate::utils::{higher, SpanlessEq};
@@ -73,7 +73,8 @@ declare_clippy_lint! {
     /// ```
     pub RANGE_PLUS_ONE,
     pedantic,
-    "`x..(y+1)` reads better as `x..=y`"
+    "`x..(y+1)` reads better as `x..=y`",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -98,7 +99,8 @@ declare_clippy_lint! {
     /// ```
     pub RANGE_MINUS_ONE,
     pedantic,
-    "`x..=(y-1)` reads better as `x..y`"
+    "`x..=(y-1)` reads better as `x..y`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -131,7 +133,8 @@ declare_clippy_lint! {
     /// ```
     pub REVERSED_EMPTY_RANGES,
     correctness,
-    "reversing the limits of range expressions, resulting in empty ranges"
+    "reversing the limits of range expressions, resulting in empty ranges",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -158,11 +161,10 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_RANGE_CONTAINS,
     style,
-    "manually reimplementing {`Range`, `RangeInclusive`}`::contains`"
+    "manually reimplementing {`Range`, `RangeInclusive`}`::contains`",
+    applicability: [MachineApplicable]
 }
 
-const MANUAL_RANGE_CONTAINS_MSRV: RustcVersion = RustcVersion::new(1, 35, 0);
-
 pub struct Ranges {
     msrv: Option<RustcVersion>,
 }
@@ -189,7 +191,7 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
                 check_range_zip_with_len(cx, path, args, expr.span);
             },
             ExprKind::Binary(ref op, ref l, ref r) => {
-                if meets_msrv(self.msrv.as_ref(), &MANUAL_RANGE_CONTAINS_MSRV) {
+                if meets_msrv(self.msrv.as_ref(), &msrvs::RANGE_CONTAINS) {
                     check_possible_range_contains(cx, op.node, l, r, expr);
                 }
             },
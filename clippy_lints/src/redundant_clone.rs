@@ -15,7 +15,7 @@ use rustc_middle::mir::{
 };
 use rustc_middle::ty::{self, fold::TypeVisitor, Ty};
 use rustc_mir::dataflow::{Analysis, AnalysisDomain, GenKill, GenKillAnalysis, ResultsCursor};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{BytePos, Span};
 use rustc_span::sym;
 use std::convert::TryFrom;
@@ -62,7 +62,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_CLONE,
     perf,
-    "`clone()` of an owned value that is going to be dropped immediately"
+    "`clone()` of an owned value that is going to be dropped immediately",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE]);
@@ -10,7 +10,7 @@ use rustc_hir::intravisit::Visitor as HirVisitor;
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Detects closures called in the same expression where they
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_CLOSURE_CALL,
     complexity,
-    "throwaway closures called in the expression they are defined"
+    "throwaway closures called in the expression they are defined",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(RedundantClosureCall => [REDUNDANT_CLOSURE_CALL]);
@@ -2,7 +2,7 @@ use crate::utils::span_lint_and_then;
 use rustc_errors::Applicability;
 use rustc_hir::{Item, ItemKind, VisibilityKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for items declared `pub(crate)` that are not crate visible because they
@@ -29,7 +29,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_PUB_CRATE,
     nursery,
-    "Using `pub(crate)` visibility on items that are not crate visible due to the visibility of the module that contains them."
+    "Using `pub(crate)` visibility on items that are not crate visible due to the visibility of the module that contains them.",
+    applicability: [MachineApplicable]
 }
 
 #[derive(Default)]
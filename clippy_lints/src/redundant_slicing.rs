@@ -3,7 +3,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, LangItem};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::{lint::in_external_macro, ty::TyS};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 use crate::utils::{is_type_lang_item, snippet_with_applicability, span_lint_and_sugg};
 
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_SLICING,
     complexity,
-    "redundant slicing of the whole range of a type"
+    "redundant slicing of the whole range of a type",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(RedundantSlicing => [REDUNDANT_SLICING]);
@@ -1,7 +1,7 @@
 use crate::utils::{last_path_segment, snippet, span_lint_and_sugg};
 use rustc_hir::{GenericArg, Mutability, Ty, TyKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::sym;
 
 use if_chain::if_chain;
@@ -27,7 +27,8 @@ declare_clippy_lint! {
     /// ```
     pub REF_OPTION_REF,
     pedantic,
-    "use `Option<&T>` instead of `&Option<&T>`"
+    "use `Option<&T>` instead of `&Option<&T>`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(RefOptionRef => [REF_OPTION_REF]);
@@ -4,7 +4,7 @@ use if_chain::if_chain;
 use rustc_ast::ast::{Expr, ExprKind, Mutability, UnOp};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::BytePos;
 
 declare_clippy_lint! {
@@ -28,7 +28,8 @@ declare_clippy_lint! {
     /// ```
     pub DEREF_ADDROF,
     complexity,
-    "use of `*&` or `*&mut` in an expression"
+    "use of `*&` or `*&mut` in an expression",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(DerefAddrOf => [DEREF_ADDROF]);
@@ -119,7 +120,8 @@ declare_clippy_lint! {
     /// ```
     pub REF_IN_DEREF,
     complexity,
-    "Use of reference in auto dereference expression."
+    "Use of reference in auto dereference expression.",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(RefInDeref => [REF_IN_DEREF]);
@@ -0,0 +1,136 @@
+use crate::utils::higher::{vec_macro, VecArgs};
+use crate::utils::{in_macro, match_def_path, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for patterns such as `vec![Vec::with_capacity(x); n]` or
+    /// `iter::repeat(Vec::with_capacity(x))`.
+    ///
+    /// **Why is this bad?** These constructs work by cloning the element, but cloning a `Vec<_>`
+    /// does not respect the old vector's capacity and effectively discards it.
+    ///
+    /// This makes `iter::repeat(Vec::with_capacity(x))` especially suspicious because the user
+    /// most certainly expected that the yielded `Vec<_>` will have the requested capacity,
+    /// otherwise one can simply write `iter::repeat(Vec::new())` instead and it will have the
+    /// same effect.
+    ///
+    /// Similarly for `vec![x; n]`, the element `x` is cloned to fill the vec. Unlike
+    /// `iter::repeat` however, the vec repeat macro does not have to clone the value `n` times
+    /// but just `n - 1` times, because it can reuse the passed value for the last slot. That
+    /// means that the last `Vec<_>` gets the requested capacity but all other ones do not.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::iter;
+    ///
+    /// let _: Vec<Vec<u8>> = vec![Vec::with_capacity(42); 123];
+    /// let _: Vec<Vec<u8>> = iter::repeat(Vec::with_capacity(42)).take(123).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::iter;
+    ///
+    /// let _: Vec<Vec<u8>> = (0..123).map(|_| Vec::with_capacity(42)).collect();
+    /// //                                      ^^^ this closure is executed 123 times
+    /// //                                          and the vecs will have the expected capacity
+    /// let _: Vec<Vec<u8>> = iter::repeat_with(|| Vec::with_capacity(42)).take(123).collect();
+    /// ```
+    pub REPEAT_VEC_WITH_CAPACITY,
+    style,
+    "repeating a `Vec::with_capacity` expression which does not retain capacity",
+    applicability: [MaybeIncorrect]
+}
+
+declare_lint_pass!(RepeatVecWithCapacity => [REPEAT_VEC_WITH_CAPACITY]);
+
+impl<'tcx> LateLintPass<'tcx> for RepeatVecWithCapacity {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        check_vec_macro(cx, expr);
+        check_repeat_fn(cx, expr);
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, span: Span, kind: &str, note: &'static str, sugg_msg: &'static str, sugg: String) {
+    span_lint_and_then(
+        cx,
+        REPEAT_VEC_WITH_CAPACITY,
+        span,
+        &format!(
+            "repeating `Vec::with_capacity` using `{}`, which does not retain capacity",
+            kind
+        ),
+        |diag| {
+            diag.note(note);
+            diag.span_suggestion_verbose(span, sugg_msg, sugg, Applicability::MaybeIncorrect);
+        },
+    );
+}
+
+/// Checks `vec![Vec::with_capacity(x); n]`
+fn check_vec_macro<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    if_chain! {
+        if let Some(VecArgs::Repeat(repeat_expr, len_expr)) = vec_macro(cx, expr);
+        if is_vec_with_capacity(cx, repeat_expr);
+        then {
+            let span = expr.span.ctxt().outer_expn_data().call_site;
+            if in_macro(span) {
+                return;
+            }
+            emit_lint(
+                cx,
+                span,
+                "vec![x; n]",
+                "only the last `Vec` will have the capacity",
+                "if you intended to initialize multiple `Vec`s with an initial capacity, try",
+                format!(
+                    "(0..{}).map(|_| {}).collect::<Vec<_>>()",
+                    snippet(cx, len_expr.span, ".."),
+                    snippet(cx, repeat_expr.span, "..")
+                ),
+            );
+        }
+    }
+}
+
+/// Checks `iter::repeat(Vec::with_capacity(x))`
+fn check_repeat_fn<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    if_chain! {
+        if !in_macro(expr.span);
+        if let ExprKind::Call(fun, [repeat_expr]) = expr.kind;
+        if let ExprKind::Path(ref qpath) = fun.kind;
+        if let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
+        if match_def_path(cx, fun_def_id, &paths::ITER_REPEAT);
+        if is_vec_with_capacity(cx, repeat_expr);
+        then {
+            emit_lint(
+                cx,
+                expr.span,
+                "iter::repeat",
+                "none of the yielded `Vec`s will have the requested capacity",
+                "if you intended to create an iterator that yields `Vec`s with an initial capacity, try",
+                format!("std::iter::repeat_with(|| {})", snippet(cx, repeat_expr.span, "..")),
+            );
+        }
+    }
+}
+
+/// Checks if the expression is `Vec::with_capacity(_)`
+fn is_vec_with_capacity(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Call(fun, [_]) = expr.kind;
+        if let ExprKind::Path(ref qpath) = fun.kind;
+        if let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
+        then {
+            match_def_path(cx, fun_def_id, &paths::VEC_WITH_CAPACITY)
+        } else {
+            false
+        }
+    }
+}
@@ -2,16 +2,20 @@ use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
-use rustc_hir::{Block, Body, Expr, ExprKind, FnDecl, HirId, MatchSource, PatKind, StmtKind};
+use rustc_hir::{Block, Body, Expr, ExprKind, FnDecl, HirId, LangItem, MatchSource, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::subst::GenericArgKind;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 
-use crate::utils::{fn_def_id, in_macro, match_qpath, snippet_opt, span_lint_and_sugg, span_lint_and_then};
+use crate::utils::{
+    fn_def_id, in_macro, is_type_diagnostic_item, match_qpath, paths, snippet_opt, snippet_with_applicability,
+    span_lint_and_sugg, span_lint_and_then,
+};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `let`-bindings, which are subsequently
@@ -37,7 +41,8 @@ declare_clippy_lint! {
     /// ```
     pub LET_AND_RETURN,
     style,
-    "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block"
+    "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -62,7 +67,41 @@ declare_clippy_lint! {
     /// ```
     pub NEEDLESS_RETURN,
     style,
-    "using a return statement like `return expr;` where an expression would suffice"
+    "using a return statement like `return expr;` where an expression would suffice",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for return statements on `Err` paired with the `?` operator.
+    ///
+    /// **Why is this bad?** The `return` is unnecessary, as `Err(e)?` already returns early, and
+    /// the `?` only converts the error, which can be done explicitly. At the end of a function
+    /// the whole statement can be replaced by the tail expression.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(x: usize) -> Result<(), String> {
+    ///     if x == 0 {
+    ///         return Err("zero".to_string())?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    /// simplify to
+    /// ```rust
+    /// fn foo(x: usize) -> Result<(), String> {
+    ///     if x == 0 {
+    ///         return Err("zero".to_string());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub NEEDLESS_RETURN_WITH_QUESTION_MARK,
+    style,
+    "using a return statement like `return Err(expr)?;` where removing the `?` would suffice",
+    applicability: [MachineApplicable]
 }
 
 #[derive(PartialEq, Eq, Copy, Clone)]
@@ -71,9 +110,51 @@ enum RetReplacement {
     Block,
 }
 
-declare_lint_pass!(Return => [LET_AND_RETURN, NEEDLESS_RETURN]);
+declare_lint_pass!(Return => [LET_AND_RETURN, NEEDLESS_RETURN, NEEDLESS_RETURN_WITH_QUESTION_MARK]);
 
 impl<'tcx> LateLintPass<'tcx> for Return {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        if_chain! {
+            if !in_external_macro(cx.sess(), stmt.span);
+            if !in_macro(stmt.span);
+            if let StmtKind::Semi(ref ret) = stmt.kind;
+            if let ExprKind::Ret(Some(ref inner)) = ret.kind;
+            if let Some(err_arg) = err_with_question_mark(inner);
+            if !in_macro(err_arg.span);
+            if let Some(err_ty) = result_error_type(cx, find_return_type(cx, inner));
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let snip = snippet_with_applicability(cx, err_arg.span, "..", &mut applicability);
+                let err = if cx.typeck_results().expr_ty(err_arg) == err_ty {
+                    format!("Err({})", snip)
+                } else {
+                    format!("Err({}.into())", snip)
+                };
+                if is_final_stmt_of_body(cx, stmt) {
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_RETURN_WITH_QUESTION_MARK,
+                        stmt.span,
+                        "unneeded `return` statement with `?` operator",
+                        "replace it with the tail expression",
+                        err,
+                        applicability,
+                    );
+                } else {
+                    span_lint_and_sugg(
+                        cx,
+                        NEEDLESS_RETURN_WITH_QUESTION_MARK,
+                        ret.span,
+                        "unneeded `?` operator in `return` statement",
+                        "remove it",
+                        format!("return {}", err),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
         // we need both a let-binding stmt and an expr
         if_chain! {
@@ -177,7 +258,8 @@ fn check_final_expr<'tcx>(
         // simple return is always "bad"
         ExprKind::Ret(ref inner) => {
             // allow `#[cfg(a)] return a; #[cfg(b)] return b;`
-            if !expr.attrs.iter().any(attr_is_cfg) {
+            // `return Err(e)?;` is handled by `NEEDLESS_RETURN_WITH_QUESTION_MARK`
+            if !expr.attrs.iter().any(attr_is_cfg) && inner.and_then(err_with_question_mark).is_none() {
                 let borrows = inner.map_or(false, |inner| last_statement_borrows(cx, inner));
                 if !borrows {
                     emit_return_lint(
@@ -268,6 +350,61 @@ fn emit_return_lint(cx: &LateContext<'_>, ret_span: Span, inner_span: Option<Spa
     }
 }
 
+/// If `expr` is `Err(e)?`, returns `e`.
+fn err_with_question_mark<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if_chain! {
+        if let ExprKind::Match(ref match_arg, _, MatchSource::TryDesugar) = expr.kind;
+        if let ExprKind::Call(ref match_fun, ref try_args) = match_arg.kind;
+        if let ExprKind::Path(QPath::LangItem(LangItem::TryIntoResult, _)) = match_fun.kind;
+        if let Some(try_arg) = try_args.get(0);
+        if let ExprKind::Call(ref err_fun, ref err_args) = try_arg.kind;
+        if let ExprKind::Path(ref err_fun_path) = err_fun.kind;
+        if match_qpath(err_fun_path, &paths::RESULT_ERR);
+        if let [err_arg] = err_args;
+        then {
+            Some(err_arg)
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds the function return type by examining the return expression of the `?` desugaring.
+fn find_return_type<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Ty<'tcx>> {
+    if let ExprKind::Match(_, ref arms, MatchSource::TryDesugar) = expr.kind {
+        for arm in arms.iter() {
+            if let ExprKind::Ret(Some(ref ret)) = arm.body.kind {
+                return Some(cx.typeck_results().expr_ty(ret));
+            }
+        }
+    }
+    None
+}
+
+/// Extracts the error type from `Result<T, E>`.
+fn result_error_type<'tcx>(cx: &LateContext<'tcx>, ty: Option<Ty<'tcx>>) -> Option<Ty<'tcx>> {
+    match ty?.kind() {
+        ty::Adt(_, subst) if is_type_diagnostic_item(cx, ty?, sym::result_type) => Some(subst.type_at(1)),
+        _ => None,
+    }
+}
+
+/// Checks if `stmt` is the last statement of the enclosing function body, which has no tail
+/// expression.
+fn is_final_stmt_of_body(cx: &LateContext<'_>, stmt: &Stmt<'_>) -> bool {
+    if_chain! {
+        if let Some(body_id) = cx.enclosing_body;
+        if let ExprKind::Block(ref block, _) = cx.tcx.hir().body(body_id).value.kind;
+        if block.expr.is_none();
+        if let Some(last) = block.stmts.last();
+        then {
+            last.hir_id == stmt.hir_id
+        } else {
+            false
+        }
+    }
+}
+
 fn last_statement_borrows<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
     let mut visitor = BorrowVisitor { cx, borrows: false };
     walk_expr(&mut visitor, expr);
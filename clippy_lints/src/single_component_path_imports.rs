@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_ast::{Item, ItemKind, UseTreeKind};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::edition::Edition;
 
 declare_clippy_lint! {
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub SINGLE_COMPONENT_PATH_IMPORTS,
     style,
-    "imports with single component path are redundant"
+    "imports with single component path are redundant",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(SingleComponentPathImports => [SINGLE_COMPONENT_PATH_IMPORTS]);
@@ -0,0 +1,111 @@
+use crate::utils::higher::{self, vec_macro, VecArgs};
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+use rustc_ast::ast::{LitIntType, LitKind, RangeLimits, UintTy};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Vec` or array initializations that contain only one range.
+    ///
+    /// **Why is this bad?** This is almost always incorrect, as it will result in a `Vec` that
+    /// has only one element. Almost always, the programmer intended for it to include all
+    /// elements in the range or for the end of the range to be the length instead.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = [0..200];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// // If it was intended to include every element in the range...
+    /// let x = (0..200).collect::<Vec<i32>>();
+    /// // ...Or if 200 was meant to be the len
+    /// let x = [0; 200];
+    /// ```
+    pub SINGLE_RANGE_IN_VEC_INIT,
+    style,
+    "checks for initialization of `Vec` or arrays which consist of a single range",
+    applicability: [MaybeIncorrect]
+}
+
+declare_lint_pass!(SingleRangeInVecInit => [SINGLE_RANGE_IN_VEC_INIT]);
+
+impl<'tcx> LateLintPass<'tcx> for SingleRangeInVecInit {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (span, elem, kind) = if let Some(VecArgs::Vec([elem])) = vec_macro(cx, expr) {
+            (expr.span.ctxt().outer_expn_data().call_site, elem, "`Vec`")
+        } else if let ExprKind::Array([elem]) = expr.kind {
+            if expr.span.from_expansion() {
+                return;
+            }
+            (expr.span, elem, "array")
+        } else {
+            return;
+        };
+
+        if in_macro(span) || elem.span.from_expansion() {
+            return;
+        }
+
+        let (start, end) = match higher::range(elem) {
+            Some(higher::Range {
+                start: Some(start),
+                end: Some(end),
+                limits: RangeLimits::HalfOpen,
+            }) => (start, end),
+            _ => return,
+        };
+
+        let (start_snippet, end_snippet) = match (snippet_opt(cx, start.span), snippet_opt(cx, end.span)) {
+            (Some(start_snippet), Some(end_snippet)) => (start_snippet, end_snippet),
+            _ => return,
+        };
+
+        // only integer ranges can be collected
+        let suggest_collect = cx.typeck_results().expr_ty(start).is_integral();
+        // the end of the range can only be used as the length if it's a `usize`
+        let suggest_len = is_usize_literal(end);
+        if !suggest_collect && !suggest_len {
+            return;
+        }
+
+        let (an, prefix) = if kind == "`Vec`" { ("a", "vec!") } else { ("an", "") };
+        span_lint_and_then(
+            cx,
+            SINGLE_RANGE_IN_VEC_INIT,
+            span,
+            &format!("{} {} of `Range` that is only one element", an, kind),
+            |diag| {
+                if suggest_collect {
+                    diag.span_suggestion(
+                        span,
+                        "if you wanted a `Vec` that contains the entire range, try",
+                        format!("({}..{}).collect::<std::vec::Vec<_>>()", start_snippet, end_snippet),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                if suggest_len {
+                    diag.span_suggestion(
+                        span,
+                        &format!("if you wanted {} {} of len {}, try", an, kind, end_snippet),
+                        format!("{}[{}; {}]", prefix, start_snippet, end_snippet),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Checks if the expression is an integer literal which can be used as a length.
+fn is_usize_literal(expr: &Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Lit(ref lit)
+            if matches!(lit.node, LitKind::Int(_, LitIntType::Unsuffixed | LitIntType::Unsigned(UintTy::Usize)))
+    )
+}
@@ -7,7 +7,7 @@ use rustc_hir::intravisit::{walk_block, walk_expr, walk_stmt, NestedVisitorMap,
 use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::hir::map::Map;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::Symbol;
 
 declare_clippy_lint! {
@@ -36,7 +36,8 @@ declare_clippy_lint! {
     /// ```
     pub SLOW_VECTOR_INITIALIZATION,
     perf,
-    "slow vector initialization"
+    "slow vector initialization",
+    applicability: [Unspecified]
 }
 
 declare_lint_pass!(SlowVectorInit => [SLOW_VECTOR_INITIALIZATION]);
@@ -5,7 +5,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:**
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub STABLE_SORT_PRIMITIVE,
     perf,
-    "use of sort() when sort_unstable() is equivalent"
+    "use of sort() when sort_unstable() is equivalent",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(StableSortPrimitive => [STABLE_SORT_PRIMITIVE]);
@@ -54,7 +55,7 @@ enum SortingKind {
 }
 impl SortingKind {
     /// The name of the stable version of this kind of sort
-    fn stable_name(&self) -> &str {
+    fn stable_name(&self) -> &'static str {
         match self {
             SortingKind::Vanilla => "sort",
             /* SortingKind::ByKey => "sort_by_key",
@@ -62,7 +63,7 @@ impl SortingKind {
         }
     }
     /// The name of the unstable version of this kind of sort
-    fn unstable_name(&self) -> &str {
+    fn unstable_name(&self) -> &'static str {
         match self {
             SortingKind::Vanilla => "sort_unstable",
             /* SortingKind::ByKey => "sort_unstable_by_key",
@@ -3,7 +3,7 @@ use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, LangItem, QPath};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 use rustc_span::sym;
 
@@ -106,7 +106,8 @@ declare_clippy_lint! {
     /// ```
     pub STRING_LIT_AS_BYTES,
     nursery,
-    "calling `as_bytes` on a string literal instead of using a byte string literal"
+    "calling `as_bytes` on a string literal instead of using a byte string literal",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(StringAdd => [STRING_ADD, STRING_ADD_ASSIGN]);
@@ -195,7 +196,8 @@ declare_clippy_lint! {
     /// ```
     pub STRING_FROM_UTF8_AS_BYTES,
     complexity,
-    "casting string slices to byte slices and back"
+    "casting string slices to byte slices and back",
+    applicability: [MachineApplicable]
 }
 
 // Max length a b"foo" string can take
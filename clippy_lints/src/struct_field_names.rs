@@ -0,0 +1,150 @@
+//! lint on struct fields that are prefixed or suffixed by the same words or by the struct's name
+
+use crate::utils::{is_present_in_source, span_lint, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_ast::ast::{Item, ItemKind, StructField, VariantData};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::source_map::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects struct fields that are prefixed or suffixed
+    /// by the same characters or the name of the struct itself.
+    ///
+    /// **Why is this bad?** Information common to all struct fields is better represented in the
+    /// struct name.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Cake {
+    ///     cake_sugar: u8,
+    ///     cake_flour: u8,
+    ///     cake_eggs: u8
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Cake {
+    ///     sugar: u8,
+    ///     flour: u8,
+    ///     eggs: u8
+    /// }
+    /// ```
+    pub STRUCT_FIELD_NAMES,
+    pedantic,
+    "structs where all fields share a prefix/postfix or contain the name of the struct"
+}
+
+pub struct StructFieldNames {
+    threshold: u64,
+}
+
+impl StructFieldNames {
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl_lint_pass!(StructFieldNames => [STRUCT_FIELD_NAMES]);
+
+impl EarlyLintPass for StructFieldNames {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if_chain! {
+            if !item.span.from_expansion() && is_present_in_source(cx, item.span);
+            if let ItemKind::Struct(VariantData::Struct(ref fields, _), _) = item.kind;
+            if (fields.len() as u64) >= self.threshold;
+            then {
+                let item_name = to_snake_case(&item.ident.name.as_str());
+                check_fields(cx, fields, &item_name, item.span);
+            }
+        }
+    }
+}
+
+fn check_fields(cx: &EarlyContext<'_>, fields: &[StructField], item_name: &str, span: Span) {
+    let field_names: Vec<String> = fields
+        .iter()
+        .filter_map(|field| field.ident.map(|ident| ident.name.to_string()))
+        .collect();
+    if field_names.len() != fields.len() {
+        return;
+    }
+
+    let mut pre: Vec<&str> = field_names[0].split('_').collect();
+    let mut post = pre.clone();
+    post.reverse();
+    for name in &field_names {
+        let words: Vec<&str> = name.split('_').collect();
+        // the common words must never make up a whole field name
+        let pre_len = pre
+            .iter()
+            .zip(&words[..words.len() - 1])
+            .take_while(|(l, r)| l == r)
+            .count();
+        pre.truncate(pre_len);
+        let post_len = post
+            .iter()
+            .zip(words[1..].iter().rev())
+            .take_while(|(l, r)| l == r)
+            .count();
+        post.truncate(post_len);
+    }
+    post.reverse();
+
+    let pre = pre.join("_");
+    let post = post.join("_");
+
+    for (field, name) in fields.iter().zip(&field_names) {
+        // if all fields share the struct's name, it is reported for the whole struct below
+        if pre != item_name && name.starts_with(item_name) && name[item_name.len()..].starts_with('_') {
+            span_lint(
+                cx,
+                STRUCT_FIELD_NAMES,
+                field.span,
+                "field name starts with the struct's name",
+            );
+        }
+        if post != item_name && name.ends_with(item_name) && name[..name.len() - item_name.len()].ends_with('_') {
+            span_lint(
+                cx,
+                STRUCT_FIELD_NAMES,
+                field.span,
+                "field name ends with the struct's name",
+            );
+        }
+    }
+
+    let (what, value) = match (pre, post) {
+        (pre, _) if !pre.is_empty() => ("pre", pre),
+        (_, post) if !post.is_empty() => ("post", post),
+        _ => return,
+    };
+    span_lint_and_help(
+        cx,
+        STRUCT_FIELD_NAMES,
+        span,
+        &format!("all fields have the same {}fix: `{}`", what, value),
+        None,
+        &format!("remove the {}fixes", what),
+    );
+}
+
+#[must_use]
+fn to_snake_case(name: &str) -> String {
+    let mut s = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            // characters without capitalization are considered lowercase
+            if i != 0 {
+                s.push('_');
+            }
+            s.extend(c.to_lowercase());
+        } else {
+            s.push(c);
+        }
+    }
+    s
+}
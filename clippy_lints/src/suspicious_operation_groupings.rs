@@ -6,7 +6,7 @@ use rustc_ast::ast::{BinOpKind, Expr, ExprKind, StmtKind};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 use rustc_span::symbol::Ident;
 use rustc_span::Span;
@@ -59,7 +59,8 @@ declare_clippy_lint! {
     /// ```
     pub SUSPICIOUS_OPERATION_GROUPINGS,
     style,
-    "groupings of binary operations that look suspiciously like typos"
+    "groupings of binary operations that look suspiciously like typos",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(SuspiciousOperationGroupings => [SUSPICIOUS_OPERATION_GROUPINGS]);
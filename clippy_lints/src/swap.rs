@@ -7,7 +7,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Block, Expr, ExprKind, PatKind, QPath, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub MANUAL_SWAP,
     complexity,
-    "manual swap of two variables"
+    "manual swap of two variables",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -60,7 +61,8 @@ declare_clippy_lint! {
     /// ```
     pub ALMOST_SWAPPED,
     correctness,
-    "`foo = bar; bar = foo` sequence"
+    "`foo = bar; bar = foo` sequence",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(Swap => [MANUAL_SWAP, ALMOST_SWAPPED]);
@@ -2,7 +2,7 @@ use crate::utils::span_lint_and_sugg;
 use rustc_ast::ast;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{BytePos, Span};
 use std::convert::TryFrom;
 
@@ -53,7 +53,8 @@ declare_clippy_lint! {
     /// ```
     pub TABS_IN_DOC_COMMENTS,
     style,
-    "using tabs in doc comments is not recommended"
+    "using tabs in doc comments is not recommended",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(TabsInDocComments => [TABS_IN_DOC_COMMENTS]);
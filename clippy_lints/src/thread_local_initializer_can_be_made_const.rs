@@ -0,0 +1,103 @@
+use crate::utils::qualify_min_const_fn::is_min_const_fn;
+use crate::utils::{fn_has_unsatisfiable_preds, is_expn_of, meets_msrv, msrvs, snippet_opt, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_semver::RustcVersion;
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Suggests to use `const` in `thread_local!` macro if possible.
+    ///
+    /// **Why is this bad?** The `thread_local!` macro wraps static declarations and makes them
+    /// thread-local. It supports using a `const` keyword that may be used for declarations that
+    /// can be evaluated as a constant expression. This can enable a more efficient thread local
+    /// implementation that can avoid lazy initialization. For types that do not need to be
+    /// dropped, this can enable an even more efficient implementation that does not need to
+    /// track any additional state.
+    ///
+    /// **Known problems:** `const` initializers of `thread_local!` are stabilized in Rust 1.59, so
+    /// the suggestion doesn't compile with older toolchains. It isn't made when the `msrv` option
+    /// is older.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // example code where clippy issues a warning
+    /// thread_local! {
+    ///     static BUF: String = String::new();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// // example code which does not raise clippy warning
+    /// thread_local! {
+    ///     static BUF: String = const { String::new() };
+    /// }
+    /// ```
+    pub THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
+    pedantic,
+    "suggest using `const` in `thread_local!` macro",
+    applicability: [MachineApplicable]
+}
+
+pub struct ThreadLocalInitializerCanBeMadeConst {
+    msrv: Option<RustcVersion>,
+}
+
+impl ThreadLocalInitializerCanBeMadeConst {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ThreadLocalInitializerCanBeMadeConst => [THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST]);
+
+impl<'tcx> LateLintPass<'tcx> for ThreadLocalInitializerCanBeMadeConst {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::THREAD_LOCAL_CONST_INIT) {
+            return;
+        }
+
+        let def_id = cx.tcx.hir().local_def_id(hir_id).to_def_id();
+        if_chain! {
+            // this is the `__init` function emitted by the `thread_local!` macro, which wraps the
+            // initializer expression when the `const` keyword is not used
+            if let FnKind::ItemFn(ident, ..) = kind;
+            if ident.as_str() == "__init";
+            if is_expn_of(span, "thread_local").is_some();
+            // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+            if !fn_has_unsatisfiable_preds(cx, def_id);
+            if is_min_const_fn(cx.tcx, cx.tcx.optimized_mir(def_id)).is_ok();
+            if let ExprKind::Block(block, _) = body.value.kind;
+            if let Some(init) = block.expr;
+            // the initializer itself is written by the user, not expanded from the macro
+            if !init.span.from_expansion();
+            if let Some(snippet) = snippet_opt(cx, init.span);
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST,
+                    init.span,
+                    "initializer for `thread_local` value can be made `const`",
+                    "replace with",
+                    format!("const {{ {} }}", snippet),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
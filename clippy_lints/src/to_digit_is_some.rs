@@ -4,7 +4,7 @@ use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `.to_digit(..).is_some()` on `char`s.
@@ -26,7 +26,8 @@ declare_clippy_lint! {
     /// ```
     pub TO_DIGIT_IS_SOME,
     style,
-    "`char.is_digit()` is clearer"
+    "`char.is_digit()` is clearer",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ToDigitIsSome => [TO_DIGIT_IS_SOME]);
@@ -8,7 +8,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, GenericArg, Mutability, QPath, TyKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, cast::CastKind, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::DUMMY_SP;
 use rustc_typeck::check::{cast::CastCheck, FnCtxt, Inherited};
 use std::borrow::Cow;
@@ -47,7 +47,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_TRANSMUTE,
     nursery,
-    "transmutes that have the same to and from types or could be a cast/coercion"
+    "transmutes that have the same to and from types or could be a cast/coercion",
+    applicability: [Unspecified]
 }
 
 // FIXME: Merge this lint with USELESS_TRANSMUTE once that is out of the nursery.
@@ -72,7 +73,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
     complexity,
-    "transmutes that could be a pointer cast"
+    "transmutes that could be a pointer cast",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -100,9 +102,9 @@ declare_clippy_lint! {
     ///
     /// **Known problems:**
     /// - `mem::transmute` in statics and constants is stable from Rust 1.46.0,
-    /// while dereferencing raw pointer is not stable yet.
-    /// If you need to do this in those places,
-    /// you would have to use `transmute` instead.
+    ///   while dereferencing raw pointer is not stable yet.
+    ///   If you need to do this in those places,
+    ///   you would have to use `transmute` instead.
     ///
     /// **Example:**
     /// ```rust,ignore
@@ -115,7 +117,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_PTR_TO_REF,
     complexity,
-    "transmutes from a pointer to a reference type"
+    "transmutes from a pointer to a reference type",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -125,10 +128,10 @@ declare_clippy_lint! {
     ///
     /// **Known problems:**
     /// - [`from_u32`] which this lint suggests using is slower than `transmute`
-    /// as it needs to validate the input.
-    /// If you are certain that the input is always a valid Unicode scalar value,
-    /// use [`from_u32_unchecked`] which is as fast as `transmute`
-    /// but has a semantically meaningful name.
+    ///   as it needs to validate the input.
+    ///   If you are certain that the input is always a valid Unicode scalar value,
+    ///   use [`from_u32_unchecked`] which is as fast as `transmute`
+    ///   but has a semantically meaningful name.
     /// - You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
     ///
     /// [`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
@@ -146,7 +149,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_INT_TO_CHAR,
     complexity,
-    "transmutes from an integer to a `char`"
+    "transmutes from an integer to a `char`",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -156,10 +160,10 @@ declare_clippy_lint! {
     ///
     /// **Known problems:**
     /// - [`from_utf8`] which this lint suggests using is slower than `transmute`
-    /// as it needs to validate the input.
-    /// If you are certain that the input is always a valid UTF-8,
-    /// use [`from_utf8_unchecked`] which is as fast as `transmute`
-    /// but has a semantically meaningful name.
+    ///   as it needs to validate the input.
+    ///   If you are certain that the input is always a valid UTF-8,
+    ///   use [`from_utf8_unchecked`] which is as fast as `transmute`
+    ///   but has a semantically meaningful name.
     /// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
     ///
     /// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
@@ -177,7 +181,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_BYTES_TO_STR,
     complexity,
-    "transmutes from a `&[u8]` to a `&str`"
+    "transmutes from a `&[u8]` to a `&str`",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -199,7 +204,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_INT_TO_BOOL,
     complexity,
-    "transmutes from an integer to a `bool`"
+    "transmutes from an integer to a `bool`",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -221,7 +227,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_INT_TO_FLOAT,
     complexity,
-    "transmutes from an integer to a float"
+    "transmutes from an integer to a float",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -243,7 +250,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_FLOAT_TO_INT,
     complexity,
-    "transmutes from a float to an integer"
+    "transmutes from a float to an integer",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -270,7 +278,8 @@ declare_clippy_lint! {
     /// ```
     pub TRANSMUTE_PTR_TO_PTR,
     complexity,
-    "transmutes from a pointer to a pointer / a reference to a reference"
+    "transmutes from a pointer to a pointer / a reference to a reference",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
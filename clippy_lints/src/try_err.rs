@@ -1,6 +1,6 @@
 use crate::utils::{
-    differing_macro_contexts, in_macro, is_type_diagnostic_item, match_def_path, match_qpath, paths, snippet,
-    snippet_with_macro_callsite, span_lint_and_sugg,
+    differing_macro_contexts, get_parent_expr, in_macro, is_type_diagnostic_item, match_def_path, match_qpath, paths,
+    snippet, snippet_with_macro_callsite, span_lint_and_sugg,
 };
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -8,7 +8,7 @@ use rustc_hir::{Expr, ExprKind, LangItem, MatchSource, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -42,7 +42,8 @@ declare_clippy_lint! {
     /// ```
     pub TRY_ERR,
     style,
-    "return errors explicitly rather than hiding them behind a `?`"
+    "return errors explicitly rather than hiding them behind a `?`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(TryErr => [TRY_ERR]);
@@ -69,6 +70,8 @@ impl<'tcx> LateLintPass<'tcx> for TryErr {
             if let Some(ref err_arg) = err_args.get(0);
             if let ExprKind::Path(ref err_fun_path) = err_fun.kind;
             if match_qpath(err_fun_path, &paths::RESULT_ERR);
+            // `return Err(x)?` is linted by `NEEDLESS_RETURN_WITH_QUESTION_MARK`
+            if !matches!(get_parent_expr(cx, expr), Some(Expr { kind: ExprKind::Ret(_), .. }));
             if let Some(return_ty) = find_return_type(cx, &expr.kind);
             then {
                 let prefix;
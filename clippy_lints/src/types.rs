@@ -20,7 +20,7 @@ use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::TypeFoldable;
 use rustc_middle::ty::{self, FloatTy, InferTy, IntTy, Ty, TyCtxt, TyS, TypeAndMut, TypeckResults, UintTy};
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
@@ -33,10 +33,10 @@ use crate::utils::paths;
 use crate::utils::sugg::Sugg;
 use crate::utils::{
     clip, comparisons, differing_macro_contexts, higher, in_constant, indent_of, int_bits, is_hir_ty_cfg_dependant,
-    is_type_diagnostic_item, last_path_segment, match_def_path, match_path, meets_msrv, method_chain_args,
+    is_type_diagnostic_item, last_path_segment, match_def_path, match_path, meets_msrv, method_chain_args, msrvs,
     multispan_sugg, numeric_literal::NumericLiteral, reindent_multiline, sext, snippet, snippet_opt,
-    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg,
-    span_lint_and_then, unsext,
+    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_note,
+    span_lint_and_sugg, span_lint_and_then, unsext,
 };
 
 declare_clippy_lint! {
@@ -94,7 +94,8 @@ declare_clippy_lint! {
     /// ```
     pub VEC_BOX,
     complexity,
-    "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap"
+    "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -142,20 +143,20 @@ declare_clippy_lint! {
     /// **Why is this bad?** Gankro says:
     ///
     /// > The TL;DR of `LinkedList` is that it's built on a massive amount of
-    /// pointers and indirection.
+    /// > pointers and indirection.
     /// > It wastes memory, it has terrible cache locality, and is all-around slow.
-    /// `RingBuf`, while
+    /// > `RingBuf`, while
     /// > "only" amortized for push/pop, should be faster in the general case for
-    /// almost every possible
+    /// > almost every possible
     /// > workload, and isn't even amortized at all if you can predict the capacity
-    /// you need.
+    /// > you need.
     /// >
     /// > `LinkedList`s are only really good if you're doing a lot of merging or
-    /// splitting of lists.
+    /// > splitting of lists.
     /// > This is because they can just mangle some pointers instead of actually
-    /// copying the data. Even
+    /// > copying the data. Even
     /// > if you're doing a lot of insertion in the middle of the list, `RingBuf`
-    /// can still be better
+    /// > can still be better
     /// > because of how expensive it is to seek to the middle of a `LinkedList`.
     ///
     /// **Known problems:** False positives – the instances where using a
@@ -192,7 +193,8 @@ declare_clippy_lint! {
     /// ```
     pub BORROWED_BOX,
     complexity,
-    "a borrow of a boxed type"
+    "a borrow of a boxed type",
+    applicability: [Unspecified]
 }
 
 declare_clippy_lint! {
@@ -216,7 +218,8 @@ declare_clippy_lint! {
     /// ```
     pub REDUNDANT_ALLOCATION,
     perf,
-    "redundant allocation"
+    "redundant allocation",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -247,7 +250,8 @@ declare_clippy_lint! {
     /// ```
     pub RC_BUFFER,
     restriction,
-    "shared ownership of a buffer type"
+    "shared ownership of a buffer type",
+    applicability: [MachineApplicable]
 }
 
 pub struct Types {
@@ -767,7 +771,8 @@ declare_clippy_lint! {
     /// ```
     pub LET_UNIT_VALUE,
     pedantic,
-    "creating a `let` binding to a value of unit type, which usually can't be used afterwards"
+    "creating a `let` binding to a value of unit type, which usually can't be used afterwards",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(LetUnitValue => [LET_UNIT_VALUE]);
@@ -921,7 +926,8 @@ declare_clippy_lint! {
     /// ```
     pub UNIT_ARG,
     complexity,
-    "passing unit to a function"
+    "passing unit to a function",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(UnitArg => [UNIT_ARG]);
@@ -1252,7 +1258,8 @@ declare_clippy_lint! {
     /// ```
     pub CAST_LOSSLESS,
     pedantic,
-    "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`"
+    "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`",
+    applicability: [HasPlaceholders, MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1277,7 +1284,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_CAST,
     complexity,
-    "cast to the same type, e.g., `x as i32` where `x: i32`"
+    "cast to the same type, e.g., `x as i32` where `x: i32`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -1328,7 +1336,8 @@ declare_clippy_lint! {
     /// ```
     pub FN_TO_NUMERIC_CAST,
     style,
-    "casting a function pointer to a numeric type other than usize"
+    "casting a function pointer to a numeric type other than usize",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -1358,7 +1367,29 @@ declare_clippy_lint! {
     /// ```
     pub FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
     style,
-    "casting a function pointer to a numeric type not wide enough to store the address"
+    "casting a function pointer to a numeric type not wide enough to store the address",
+    applicability: [MaybeIncorrect]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for a known NaN float being cast to an integer.
+    ///
+    /// **Why is this bad?** NaNs are cast into zero, so one could simply use this and make the
+    /// code more readable. The lint could also hint at a programmer error.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = (0.0_f32 / 0.0) as u64;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x: u64 = 0;
+    /// ```
+    pub CAST_NAN_TO_INT,
+    style,
+    "casting a known floating-point NaN into an integer"
 }
 
 /// Returns the size in bits of an integral type.
@@ -1605,6 +1636,7 @@ declare_lint_pass!(Casts => [
     CAST_PTR_ALIGNMENT,
     FN_TO_NUMERIC_CAST,
     FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+    CAST_NAN_TO_INT,
 ]);
 
 // Check if the given type is either `core::ffi::c_void` or
@@ -1644,6 +1676,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             }
             let (cast_from, cast_to) = (cx.typeck_results().expr_ty(ex), cx.typeck_results().expr_ty(expr));
             lint_fn_to_numeric_cast(cx, expr, ex, cast_from, cast_to);
+            lint_cast_nan_to_int(cx, expr, ex, cast_from, cast_to);
             if let Some(lit) = get_numeric_literal(ex) {
                 let literal_str = snippet_opt(cx, ex.span).unwrap_or_default();
 
@@ -1828,6 +1861,33 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
     }
 }
 
+fn lint_cast_nan_to_int(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'_>,
+    cast_to: Ty<'_>,
+) {
+    if cast_from.is_floating_point() && cast_to.is_integral() && is_known_nan(cx, cast_expr) {
+        span_lint_and_note(
+            cx,
+            CAST_NAN_TO_INT,
+            expr.span,
+            &format!("casting a known NaN to {}", cast_to),
+            None,
+            "this always evaluates to 0",
+        );
+    }
+}
+
+fn is_known_nan(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match constant(cx, cx.typeck_results(), expr) {
+        Some((Constant::F64(n), _)) => n.is_nan(),
+        Some((Constant::F32(n), _)) => n.is_nan(),
+        _ => false,
+    }
+}
+
 fn lint_fn_to_numeric_cast(
     cx: &LateContext<'_>,
     expr: &Expr<'_>,
@@ -2064,7 +2124,8 @@ declare_clippy_lint! {
     /// ```
     pub CHAR_LIT_AS_U8,
     complexity,
-    "casting a character literal to `u8` truncates"
+    "casting a character literal to `u8` truncates",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(CharLitAsU8 => [CHAR_LIT_AS_U8]);
@@ -2511,7 +2572,8 @@ declare_clippy_lint! {
     /// ```
     pub IMPLICIT_HASHER,
     pedantic,
-    "missing generalization over different hashers"
+    "missing generalization over different hashers",
+    applicability: [Unspecified]
 }
 
 declare_lint_pass!(ImplicitHasher => [IMPLICIT_HASHER]);
@@ -2888,8 +2950,6 @@ impl<'tcx> LateLintPass<'tcx> for RefToMut {
     }
 }
 
-const PTR_AS_PTR_MSRV: RustcVersion = RustcVersion::new(1, 38, 0);
-
 declare_clippy_lint! {
     /// **What it does:**
     /// Checks for `as` casts between raw pointers without changing its mutability,
@@ -2918,7 +2978,8 @@ declare_clippy_lint! {
     /// ```
     pub PTR_AS_PTR,
     pedantic,
-    "casting using `as` from and to raw pointers that doesn't change its mutability, where `pointer::cast` could take the place of `as`"
+    "casting using `as` from and to raw pointers that doesn't change its mutability, where `pointer::cast` could take the place of `as`",
+    applicability: [MachineApplicable]
 }
 
 pub struct PtrAsPtr {
@@ -2936,7 +2997,7 @@ impl_lint_pass!(PtrAsPtr => [PTR_AS_PTR]);
 
 impl<'tcx> LateLintPass<'tcx> for PtrAsPtr {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &PTR_AS_PTR_MSRV) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::POINTER_CAST) {
             return;
         }
 
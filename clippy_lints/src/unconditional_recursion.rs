@@ -0,0 +1,180 @@
+use crate::utils::{in_macro, match_def_path, paths, peel_mid_ty_refs, remove_blocks, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{BinOpKind, Body, Expr, ExprKind, FnDecl, HirId, Item, ItemKind, Node, QPath, TraitRef};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{DefIdTree, Ty, TypeckResults};
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks that there isn't an infinite recursion in trait
+    /// implementations.
+    ///
+    /// **Why is this bad?** This is a hard to find infinite recursion that will crash any code
+    /// using it. The compiler's own `unconditional_recursion` lint doesn't see it, because the
+    /// recursion goes through another function, e.g. `==` on references calling `PartialEq::eq`
+    /// of the referenced type.
+    ///
+    /// **Known problems:** Only `PartialEq` and `Default` implementations are checked. Calling
+    /// `to_string()` in a `Display` implementation is already covered by the
+    /// `to_string_in_display` lint.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum Foo {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// impl PartialEq for Foo {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self == other // bad!
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    ///
+    /// In such cases, either use `#[derive(PartialEq)]` or don't implement it.
+    pub UNCONDITIONAL_RECURSION,
+    style,
+    "detect unconditional recursion in some traits implementation"
+}
+
+declare_lint_pass!(UnconditionalRecursion => [UNCONDITIONAL_RECURSION]);
+
+impl<'tcx> LateLintPass<'tcx> for UnconditionalRecursion {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        if_chain! {
+            if let FnKind::Method(ident, sig, ..) = kind;
+            let impl_id = cx.tcx.hir().get_parent_item(hir_id);
+            if let Some(Node::Item(Item { kind: ItemKind::Impl(impl_), .. })) = cx.tcx.hir().find(impl_id);
+            if let Some(trait_def_id) = impl_.of_trait.as_ref().and_then(TraitRef::trait_def_id);
+            if let Some(trait_ref) = cx.tcx.impl_trait_ref(cx.tcx.hir().local_def_id(impl_id));
+            then {
+                let self_ty = trait_ref.self_ty();
+                let typeck = cx.typeck_results();
+                let recursive_call = if Some(trait_def_id) == cx.tcx.lang_items().eq_trait() {
+                    let op = match &*ident.as_str() {
+                        "eq" => BinOpKind::Eq,
+                        "ne" => BinOpKind::Ne,
+                        _ => return,
+                    };
+                    let rhs_ty = trait_ref.substs.type_at(1);
+                    check_partial_eq(typeck, body, op, self_ty, rhs_ty)
+                } else if match_def_path(cx, trait_def_id, &paths::DEFAULT_TRAIT) {
+                    check_default(cx, typeck, body, self_ty)
+                } else {
+                    None
+                };
+
+                if let Some(call_span) = recursive_call {
+                    span_lint_and_then(
+                        cx,
+                        UNCONDITIONAL_RECURSION,
+                        sig.span,
+                        "function cannot return without recursing",
+                        |diag| {
+                            diag.span_note(call_span, "recursive call site");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks for `eq` (resp. `ne`) implementations that return `self == other` (resp. `!=`), which
+/// calls the method that is implemented again.
+fn check_partial_eq<'tcx>(
+    typeck: &TypeckResults<'tcx>,
+    body: &Body<'_>,
+    op: BinOpKind,
+    self_ty: Ty<'tcx>,
+    rhs_ty: Ty<'tcx>,
+) -> Option<Span> {
+    let expr = remove_blocks(&body.value);
+    if_chain! {
+        if let ExprKind::Binary(bin_op, left, right) = expr.kind;
+        if bin_op.node == op;
+        if peel_mid_ty_refs(typeck.expr_ty(left)).0 == self_ty;
+        if peel_mid_ty_refs(typeck.expr_ty(right)).0 == rhs_ty;
+        then {
+            Some(expr.span)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks for `Default::default` implementations that create `Self { ..Default::default() }`,
+/// or that call another associated function which in turn returns `Self::default()`.
+fn check_default<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'tcx>,
+    body: &Body<'_>,
+    self_ty: Ty<'tcx>,
+) -> Option<Span> {
+    let expr = remove_blocks(&body.value);
+    match expr.kind {
+        ExprKind::Struct(_, _, Some(base)) if typeck.expr_ty(expr) == self_ty => {
+            if is_default_call(cx, typeck, base, self_ty) {
+                Some(base.span)
+            } else {
+                None
+            }
+        },
+        ExprKind::Call(func, []) => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.kind;
+                if let Some(def_id) = typeck.qpath_res(qpath, func.hir_id).opt_def_id();
+                if let Some(local_def_id) = def_id.as_local();
+                if !cx.tcx.is_trait(cx.tcx.parent(def_id)?);
+                let callee_hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+                if let Some(callee_body_id) = cx.tcx.hir().maybe_body_owned_by(callee_hir_id);
+                let callee_body = cx.tcx.hir().body(callee_body_id);
+                let callee_typeck = cx.tcx.typeck(local_def_id);
+                if is_default_call(cx, callee_typeck, remove_blocks(&callee_body.value), self_ty);
+                then {
+                    Some(expr.span)
+                } else {
+                    None
+                }
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether the expression is `Default::default()` (or `Self::default()`) returning
+/// `self_ty`.
+fn is_default_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'tcx>,
+    expr: &Expr<'_>,
+    self_ty: Ty<'tcx>,
+) -> bool {
+    if_chain! {
+        if let ExprKind::Call(func, []) = expr.kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if !matches!(qpath, QPath::LangItem(..));
+        if let Some(def_id) = typeck.qpath_res(qpath, func.hir_id).opt_def_id();
+        if match_def_path(cx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+        then {
+            typeck.expr_ty(expr) == self_ty
+        } else {
+            false
+        }
+    }
+}
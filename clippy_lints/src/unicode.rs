@@ -3,7 +3,7 @@ use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, HirId};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use unicode_normalization::UnicodeNormalization;
 
@@ -19,7 +19,8 @@ declare_clippy_lint! {
     /// some­where in this text.
     pub INVISIBLE_CHARACTERS,
     correctness,
-    "using an invisible character in a string literal, which is confusing"
+    "using an invisible character in a string literal, which is confusing",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -43,7 +44,8 @@ declare_clippy_lint! {
     /// ```
     pub NON_ASCII_LITERAL,
     pedantic,
-    "using any literal non-ASCII chars in a string literal instead of using the `\\u` escape"
+    "using any literal non-ASCII chars in a string literal instead of using the `\\u` escape",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -60,7 +62,8 @@ declare_clippy_lint! {
     /// former when escaped is actually `"a\u{300}"` while the latter is `"\u{e0}"`.
     pub UNICODE_NOT_NFC,
     pedantic,
-    "using a Unicode literal not in NFC normal form (see [Unicode tr15](http://www.unicode.org/reports/tr15/) for further information)"
+    "using a Unicode literal not in NFC normal form (see [Unicode tr15](http://www.unicode.org/reports/tr15/) for further information)",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(Unicode => [INVISIBLE_CHARACTERS, NON_ASCII_LITERAL, UNICODE_NOT_NFC]);
@@ -0,0 +1,116 @@
+use crate::utils::{match_def_path, match_function_call, match_type, paths, snippet, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, MatchSource, Pat, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Debug` formatting (`{:?}`) applied to an `OsStr`,
+    /// `OsString`, `Path` or `PathBuf`.
+    ///
+    /// **Why is this bad?** Rust doesn't guarantee what `Debug` formatting looks like, and it
+    /// quotes and escapes the value, which is rarely what should be shown to a user. The
+    /// `display` method of `Path`, or `to_string_lossy` for `OsStr`, should be used instead.
+    ///
+    /// **Known problems:** The lint doesn't know whether the output is user-facing, so it also
+    /// fires for output only meant for debugging.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::path::Path;
+    /// let path = Path::new("...");
+    /// println!("The path is {:?}", path);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::Path;
+    /// let path = Path::new("...");
+    /// println!("The path is {}", path.display());
+    /// ```
+    pub UNNECESSARY_DEBUG_FORMATTING,
+    pedantic,
+    "`Debug` formatting applied to an `OsStr` or `Path` when `.display()` is available"
+}
+
+declare_lint_pass!(UnnecessaryDebugFormatting => [UNNECESSARY_DEBUG_FORMATTING]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryDebugFormatting {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `format_args!("{:?}", x)` expands to
+        // `match (&x,) { (arg0,) => [ArgumentV1::new(arg0, Debug::fmt)] }`
+        if_chain! {
+            if expr.span.from_expansion();
+            if let ExprKind::Match(scrutinee, [arm], MatchSource::Normal) = expr.kind;
+            if let ExprKind::Tup(values) = scrutinee.kind;
+            if let PatKind::Tuple(bindings, None) = arm.pat.kind;
+            if let ExprKind::Array(arguments) = arm.body.kind;
+            then {
+                for argument in arguments {
+                    if let Some(value) = debug_formatted_value(cx, argument, bindings, values) {
+                        check_value(cx, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the formatted value if the argument is `ArgumentV1::new(argN, Debug::fmt)`.
+fn debug_formatted_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    argument: &'tcx Expr<'tcx>,
+    bindings: &[&Pat<'_>],
+    values: &'tcx [Expr<'tcx>],
+) -> Option<&'tcx Expr<'tcx>> {
+    if_chain! {
+        if let Some([binding, fmt]) = match_function_call(cx, argument, &paths::FMT_ARGUMENTV1_NEW);
+        if let ExprKind::Path(ref fmt_qpath) = fmt.kind;
+        if let Some(fmt_did) = cx.qpath_res(fmt_qpath, fmt.hir_id).opt_def_id();
+        if match_def_path(cx, fmt_did, &paths::DEBUG_FMT_METHOD);
+        if let ExprKind::Path(QPath::Resolved(None, path)) = binding.kind;
+        if let Res::Local(binding_id) = path.res;
+        if let Some(index) = bindings
+            .iter()
+            .position(|pat| matches!(pat.kind, PatKind::Binding(_, id, ..) if id == binding_id));
+        if let Some(value) = values.get(index);
+        if let ExprKind::AddrOf(BorrowKind::Ref, _, value) = value.kind;
+        then {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+fn check_value(cx: &LateContext<'_>, value: &Expr<'_>) {
+    if value.span.from_expansion() {
+        return;
+    }
+    let ty = cx.typeck_results().expr_ty(value).peel_refs();
+    let method = if match_type(cx, ty, &paths::PATH) || match_type(cx, ty, &paths::PATH_BUF) {
+        "display"
+    } else if match_type(cx, ty, &paths::OS_STR) || match_type(cx, ty, &paths::OS_STRING) {
+        "to_string_lossy"
+    } else {
+        return;
+    };
+
+    span_lint_and_then(
+        cx,
+        UNNECESSARY_DEBUG_FORMATTING,
+        value.span,
+        "unnecessary `Debug` formatting in format arguments",
+        |diag| {
+            diag.help(&format!(
+                "use `Display` formatting and change this to `{}.{}()`",
+                snippet(cx, value.span, ".."),
+                method
+            ));
+            diag.note(
+                "switching to `Display` formatting will change how the value is shown; \
+                 escaped characters will no longer be escaped and surrounding quotes will be removed",
+            );
+        },
+    );
+}
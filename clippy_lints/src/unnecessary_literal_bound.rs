@@ -0,0 +1,134 @@
+use crate::utils::{in_macro, span_lint_and_sugg, visitors::find_all_ret_expressions};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    Body, ExprKind, FnDecl, FnRetTy, HirId, Impl, ItemKind, MutTy, Mutability, Node, PrimTy, QPath, TyKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects functions that are written to return `&str` that could return
+    /// `&'static str` but instead return a `&'a str`.
+    ///
+    /// **Why is this bad?** This leaves the caller unable to use the `&str` as `&'static str`,
+    /// causing unnecessary allocations or confusion. This is also most likely what you meant to
+    /// write.
+    ///
+    /// **Known problems:** Only functions returning string literals on all paths are checked.
+    /// Exported functions are not linted unless `avoid-breaking-exported-api` is disabled.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # struct MyType;
+    /// impl MyType {
+    ///     fn returns_literal(&self) -> &str {
+    ///         "Literal"
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct MyType;
+    /// impl MyType {
+    ///     fn returns_literal(&self) -> &'static str {
+    ///         "Literal"
+    ///     }
+    /// }
+    /// ```
+    pub UNNECESSARY_LITERAL_BOUND,
+    pedantic,
+    "detects `&str` that could be `&'static str` in function return types",
+    applicability: [MachineApplicable]
+}
+
+pub struct UnnecessaryLiteralBound {
+    avoid_breaking_exported_api: bool,
+}
+
+impl UnnecessaryLiteralBound {
+    #[must_use]
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(UnnecessaryLiteralBound => [UNNECESSARY_LITERAL_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryLiteralBound {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        fn_decl: &FnDecl<'tcx>,
+        body: &Body<'tcx>,
+        span: Span,
+        hir_id: HirId,
+    ) {
+        // checking closures would be a little silly
+        if in_macro(span) || matches!(fn_kind, FnKind::Closure(..)) {
+            return;
+        }
+
+        if self.avoid_breaking_exported_api && cx.access_levels.is_exported(hir_id) {
+            return;
+        }
+
+        // the signature has to match the trait's one
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(hir_id)) {
+            if matches!(
+                item.kind,
+                ItemKind::Impl(Impl { of_trait: Some(_), .. }) | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+
+        // check for `-> &str` with an elided lifetime
+        let ret_ty = match fn_decl.output {
+            FnRetTy::Return(ret_ty) => ret_ty,
+            FnRetTy::DefaultReturn(_) => return,
+        };
+        let (lifetime, inner_ty) = match ret_ty.kind {
+            TyKind::Rptr(
+                ref lifetime,
+                MutTy {
+                    ty: inner_ty,
+                    mutbl: Mutability::Not,
+                },
+            ) => (lifetime, inner_ty),
+            _ => return,
+        };
+        if !lifetime.is_elided() || !is_str(inner_ty) {
+            return;
+        }
+
+        // check that all returned expressions are string literals
+        let all_literals = find_all_ret_expressions(
+            cx,
+            &body.value,
+            |ret_expr| matches!(ret_expr.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Str(..))),
+        );
+
+        if all_literals {
+            span_lint_and_sugg(
+                cx,
+                UNNECESSARY_LITERAL_BOUND,
+                ret_ty.span,
+                "returning a `str` unnecessarily tied to the lifetime of arguments",
+                "try",
+                "&'static str".into(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+fn is_str(ty: &rustc_hir::Ty<'_>) -> bool {
+    matches!(ty.kind, TyKind::Path(QPath::Resolved(None, path)) if path.res == Res::PrimTy(PrimTy::Str))
+}
@@ -5,7 +5,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, Mutability, Param, Pat, PatKind, Path, PathSegment, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, subst::GenericArgKind};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::sym;
 use rustc_span::symbol::Ident;
 
@@ -40,7 +40,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_SORT_BY,
     complexity,
-    "Use of `Vec::sort_by` when `Vec::sort_by_key` or `Vec::sort` would be clearer"
+    "Use of `Vec::sort_by` when `Vec::sort_by_key` or `Vec::sort` would be clearer",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(UnnecessarySortBy => [UNNECESSARY_SORT_BY]);
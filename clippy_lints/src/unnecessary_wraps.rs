@@ -8,7 +8,7 @@ use rustc_hir::intravisit::FnKind;
 use rustc_hir::{Body, ExprKind, FnDecl, HirId, Impl, ItemKind, Node};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::subst::GenericArgKind;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
 
@@ -49,7 +49,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_WRAPS,
     complexity,
-    "functions that only return `Ok` or `Some`"
+    "functions that only return `Ok` or `Some`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(UnnecessaryWraps => [UNNECESSARY_WRAPS]);
@@ -8,7 +8,7 @@ use rustc_ast::{self as ast, Pat, PatKind, PatKind::*, DUMMY_NODE_ID};
 use rustc_ast_pretty::pprust;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::DUMMY_SP;
 
 use std::cell::Cell;
@@ -46,7 +46,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNESTED_OR_PATTERNS,
     pedantic,
-    "unnested or-patterns, e.g., `Foo(Bar) | Foo(Baz) instead of `Foo(Bar | Baz)`"
+    "unnested or-patterns, e.g., `Foo(Bar) | Foo(Baz) instead of `Foo(Bar | Baz)`",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(UnnestedOrPatterns => [UNNESTED_OR_PATTERNS]);
@@ -0,0 +1,112 @@
+use crate::attrs::extract_clippy_lint;
+use crate::utils::file_filter::PLUGIN_GROUP;
+use crate::utils::{in_macro, is_allow_used, span_lint_hir_and_then};
+use rustc_ast::ast::Attribute;
+use rustc_hir::{Crate, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for Clippy lints and groups given to `#[allow]` attributes which
+    /// didn't allow any lint emitted in the code they apply to.
+    ///
+    /// **Why is this bad?** The allowed lints are often fixed or the code which triggered them
+    /// removed, and the stale attributes hide the lints of the code written later.
+    ///
+    /// **Known problems:** The lints of the code left out by `cfg` attributes, or disabled with
+    /// `cargo clippy --only`, aren't emitted, so the attributes allowing them are reported.
+    /// Conversely, some lints skip the code where they are allowed before checking it, so the
+    /// attributes allowing them are never reported.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// #[allow(clippy::needless_return)]
+    /// fn f() -> u32 {
+    ///     1
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f() -> u32 {
+    ///     1
+    /// }
+    /// ```
+    pub UNUSED_LINT_ALLOWS,
+    restriction,
+    "`#[allow]` attributes of Clippy lints which allowed nothing"
+}
+
+/// A Clippy lint or group of the list of an `#[allow]` attribute.
+struct Allow {
+    /// The node the attribute applies to
+    hir_id: HirId,
+    span: Span,
+    name: String,
+}
+
+/// Collects the Clippy lints of the `#[allow]` attributes, and reports the ones which didn't allow
+/// any lint once all the lints were emitted, so it's registered after all the other passes.
+#[derive(Default)]
+pub struct UnusedLintAllows {
+    allows: Vec<Allow>,
+}
+
+impl_lint_pass!(UnusedLintAllows => [UNUSED_LINT_ALLOWS]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedLintAllows {
+    fn check_attribute(&mut self, cx: &LateContext<'tcx>, attr: &'tcx Attribute) {
+        // the attributes added for the files filtered out by the configuration have dummy spans
+        if !attr.has_name(sym::allow) || in_macro(attr.span) || attr.span.is_dummy() {
+            return;
+        }
+        for item in attr.meta_item_list().unwrap_or_default() {
+            match extract_clippy_lint(&item) {
+                Some(name) if name != "unused_lint_allows" => {
+                    let name = format!("clippy::{}", name);
+                    let span = item.span();
+                    if !is_plugin_lint(cx, &name) && self.allows.iter().all(|allow| allow.span != span) {
+                        self.allows.push(Allow {
+                            hir_id: cx.last_node_with_lint_attrs,
+                            span,
+                            name,
+                        });
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        for allow in &self.allows {
+            if !is_allow_used(cx.tcx.sess, allow.span) {
+                span_lint_hir_and_then(
+                    cx,
+                    UNUSED_LINT_ALLOWS,
+                    allow.hir_id,
+                    allow.span,
+                    &format!("`{}` is allowed but no such lint was emitted here", allow.name),
+                    |diag| {
+                        diag.help("remove it from the attribute");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the lint or group is the group of the lints of the plugins or one of them, whose
+/// emissions can't be tracked since the plugins may emit them without the functions of Clippy.
+fn is_plugin_lint(cx: &LateContext<'_>, name: &str) -> bool {
+    let plugin_lints = match cx.lint_store.find_lints(PLUGIN_GROUP) {
+        Ok(plugin_lints) => plugin_lints,
+        Err(_) => return false,
+    };
+    name == PLUGIN_GROUP
+        || cx
+            .lint_store
+            .find_lints(name)
+            .map_or(false, |lints| lints.iter().any(|lint| plugin_lints.contains(lint)))
+}
@@ -0,0 +1,60 @@
+use crate::utils::{in_macro, is_type_diagnostic_item, snippet_with_macro_callsite, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{ExprKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_tool_lint;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Result::ok()` without using the returned `Option`.
+    ///
+    /// **Why is this bad?** Using `Result::ok()` may look like the result is checked like `unwrap`
+    /// or `expect` would do but it only silences the warning caused by `#[must_use]` on the
+    /// `Result`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn some_function() -> Result<(), ()> { Ok(()) }
+    /// some_function().ok();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn some_function() -> Result<(), ()> { Ok(()) }
+    /// let _ = some_function();
+    /// ```
+    pub UNUSED_RESULT_OK,
+    restriction,
+    "Use of `.ok()` to silence `Result`'s `#[must_use]` is misleading. Use `let _ =` instead.",
+    applicability: [MaybeIncorrect]
+}
+
+declare_lint_pass!(UnusedResultOk => [UNUSED_RESULT_OK]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedResultOk {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &Stmt<'_>) {
+        if_chain! {
+            if let StmtKind::Semi(expr) = stmt.kind;
+            if let ExprKind::MethodCall(path, _, [receiver], _) = expr.kind;
+            if path.ident.name == sym!(ok);
+            if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(receiver), sym::result_type);
+            if !in_macro(stmt.span) && !in_external_macro(cx.sess(), stmt.span);
+            then {
+                let snippet = snippet_with_macro_callsite(cx, receiver.span, "..");
+                let sugg = format!("let _ = {};", snippet);
+                span_lint_and_sugg(
+                    cx,
+                    UNUSED_RESULT_OK,
+                    stmt.span,
+                    "ignoring a result with `.ok()` is misleading",
+                    "consider using `let _ =` and removing the call to `.ok()` instead",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
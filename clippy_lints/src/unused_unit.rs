@@ -3,7 +3,7 @@ use rustc_ast::ast;
 use rustc_ast::visit::FnKind;
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 use rustc_span::BytePos;
 
@@ -26,7 +26,8 @@ declare_clippy_lint! {
     /// ```
     pub UNUSED_UNIT,
     style,
-    "needless unit expression"
+    "needless unit expression",
+    applicability: [MachineApplicable, MaybeIncorrect]
 }
 
 declare_lint_pass!(UnusedUnit => [UNUSED_UNIT]);
@@ -5,7 +5,7 @@ use rustc_ast::ast::{Item, ItemKind, Variant};
 use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::Ident;
 
 declare_clippy_lint! {
@@ -30,7 +30,8 @@ declare_clippy_lint! {
     /// ```
     pub UPPER_CASE_ACRONYMS,
     style,
-    "capitalized acronyms are against the naming convention"
+    "capitalized acronyms are against the naming convention",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(UpperCaseAcronyms => [UPPER_CASE_ACRONYMS]);
@@ -13,11 +13,11 @@ use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_middle::ty::{DefIdTree, Ty};
 use rustc_semver::RustcVersion;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::kw;
 use rustc_typeck::hir_ty_to_ty;
 
-use crate::utils::{differing_macro_contexts, meets_msrv, span_lint_and_sugg};
+use crate::utils::{differing_macro_contexts, meets_msrv, msrvs, span_lint_and_sugg};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for unnecessary repetition of structure name when a
@@ -51,7 +51,8 @@ declare_clippy_lint! {
     /// ```
     pub USE_SELF,
     nursery,
-    "unnecessary structure name repetition whereas `Self` is applicable"
+    "unnecessary structure name repetition whereas `Self` is applicable",
+    applicability: [MachineApplicable]
 }
 
 impl_lint_pass!(UseSelf => [USE_SELF]);
@@ -158,8 +159,6 @@ fn check_trait_method_impl_decl<'tcx>(
     }
 }
 
-const USE_SELF_MSRV: RustcVersion = RustcVersion::new(1, 37, 0);
-
 pub struct UseSelf {
     msrv: Option<RustcVersion>,
 }
@@ -173,7 +172,7 @@ impl UseSelf {
 
 impl<'tcx> LateLintPass<'tcx> for UseSelf {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
-        if !meets_msrv(self.msrv.as_ref(), &USE_SELF_MSRV) {
+        if !meets_msrv(self.msrv.as_ref(), &msrvs::TYPE_ALIAS_ENUM_VARIANTS) {
             return;
         }
 
@@ -1,7 +1,9 @@
+use super::conf;
 use rustc_ast::ast;
 use rustc_errors::Applicability;
 use rustc_session::Session;
-use rustc_span::sym;
+use rustc_span::{sym, Symbol};
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 /// Deprecation status of attributes known by Clippy.
@@ -23,8 +25,14 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ),
     ("dump", DeprecationStatus::None),
     ("msrv", DeprecationStatus::None),
+    ("too_many_arguments", DeprecationStatus::None),
+    ("too_many_lines", DeprecationStatus::None),
 ];
 
+/// The lints whose threshold can be overridden for an item with an attribute, like
+/// `#[clippy::too_many_lines(threshold = 200)]`.
+pub const THRESHOLD_ATTRIBUTES: [&str; 3] = ["cognitive_complexity", "too_many_arguments", "too_many_lines"];
+
 pub struct LimitStack {
     stack: Vec<u64>,
 }
@@ -43,6 +51,13 @@ impl LimitStack {
     pub fn limit(&self) -> u64 {
         *self.stack.last().expect("there should always be a value in the stack")
     }
+    /// Like `limit`, but returns `default` when no attribute overrides the configured limit.
+    pub fn limit_or(&self, default: Option<u64>) -> u64 {
+        match default {
+            Some(default) if self.stack.len() == 1 => default,
+            _ => self.limit(),
+        }
+    }
     pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
         let stack = &mut self.stack;
         parse_attrs(sess, attrs, name, |val| stack.push(val));
@@ -51,6 +66,16 @@ impl LimitStack {
         let stack = &mut self.stack;
         parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
     }
+    /// Like `push_attrs`, but for attributes taking the limit as an argument, like
+    /// `#[clippy::too_many_lines(threshold = 200)]`.
+    pub fn push_arg_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str, arg: &str) {
+        let stack = &mut self.stack;
+        parse_arg_attrs(sess, attrs, name, arg, |val| stack.push(val));
+    }
+    pub fn pop_arg_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str, arg: &str) {
+        let stack = &mut self.stack;
+        parse_arg_attrs(sess, attrs, name, arg, |val| assert_eq!(stack.pop(), Some(val)));
+    }
 }
 
 pub fn get_attr<'a>(
@@ -77,7 +102,21 @@ pub fn get_attr<'a>(
                 })
                 .map_or_else(
                     || {
-                        sess.span_err(attr_segments[1].ident.span, "usage of unknown attribute");
+                        let lint = attr_segments[1].ident.name.as_str();
+                        if conf::is_threshold_lint(&lint) {
+                            sess.struct_span_err(
+                                attr_segments[1].ident.span,
+                                &format!("the threshold of `{}` can't be overridden with an attribute", lint),
+                            )
+                            .help(&format!(
+                                "only the thresholds of `{}` can, the other ones are set in the `[thresholds]` table \
+                                 of `clippy.toml`",
+                                THRESHOLD_ATTRIBUTES.join("`, `")
+                            ))
+                            .emit();
+                        } else {
+                            sess.span_err(attr_segments[1].ident.span, "usage of unknown attribute");
+                        }
                         false
                     },
                     |deprecation_status| {
@@ -111,6 +150,8 @@ pub fn get_attr<'a>(
     })
 }
 
+/// Parses the limits of attributes like `#[clippy::cognitive_complexity = "25"]`, which can also
+/// be given like the ones of the other lints, as `#[clippy::cognitive_complexity(threshold = 25)]`.
 fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, mut f: F) {
     for attr in get_attr(sess, attrs, name) {
         if let Some(ref value) = attr.value_str() {
@@ -119,6 +160,8 @@ fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'
             } else {
                 sess.span_err(attr.span, "not a number");
             }
+        } else if attr.meta_item_list().is_some() {
+            parse_args(sess, attr, "threshold", &mut f);
         } else {
             sess.span_err(attr.span, "bad clippy attribute");
         }
@@ -143,6 +186,36 @@ pub fn get_unique_inner_attr(sess: &Session, attrs: &[ast::Attribute], name: &'s
     unique_attr
 }
 
+fn parse_arg_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, arg: &str, mut f: F) {
+    for attr in get_attr(sess, attrs, name) {
+        if attr.meta_item_list().is_some() {
+            parse_args(sess, attr, arg, &mut f);
+        } else {
+            sess.span_err(attr.span, "bad clippy attribute");
+        }
+    }
+}
+
+/// Parses the limits given by the `arg` argument of the attribute, like `threshold = 200`.
+fn parse_args<F: FnMut(u64)>(sess: &Session, attr: &ast::Attribute, arg: &str, f: &mut F) {
+    for nested in attr.meta_item_list().unwrap_or_default() {
+        match nested.meta_item() {
+            Some(item) if item.has_name(Symbol::intern(arg)) => {
+                let value = item.name_value_literal().and_then(|lit| match lit.kind {
+                    ast::LitKind::Int(value, _) => u64::try_from(value).ok(),
+                    _ => None,
+                });
+                if let Some(value) = value {
+                    f(value)
+                } else {
+                    sess.span_err(item.span, "not a number");
+                }
+            },
+            _ => sess.span_err(nested.span(), &format!("unknown argument, expected `{}`", arg)),
+        }
+    }
+}
+
 /// Return true if the attributes contain any of `proc_macro`,
 /// `proc_macro_derive` or `proc_macro_attribute`, false otherwise
 pub fn is_proc_macro(sess: &Session, attrs: &[ast::Attribute]) -> bool {
@@ -2,32 +2,53 @@
 
 #![deny(clippy::missing_docs_in_private_items)]
 
-use rustc_ast::ast::{LitKind, MetaItemKind, NestedMetaItem};
-use rustc_span::source_map;
-use source_map::Span;
+use super::file_filter;
+use ::regex::Regex;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_span::lev_distance::lev_distance;
+use std::collections::BTreeMap;
 use std::lazy::SyncLazy;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::{env, fmt, fs, io};
 
-/// Gets the configuration file from arguments.
-pub fn file_from_args(args: &[NestedMetaItem]) -> Result<Option<PathBuf>, (&'static str, Span)> {
-    for arg in args.iter().filter_map(NestedMetaItem::meta_item) {
-        if arg.has_name(sym!(conf_file)) {
-            return match arg.kind {
-                MetaItemKind::Word | MetaItemKind::List(_) => Err(("`conf_file` must be a named value", arg.span)),
-                MetaItemKind::NameValue(ref value) => {
-                    if let LitKind::Str(ref file, _) = value.kind {
-                        Ok(Some(file.to_string().into()))
-                    } else {
-                        Err(("`conf_file` value must be a string", value.span))
-                    }
-                },
-            };
+/// A list of names from the configuration, like `blacklisted-names`.
+///
+/// Entries starting with `^` are regular expressions, the other ones have to match exactly.
+#[derive(Clone, Debug, Default)]
+pub struct NameList {
+    /// The names which have to match exactly
+    names: FxHashSet<String>,
+    /// The regular expressions names are matched against
+    patterns: Vec<Regex>,
+}
+
+impl NameList {
+    /// Builds the list from the entries of the configuration. Also returns the error messages
+    /// of the entries which aren't valid regular expressions, which are skipped.
+    #[must_use]
+    pub fn new(entries: &[String]) -> (Self, Vec<String>) {
+        let mut list = Self::default();
+        let mut errors = Vec::new();
+        for entry in entries {
+            if entry.starts_with('^') {
+                match Regex::new(entry) {
+                    Ok(pattern) => list.patterns.push(pattern),
+                    Err(error) => errors.push(format!("invalid regular expression `{}`: {}", entry, error)),
+                }
+            } else {
+                list.names.insert(entry.clone());
+            }
         }
+        (list, errors)
     }
 
-    Ok(None)
+    /// Checks whether the name is one of the names of the list, or matches one of its regular
+    /// expressions.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name) || self.patterns.iter().any(|pattern| pattern.is_match(name))
+    }
 }
 
 /// Error from reading a configuration file.
@@ -37,6 +58,22 @@ pub enum Error {
     Io(io::Error),
     /// Not valid toml or doesn't fit the expected config format
     Toml(String),
+    /// A key which isn't a configuration option, with the most similar option, if any
+    UnknownKey(String, Option<String>),
+    /// A deprecated threshold option, with the key replacing it in the `[thresholds]` table
+    DeprecatedThreshold(String, String),
+    /// A file extending itself through the chain of files of `extends`, starting and ending with it
+    CyclicExtends(Vec<PathBuf>),
+    /// A glob of `exclude` which can't be matched, with the reason
+    InvalidGlob(String, String),
+}
+
+impl Error {
+    /// Whether the error is only worth a warning, the rest of the file being used anyway.
+    #[must_use]
+    pub fn is_warning(&self) -> bool {
+        matches!(self, Self::UnknownKey(..) | Self::DeprecatedThreshold(..))
+    }
 }
 
 impl fmt::Display for Error {
@@ -44,6 +81,20 @@ impl fmt::Display for Error {
         match self {
             Self::Io(err) => err.fmt(f),
             Self::Toml(err) => err.fmt(f),
+            Self::UnknownKey(key, None) => write!(f, "unknown key `{}`", key),
+            Self::UnknownKey(key, Some(suggestion)) => {
+                write!(f, "unknown key `{}`, did you mean `{}`?", key, suggestion)
+            },
+            Self::DeprecatedThreshold(key, replacement) => write!(
+                f,
+                "`{}` is deprecated, use `{}` in the `[thresholds]` table instead",
+                key, replacement
+            ),
+            Self::CyclicExtends(chain) => {
+                let chain: Vec<_> = chain.iter().map(|path| format!("`{}`", path.display())).collect();
+                write!(f, "cyclic `extends`: {}", chain.join(" -> "))
+            },
+            Self::InvalidGlob(glob, reason) => write!(f, "invalid glob `{}` in `exclude`: {}", glob, reason),
         }
     }
 }
@@ -58,7 +109,7 @@ impl From<io::Error> for Error {
 static ERRORS: SyncLazy<Mutex<Vec<Error>>> = SyncLazy::new(|| Mutex::new(Vec::new()));
 
 macro_rules! define_Conf {
-    ($(#[$doc:meta] ($config:ident, $config_str:literal: $Ty:ty, $default:expr),)+) => {
+    ($(#[doc = $doc:literal] ($config:ident, $config_str:literal: $Ty:ty, $default:expr),)+) => {
         mod helpers {
             use serde::Deserialize;
             /// Type used to store lint configuration.
@@ -66,7 +117,7 @@ macro_rules! define_Conf {
             #[serde(rename_all = "kebab-case", deny_unknown_fields)]
             pub struct Conf {
                 $(
-                    #[$doc]
+                    #[doc = $doc]
                     #[serde(default = $config_str)]
                     #[serde(with = $config_str)]
                     pub $config: $Ty,
@@ -76,6 +127,20 @@ macro_rules! define_Conf {
                 third_party: Option<::toml::Value>,
             }
 
+            /// The names of the configuration options, as written in the configuration files, and
+            /// their types.
+            pub const KEYS: &[(&str, &str)] = &[
+                $(($config_str, stringify!($Ty)),)+
+                ("third_party", "Option<toml::Value>"),
+            ];
+
+            /// Describes the configuration options, see `super::schema`.
+            pub fn options() -> Vec<super::OptionSchema> {
+                vec![$(
+                    super::OptionSchema::new($config_str, stringify!($Ty), serde_json::to_value($config()), $doc),
+                )+]
+            }
+
             $(
                 mod $config {
                     use serde::Deserialize;
@@ -104,17 +169,19 @@ macro_rules! define_Conf {
     };
 }
 
-pub use self::helpers::Conf;
+pub use self::helpers::{Conf, KEYS};
 define_Conf! {
-    /// Lint: REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN. The minimum rust version that the project supports
+    /// Lint: REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, MANUAL_HASH_ONE, THREAD_LOCAL_INITIALIZER_CAN_BE_MADE_CONST, INCOMPATIBLE_MSRV, ASSIGNING_CLONES, LEGACY_NUMERIC_CONSTANTS, MANUAL_DIV_CEIL, MANUAL_RETAIN, MANUAL_CLAMP, SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND. The minimum rust version that the project supports
     (msrv, "msrv": Option<String>, None),
-    /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about. NB: `bar` is not here since it has legitimate uses
+    /// Lint: ALL. The path of a configuration file, relative to this one, whose values are used unless this file overrides them
+    (extends, "extends": Option<String>, None),
+    /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about, entries starting with `^` being regular expressions. NB: `bar` is not here since it has legitimate uses
     (blacklisted_names, "blacklisted_names": Vec<String>, ["foo", "baz", "quux"].iter().map(ToString::to_string).collect()),
-    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have, overridable for an item with `#[clippy::cognitive_complexity(threshold = N)]`
     (cognitive_complexity_threshold, "cognitive_complexity_threshold": u64, 25),
     /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY. Use the Cognitive Complexity lint instead.
     (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold": Option<u64>, None),
-    /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks
+    /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks, entries starting with `^` being regular expressions
     (doc_valid_idents, "doc_valid_idents": Vec<String>, [
         "KiB", "MiB", "GiB", "TiB", "PiB", "EiB",
         "DirectX",
@@ -135,7 +202,7 @@ define_Conf! {
         "MinGW",
         "CamelCase",
     ].iter().map(ToString::to_string).collect()),
-    /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
+    /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have, overridable for an item with `#[clippy::too_many_arguments(threshold = N)]`
     (too_many_arguments_threshold, "too_many_arguments_threshold": u64, 7),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
     (type_complexity_threshold, "type_complexity_threshold": u64, 250),
@@ -145,6 +212,8 @@ define_Conf! {
     (too_large_for_stack, "too_large_for_stack": u64, 200),
     /// Lint: ENUM_VARIANT_NAMES. The minimum number of enum variants for the lints about variant names to trigger
     (enum_variant_name_threshold, "enum_variant_name_threshold": u64, 3),
+    /// Lint: STRUCT_FIELD_NAMES. The minimum number of struct fields for the lints about field names to trigger
+    (struct_field_name_threshold, "struct_field_name_threshold": u64, 3),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a enum's variant to avoid box suggestion
     (enum_variant_size_threshold, "enum_variant_size_threshold": u64, 200),
     /// Lint: VERBOSE_BIT_MASK. The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
@@ -155,7 +224,7 @@ define_Conf! {
     (trivial_copy_size_limit, "trivial_copy_size_limit": Option<u64>, None),
     /// Lint: LARGE_TYPE_PASS_BY_MOVE. The minimum size (in bytes) to consider a type for passing by reference instead of by value.
     (pass_by_value_size_limit, "pass_by_value_size_limit": u64, 256),
-    /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
+    /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have, overridable for an item with `#[clippy::too_many_lines(threshold = N)]`
     (too_many_lines_threshold, "too_many_lines_threshold": u64, 100),
     /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS. The maximum allowed size for arrays on the stack
     (array_size_threshold, "array_size_threshold": u64, 512_000),
@@ -173,6 +242,146 @@ define_Conf! {
     (disallowed_methods, "disallowed_methods": Vec<String>, Vec::<String>::new()),
     /// Lint: UNREADABLE_LITERAL. Should the fraction of a decimal be linted to include separators.
     (unreadable_literal_lint_fractions, "unreadable_literal_lint_fractions": bool, true),
+    /// Lint: UNNECESSARY_LITERAL_BOUND. Suppress lints whenever the suggested change would cause breakage for other crates.
+    (avoid_breaking_exported_api, "avoid_breaking_exported_api": bool, true),
+    /// Lint: RESULT_LARGE_ERR. The maximum size of the `Err`-variant in a `Result` returned from a function
+    (large_error_threshold, "large_error_threshold": u64, 128),
+    /// Lint: ALL. The thresholds of the lints, by lint name, like `too_many_arguments = 10`. They replace the deprecated numeric options of the lints, like `too-many-arguments-threshold`
+    (thresholds, "thresholds": std::collections::BTreeMap<String, u64>, std::collections::BTreeMap::new()),
+    /// Lint: NOT_USING_ASSOCIATED_TYPE. Whether to only lint associated types set to a type defined in another crate
+    (not_using_associated_type_foreign_only, "not_using_associated_type_foreign_only": bool, false),
+    /// Lint: NOT_USING_ASSOCIATED_TYPE. The paths of the types, as they are defined (e.g. `alloc::string::String`), which are not linted when an associated type is set to them
+    (not_using_associated_type_ignored_types, "not_using_associated_type_ignored_types": Vec<String>, [
+        "alloc::boxed::Box",
+        "alloc::string::String",
+        "alloc::vec::Vec",
+        "core::option::Option",
+        "core::result::Result",
+    ].iter().map(ToString::to_string).collect()),
+    /// Lint: ALL. The levels of lints and lint groups, like `unwrap_used = "deny"` or `pedantic = { level = "warn", allow = ["module_name_repetitions"] }`, overridden by the command line and by attributes
+    (levels, "levels": std::collections::BTreeMap<String, crate::utils::conf::LintLevel>, std::collections::BTreeMap::new()),
+    /// Lint: ALL. Globs of the files which aren't linted, relative to the package root, like `src/generated/**`
+    (exclude, "exclude": Vec<String>, Vec::new()),
+    /// Lint: ALL. Whether the code generated by build scripts in `OUT_DIR` is linted, otherwise only the correctness lints are emitted there
+    (lint_generated_code, "lint_generated_code": bool, false),
+    /// Lint: ALL. Whether the tests, the unit tests compiled with `--test` and the integration tests of `tests/`, are linted
+    (lint_tests, "lint_tests": bool, true),
+    /// Lint: ALL. Whether the examples of `examples/` are linted
+    (lint_examples, "lint_examples": bool, true),
+    /// Lint: ALL. Whether the benches of `benches/` are linted
+    (lint_benches, "lint_benches": bool, true),
+    /// Lint: ALL. The paths of dynamic libraries registering additional lints, relative to the package root, see `utils::plugins`
+    (plugins, "plugins": Vec<String>, Vec::new()),
+    /// Lint: ALLOW_ATTRIBUTES_WITHOUT_REASON. Whether `#[allow(clippy::...)]` attributes must give a `reason = "..."` or be accompanied by a justification comment
+    (require_allow_reason, "require_allow_reason": bool, false),
+    /// Lint: COGNITIVE_COMPLEXITY, TOO_MANY_ARGUMENTS, TOO_MANY_LINES. Thresholds and lint levels used instead of the other ones for test code, see `TestOverrides`
+    (test_overrides, "test_overrides": crate::utils::conf::TestOverrides, crate::utils::conf::TestOverrides::default()),
+}
+
+/// The `[test-overrides]` table of the configuration, replacing some values for test code.
+///
+/// The thresholds apply to `#[test]` functions, to `#[cfg(test)]` items and to integration tests,
+/// see `utils::is_in_test_context`. The levels apply to the crates compiled with `--test`, which
+/// are checked in addition to the regular build by `cargo clippy --all-targets`.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TestOverrides {
+    /// Replaces `cognitive-complexity-threshold`
+    pub cognitive_complexity_threshold: Option<u64>,
+    /// Replaces `too-many-arguments-threshold`
+    pub too_many_arguments_threshold: Option<u64>,
+    /// Replaces `too-many-lines-threshold`
+    pub too_many_lines_threshold: Option<u64>,
+    /// The thresholds replaced for test code, by lint name, like `too_many_lines = 200`, which
+    /// set the three options above
+    #[serde(default)]
+    pub thresholds: BTreeMap<String, u64>,
+    /// Applied after the `[levels]` table
+    #[serde(default)]
+    pub levels: BTreeMap<String, LintLevel>,
+}
+
+/// The level of a lint or lint group in a `[levels]` table, like `unwrap_used = "deny"`. Lint
+/// groups can also set the levels of some of their lints, like
+/// `pedantic = { level = "warn", allow = ["module_name_repetitions"] }`.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum LintLevel {
+    /// The level of the lint or lint group
+    Level(String),
+    /// The level of the lint group, and the levels of some of its lints
+    Group {
+        /// The level of the lint group, if it changes
+        level: Option<String>,
+        /// The lints of the group which are allowed
+        #[serde(default)]
+        allow: Vec<String>,
+        /// The lints of the group which are warned about
+        #[serde(default)]
+        warn: Vec<String>,
+        /// The lints of the group which are denied
+        #[serde(default)]
+        deny: Vec<String>,
+        /// The lints of the group which are forbidden
+        #[serde(default)]
+        forbid: Vec<String>,
+    },
+}
+
+impl LintLevel {
+    /// The level of the lint or lint group itself.
+    #[must_use]
+    pub fn level(&self) -> Option<&str> {
+        match self {
+            Self::Level(level) => Some(level),
+            Self::Group { level, .. } => level.as_deref(),
+        }
+    }
+}
+
+/// The lint groups of Clippy.
+const LINT_GROUPS: &[&str] = &[
+    "all",
+    "cargo",
+    "complexity",
+    "correctness",
+    "internal",
+    "nursery",
+    "pedantic",
+    "perf",
+    "restriction",
+    "style",
+];
+
+/// Returns the lint levels of a `[levels]` table as `(lint, level)` pairs, in the order they are
+/// passed to the compiler: the lint groups come first, then the single lints, and last the lints
+/// listed by the groups, so that the most specific levels win.
+#[must_use]
+pub fn lint_levels(levels: &BTreeMap<String, LintLevel>) -> Vec<(String, &str)> {
+    let (groups, lints): (Vec<_>, Vec<_>) = levels.iter().partition(|(name, level)| {
+        matches!(level, LintLevel::Group { .. }) || LINT_GROUPS.contains(&&*name.replace('-', "_"))
+    });
+
+    let mut lint_levels: Vec<_> = groups
+        .iter()
+        .chain(&lints)
+        .filter_map(|(name, level)| Some((level_lint_name(name), level.level()?)))
+        .collect();
+    for (_, level) in groups {
+        if let LintLevel::Group {
+            allow,
+            warn,
+            deny,
+            forbid,
+            ..
+        } = level
+        {
+            for (names, level) in &[(allow, "allow"), (warn, "warn"), (deny, "deny"), (forbid, "forbid")] {
+                lint_levels.extend(names.iter().map(|name| (level_lint_name(name), *level)));
+            }
+        }
+    }
+    lint_levels
 }
 
 impl Default for Conf {
@@ -182,8 +391,108 @@ impl Default for Conf {
     }
 }
 
-/// Search for the configuration file.
-pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
+/// The description of a configuration option, generated from its definition.
+#[derive(Debug, serde::Serialize)]
+pub struct OptionSchema {
+    /// The key of the option
+    name: String,
+    /// The type of the option, as written in its definition
+    #[serde(rename = "type")]
+    ty: String,
+    /// The default value of the option
+    default: serde_json::Value,
+    /// The lints using the option, in snake case
+    lints: Vec<String>,
+    /// What the option does
+    description: String,
+}
+
+impl OptionSchema {
+    /// Builds the description of an option from its definition, the documentation of the options
+    /// looking like ` Lint: LINT_A, LINT_B. Description`.
+    fn new(name: &str, ty: &str, default: serde_json::Result<serde_json::Value>, doc: &str) -> Self {
+        let doc = doc.trim();
+        let lints_and_description = doc
+            .strip_prefix("Lint: ")
+            .and_then(|doc| doc.find(". ").map(|end| (&doc[..end], &doc[end + 2..])));
+        let (lints, description) = match lints_and_description {
+            Some((lints, description)) => (
+                lints.split(", ").map(str::to_ascii_lowercase).collect(),
+                description.trim_end_matches('.'),
+            ),
+            None => (Vec::new(), doc),
+        };
+        Self {
+            name: name.replace('_', "-"),
+            // only keep the last segment of the paths, e.g. `BTreeMap` of `std::collections::BTreeMap`
+            ty: Regex::new(r"\b\w+::")
+                .expect("the regex is valid")
+                .replace_all(&ty.replace(' ', ""), "")
+                .into_owned(),
+            default: default.unwrap_or(serde_json::Value::Null),
+            lints,
+            description: description.to_string(),
+        }
+    }
+}
+
+/// Returns the description of all the configuration options as JSON, to validate configuration
+/// files with other tools.
+#[must_use]
+pub fn schema() -> String {
+    serde_json::to_string_pretty(&helpers::options()).expect("the options can be serialized")
+}
+
+/// Describes the configuration options used by a lint, given in snake case, for
+/// `cargo clippy --explain <lint>`. The numeric options are given as their key in the
+/// `[thresholds]` table.
+#[must_use]
+pub fn lint_options(lint: &str) -> Vec<String> {
+    let thresholds = threshold_options();
+    helpers::options()
+        .into_iter()
+        .filter(|option| option.lints.iter().any(|name| name == lint))
+        .map(|option| {
+            let key = if thresholds.iter().any(|threshold| threshold.name == option.name) {
+                format!("thresholds.{}", lint)
+            } else {
+                option.name
+            };
+            if option.default.is_object() {
+                format!("`{}`: {}", key, option.description)
+            } else {
+                format!("`{}`: {} (default: `{}`)", key, option.description, option.default)
+            }
+        })
+        .collect()
+}
+
+/// Checks whether the lint, given in snake case, has a numeric option set in the `[thresholds]`
+/// table.
+#[must_use]
+pub fn is_threshold_lint(lint: &str) -> bool {
+    threshold_options()
+        .iter()
+        .any(|option| option.lints.iter().any(|name| name == lint))
+}
+
+/// Turns the name of a lint or lint group of the `[levels]` table into the name used on the
+/// command line, e.g. `unwrap-used` into `clippy::unwrap_used`.
+#[must_use]
+fn level_lint_name(name: &str) -> String {
+    let name = name.replace('-', "_");
+    if name.contains("::") {
+        name
+    } else {
+        format!("clippy::{}", name)
+    }
+}
+
+/// Search for the configuration files.
+///
+/// The nearest configuration file comes first. Inside of a workspace, it is followed by the
+/// configuration files of the parent directories up to the workspace root, which it overrides.
+pub fn lookup_conf_files() -> io::Result<Vec<PathBuf>> {
     /// Possible filename to search for.
     const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
 
@@ -192,13 +501,17 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
     let mut current = env::var_os("CLIPPY_CONF_DIR")
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
         .map_or_else(|| PathBuf::from("."), PathBuf::from);
+    let mut found = Vec::new();
     loop {
         for config_file_name in &CONFIG_FILE_NAMES {
             let config_file = current.join(config_file_name);
             match fs::metadata(&config_file) {
-                // Only return if it's a file to handle the unlikely situation of a directory named
+                // Only use it if it's a file to handle the unlikely situation of a directory named
                 // `clippy.toml`.
-                Ok(ref md) if !md.is_dir() => return Ok(Some(config_file)),
+                Ok(ref md) if !md.is_dir() => {
+                    found.push(config_file);
+                    break;
+                },
                 // Return the error if it's something other than `NotFound`; otherwise we didn't
                 // find the project file yet, and continue searching.
                 Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
@@ -206,13 +519,90 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
             }
         }
 
-        // If the current directory has no parent, we're done searching.
+        // The configuration files are merged up to the workspace root.
+        if is_workspace_root(&current) {
+            return Ok(found);
+        }
+
+        // If the current directory has no parent, we're done searching. Outside of a workspace,
+        // only the nearest configuration file is used.
         if !current.pop() {
-            return Ok(None);
+            found.truncate(1);
+            return Ok(found);
+        }
+    }
+}
+
+/// Adds the files extended with `extends = "..."` to the list of configuration files, each file
+/// being followed by the chain of files it extends, which it overrides.
+///
+/// Also returns an error for each cyclic chain, with the file whose `extends` closes the cycle.
+pub fn with_extended_files(paths: &[PathBuf]) -> (Vec<PathBuf>, Vec<(PathBuf, Error)>) {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let mut chain = Vec::new();
+        let mut canonical_chain = Vec::new();
+        let mut current = path.clone();
+        loop {
+            let canonical = fs::canonicalize(&current).unwrap_or_else(|_| current.clone());
+            if canonical_chain.contains(&canonical) {
+                let extending = chain.last().cloned().unwrap_or_else(|| current.clone());
+                chain.push(current);
+                errors.push((extending, Error::CyclicExtends(chain)));
+                break;
+            }
+            canonical_chain.push(canonical);
+            chain.push(current.clone());
+            files.push(current.clone());
+            match extended_file(&current) {
+                Some(extended) => current = extended,
+                None => break,
+            }
         }
     }
+    (files, errors)
 }
 
+/// Returns the path of the file extended by a configuration file, relative to the directory of
+/// the configuration file. The errors of the file are reported when reading it.
+fn extended_file(path: &Path) -> Option<PathBuf> {
+    let content = fs::read_to_string(path).ok()?;
+    let table = toml::from_str::<toml::map::Map<String, toml::Value>>(&content).ok()?;
+    let extended = table.get("extends")?.as_str()?;
+    Some(path.parent().unwrap_or_else(|| Path::new("")).join(extended))
+}
+
+/// Checks whether the directory contains the manifest of a workspace.
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| toml::from_str::<toml::Value>(&manifest).ok())
+        .map_or(false, |manifest| manifest.get("workspace").is_some())
+}
+
+/// Where configuration values are read from.
+#[derive(Clone, Debug)]
+pub enum Source {
+    /// A configuration file
+    File(PathBuf),
+    /// A `CLIPPY_CONF_<KEY>` environment variable
+    Env(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file `{}`", path.display()),
+            Self::Env(var) => write!(f, "environment variable `{}`", var),
+        }
+    }
+}
+
+/// The prefix of the environment variables overriding configuration values, like
+/// `CLIPPY_CONF_TOO_MANY_LINES_THRESHOLD=100`.
+const ENV_PREFIX: &str = "CLIPPY_CONF_";
+
 /// Produces a `Conf` filled with the default values and forwards the errors
 ///
 /// Used internally for convenience
@@ -220,6 +610,155 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (Conf::default(), errors)
 }
 
+/// Removes the keys which aren't configuration options from the table, returning an error for
+/// each of them.
+fn remove_unknown_keys(table: &mut toml::map::Map<String, toml::Value>) -> Vec<Error> {
+    let keys: Vec<_> = KEYS.iter().map(|(key, _)| key.replace('_', "-")).collect();
+    let unknown: Vec<_> = table.keys().filter(|key| !keys.contains(key)).cloned().collect();
+    unknown
+        .into_iter()
+        .map(|key| {
+            table.remove(&key);
+            // the same maximum distance as rustc when suggesting names
+            let max_dist = std::cmp::max(key.len(), 3) / 3;
+            let suggestion = keys
+                .iter()
+                .map(|name| (lev_distance(&key, name), name))
+                .filter(|&(dist, _)| dist <= max_dist)
+                .min_by_key(|&(dist, _)| dist)
+                .map(|(_, name)| name.clone());
+            Error::UnknownKey(key, suggestion)
+        })
+        .collect()
+}
+
+/// Returns the numeric options used by lints, which are set in the `[thresholds]` table.
+fn threshold_options() -> Vec<OptionSchema> {
+    helpers::options()
+        .into_iter()
+        .filter(|option| matches!(&*option.ty, "u64" | "Option<u64>") && !option.lints.is_empty())
+        .collect()
+}
+
+/// The options of the `[test-overrides]` table set by its `thresholds` table, with their lints.
+const TEST_THRESHOLDS: [(&str, &str); 3] = [
+    ("cognitive-complexity-threshold", "cognitive_complexity"),
+    ("too-many-arguments-threshold", "too_many_arguments"),
+    ("too-many-lines-threshold", "too_many_lines"),
+];
+
+/// Sets the numeric options of the lints from the `[thresholds]` table, which overrides the
+/// deprecated options set in the same table, and the thresholds of the `[test-overrides]` table
+/// from its `thresholds` table. Returns an error for each deprecated option and each unknown lint.
+fn expand_thresholds(table: &mut toml::map::Map<String, toml::Value>) -> Vec<Error> {
+    let options = threshold_options();
+    let mut errors: Vec<_> = options
+        .iter()
+        .filter(|option| table.contains_key(&option.name))
+        .map(|option| Error::DeprecatedThreshold(option.name.clone(), option.lints[0].clone()))
+        .collect();
+
+    let lints: Vec<_> = options
+        .iter()
+        .flat_map(|option| option.lints.iter().map(String::as_str))
+        .collect();
+    let values = take_thresholds(table, "thresholds", &lints, &mut errors);
+    for (lint, value) in values {
+        for option in options.iter().filter(|option| option.lints.contains(&lint)) {
+            table.insert(option.name.clone(), value.clone());
+        }
+    }
+
+    if let Some(toml::Value::Table(test_overrides)) = table.get_mut("test-overrides") {
+        let lints: Vec<_> = TEST_THRESHOLDS.iter().map(|&(_, lint)| lint).collect();
+        let values = take_thresholds(test_overrides, "test-overrides.thresholds", &lints, &mut errors);
+        for (lint, value) in values {
+            if let Some(&(name, _)) = TEST_THRESHOLDS.iter().find(|&&(_, name)| name == lint) {
+                test_overrides.insert(name.to_string(), value);
+            }
+        }
+    }
+    errors
+}
+
+/// Returns the values of the `thresholds` table of the table, by lint name in snake case, and
+/// removes its unknown lints, adding an error for each of them. `key` is the full key of the
+/// `thresholds` table.
+fn take_thresholds(
+    table: &mut toml::map::Map<String, toml::Value>,
+    key: &str,
+    lints: &[&str],
+    errors: &mut Vec<Error>,
+) -> Vec<(String, toml::Value)> {
+    let thresholds = match table.get_mut("thresholds") {
+        Some(toml::Value::Table(thresholds)) => thresholds,
+        _ => return Vec::new(),
+    };
+    let unknown: Vec<_> = thresholds
+        .keys()
+        .filter(|lint| !lints.contains(&&*lint.replace('-', "_")))
+        .cloned()
+        .collect();
+    for lint in unknown {
+        thresholds.remove(&lint);
+        // the same maximum distance as rustc when suggesting names
+        let max_dist = std::cmp::max(lint.len(), 3) / 3;
+        let suggestion = lints
+            .iter()
+            .map(|name| (lev_distance(&lint, name), name))
+            .filter(|&(dist, _)| dist <= max_dist)
+            .min_by_key(|&(dist, _)| dist)
+            .map(|(_, name)| format!("{}.{}", key, name));
+        errors.push(Error::UnknownKey(format!("{}.{}", key, lint), suggestion));
+    }
+
+    thresholds
+        .iter()
+        .map(|(lint, value)| (lint.replace('-', "_"), value.clone()))
+        .collect()
+}
+
+/// Reads the value of a `CLIPPY_CONF_<KEY>` environment variable into a table, which is empty if
+/// the value can't be used.
+///
+/// The values are read as TOML, except for the options taking strings, which take the value as is,
+/// e.g. `CLIPPY_CONF_MSRV=1.40`.
+fn read_env_value(key: String, value: &str) -> (toml::map::Map<String, toml::Value>, Vec<Error>) {
+    let mut table = toml::map::Map::new();
+    let is_string = KEYS
+        .iter()
+        .any(|&(name, ty)| name.replace('_', "-") == key && matches!(ty, "String" | "Option<String>"));
+    let value = if is_string {
+        toml::Value::String(value.to_string())
+    } else {
+        let parsed = toml::from_str::<toml::map::Map<String, toml::Value>>(&format!("value = {}", value))
+            .ok()
+            .and_then(|mut parsed| parsed.remove("value"));
+        match parsed {
+            Some(parsed) => parsed,
+            None => {
+                return (
+                    table,
+                    vec![Error::Toml(format!("invalid value `{}` for `{}`", value, key))],
+                )
+            },
+        }
+    };
+    table.insert(key, value);
+
+    let mut errors = remove_unknown_keys(&mut table);
+    errors.extend(expand_thresholds(&mut table));
+    // like for the files, reading the value on its own reports its errors
+    assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
+    let _: Result<Conf, _> = toml::Value::Table(table.clone()).try_into();
+    let type_errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    if !type_errors.is_empty() {
+        table.clear();
+        errors.extend(type_errors);
+    }
+    (table, errors)
+}
+
 /// Read the `toml` configuration file.
 ///
 /// In case of error, the function tries to continue as much as possible.
@@ -230,7 +769,17 @@ pub fn read(path: &Path) -> (Conf, Vec<Error>) {
     };
 
     assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
-    match toml::from_str(&content) {
+    let toml = toml::from_str(&content).and_then(|mut table| {
+        // unknown keys are reported but do not prevent reading the other ones
+        let mut unknown_keys = remove_unknown_keys(&mut table);
+        unknown_keys.extend(expand_thresholds(&mut table));
+        ERRORS
+            .lock()
+            .expect("no threading -> mutex always safe")
+            .extend(unknown_keys);
+        toml::Value::Table(table).try_into()
+    });
+    match toml {
         Ok(toml) => {
             let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
 
@@ -253,3 +802,81 @@ pub fn read(path: &Path) -> (Conf, Vec<Error>) {
         },
     }
 }
+
+/// The tables whose entries are separate options, which are merged one by one, see `merge_value`.
+const MERGED_TABLES: [&str; 3] = ["levels", "test-overrides", "thresholds"];
+
+/// Sets a value of the merged configuration. The entries of the `[levels]`, `[test-overrides]` and
+/// `[thresholds]` tables, and of the tables nested in `[test-overrides]`, are merged one by one,
+/// as they are separate options.
+fn merge_value(merged: &mut toml::map::Map<String, toml::Value>, key: String, value: toml::Value) {
+    match (merged.get_mut(&key), value) {
+        (Some(toml::Value::Table(table)), toml::Value::Table(value)) if MERGED_TABLES.contains(&key.as_str()) => {
+            for (key, value) in value {
+                merge_value(table, key, value);
+            }
+        },
+        (_, value) => {
+            merged.insert(key, value);
+        },
+    }
+}
+
+/// Read and merge the `toml` configuration files, the values of the files given first overriding
+/// the ones of the files given later. The `CLIPPY_CONF_<KEY>` environment variables override the
+/// values of all the files.
+///
+/// Returns the configuration, the errors of each source, and the source each configured value is
+/// read from.
+pub fn read_files(paths: &[PathBuf]) -> (Conf, Vec<(Source, Error)>, BTreeMap<String, Source>) {
+    let mut errors = Vec::new();
+    let mut merged = toml::map::Map::new();
+    let mut sources = BTreeMap::new();
+
+    for path in paths.iter().rev() {
+        // reading each file on its own reports the errors of the file
+        let (_, file_errors) = read(path);
+        errors.extend(file_errors.into_iter().map(|error| (Source::File(path.clone()), error)));
+
+        let table = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Value>(&content).ok());
+        if let Some(toml::Value::Table(mut table)) = table {
+            remove_unknown_keys(&mut table);
+            expand_thresholds(&mut table);
+            for (key, value) in table {
+                sources.insert(key.clone(), Source::File(path.clone()));
+                merge_value(&mut merged, key, value);
+            }
+        }
+    }
+
+    let vars = env::vars_os().filter_map(|(var, value)| Some((var.into_string().ok()?, value.into_string().ok()?)));
+    for (var, value) in vars {
+        let key = match var.strip_prefix(ENV_PREFIX) {
+            // `CLIPPY_CONF_DIR` is the directory of the configuration file
+            Some(key) if key != "DIR" => key.to_ascii_lowercase().replace('_', "-"),
+            _ => continue,
+        };
+        let (table, env_errors) = read_env_value(key, &value);
+        errors.extend(env_errors.into_iter().map(|error| (Source::Env(var.clone()), error)));
+        for (key, value) in table {
+            sources.insert(key.clone(), Source::Env(var.clone()));
+            merge_value(&mut merged, key, value);
+        }
+    }
+
+    let conf: Conf = toml::Value::Table(merged).try_into().unwrap_or_default();
+    // the errors have already been collected from the single files
+    ERRORS.lock().expect("no threading -> mutex always safe").clear();
+
+    if let Some(source) = sources.get("exclude") {
+        for glob in &conf.exclude {
+            if let Err(reason) = file_filter::glob_to_regex(glob) {
+                errors.push((source.clone(), Error::InvalidGlob(glob.clone(), reason)));
+            }
+        }
+    }
+
+    (conf, errors, sources)
+}
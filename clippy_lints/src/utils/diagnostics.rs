@@ -1,10 +1,172 @@
 //! Clippy wrappers around rustc's diagnostic functions.
 
+use crate::utils::lint_docs;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::sync::Lrc;
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
-use rustc_span::source_map::{MultiSpan, Span};
+use rustc_lexer::{tokenize, TokenKind};
+use rustc_lint::{EarlyContext, LateContext, Level, Lint, LintContext};
+use rustc_middle::lint::LintLevelSource;
+use rustc_session::Session;
+use rustc_span::source_map::{MultiSpan, SourceMap, Span};
+use rustc_span::BytePos;
+use std::cell::RefCell;
 use std::env;
+use std::iter;
+use std::lazy::SyncLazy;
+
+/// The lints allowed by the suppression comments of a file, by line index.
+type Suppressions = FxHashMap<usize, Vec<String>>;
+
+/// The state of the session running on the thread, with its source map, which is kept to tell the
+/// sessions apart.
+type SessionState<T> = Option<(Lrc<SourceMap>, T)>;
+
+thread_local! {
+    /// The suppressions of the files scanned so far, the files being identified by their start in
+    /// the source map.
+    // `const` initializers of `thread_local!` aren't stable with the toolchain of Clippy
+    #[allow(clippy::thread_local_initializer_can_be_made_const)]
+    static SUPPRESSIONS: RefCell<SessionState<FxHashMap<BytePos, Suppressions>>> = RefCell::new(None);
+    /// The start and end of the allow attributes which allowed a lint emitted so far, see
+    /// `record_allow`.
+    #[allow(clippy::thread_local_initializer_can_be_made_const)]
+    static USED_ALLOWS: RefCell<SessionState<FxHashSet<(BytePos, BytePos)>>> = RefCell::new(None);
+}
+
+/// Returns the state of the session, the state of a previous session on the thread being
+/// forgotten.
+fn session_state<'a, T: Default>(state: &'a mut SessionState<T>, sess: &Session) -> &'a mut T {
+    let source_map = sess.parse_sess.clone_source_map();
+    if !matches!(state, Some((previous, _)) if Lrc::ptr_eq(previous, &source_map)) {
+        *state = None;
+    }
+    &mut state.get_or_insert_with(|| (source_map, T::default())).1
+}
+
+/// Returns the lints allowed by the suppression comments of the source, by line index, without
+/// their `clippy::` prefix. `// clippy: allow(a, b)` allows the lints on its own line and
+/// `// clippy: allow-next-line(a, b)` on the next line.
+fn scan_suppressions(src: &str) -> Suppressions {
+    let mut suppressions = Suppressions::default();
+    let mut index = 0;
+    let mut pos = 0;
+    // only the comments are scanned, not the string literals looking like them
+    for token in tokenize(src) {
+        let text = &src[pos..pos + token.len];
+        pos += token.len;
+        if token.kind != (TokenKind::LineComment { doc_style: None }) {
+            index += text.matches('\n').count();
+            continue;
+        }
+        let comment = text[2..].trim_start();
+        let comment = match comment.strip_prefix("clippy:") {
+            Some(comment) => comment.trim_start(),
+            None => continue,
+        };
+        let (line_index, lints) = if let Some(lints) = comment.strip_prefix("allow-next-line(") {
+            (index + 1, lints)
+        } else if let Some(lints) = comment.strip_prefix("allow(") {
+            (index, lints)
+        } else {
+            continue;
+        };
+        let lints = match lints.find(')') {
+            Some(end) => &lints[..end],
+            None => continue,
+        };
+        let lints = lints
+            .split(',')
+            .map(|lint| lint.trim().replace('-', "_").to_lowercase())
+            .map(|lint| lint.strip_prefix("clippy::").map_or(lint.clone(), ToString::to_string))
+            .filter(|lint| !lint.is_empty());
+        suppressions.entry(line_index).or_insert_with(Vec::new).extend(lints);
+    }
+    suppressions
+}
+
+/// Checks whether the lint is allowed at one of the spans by a suppression comment like
+/// `// clippy: allow(needless_return)`, on the line of the span or on the line of the macro call
+/// it comes from, where attributes can't always be written.
+fn is_suppressed<'a>(sess: &Session, lint: &Lint, spans: impl IntoIterator<Item = &'a MultiSpan>) -> bool {
+    let name = lint.name_lower();
+    let name = name.strip_prefix("clippy::").unwrap_or(&name);
+    let mut all_spans = Vec::new();
+    for span in spans
+        .into_iter()
+        .flat_map(MultiSpan::span_labels)
+        .map(|label| label.span)
+    {
+        all_spans.push(span);
+        if span.from_expansion() {
+            all_spans.push(span.source_callsite());
+        }
+    }
+    SUPPRESSIONS.with(|suppressions| {
+        let mut suppressions = suppressions.borrow_mut();
+        let files = session_state(&mut suppressions, sess);
+        all_spans.into_iter().filter(|span| !span.is_dummy()).any(|span| {
+            let loc = sess.source_map().lookup_char_pos(span.lo());
+            let file = &loc.file;
+            let lines = files.entry(file.start_pos).or_insert_with(|| {
+                file.src
+                    .as_ref()
+                    .map_or_else(Suppressions::default, |src| scan_suppressions(src))
+            });
+            // the lines of the source map start at one
+            lines
+                .get(&(loc.line - 1))
+                .map_or(false, |lints| lints.iter().any(|lint| lint == name))
+        })
+    })
+}
+
+/// The contexts in which the lints are emitted, which know the levels of the lints at the nodes
+/// being linted.
+pub trait LintLevels {
+    fn lint_level(&self, lint: &'static Lint) -> (Level, LintLevelSource);
+}
+
+impl LintLevels for EarlyContext<'_> {
+    fn lint_level(&self, lint: &'static Lint) -> (Level, LintLevelSource) {
+        self.builder.lint_level(lint)
+    }
+}
+
+impl LintLevels for LateContext<'_> {
+    fn lint_level(&self, lint: &'static Lint) -> (Level, LintLevelSource) {
+        self.tcx.lint_level_at_node(lint, self.last_node_with_lint_attrs)
+    }
+}
+
+/// Records the allow attribute which allows the lint being emitted if any, the span of the
+/// attribute being the one of the lint or group in its list.
+pub(crate) fn record_allow(sess: &Session, (level, source): (Level, LintLevelSource)) {
+    if let (Level::Allow, LintLevelSource::Node(_, span, _)) = (level, source) {
+        USED_ALLOWS.with(|allows| session_state(&mut allows.borrow_mut(), sess).insert((span.lo(), span.hi())));
+    }
+}
+
+/// Checks whether the allow attribute of the span allowed a lint emitted so far, see
+/// `unused_lint_allows`.
+pub fn is_allow_used(sess: &Session, span: Span) -> bool {
+    USED_ALLOWS.with(|allows| session_state(&mut allows.borrow_mut(), sess).contains(&(span.lo(), span.hi())))
+}
+
+/// Emits the diagnostic of a lint, unless a suppression comment allows the lint at one of its
+/// spans. The spans known before building the diagnostic are checked before too, so that the
+/// one-time notes of the compiler aren't lost to the diagnostics of the suppressed lints.
+fn emit(sess: &Session, lint: &'static Lint, mut diag: DiagnosticBuilder<'_>) {
+    let spans = iter::once(&diag.span).chain(diag.children.iter().map(|child| &child.span));
+    if is_suppressed(sess, lint, spans) {
+        diag.cancel();
+        return;
+    }
+    check_applicabilities(&diag, lint);
+    docs_link(&mut diag, lint);
+    diag.emit();
+}
 
 fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
@@ -19,6 +181,32 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
     }
 }
 
+/// Whether Clippy is run by its tests, which check the applicabilities of the suggestions.
+static IS_TESTED: SyncLazy<bool> =
+    SyncLazy::new(|| env::var("__CLIPPY_INTERNAL_TESTS").map_or(false, |val| val == "true"));
+
+/// Checks that the applicabilities of the suggestions made with a lint are declared by the lint,
+/// see `declare_clippy_lint!`, when Clippy is run by its tests.
+fn check_applicabilities(diag: &DiagnosticBuilder<'_>, lint: &'static Lint) {
+    if !*IS_TESTED {
+        return;
+    }
+    let name = lint.name_lower();
+    let declared = match name.strip_prefix("clippy::") {
+        Some(name) => lint_docs::docs(name).map_or(&[][..], |(_, applicability)| applicability),
+        None => return,
+    };
+    for suggestion in &diag.suggestions {
+        let applicability = format!("{:?}", suggestion.applicability);
+        assert!(
+            declared.contains(&applicability.as_str()),
+            "the `{}` suggestions of `{}` aren't declared in its `applicability`",
+            applicability,
+            name
+        );
+    }
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -36,11 +224,14 @@ fn docs_link(diag: &mut DiagnosticBuilder<'_>, lint: &'static Lint) {
 /// 17 |     std::mem::forget(seven);
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
-pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+pub fn span_lint<T: LintContext + LintLevels>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    record_allow(cx.sess(), cx.lint_level(lint));
+    let sp = sp.into();
+    if is_suppressed(cx.sess(), lint, iter::once(&sp)) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
-        let mut diag = diag.build(msg);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag.build(msg));
     });
 }
 
@@ -64,7 +255,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
 ///    |
 ///    = help: Consider using `f64::NAN` if you would like a constant representing NaN
 /// ```
-pub fn span_lint_and_help<'a, T: LintContext>(
+pub fn span_lint_and_help<'a, T: LintContext + LintLevels>(
     cx: &'a T,
     lint: &'static Lint,
     span: Span,
@@ -72,6 +263,11 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    record_allow(cx.sess(), cx.lint_level(lint));
+    let help_spans = help_span.map_or_else(MultiSpan::new, MultiSpan::from);
+    if is_suppressed(cx.sess(), lint, &[MultiSpan::from(span), help_spans]) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(help_span) = help_span {
@@ -79,8 +275,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
         } else {
             diag.help(help);
         }
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag);
     });
 }
 
@@ -107,7 +302,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
 /// 10 |     forget(&SomeStruct);
 ///    |            ^^^^^^^^^^^
 /// ```
-pub fn span_lint_and_note<'a, T: LintContext>(
+pub fn span_lint_and_note<'a, T: LintContext + LintLevels>(
     cx: &'a T,
     lint: &'static Lint,
     span: Span,
@@ -115,6 +310,11 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    record_allow(cx.sess(), cx.lint_level(lint));
+    let note_spans = note_span.map_or_else(MultiSpan::new, MultiSpan::from);
+    if is_suppressed(cx.sess(), lint, &[MultiSpan::from(span), note_spans]) {
+        return;
+    }
     cx.struct_span_lint(lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(note_span) = note_span {
@@ -122,8 +322,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
         } else {
             diag.note(note);
         }
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag);
     });
 }
 
@@ -131,23 +330,28 @@ pub fn span_lint_and_note<'a, T: LintContext>(
 ///
 /// If you need to customize your lint output a lot, use this function.
 /// If you change the signature, remember to update the internal lint `CollapsibleCalls`
-pub fn span_lint_and_then<'a, T: LintContext, F>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str, f: F)
+pub fn span_lint_and_then<'a, T: LintContext + LintLevels, F>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str, f: F)
 where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
+    record_allow(cx.sess(), cx.lint_level(lint));
+    if is_suppressed(cx.sess(), lint, &[MultiSpan::from(sp)]) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag);
     });
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    record_allow(cx.sess(), cx.tcx.lint_level_at_node(lint, hir_id));
+    if is_suppressed(cx.sess(), lint, &[MultiSpan::from(sp)]) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
-        let mut diag = diag.build(msg);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag.build(msg));
     });
 }
 
@@ -159,11 +363,14 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
+    record_allow(cx.sess(), cx.tcx.lint_level_at_node(lint, hir_id));
+    if is_suppressed(cx.sess(), lint, &[MultiSpan::from(sp)]) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
-        docs_link(&mut diag, lint);
-        diag.emit();
+        emit(cx.sess(), lint, diag);
     });
 }
 
@@ -189,7 +396,7 @@ pub fn span_lint_hir_and_then(
 
 #[allow(clippy::unknown_clippy_lints)]
 #[cfg_attr(feature = "internal-lints", allow(clippy::collapsible_span_lint_calls))]
-pub fn span_lint_and_sugg<'a, T: LintContext>(
+pub fn span_lint_and_sugg<'a, T: LintContext + LintLevels>(
     cx: &'a T,
     lint: &'static Lint,
     sp: Span,
@@ -0,0 +1,332 @@
+//! Allows the lints of Clippy and of the plugins in the files filtered out by the `exclude` and
+//! `lint-generated-code` options.
+//!
+//! The files are filtered once the crate is expanded, before it is linted, by adding `#[allow]`
+//! attributes to the outermost items and expressions of their code, so that the lints are allowed
+//! by rustc whatever the pass emitting them and the way it emits them.
+
+use regex::Regex;
+use rustc_ast::ast::{self, AssocItem, Attribute, Crate, Expr, ItemKind, MetaItem, MetaItemKind, NestedMetaItem};
+use rustc_ast::attr;
+use rustc_ast::mut_visit::{self, MutVisitor};
+use rustc_ast::ptr::P;
+use rustc_ast_pretty::pprust;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::map_in_place::MapInPlace;
+use rustc_lint::{Level, LintStore};
+use rustc_session::lint::LintId;
+use rustc_session::Session;
+use rustc_span::symbol::{sym, Ident};
+use rustc_span::{BytePos, FileName, Span, DUMMY_SP};
+use std::env;
+use std::lazy::SyncOnceCell;
+use std::path::{Path, PathBuf};
+
+/// The group of the lints registered by the plugins, see `plugins::register_lints`.
+pub const PLUGIN_GROUP: &str = "clippy::plugins";
+
+/// The files which aren't linted, see `filter_files`.
+static FILE_FILTER: SyncOnceCell<FileFilter> = SyncOnceCell::new();
+
+struct FileFilter {
+    /// The files of the `exclude` option
+    excluded: Vec<Regex>,
+    /// Whether the files generated by build scripts are linted
+    lint_generated_code: bool,
+}
+
+/// Sets the files in which the lints of Clippy and of the plugins are allowed by
+/// `allow_filtered_lints`: the files matching the globs of `exclude`, relative to the package
+/// root, and unless `lint_generated_code` is set, the files generated under `OUT_DIR` by build
+/// scripts for the lints not denied by default, i.e. the ones which aren't correctness lints.
+///
+/// The invalid globs are reported with the other errors of the configuration, and left out.
+pub fn filter_files(exclude: &[String], lint_generated_code: bool) {
+    let excluded = exclude.iter().filter_map(|glob| glob_to_regex(glob).ok()).collect();
+    // the configuration is only read once per compilation
+    drop(FILE_FILTER.set(FileFilter {
+        excluded,
+        lint_generated_code,
+    }));
+}
+
+/// Returns the regular expression matching the paths matched by the glob, or why the glob is
+/// invalid.
+///
+/// `*` matches any part of a file or directory name, `**` any number of directories and `?` any
+/// character, the other characters matching themselves.
+pub fn glob_to_regex(glob: &str) -> Result<Regex, String> {
+    if glob.is_empty() {
+        return Err("it is empty".to_string());
+    }
+    if Path::new(glob).is_absolute() {
+        return Err("it must be relative to the package root".to_string());
+    }
+    if let Some(c) = glob.chars().find(|c| matches!(c, '[' | ']' | '{' | '}')) {
+        return Err(format!("`{}` isn't supported, only `*`, `**` and `?` are", c));
+    }
+
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `**/` also matches no directory at all
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            },
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).map_err(|error| error.to_string())
+}
+
+/// Why a file isn't linted.
+#[derive(Clone, Copy, PartialEq)]
+enum Filtered {
+    /// It matches a glob of `exclude`
+    Excluded,
+    /// It was generated under `OUT_DIR` by a build script
+    Generated,
+}
+
+impl FileFilter {
+    fn filter(&self, name: &FileName) -> Option<Filtered> {
+        let path = match name {
+            FileName::Real(name) => name.local_path().to_path_buf(),
+            _ => return None,
+        };
+        // the paths are relative to the working directory, the globs to the package root
+        let path = env::current_dir().map_or(path.clone(), |dir| dir.join(&path));
+
+        let root = env::var_os("CARGO_MANIFEST_DIR");
+        let relative = root
+            .and_then(|root| path.strip_prefix(Path::new(&root)).ok().map(Path::to_path_buf))
+            .unwrap_or_else(|| path.clone());
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if self.excluded.iter().any(|pattern| pattern.is_match(&relative)) {
+            return Some(Filtered::Excluded);
+        }
+
+        // code included from `OUT_DIR` by `include!(concat!(env!("OUT_DIR"), ...))` can't be fixed
+        let out_dir = env::var_os("OUT_DIR")
+            .map(|out_dir| env::current_dir().map_or(PathBuf::from(&out_dir), |dir| dir.join(&out_dir)));
+        let is_generated = out_dir.map_or(false, |out_dir| path.starts_with(out_dir));
+        if is_generated && !self.lint_generated_code {
+            return Some(Filtered::Generated);
+        }
+        None
+    }
+}
+
+/// Allows the lints of Clippy and of the plugins in the files filtered out by `filter_files`,
+/// adding `#[allow]` attributes to the outermost items and expressions of their code.
+///
+/// The lints forbidden on the command line or by the attributes of the crate can't be allowed, so
+/// they are still emitted.
+pub fn allow_filtered_lints(sess: &Session, krate: &mut Crate, store: &LintStore) {
+    let filter = match FILE_FILTER.get() {
+        Some(filter) if !filter.excluded.is_empty() || !filter.lint_generated_code => filter,
+        _ => return,
+    };
+
+    let forbidden = forbidden_lints(sess, krate, store);
+    let plugin_lints: FxHashSet<_> = store
+        .find_lints(PLUGIN_GROUP)
+        .map(|lints| lints.into_iter().collect())
+        .unwrap_or_default();
+    let is_filtered = |lint: LintId| {
+        (lint.lint.name.starts_with("clippy::") || plugin_lints.contains(&lint)) && !forbidden.contains(&lint)
+    };
+    let mut visitor = AllowFilteredLints {
+        sess,
+        filter,
+        excluded: allowed_names(store, is_filtered),
+        generated: allowed_names(store, |lint| {
+            is_filtered(lint) && lint.lint.default_level != Level::Deny
+        }),
+        files: FxHashMap::default(),
+        current: None,
+    };
+    visitor.visit_crate(krate);
+}
+
+/// Returns the lints forbidden on the command line or by the attributes of the crate, which can't
+/// be allowed by the attributes of its items.
+fn forbidden_lints(sess: &Session, krate: &Crate, store: &LintStore) -> FxHashSet<LintId> {
+    let command_line = sess
+        .opts
+        .lint_opts
+        .iter()
+        .filter(|(_, level)| *level == Level::Forbid)
+        .map(|(name, _)| name.replace('-', "_"));
+    let attributes = krate
+        .attrs
+        .iter()
+        .filter_map(|attr| {
+            if attr.has_name(sym::forbid) {
+                attr.meta_item_list()
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|item| Some(pprust::path_to_string(&item.meta_item()?.path)));
+    command_line
+        .chain(attributes)
+        .filter_map(|name| store.find_lints(&name).ok())
+        .flatten()
+        .collect()
+}
+
+/// Returns the names of the lints and groups allowing the lints matching the predicate, the groups
+/// of Clippy whose lints all match being used instead of their lints.
+fn allowed_names(store: &LintStore, is_allowed: impl Fn(LintId) -> bool) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut covered = FxHashSet::default();
+    let mut groups = store.get_lint_groups();
+    // the groups including the others come first, like `clippy::all`
+    groups.sort_by_key(|(_, lints, _)| std::cmp::Reverse(lints.len()));
+    for (name, lints, _) in groups {
+        if name.starts_with("clippy::")
+            && !lints.iter().all(|lint| covered.contains(lint))
+            && lints.iter().all(|&lint| is_allowed(lint))
+        {
+            names.push(name.to_string());
+            covered.extend(lints);
+        }
+    }
+    for lint in store.get_lints() {
+        let lint = LintId::of(lint);
+        if !covered.contains(&lint) && is_allowed(lint) {
+            names.push(lint.to_string());
+        }
+    }
+    names
+}
+
+struct AllowFilteredLints<'a> {
+    sess: &'a Session,
+    filter: &'a FileFilter,
+    /// The lints and groups allowed in the excluded files
+    excluded: Vec<String>,
+    /// The lints and groups allowed in the generated files
+    generated: Vec<String>,
+    /// The filters of the files seen so far, by start in the source map
+    files: FxHashMap<BytePos, Option<Filtered>>,
+    /// The filter of the file of the node being visited, whose lints are allowed by an attribute
+    current: Option<Filtered>,
+}
+
+impl AllowFilteredLints<'_> {
+    /// Returns the attribute allowing the lints of the node, if it is the outermost node of the
+    /// code of a filtered file, in which case it is the node being visited until the returned
+    /// filter is restored.
+    fn enter(&mut self, span: Span) -> (Option<Attribute>, Option<Filtered>) {
+        let previous = self.current;
+        if span.is_dummy() {
+            return (None, previous);
+        }
+        let file = self.sess.source_map().lookup_source_file(span.lo());
+        let filter = self.filter;
+        let filtered = *self
+            .files
+            .entry(file.start_pos)
+            .or_insert_with(|| filter.filter(&file.name));
+        match filtered {
+            Some(filtered) if Some(filtered) != previous => {
+                self.current = Some(filtered);
+                let names = match filtered {
+                    Filtered::Excluded => &self.excluded,
+                    Filtered::Generated => &self.generated,
+                };
+                (Some(allow_attribute(names)), previous)
+            },
+            _ => (None, previous),
+        }
+    }
+
+    fn flat_map_assoc_items(&mut self, items: &mut Vec<P<AssocItem>>) {
+        items.flat_map_in_place(|mut item| {
+            let (attr, previous) = self.enter(item.span);
+            item.attrs.extend(attr);
+            let items = mut_visit::noop_flat_map_assoc_item(item, self);
+            self.current = previous;
+            items
+        });
+    }
+}
+
+/// Returns `#[allow(..)]` with the lints and groups.
+fn allow_attribute(names: &[String]) -> Attribute {
+    let items = names
+        .iter()
+        .map(|name| {
+            let segments = name
+                .split("::")
+                .map(|segment| ast::PathSegment::from_ident(Ident::from_str(segment)))
+                .collect();
+            NestedMetaItem::MetaItem(MetaItem {
+                path: ast::Path {
+                    span: DUMMY_SP,
+                    segments,
+                    tokens: None,
+                },
+                kind: MetaItemKind::Word,
+                span: DUMMY_SP,
+            })
+        })
+        .collect();
+    attr::mk_attr_outer(attr::mk_list_item(Ident::with_dummy_span(sym::allow), items))
+}
+
+impl MutVisitor for AllowFilteredLints<'_> {
+    fn visit_mod(&mut self, module: &mut ast::Mod) {
+        module.items.flat_map_in_place(|mut item| {
+            let (attr, previous) = self.enter(item.span);
+            item.attrs.extend(attr);
+            let items = mut_visit::noop_flat_map_item(item, self);
+            self.current = previous;
+            items
+        });
+    }
+
+    fn visit_item_kind(&mut self, kind: &mut ItemKind) {
+        match kind {
+            ItemKind::Impl {
+                generics,
+                of_trait,
+                self_ty,
+                items,
+                ..
+            } => {
+                self.visit_generics(generics);
+                if let Some(trait_ref) = of_trait {
+                    self.visit_trait_ref(trait_ref);
+                }
+                self.visit_ty(self_ty);
+                self.flat_map_assoc_items(items);
+            },
+            ItemKind::Trait(_, _, generics, _, items) => {
+                self.visit_generics(generics);
+                self.flat_map_assoc_items(items);
+            },
+            _ => mut_visit::noop_visit_item_kind(kind, self),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &mut P<Expr>) {
+        let (attr, previous) = self.enter(expr.span);
+        expr.attrs.extend(attr);
+        mut_visit::noop_visit_expr(expr, self);
+        self.current = previous;
+    }
+}
@@ -218,6 +218,7 @@ pub fn vec_macro<'e>(cx: &LateContext<'_>, expr: &'e hir::Expr<'_>) -> Option<Ve
 /// Currently working with:
 /// - `assert!`, `assert_eq!` and `assert_ne!`
 /// - `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!`
+///
 /// For example:
 /// `assert!(expr)` will return Some([expr])
 /// `debug_assert_eq!(a, b)` will return Some([a, b])
@@ -4,8 +4,8 @@ use crate::utils::get_attr;
 use rustc_ast::ast::{Attribute, InlineAsmTemplatePiece};
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::declare_tool_lint;
 use rustc_session::Session;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
     /// **What it does:** Dumps every ast/hir node which has the `#[clippy::dump]`
@@ -387,10 +387,10 @@ fn print_item(cx: &LateContext<'_>, item: &hir::Item<'_>) {
             if let Some(crate_id) = cx.tcx.extern_mod_stmt_cnum(def_id) {
                 let source = cx.tcx.used_crate_source(crate_id);
                 if let Some(ref src) = source.dylib {
-                    println!("extern crate dylib source: {:?}", src.0);
+                    println!("extern crate dylib source: {}", src.0.display());
                 }
                 if let Some(ref src) = source.rlib {
-                    println!("extern crate rlib source: {:?}", src.0);
+                    println!("extern crate rlib source: {}", src.0.display());
                 }
             } else {
                 println!("weird extern crate without a crate id");
@@ -20,7 +20,7 @@ use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_middle::mir::interpret::ConstValue;
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::{Span, Spanned};
 use rustc_span::symbol::{Symbol, SymbolStr};
 use rustc_typeck::hir_ty_to_ty;
@@ -108,7 +108,8 @@ declare_clippy_lint! {
     /// ```
     pub OUTER_EXPN_EXPN_DATA,
     internal,
-    "using `cx.outer_expn().expn_data()` instead of `cx.outer_expn_data()`"
+    "using `cx.outer_expn().expn_data()` instead of `cx.outer_expn_data()`",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -210,7 +211,8 @@ declare_clippy_lint! {
     /// ```
     pub COLLAPSIBLE_SPAN_LINT_CALLS,
     internal,
-    "found collapsible `span_lint_and_then` calls"
+    "found collapsible `span_lint_and_then` calls",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -233,7 +235,8 @@ declare_clippy_lint! {
     /// ```
     pub MATCH_TYPE_ON_DIAGNOSTIC_ITEM,
     internal,
-    "using `utils::match_type()` instead of `utils::is_type_diagnostic_item()`"
+    "using `utils::match_type()` instead of `utils::is_type_diagnostic_item()`",
+    applicability: [MaybeIncorrect]
 }
 
 declare_clippy_lint! {
@@ -272,7 +275,8 @@ declare_clippy_lint! {
     /// ```
     pub INTERNING_DEFINED_SYMBOL,
     internal,
-    "interning a symbol that is pre-interned and defined as a constant"
+    "interning a symbol that is pre-interned and defined as a constant",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -294,7 +298,8 @@ declare_clippy_lint! {
     /// ```
     pub UNNECESSARY_SYMBOL_STR,
     internal,
-    "unnecessary conversion between Symbol and string"
+    "unnecessary conversion between Symbol and string",
+    applicability: [MachineApplicable]
 }
 
 declare_lint_pass!(ClippyLintsInternal => [CLIPPY_LINTS_INTERNAL]);
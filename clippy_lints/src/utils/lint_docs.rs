@@ -0,0 +1,52 @@
+//! The documentation of the lints, for `cargo clippy --explain <lint>` and
+//! `cargo clippy -- --print-lints-json`.
+
+use crate::utils::conf;
+
+/// The name, group, documentation and applicabilities of the suggestions of the lints, extracted
+/// from the sources by the build script.
+static LINT_DOCS: &[(&str, &str, &str, &[&str])] = include!(concat!(env!("OUT_DIR"), "/lint_docs.rs"));
+
+/// Returns the documentation of a lint and the applicabilities of the suggestions made with it,
+/// which is empty if it makes none, given its name without the `clippy::` prefix.
+#[must_use]
+pub fn docs(lint: &str) -> Option<(&'static str, &'static [&'static str])> {
+    LINT_DOCS
+        .iter()
+        .find(|(name, ..)| *name == lint)
+        .map(|(_, _, docs, applicability)| (*docs, *applicability))
+}
+
+/// Returns the documentation of a lint, given like `needless_return`, `needless-return` or
+/// `clippy::needless_return`, with its group and the configuration options it uses.
+#[must_use]
+pub fn explain(lint: &str) -> Option<String> {
+    let lint = lint
+        .strip_prefix("clippy::")
+        .unwrap_or(lint)
+        .replace('-', "_")
+        .to_ascii_lowercase();
+    let (name, group, docs, _) = LINT_DOCS.iter().find(|(name, ..)| *name == lint)?;
+
+    let mut explanation = format!("clippy::{} ({})\n\n", name, group);
+    // the hidden lines of the examples are only needed by the doctests
+    let mut in_code = false;
+    for line in docs.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        } else if in_code && (line == "#" || line.starts_with("# ")) {
+            continue;
+        }
+        explanation.push_str(line);
+        explanation.push('\n');
+    }
+
+    let options = conf::lint_options(name);
+    if !options.is_empty() {
+        explanation.push_str("\n**Configuration:**\n");
+        for option in options {
+            explanation.push_str(&format!("- {}\n", option));
+        }
+    }
+    Some(explanation)
+}
@@ -11,13 +11,18 @@ pub mod conf;
 pub mod constants;
 mod diagnostics;
 pub mod eager_or_lazy;
+pub mod file_filter;
 pub mod higher;
 mod hir_utils;
 pub mod inspector;
 #[cfg(feature = "internal-lints")]
 pub mod internal_lints;
+pub mod lint_docs;
+pub mod msrvs;
 pub mod numeric_literal;
 pub mod paths;
+pub mod plugins;
+pub mod profile;
 pub mod ptr;
 pub mod qualify_min_const_fn;
 pub mod sugg;
@@ -43,7 +48,8 @@ use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_hir::Node;
 use rustc_hir::{
     def, Arm, Block, Body, Constness, Crate, Expr, ExprKind, FnDecl, HirId, ImplItem, ImplItemKind, Item, ItemKind,
-    MatchSource, Param, Pat, PatKind, Path, PathSegment, QPath, TraitItem, TraitItemKind, TraitRef, TyKind, Unsafety,
+    MatchSource, Param, Pat, PatKind, Path, PathSegment, QPath, StmtKind, TraitItem, TraitItemKind, TraitRef, TyKind,
+    Unsafety,
 };
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, Level, Lint, LintContext};
@@ -75,6 +81,13 @@ pub fn parse_msrv(msrv: &str, sess: Option<&Session>, span: Option<Span>) -> Opt
     None
 }
 
+/// Checks whether the minimum supported Rust version allows the suggestion of a lint, i.e. is at
+/// least `lint_msrv`, the version of `msrvs` stabilizing what the suggestion uses. Always true
+/// without a configured version.
+///
+/// The version comes from the `msrv` option of the configuration, and can be overridden for a
+/// crate with `#![clippy::msrv = "1.40"]`, which is handled by the `extract_msrv_attr!` macro of
+/// the lint pass.
 pub fn meets_msrv(msrv: Option<&RustcVersion>, lint_msrv: &RustcVersion) -> bool {
     msrv.map_or(true, |msrv| msrv.meets(*lint_msrv))
 }
@@ -616,7 +629,7 @@ pub fn snippet<'a, T: LintContext>(cx: &T, span: Span, default: &'a str) -> Cow<
 /// - Applicability level `Unspecified` will never be changed.
 /// - If the span is inside a macro, change the applicability level to `MaybeIncorrect`.
 /// - If the default value is used and the applicability level is `MachineApplicable`, change it to
-/// `HasPlaceholders`
+///   `HasPlaceholders`
 pub fn snippet_with_applicability<'a, T: LintContext>(
     cx: &T,
     span: Span,
@@ -1102,6 +1115,56 @@ pub fn remove_blocks<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     expr
 }
 
+/// Checks if an expression represents the identity function
+/// Only examines closures and `std::convert::identity`
+pub fn is_expr_identity_function(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Closure(_, _, body_id, _, _) => is_body_identity_function(cx, cx.tcx.hir().body(body_id)),
+        ExprKind::Path(QPath::Resolved(_, ref path)) => match_path(path, &paths::STD_CONVERT_IDENTITY),
+        _ => false,
+    }
+}
+
+/// Checks if a function's body represents the identity function
+/// Looks for bodies of the form `|x| x`, `|x| return x`, `|x| { return x }` or `|x| {
+/// return x; }`
+fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
+    let params = func.params;
+    let body = remove_blocks(&func.value);
+
+    // if there's less/more than one parameter, then it is not the identity function
+    if params.len() != 1 {
+        return false;
+    }
+
+    match body.kind {
+        ExprKind::Path(QPath::Resolved(None, _)) => match_expr_param(cx, body, params[0].pat),
+        ExprKind::Ret(Some(ref ret_val)) => match_expr_param(cx, ret_val, params[0].pat),
+        ExprKind::Block(ref block, _) => {
+            if_chain! {
+                if block.stmts.len() == 1;
+                if let StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) = block.stmts[0].kind;
+                if let ExprKind::Ret(Some(ref ret_val)) = expr.kind;
+                then {
+                    match_expr_param(cx, ret_val, params[0].pat)
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Returns true iff an expression returns the same thing as a parameter's pattern
+fn match_expr_param(cx: &LateContext<'_>, expr: &Expr<'_>, pat: &Pat<'_>) -> bool {
+    if let PatKind::Binding(_, _, ident, _) = pat.kind {
+        match_var(expr, ident.name) && !(cx.typeck_results().hir_owner == expr.hir_id.owner && is_adjusted(cx, expr))
+    } else {
+        false
+    }
+}
+
 pub fn is_self(slf: &Param<'_>) -> bool {
     if let PatKind::Binding(.., name, _) = slf.pat.kind {
         name.name == kw::SelfLower
@@ -1176,7 +1239,10 @@ pub fn is_try<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
 ///
 /// Useful for skipping long running code when it's unnecessary
 pub fn is_allowed(cx: &LateContext<'_>, lint: &'static Lint, id: HirId) -> bool {
-    cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
+    let (level, source) = cx.tcx.lint_level_at_node(lint, id);
+    // the code is skipped as if the lint was emitted
+    record_allow(cx.sess(), (level, source));
+    level == Level::Allow
 }
 
 pub fn get_arg_name(pat: &Pat<'_>) -> Option<Symbol> {
@@ -1475,6 +1541,40 @@ pub fn is_must_use_func_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     did.map_or(false, |did| must_use_attr(&cx.tcx.get_attrs(did)).is_some())
 }
 
+/// Checks whether the node is test code, which is the case inside of `#[test]` functions, of items
+/// under `#[cfg(test)]` and of integration tests.
+///
+/// Lints use this before applying the `[test-overrides]` table of the configuration.
+pub fn is_in_test_context(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let map = cx.tcx.hir();
+    is_integration_test(cx.sess())
+        || std::iter::once(hir_id)
+            .chain(map.parent_iter(hir_id).map(|(id, _)| id))
+            .any(|id| map.attrs(id).iter().any(is_test_attr))
+}
+
+fn is_test_attr(attr: &Attribute) -> bool {
+    attr.has_name(sym::test)
+        || attr.has_name(sym::rustc_test_marker)
+        || (attr.has_name(sym::cfg)
+            && attr
+                .meta_item_list()
+                .map_or(false, |items| items.iter().any(|item| item.has_name(sym::test))))
+}
+
+/// Checks whether the crate is an integration test, i.e. compiled with `--test` from the `tests`
+/// directory of a package, following the layout of Cargo.
+fn is_integration_test(sess: &Session) -> bool {
+    let is_tests_dir =
+        |dir: Option<&std::path::Path>| dir.and_then(std::path::Path::file_name) == Some("tests".as_ref());
+    sess.opts.test
+        && sess.local_crate_source_file.as_deref().map_or(false, |file| {
+            let dir = file.parent();
+            is_tests_dir(dir)
+                || (file.file_name() == Some("main.rs".as_ref()) && is_tests_dir(dir.and_then(std::path::Path::parent)))
+        })
+}
+
 pub fn is_no_std_crate(krate: &Crate<'_>) -> bool {
     krate.item.attrs.iter().any(|attr| {
         if let ast::AttrKind::Normal(ref attr, _) = attr.kind {
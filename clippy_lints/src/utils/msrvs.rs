@@ -0,0 +1,40 @@
+//! The versions of Rust stabilizing the features which the lints suggest, checked with
+//! `meets_msrv`.
+
+use rustc_semver::RustcVersion;
+
+macro_rules! msrv_aliases {
+    ($($major:literal,$minor:literal,$patch:literal {
+        $($name:ident),* $(,)?
+    })*) => {
+        $($(
+        pub const $name: RustcVersion = RustcVersion::new($major, $minor, $patch);
+        )*)*
+    };
+}
+
+// names may refer to stabilized feature flags or library items
+msrv_aliases! {
+    1,73,0 { DIV_CEIL }
+    1,71,0 { BUILD_HASHER_HASH_ONE }
+    1,63,0 { CLONE_INTO }
+    1,59,0 { THREAD_LOCAL_CONST_INIT }
+    1,55,0 { SEEK_REWIND }
+    1,51,0 { SEEK_STREAM_POSITION }
+    1,50,0 { CLAMP }
+    1,45,0 { STR_STRIP_PREFIX }
+    1,43,0 { ASSOC_INT_CONSTS }
+    1,42,0 { MATCHES_MACRO }
+    1,41,0 { RE_REBALANCING_COHERENCE, RESULT_MAP_OR_ELSE }
+    1,40,0 { MEM_TAKE, NON_EXHAUSTIVE, OPTION_AS_DEREF }
+    1,38,0 { POINTER_CAST }
+    1,37,0 { TYPE_ALIAS_ENUM_VARIANTS }
+    1,35,0 { RANGE_CONTAINS }
+    1,34,0 { TRY_FROM }
+    1,30,0 { ITERATOR_FIND_MAP }
+    1,26,0 { STRING_RETAIN }
+    1,22,0 { QUESTION_MARK_OPTION }
+    1,18,0 { HASH_MAP_RETAIN }
+    1,17,0 { FIELD_INIT_SHORTHAND, STATIC_IN_CONST }
+    1,13,0 { QUESTION_MARK_OPERATOR }
+}
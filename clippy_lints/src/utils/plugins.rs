@@ -0,0 +1,86 @@
+//! Loads the lint passes of the dynamic libraries given to the `plugins` option, so that custom
+//! lints can be run by Clippy without forking it.
+//!
+//! A plugin is a `cdylib` built with the same toolchain as Clippy, exporting the version of the
+//! registration ABI it was written for and the function registering its lints:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub extern "C" fn clippy_plugin_abi_version() -> u32 {
+//!     1
+//! }
+//!
+//! #[no_mangle]
+//! pub fn clippy_plugin_register_lints(sess: &rustc_session::Session, store: &mut rustc_lint::LintStore) {
+//!     store.register_lints(&[COMPANY_LINT]);
+//!     store.register_late_pass(|| Box::new(CompanyLint));
+//! }
+//! ```
+
+use super::file_filter::PLUGIN_GROUP;
+use rustc_lint::LintStore;
+use rustc_metadata::dynamic_lib::DynamicLibrary;
+use rustc_session::lint::LintId;
+use rustc_session::Session;
+use std::env;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+/// The version of the registration ABI, increased whenever the signature or the meaning of the
+/// functions exported by the plugins change.
+pub const ABI_VERSION: u32 = 1;
+
+type AbiVersionFn = extern "C" fn() -> u32;
+type RegisterLintsFn = fn(&Session, &mut LintStore);
+
+/// Returns the path of the plugin, relative paths being relative to the package root.
+fn plugin_path(plugin: &str) -> PathBuf {
+    let root = env::var_os("CARGO_MANIFEST_DIR").map_or_else(|| env::current_dir().unwrap_or_default(), PathBuf::from);
+    root.join(plugin)
+}
+
+/// Loads the plugin and registers its lints, returning an error if it can't be loaded or was
+/// written for another version of the registration ABI.
+fn load(sess: &Session, store: &mut LintStore, path: &Path) -> Result<(), String> {
+    let lib = DynamicLibrary::open(path)?;
+    // SAFETY: the symbols have the types documented in the module, which the plugins export them
+    // with, and the library is never unloaded since the lint passes it registers outlive it
+    unsafe {
+        let abi_version = lib
+            .symbol::<u8>("clippy_plugin_abi_version")
+            .map_err(|_| "it doesn't export `clippy_plugin_abi_version`".to_string())?;
+        let abi_version = mem::transmute::<*mut u8, AbiVersionFn>(abi_version)();
+        if abi_version != ABI_VERSION {
+            return Err(format!(
+                "it was written for version {} of the plugin ABI, but Clippy uses version {}",
+                abi_version, ABI_VERSION
+            ));
+        }
+        let register_lints = lib
+            .symbol::<u8>("clippy_plugin_register_lints")
+            .map_err(|_| "it doesn't export `clippy_plugin_register_lints`".to_string())?;
+        mem::transmute::<*mut u8, RegisterLintsFn>(register_lints)(sess, store);
+    }
+    mem::forget(lib);
+    Ok(())
+}
+
+/// Registers the lints of the plugins given to the `plugins` option, reporting the ones which
+/// can't be loaded. The lints are put in the `clippy::plugins` group, which tells them apart from
+/// the lints of Clippy and of rustc.
+pub fn register_lints(sess: &Session, store: &mut LintStore, plugins: &[String]) {
+    let registered = store.get_lints().len();
+    for plugin in plugins {
+        if let Err(error) = load(sess, store, &plugin_path(plugin)) {
+            sess.err(&format!("could not load the Clippy plugin `{}`: {}", plugin, error));
+        }
+    }
+    let lints: Vec<_> = store.get_lints()[registered..]
+        .iter()
+        .copied()
+        .map(LintId::of)
+        .collect();
+    if !lints.is_empty() {
+        store.register_group(true, PLUGIN_GROUP, None, lints);
+    }
+}
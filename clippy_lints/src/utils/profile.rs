@@ -0,0 +1,133 @@
+//! Measures the time taken by each lint pass, for `clippy-driver --profile-lints`.
+//!
+//! The passes of the lint store are wrapped in passes timing every call to the wrapped pass, the
+//! times being added up by pass name when the passes are dropped at the end of the linting.
+
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync;
+use rustc_hir as hir;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore};
+use rustc_span::symbol::{Ident, Symbol};
+use rustc_span::Span;
+use std::lazy::SyncLazy;
+use std::mem;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+type EarlyLintPassObject = Box<dyn EarlyLintPass + sync::Send + sync::Sync + 'static>;
+type LateLintPassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + sync::Send + sync::Sync + 'static>;
+
+/// The time taken by the lint passes dropped so far, by pass name.
+static PASS_TIMES: SyncLazy<Mutex<FxHashMap<&'static str, Duration>>> = SyncLazy::new(Mutex::default);
+
+/// A lint pass timing the calls to the pass it wraps.
+struct Profiled<P: ?Sized + LintPass> {
+    pass: Box<P>,
+    time: Duration,
+}
+
+impl<P: ?Sized + LintPass> Profiled<P> {
+    fn new(pass: Box<P>) -> Self {
+        Self {
+            pass,
+            time: Duration::default(),
+        }
+    }
+}
+
+impl<P: ?Sized + LintPass> LintPass for Profiled<P> {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+impl<P: ?Sized + LintPass> Drop for Profiled<P> {
+    fn drop(&mut self) {
+        *PASS_TIMES.lock().unwrap().entry(self.pass.name()).or_default() += self.time;
+    }
+}
+
+macro_rules! profile_early_lint_pass_methods {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+            let start = Instant::now();
+            self.pass.$name(cx, $($param),*);
+            self.time += start.elapsed();
+        })*
+    )
+}
+
+macro_rules! profile_late_lint_pass_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        $(fn $name(&mut self, cx: &LateContext<$hir>, $($param: $arg),*) {
+            let start = Instant::now();
+            self.pass.$name(cx, $($param),*);
+            self.time += start.elapsed();
+        })*
+    )
+}
+
+impl EarlyLintPass for Profiled<dyn EarlyLintPass + sync::Send + sync::Sync> {
+    rustc_lint::early_lint_methods!(profile_early_lint_pass_methods, []);
+}
+
+impl<'tcx> LateLintPass<'tcx> for Profiled<dyn for<'a> LateLintPass<'a> + sync::Send + sync::Sync> {
+    rustc_lint::late_lint_methods!(profile_late_lint_pass_methods, [], ['tcx]);
+}
+
+fn profile_early(passes: &mut Vec<Box<dyn Fn() -> EarlyLintPassObject + sync::Send + sync::Sync>>) {
+    *passes = mem::take(passes)
+        .into_iter()
+        .map(
+            |create| -> Box<dyn Fn() -> EarlyLintPassObject + sync::Send + sync::Sync> {
+                Box::new(move || Box::new(Profiled::new(create())))
+            },
+        )
+        .collect();
+}
+
+fn profile_late(passes: &mut Vec<Box<dyn Fn() -> LateLintPassObject + sync::Send + sync::Sync>>) {
+    *passes = mem::take(passes)
+        .into_iter()
+        .map(
+            |create| -> Box<dyn Fn() -> LateLintPassObject + sync::Send + sync::Sync> {
+                Box::new(move || Box::new(Profiled::new(create())))
+            },
+        )
+        .collect();
+}
+
+/// Makes the registered lint passes measure the time they take.
+pub fn measure_lint_passes(store: &mut LintStore) {
+    profile_early(&mut store.pre_expansion_passes);
+    profile_early(&mut store.early_passes);
+    profile_late(&mut store.late_passes);
+    profile_late(&mut store.late_module_passes);
+}
+
+/// Formats the lint passes which took the most time so far linting the crate, at most `count` of
+/// them, with their share of the time taken by all the passes.
+pub fn report(crate_name: &str, count: usize) -> String {
+    let times = PASS_TIMES.lock().unwrap();
+    let mut passes: Vec<_> = times.iter().map(|(&name, &time)| (name, time)).collect();
+    passes.sort_by(|(name, time), (other_name, other_time)| other_time.cmp(time).then(name.cmp(other_name)));
+    let total: Duration = passes.iter().map(|(_, time)| *time).sum();
+
+    let mut report = format!(
+        "lint passes of `{}` taking the most time ({} of {}, {:.3}s in total):\n",
+        crate_name,
+        count.min(passes.len()),
+        passes.len(),
+        total.as_secs_f64()
+    );
+    for (name, time) in passes.into_iter().take(count) {
+        let share = if total == Duration::default() {
+            0.0
+        } else {
+            time.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        report.push_str(&format!("{:>9.3}s {:>5.1}%  {}\n", time.as_secs_f64(), share, name));
+    }
+    report
+}
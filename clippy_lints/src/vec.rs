@@ -6,7 +6,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{BorrowKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
 
 #[allow(clippy::module_name_repetitions)]
@@ -35,7 +35,8 @@ declare_clippy_lint! {
     /// ```
     pub USELESS_VEC,
     perf,
-    "useless `vec!`"
+    "useless `vec!`",
+    applicability: [MachineApplicable]
 }
 
 impl_lint_pass!(UselessVec => [USELESS_VEC]);
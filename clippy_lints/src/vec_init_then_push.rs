@@ -5,7 +5,7 @@ use rustc_errors::Applicability;
 use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, Local, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::{symbol::sym, Span, Symbol};
 use std::convert::TryInto;
 
@@ -29,7 +29,8 @@ declare_clippy_lint! {
     /// ```
     pub VEC_INIT_THEN_PUSH,
     perf,
-    "`push` immediately after `Vec` creation"
+    "`push` immediately after `Vec` creation",
+    applicability: [HasPlaceholders]
 }
 
 impl_lint_pass!(VecInitThenPush => [VEC_INIT_THEN_PUSH]);
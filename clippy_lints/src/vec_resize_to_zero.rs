@@ -3,7 +3,7 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Spanned;
 
 use crate::utils::{match_def_path, paths};
@@ -23,7 +23,8 @@ declare_clippy_lint! {
     /// ```
     pub VEC_RESIZE_TO_ZERO,
     correctness,
-    "emptying a vector with `resize(0, an_int)` instead of `clear()` is probably an argument inversion mistake"
+    "emptying a vector with `resize(0, an_int)` instead of `clear()` is probably an argument inversion mistake",
+    applicability: [MaybeIncorrect]
 }
 
 declare_lint_pass!(VecResizeToZero => [VEC_RESIZE_TO_ZERO]);
@@ -0,0 +1,162 @@
+use crate::utils::{in_macro, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_ast::ast::{CrateSugar, Item, VisibilityKind};
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_tool_lint;
+use rustc_span::symbol::{kw, Symbol};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `pub(self)` and `pub(in self)`.
+    ///
+    /// **Why is this bad?** It's unnecessary, omitting the `pub` entirely will give the same
+    /// results.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// pub(self) type OptBox<T> = Option<Box<T>>;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// type OptBox<T> = Option<Box<T>>;
+    /// ```
+    pub NEEDLESS_PUB_SELF,
+    style,
+    "checks for usage of `pub(self)` and `pub(in self)`.",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `pub(<loc>)` without `in`.
+    ///
+    /// Note: As you cannot write a module's path in `pub(<loc>)`, this will only trigger on
+    /// `pub(super)` and the like.
+    ///
+    /// **Why is this bad?** Consistency. Use it or don't, just be consistent about it.
+    ///
+    /// Also see the `pub_without_shorthand` lint for an alternative.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// pub(super) type OptBox<T> = Option<Box<T>>;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub(in super) type OptBox<T> = Option<Box<T>>;
+    /// ```
+    pub PUB_WITH_SHORTHAND,
+    restriction,
+    "disallows usage of `pub(<loc>)`, without `in`",
+    applicability: [MachineApplicable]
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `pub(<loc>)` with `in`.
+    ///
+    /// Note: As you cannot write a module's path in `pub(<loc>)`, this will only trigger on
+    /// `pub(in super)` and the like.
+    ///
+    /// **Why is this bad?** Consistency. Use it or don't, just be consistent about it.
+    ///
+    /// Also see the `pub_with_shorthand` lint for an alternative.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// pub(in super) type OptBox<T> = Option<Box<T>>;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub(super) type OptBox<T> = Option<Box<T>>;
+    /// ```
+    pub PUB_WITHOUT_SHORTHAND,
+    restriction,
+    "disallows usage of `pub(in <loc>)` with `in`",
+    applicability: [MachineApplicable]
+}
+
+declare_lint_pass!(Visibility => [NEEDLESS_PUB_SELF, PUB_WITH_SHORTHAND, PUB_WITHOUT_SHORTHAND]);
+
+impl EarlyLintPass for Visibility {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+
+        if let Some((loc, shorthand)) = restricted_to(cx, &item.vis) {
+            if shorthand {
+                span_lint_and_sugg(
+                    cx,
+                    PUB_WITH_SHORTHAND,
+                    item.vis.span,
+                    "usage of `pub` without `in`",
+                    "add it",
+                    format!("pub(in {})", loc),
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                span_lint_and_sugg(
+                    cx,
+                    PUB_WITHOUT_SHORTHAND,
+                    item.vis.span,
+                    "usage of `pub` with `in`",
+                    "remove it",
+                    format!("pub({})", loc),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+
+        if_chain! {
+            if let VisibilityKind::Restricted { path, .. } = &item.vis.kind;
+            if let [segment] = &*path.segments;
+            if segment.ident.name == kw::SelfLower;
+            then {
+                // also remove the whitespace between the visibility and the rest of the item
+                let trailing = item.vis.span.shrink_to_hi().with_hi(item.span.hi());
+                let trailing = cx.sess.source_map().span_take_while(trailing, |c| c.is_whitespace());
+                span_lint_and_sugg(
+                    cx,
+                    NEEDLESS_PUB_SELF,
+                    item.vis.span.to(trailing),
+                    &format!("unnecessary `{}`", snippet(cx, item.vis.span, "pub(self)")),
+                    "remove it",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// If the visibility is `pub(self)`, `pub(super)` or `pub(crate)` (or one of their `pub(in ..)`
+/// forms), returns the location it is restricted to and whether the shorthand form is used.
+fn restricted_to(cx: &EarlyContext<'_>, vis: &rustc_ast::ast::Visibility) -> Option<(Symbol, bool)> {
+    match &vis.kind {
+        VisibilityKind::Crate(CrateSugar::PubCrate) => Some((kw::Crate, true)),
+        VisibilityKind::Restricted { path, .. } => match &*path.segments {
+            [segment] if [kw::SelfLower, kw::Super, kw::Crate].contains(&segment.ident.name) => {
+                Some((segment.ident.name, !uses_in(cx, vis.span)))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether the `pub(..)` visibility at `span` is written with `in`.
+fn uses_in(cx: &EarlyContext<'_>, span: Span) -> bool {
+    let vis = snippet(cx, span, "");
+    vis.trim_start_matches("pub")
+        .trim_start()
+        .trim_start_matches('(')
+        .trim_start()
+        .strip_prefix("in")
+        .map_or(false, |rest| rest.starts_with(char::is_whitespace))
+}
@@ -6,7 +6,7 @@ use rustc_hir::{
     Item, ItemKind, PathSegment, UseKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::kw;
 use rustc_span::{sym, BytePos};
 
@@ -31,7 +31,8 @@ declare_clippy_lint! {
     /// ```
     pub ENUM_GLOB_USE,
     pedantic,
-    "use items that import all variants of an enum"
+    "use items that import all variants of an enum",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -82,7 +83,8 @@ declare_clippy_lint! {
     /// ```
     pub WILDCARD_IMPORTS,
     pedantic,
-    "lint `use _::*` statements"
+    "lint `use _::*` statements",
+    applicability: [MachineApplicable]
 }
 
 #[derive(Default)]
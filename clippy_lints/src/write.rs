@@ -10,7 +10,7 @@ use rustc_errors::Applicability;
 use rustc_lexer::unescape::{self, EscapeError};
 use rustc_lint::{EarlyContext, EarlyLintPass};
 use rustc_parse::parser;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::declare_tool_lint;
 use rustc_span::symbol::kw;
 use rustc_span::{sym, BytePos, Span};
 
@@ -32,7 +32,8 @@ declare_clippy_lint! {
     /// ```
     pub PRINTLN_EMPTY_STRING,
     style,
-    "using `println!(\"\")` with an empty string"
+    "using `println!(\"\")` with an empty string",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -56,7 +57,8 @@ declare_clippy_lint! {
     /// ```
     pub PRINT_WITH_NEWLINE,
     style,
-    "using `print!()` with a format string that ends in a single newline"
+    "using `print!()` with a format string that ends in a single newline",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -156,7 +158,8 @@ declare_clippy_lint! {
     /// ```
     pub WRITELN_EMPTY_STRING,
     style,
-    "using `writeln!(buf, \"\")` with an empty string"
+    "using `writeln!(buf, \"\")` with an empty string",
+    applicability: [HasPlaceholders, MachineApplicable]
 }
 
 declare_clippy_lint! {
@@ -183,7 +186,8 @@ declare_clippy_lint! {
     /// ```
     pub WRITE_WITH_NEWLINE,
     style,
-    "using `write!()` with a format string that ends in a single newline"
+    "using `write!()` with a format string that ends in a single newline",
+    applicability: [MachineApplicable]
 }
 
 declare_clippy_lint! {
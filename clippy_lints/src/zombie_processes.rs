@@ -0,0 +1,135 @@
+use crate::utils::{get_parent_expr, in_macro, match_type, paths, span_lint_and_then};
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, Local, Mutability, Node, PatKind, QPath, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::declare_tool_lint;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for code that spawns a process but never calls `wait()` on the
+    /// child.
+    ///
+    /// **Why is this bad?** As explained in the [standard library documentation](https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning),
+    /// calling `wait()` is necessary on Unix platforms to properly release all OS resources
+    /// associated with the process. Not doing so will effectively leak process IDs and/or other
+    /// limited global resources, which can eventually lead to resource exhaustion, so it's
+    /// recommended to call `wait()` in long-running applications.
+    ///
+    /// **Known problems:** The child is not linted if it is moved or borrowed somewhere else,
+    /// since it may be waited on there. It is also not checked whether `wait()` is called on all
+    /// paths.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let _child = Command::new("ls").spawn().expect("failed to execute child");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("ls").spawn().expect("failed to execute child");
+    /// child.wait().expect("failed to wait on child");
+    /// ```
+    pub ZOMBIE_PROCESSES,
+    style,
+    "not waiting on a spawned child process"
+}
+
+declare_lint_pass!(ZombieProcesses => [ZOMBIE_PROCESSES]);
+
+impl<'tcx> LateLintPass<'tcx> for ZombieProcesses {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span)
+            || !matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || !match_type(cx, cx.typeck_results().expr_ty(expr), &paths::CHILD)
+        {
+            return;
+        }
+
+        match cx.tcx.hir().get(cx.tcx.hir().get_parent_node(expr.hir_id)) {
+            Node::Local(Local {
+                pat, init: Some(init), ..
+            }) if init.hir_id == expr.hir_id => match pat.kind {
+                PatKind::Binding(_, local_id, ..) => {
+                    let body_id = cx
+                        .tcx
+                        .hir()
+                        .body_owned_by(cx.tcx.hir().enclosing_body_owner(expr.hir_id));
+                    let mut visitor = WaitFinder {
+                        cx,
+                        local_id,
+                        found: false,
+                    };
+                    visitor.visit_body(cx.tcx.hir().body(body_id));
+                    if !visitor.found {
+                        emit_lint(cx, expr.span);
+                    }
+                },
+                // `let _ = ..` drops the child right away
+                PatKind::Wild => emit_lint(cx, expr.span),
+                _ => {},
+            },
+            // the child is dropped at the end of the statement
+            Node::Stmt(Stmt {
+                kind: StmtKind::Semi(_),
+                ..
+            }) => emit_lint(cx, expr.span),
+            _ => {},
+        }
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, span: Span) {
+    span_lint_and_then(
+        cx,
+        ZOMBIE_PROCESSES,
+        span,
+        "spawned process is never `wait()`ed on",
+        |diag| {
+            diag.note("consider calling `.wait()`");
+            diag.note("not doing so might leave behind zombie processes");
+            diag.note("see https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning");
+        },
+    );
+}
+
+/// Looks for a `wait()` call on the child, or any use of it which might wait on it elsewhere,
+/// like moving it or passing a reference to it to a function.
+struct WaitFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for WaitFinder<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found {
+            return;
+        }
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+            if path.res == Res::Local(self.local_id) {
+                self.found = match get_parent_expr(self.cx, expr).map(|parent| &parent.kind) {
+                    Some(ExprKind::MethodCall(method, _, [receiver, ..], _)) if receiver.hir_id == expr.hir_id => {
+                        matches!(&*method.ident.as_str(), "wait" | "try_wait" | "wait_with_output")
+                    },
+                    // e.g. `child.stdin.take()`, or a shared reference through which the child
+                    // can't be waited on
+                    Some(ExprKind::Field(..) | ExprKind::AddrOf(_, Mutability::Not, _)) => false,
+                    _ => true,
+                };
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
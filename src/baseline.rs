@@ -0,0 +1,179 @@
+//! Records the lints emitted on a codebase in a baseline file, to only report the lints which
+//! aren't in it, for `cargo clippy --baseline <file>`.
+//!
+//! The lints are identified by their name, their file and the code of their span with normalized
+//! whitespace, so that they are still found when the code around them moves. The same lint on the
+//! same code can be in a file several times, which the baseline counts.
+
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The lint, file and normalized code identifying a lint in the baseline.
+pub type Key = (String, String, String);
+
+/// The version of the format of the baseline file.
+const VERSION: u64 = 1;
+
+/// Returns the key of a Cargo message if it is a Clippy lint with a primary span.
+fn key(message: &Value) -> Option<Key> {
+    let diagnostic = crate::diagnostic(message)?;
+    let lint = diagnostic["code"]["code"]
+        .as_str()
+        .filter(|code| code.starts_with("clippy::"))?;
+    let span = diagnostic["spans"]
+        .as_array()?
+        .iter()
+        .find(|span| span["is_primary"] == true)?;
+    let file = span["file_name"].as_str()?;
+    let code: Vec<_> = span["text"]
+        .as_array()?
+        .iter()
+        .filter_map(|line| {
+            let text = line["text"].as_str()?;
+            let start = usize::try_from(line["highlight_start"].as_u64()?).ok()?;
+            let end = usize::try_from(line["highlight_end"].as_u64()?).ok()?;
+            Some(
+                text.chars()
+                    .skip(start.saturating_sub(1))
+                    .take(end.saturating_sub(start))
+                    .collect::<String>(),
+            )
+        })
+        .collect();
+    let code = code.join(" ").split_whitespace().collect::<Vec<_>>().join(" ");
+    Some((lint.to_string(), file.to_string(), code))
+}
+
+/// Checks whether a Cargo message is a Clippy lint which can be recorded in the baseline.
+pub fn is_recorded(message: &Value) -> bool {
+    key(message).is_some()
+}
+
+/// Reads the baseline file, returning how many times each lint is in it. A missing file is an
+/// empty baseline.
+pub fn read(path: &Path) -> io::Result<BTreeMap<Key, u64>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(error) => return Err(error),
+    };
+    let baseline: Value =
+        serde_json::from_str(&content).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    if baseline["version"] != VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported version `{}`, expected `{}`", baseline["version"], VERSION),
+        ));
+    }
+
+    let mut known = BTreeMap::new();
+    for finding in baseline["lints"].as_array().map_or(&[][..], Vec::as_slice) {
+        let field = |name: &str| finding[name].as_str().map(ToString::to_string);
+        if let (Some(lint), Some(file), Some(code)) = (field("lint"), field("file"), field("code")) {
+            *known.entry((lint, file, code)).or_insert(0) += finding["count"].as_u64().unwrap_or(1);
+        }
+    }
+    Ok(known)
+}
+
+/// Counts the lints of the Cargo messages by key.
+pub fn counts(messages: &[Value]) -> BTreeMap<Key, u64> {
+    let mut counts: BTreeMap<Key, u64> = BTreeMap::new();
+    for key in messages.iter().filter_map(key) {
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Writes the lints of the Cargo messages to the baseline file, sorted so that the file can be
+/// versioned. Returns the number of lints written.
+pub fn write(path: &Path, messages: &[Value]) -> io::Result<u64> {
+    let counts = counts(messages);
+    let lints: Vec<_> = counts
+        .iter()
+        .map(|((lint, file, code), count)| {
+            json!({
+                "lint": lint,
+                "file": file,
+                "code": code,
+                "count": count,
+            })
+        })
+        .collect();
+    let baseline = json!({ "version": VERSION, "lints": lints });
+    let content = serde_json::to_string_pretty(&baseline).expect("the baseline can be serialized");
+    fs::write(path, content + "\n")?;
+    Ok(counts.values().sum())
+}
+
+/// Removes the Cargo messages of the lints which are in the baseline, each entry of the baseline
+/// removing as many lints as it counts. Returns the number of lints removed.
+pub fn remove_known(messages: &mut Vec<Value>, mut known: BTreeMap<Key, u64>) -> u64 {
+    let mut removed = 0;
+    messages.retain(|message| {
+        let count = key(message).and_then(|key| known.get_mut(&key));
+        match count {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                removed += 1;
+                false
+            },
+            _ => true,
+        }
+    });
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{key, read, remove_known, write};
+    use serde_json::{json, Value};
+
+    fn lint(name: &str, line: &str, start: u64, end: u64) -> Value {
+        json!({
+            "reason": "compiler-message",
+            "message": {
+                "message": "lint",
+                "code": { "code": name },
+                "level": "warning",
+                "spans": [{
+                    "file_name": "src/main.rs",
+                    "is_primary": true,
+                    "text": [{ "text": line, "highlight_start": start, "highlight_end": end }],
+                }],
+                "children": [],
+            },
+        })
+    }
+
+    #[test]
+    fn key_normalizes_the_code() {
+        let message = lint("clippy::needless_return", "    return  1;", 5, 15);
+        let key = key(&message).unwrap();
+        assert_eq!(key.0, "clippy::needless_return");
+        assert_eq!(key.2, "return 1;");
+        assert!(super::key(&lint("dead_code", "fn f() {}", 1, 10)).is_none());
+    }
+
+    #[test]
+    fn only_new_lints_are_kept() {
+        let path = std::env::temp_dir().join("clippy-baseline-test.json");
+        let recorded = vec![lint("clippy::needless_return", "return 1;", 1, 10)];
+        assert_eq!(write(&path, &recorded).unwrap(), 1);
+
+        let mut messages = vec![
+            lint("clippy::needless_return", "        return 1;", 9, 18),
+            lint("clippy::needless_return", "return 1;", 1, 10),
+            lint("clippy::needless_return", "return 2;", 1, 10),
+        ];
+        let known = read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(remove_known(&mut messages, known), 1);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(key(&messages[1]).unwrap().2, "return 2;");
+    }
+}
@@ -0,0 +1,202 @@
+//! Caches the Clippy lints emitted on a crate, so that they are replayed instead of running the
+//! lint passes again when Cargo compiles the crate again without any change.
+//!
+//! The cache of a crate is keyed on the Clippy version and binary, the arguments of the compiler,
+//! the environment of Clippy and Cargo, the configuration files and the dependencies. It records
+//! the hashes of the source files of the crate and the environment variables it read, which must
+//! be unchanged for the lints to be replayed. The lints are only cached with JSON diagnostics, as
+//! asked for by Cargo.
+
+use rustc_session::{DiagnosticOutput, Session};
+use rustc_span::FileName;
+use serde_json::{json, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::arg_value;
+
+/// The cache of the lints of the crate being compiled.
+pub struct Cache {
+    /// The cache file, in the output directory
+    path: PathBuf,
+    /// The hash of everything the lints depend on besides the sources of the crate
+    key: u64,
+    /// The diagnostics emitted by the compiler, which are written to `stderr` too
+    output: Arc<Mutex<Vec<u8>>>,
+}
+
+/// Writes to `stderr` and records what is written.
+struct Recorder(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Recorder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        io::stderr().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+/// The size and modification time of a file.
+fn file_stamp(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// Hashes the source of a file, normalized like the compiler does.
+fn source_hash(source: &str) -> u64 {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source).replace("\r\n", "\n");
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes what the lints depend on besides the sources of the crate.
+fn key(args: &[String], conf_files: &[PathBuf]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    // rebuilding Clippy invalidates the cache
+    env::current_exe()
+        .ok()
+        .and_then(|exe| file_stamp(&exe))
+        .hash(&mut hasher);
+    args.hash(&mut hasher);
+
+    let mut vars: Vec<_> = env::vars()
+        .filter(|(name, _)| {
+            name.starts_with("CLIPPY_")
+                || name.starts_with("CARGO_PKG_")
+                || name == "CARGO_MANIFEST_DIR"
+                || name == "CARGO_PRIMARY_PACKAGE"
+        })
+        .collect();
+    vars.sort();
+    vars.hash(&mut hasher);
+
+    for file in conf_files {
+        fs::read(&file).ok().hash(&mut hasher);
+        file.hash(&mut hasher);
+    }
+
+    // the dependencies are given like `--extern name=path`
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let dependency = match arg.strip_prefix("--extern=") {
+            Some(dependency) => Some(dependency),
+            None if arg == "--extern" => args.next().map(String::as_str),
+            None => None,
+        };
+        if let Some(path) = dependency.and_then(|dependency| dependency.splitn(2, '=').nth(1)) {
+            file_stamp(Path::new(path)).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+impl Cache {
+    /// Returns the cache of the crate compiled with the arguments and the configuration files, if
+    /// it compiles a crate of Cargo with JSON diagnostics.
+    pub fn new(args: &[String], conf_files: &[PathBuf]) -> Option<Self> {
+        arg_value(args, "--error-format", |format| format == "json")?;
+        let out_dir = arg_value(args, "--out-dir", |_| true)?;
+        let crate_name = arg_value(args, "--crate-name", |_| true)?;
+        let metadata = arg_value(args, "-C", |option| option.starts_with("metadata="))?;
+        let file_name = format!("{}-{}.json", crate_name, &metadata["metadata=".len()..]);
+        Some(Self {
+            path: Path::new(out_dir).join("clippy-cache").join(file_name),
+            key: key(args, conf_files),
+            output: Arc::default(),
+        })
+    }
+
+    /// Returns the lints of the previous compilation of the crate, if nothing they depend on
+    /// changed since.
+    pub fn lookup(&self) -> Option<Vec<String>> {
+        let content = fs::read_to_string(&self.path).ok()?;
+        let entry: Value = serde_json::from_str(&content).ok()?;
+        if entry["key"] != self.key {
+            return None;
+        }
+
+        let files = entry["files"].as_array()?;
+        let files_unchanged = files.iter().all(|file| {
+            let source = file["path"].as_str().and_then(|path| fs::read_to_string(path).ok());
+            source.map_or(false, |source| file["hash"] == source_hash(&source))
+        });
+        let vars = entry["env"].as_array()?;
+        let env_unchanged = vars.iter().all(|var| {
+            let value = var["name"].as_str().and_then(|name| env::var(name).ok());
+            var["value"] == value.map_or(Value::Null, Value::from)
+        });
+        if !files_unchanged || !env_unchanged {
+            return None;
+        }
+
+        let lints = entry["lints"].as_array()?;
+        lints
+            .iter()
+            .map(|lint| lint.as_str().map(ToString::to_string))
+            .collect()
+    }
+
+    /// Returns the output of the diagnostics recording them, to store the lints in the cache.
+    pub fn recorder(&self) -> DiagnosticOutput {
+        DiagnosticOutput::Raw(Box::new(Recorder(Arc::clone(&self.output))))
+    }
+
+    /// Stores the lints emitted so far in the cache, with the source files and the environment
+    /// variables read by the compilation. Nothing is stored if it failed.
+    pub fn store(&self, sess: &Session) {
+        if sess.has_errors() {
+            return;
+        }
+
+        let files: Vec<_> = sess
+            .source_map()
+            .files()
+            .iter()
+            .filter_map(|file| match (&file.name, &file.src) {
+                (FileName::Real(name), Some(source)) => Some(json!({
+                    "path": name.local_path(),
+                    "hash": source_hash(source),
+                })),
+                _ => None,
+            })
+            .collect();
+        let vars: Vec<_> = sess
+            .parse_sess
+            .env_depinfo
+            .lock()
+            .iter()
+            .map(|(name, value)| {
+                let value = value.map(|value| value.to_string());
+                json!({ "name": &*name.as_str(), "value": value })
+            })
+            .collect();
+        let output = String::from_utf8_lossy(&self.output.lock().unwrap()).into_owned();
+        let lints: Vec<_> = output
+            .lines()
+            .filter(|line| {
+                serde_json::from_str::<Value>(line).map_or(false, |diagnostic| {
+                    diagnostic["code"]["code"]
+                        .as_str()
+                        .map_or(false, |code| code.starts_with("clippy::"))
+                })
+            })
+            .collect();
+
+        let entry = json!({ "key": self.key, "files": files, "env": vars, "lints": lints });
+        // the cache is only an optimization
+        if let Some(dir) = self.path.parent() {
+            let _ = fs::create_dir_all(dir).and_then(|_| fs::write(&self.path, entry.to_string()));
+        }
+    }
+}
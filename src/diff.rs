@@ -0,0 +1,143 @@
+//! Finds the lines changed since a git revision, to only report the lints of the changed code with
+//! `cargo clippy --diff-base <rev>`.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The ranges of changed lines of the changed files, by absolute path. The lines are counted from 1
+/// and the ranges are inclusive.
+pub type ChangedLines = BTreeMap<PathBuf, Vec<(u64, u64)>>;
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(&["-c", "core.quotePath=false"])
+        .args(args)
+        .output()
+        .map_err(|error| format!("could not run git: {}", error))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Parses the ranges of added lines of `git diff --unified=0`, the paths being relative to the
+/// root of the repository. The lines around removed lines count as changed.
+fn parse_diff(diff: &str, root: &Path) -> ChangedLines {
+    let mut changed = ChangedLines::new();
+    let mut file = None;
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            file = path.strip_prefix("b/").map(|path| root.join(path));
+        } else if let (Some(hunk), Some(file)) = (line.strip_prefix("@@ "), &file) {
+            // like `@@ -10,2 +12,3 @@`, the count being 1 if omitted
+            let added = hunk.split_whitespace().find_map(|range| range.strip_prefix('+'));
+            let mut numbers = added.unwrap_or_default().splitn(2, ',').map(str::parse::<u64>);
+            if let Some(Ok(start)) = numbers.next() {
+                let count = numbers.next().and_then(Result::ok).unwrap_or(1);
+                let range = if count == 0 {
+                    (start, start + 1)
+                } else {
+                    (start, start + count - 1)
+                };
+                changed.entry(file.clone()).or_default().push(range);
+            }
+        }
+    }
+    changed
+}
+
+/// Returns the lines changed in the working tree since the revision, the files which aren't
+/// tracked by git being changed entirely.
+pub fn changed_lines(base: &str) -> Result<ChangedLines, String> {
+    let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+    let diff = git(&["diff", "--no-color", "--no-ext-diff", "--unified=0", base, "--"])?;
+    let mut changed = parse_diff(&diff, &root);
+    let untracked = git(&["ls-files", "--others", "--exclude-standard", "--full-name", ":/"])?;
+    for file in untracked.lines() {
+        changed.insert(root.join(file), vec![(1, u64::MAX)]);
+    }
+    Ok(changed)
+}
+
+/// Checks whether the primary span of a diagnostic is on changed lines. The paths of the spans
+/// are relative to the root of the workspace, which can be in the repository.
+fn is_changed(diagnostic: &Value, changed: &ChangedLines) -> bool {
+    let spans = diagnostic["spans"].as_array().map_or(&[][..], Vec::as_slice);
+    spans.iter().filter(|span| span["is_primary"] == true).any(|span| {
+        let file = Path::new(span["file_name"].as_str().unwrap_or_default());
+        let (start, end) = (span["line_start"].as_u64(), span["line_end"].as_u64());
+        changed.iter().any(|(path, ranges)| {
+            path.ends_with(file)
+                && ranges.iter().any(|&(first, last)| {
+                    start.map_or(false, |start| start <= last) && end.map_or(false, |end| end >= first)
+                })
+        })
+    })
+}
+
+/// Removes the Clippy lints which aren't on changed lines from the Cargo messages, returning their
+/// number.
+pub fn remove_unchanged(messages: &mut Vec<Value>, changed: &ChangedLines) -> usize {
+    let count = messages.len();
+    messages.retain(|message| {
+        crate::diagnostic(message).map_or(true, |diagnostic| {
+            let is_lint = diagnostic["code"]["code"]
+                .as_str()
+                .map_or(false, |code| code.starts_with("clippy::"));
+            !is_lint || is_changed(diagnostic, changed)
+        })
+    });
+    count - messages.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_diff, remove_unchanged};
+    use crate::read_messages;
+    use std::path::Path;
+
+    const DIFF: &str = "diff --git a/src/main.rs b/src/main.rs
+index 1234567..89abcde 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -2 +2,2 @@ fn main() {
+-    return 1;
++    let x = 1;
++    return x;
+@@ -10,2 +11,0 @@ fn f() {
+-    g();
+-    g();
+diff --git a/old.rs b/old.rs
+deleted file mode 100644
+--- a/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-fn old() {}
+";
+
+    fn lint(line: u64) -> String {
+        format!(
+            r#"{{"reason":"compiler-message","message":{{"message":"lint","code":{{"code":"clippy::needless_return"}},"level":"warning","spans":[{{"file_name":"src/main.rs","line_start":{0},"line_end":{0},"is_primary":true}}],"children":[]}}}}"#,
+            line
+        )
+    }
+
+    #[test]
+    fn keeps_the_lints_of_changed_lines() {
+        let changed = parse_diff(DIFF, Path::new("/repo"));
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[Path::new("/repo/src/main.rs")], [(2, 3), (11, 12)]);
+
+        let messages: Vec<_> = [1, 3, 5, 12].iter().map(|&line| lint(line)).collect();
+        let mut messages = read_messages(messages.join("\n").as_bytes());
+        assert_eq!(remove_unchanged(&mut messages, &changed), 2);
+        let lines: Vec<_> = messages
+            .iter()
+            .map(|message| message["message"]["spans"][0]["line_start"].clone())
+            .collect();
+        assert_eq!(lines, [3, 12]);
+    }
+}
@@ -12,19 +12,34 @@ extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_interface;
 extern crate rustc_middle;
+extern crate rustc_session;
+extern crate rustc_span;
 
+use clippy_lints::TargetKind;
+use rustc_driver::Compilation;
 use rustc_interface::interface;
+use rustc_interface::Queries;
 use rustc_middle::ty::TyCtxt;
+use rustc_session::config::Input;
+use rustc_session::lint::Level;
+use rustc_session::DiagnosticOutput;
+use rustc_span::FileName;
 use rustc_tools_util::VersionInfo;
 
 use std::borrow::Cow;
 use std::env;
+use std::ffi::OsStr;
+use std::io::{self, Write};
 use std::lazy::SyncLazy;
+use std::mem;
 use std::ops::Deref;
 use std::panic;
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command};
 
+mod cache;
+mod fix_output;
+
 /// If a command-line option matches `find_arg`, then apply the predicate `pred` on its value. If
 /// true, then return it. The parameter is assumed to be either `--arg=value` or `--arg value`.
 fn arg_value<'a, T: Deref<Target = str>>(
@@ -47,6 +62,103 @@ fn arg_value<'a, T: Deref<Target = str>>(
     None
 }
 
+/// Checks whether the argument of `--explain` is an error code of rustc, like `E0308`.
+fn is_error_code(code: &str) -> bool {
+    code.strip_prefix('E').map_or(false, |digits| {
+        digits.len() == 4 && digits.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+/// Splits the lints of `--only` and `--except`, separated by commas.
+fn split_lints(lints: &str) -> Vec<String> {
+    lints
+        .split(',')
+        .map(str::trim)
+        .filter(|lint| !lint.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Removes the options of Clippy from the command line of `clippy-driver <file>`, returning them,
+/// since `cargo clippy` gives them in `CLIPPY_ARGS` instead.
+fn take_clippy_options(args: &mut Vec<String>) -> Vec<String> {
+    let mut options = Vec::new();
+    let mut index = 0;
+    while index < args.len() {
+        let count = match args[index].as_str() {
+            "--profile-lints" => 1,
+            "--only" | "--except" => 2,
+            arg if arg.starts_with("--only=") || arg.starts_with("--except=") || target_option(arg).is_some() => 1,
+            _ => 0,
+        };
+        if count == 0 {
+            index += 1;
+        } else {
+            options.extend(args.drain(index..(index + count).min(args.len())));
+        }
+    }
+    options
+}
+
+/// Adds the defaults of `clippy-driver <file>` run without Cargo to the arguments of rustc: the
+/// 2018 edition, and only checking the crate, its metadata being written to the temporary directory
+/// rather than next to the file.
+fn add_standalone_defaults(args: &mut Vec<String>) {
+    if arg_value(args, "--edition", |_| true).is_none() {
+        args.extend(vec!["--edition".into(), "2018".into()]);
+    }
+    if arg_value(args, "--emit", |_| true).is_none() {
+        args.push("--emit=metadata".into());
+        if arg_value(args, "--out-dir", |_| true).is_none() && arg_value(args, "-o", |_| true).is_none() {
+            let out_dir = env::temp_dir().join("clippy-driver");
+            args.extend(vec!["--out-dir".into(), out_dir.to_string_lossy().into_owned()]);
+        }
+    }
+}
+
+/// Parses the `--lint-tests=<bool>`, `--lint-examples=<bool>` and `--lint-benches=<bool>` options,
+/// which override the options of the configuration files.
+fn target_option(arg: &str) -> Option<(TargetKind, bool)> {
+    let mut option = arg.strip_prefix("--lint-")?.splitn(2, '=');
+    let kind = match option.next()? {
+        "tests" => TargetKind::Test,
+        "examples" => TargetKind::Example,
+        "benches" => TargetKind::Bench,
+        _ => return None,
+    };
+    Some((kind, option.next()?.parse().ok()?))
+}
+
+/// Returns the root file of the crate compiled.
+fn input_file(args: &[String]) -> Option<&str> {
+    args.iter()
+        .skip(1)
+        .map(String::as_str)
+        .find(|arg| arg.ends_with(".rs") && !arg.starts_with('-'))
+}
+
+/// Returns the kind of the target compiled if it is a test, an example or a bench, from the
+/// directory of its root file in the package, or from `--test` for the unit tests.
+fn target_kind(args: &[String]) -> Option<TargetKind> {
+    let file = input_file(args)?;
+    let current_dir = env::current_dir().unwrap_or_default();
+    let path = current_dir.join(file);
+    let root = env::var_os("CARGO_MANIFEST_DIR").map_or(current_dir, PathBuf::from);
+    let dir = path
+        .strip_prefix(root)
+        .ok()
+        .and_then(|path| path.iter().next())
+        .and_then(OsStr::to_str);
+    match dir {
+        // the benches are compiled with `--test` too
+        Some("benches") => Some(TargetKind::Bench),
+        Some("examples") => Some(TargetKind::Example),
+        Some("tests") => Some(TargetKind::Test),
+        _ if args.iter().any(|arg| arg == "--test") => Some(TargetKind::Test),
+        _ => None,
+    }
+}
+
 #[test]
 fn test_arg_value() {
     let args = &["--bar=bar", "--foobar", "123", "--foo"];
@@ -61,13 +173,132 @@ fn test_arg_value() {
     assert_eq!(arg_value(args, "--foo", |_| true), None);
 }
 
+#[test]
+fn test_split_lints() {
+    assert_eq!(
+        split_lints("clippy::redundant_clone, clippy::needless_collect,"),
+        vec!["clippy::redundant_clone", "clippy::needless_collect"]
+    );
+}
+
+#[test]
+fn test_target_kind() {
+    let kind = |args: &str| target_kind(&args.split_whitespace().map(ToString::to_string).collect::<Vec<_>>());
+    assert_eq!(
+        kind("rustc --crate-name b --edition=2018 benches/b.rs --test"),
+        Some(TargetKind::Bench)
+    );
+    assert_eq!(kind("rustc --crate-name e examples/e.rs"), Some(TargetKind::Example));
+    assert_eq!(kind("rustc --crate-name t tests/t.rs --test"), Some(TargetKind::Test));
+    assert_eq!(kind("rustc --crate-name l src/lib.rs --test"), Some(TargetKind::Test));
+    assert_eq!(kind("rustc --crate-name l src/lib.rs --crate-type lib"), None);
+
+    assert_eq!(target_option("--lint-benches=false"), Some((TargetKind::Bench, false)));
+    assert_eq!(target_option("--lint-tests=true"), Some((TargetKind::Test, true)));
+    assert_eq!(target_option("--lint-docs=false"), None);
+}
+
+#[test]
+fn test_standalone_args() {
+    let mut args: Vec<_> =
+        "clippy-driver f.rs --only clippy::len_zero --profile-lints -W clippy::pedantic --except=clippy::doc_markdown"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect();
+    assert_eq!(
+        take_clippy_options(&mut args),
+        vec![
+            "--only",
+            "clippy::len_zero",
+            "--profile-lints",
+            "--except=clippy::doc_markdown"
+        ]
+    );
+    assert_eq!(args, vec!["clippy-driver", "f.rs", "-W", "clippy::pedantic"]);
+
+    let mut args = vec![
+        "f.rs".to_string(),
+        "--edition=2015".to_string(),
+        "--emit=link".to_string(),
+    ];
+    add_standalone_defaults(&mut args);
+    assert_eq!(args, vec!["f.rs", "--edition=2015", "--emit=link"]);
+    let mut args = vec!["f.rs".to_string()];
+    add_standalone_defaults(&mut args);
+    assert_eq!(args[1..4], ["--edition", "2018", "--emit=metadata"]);
+    assert_eq!(args[4], "--out-dir");
+}
+
+#[test]
+fn test_is_error_code() {
+    assert!(is_error_code("E0308"));
+    assert!(!is_error_code("needless_return"));
+    assert!(!is_error_code("E03"));
+}
+
 struct DefaultCallbacks;
 impl rustc_driver::Callbacks for DefaultCallbacks {}
 
-struct ClippyCallbacks;
+struct ClippyCallbacks {
+    /// The lints of `--only`, whose passes are the only ones registered
+    only_lints: Option<Vec<String>>,
+    /// The cache of the lints of the crate, if they are cached
+    cache: Option<cache::Cache>,
+    /// Whether the lints are replayed from the cache instead of running the lint passes
+    replay: bool,
+    /// Whether the time taken by the lint passes is measured, with `--profile-lints`
+    profile_lints: bool,
+    /// Whether the suggestions are applied by `cargo clippy --fix`, with `--fix`
+    fix: bool,
+    /// The lints whose suggestions are the only ones applied by `cargo clippy --fix --lint`
+    fix_lints: Option<Vec<String>>,
+    /// The lints of `--except`, allowed whatever the groups given before or after them
+    except_lints: Vec<String>,
+    /// The configuration, read once for the whole compilation
+    conf: Option<clippy_lints::LoadedConf>,
+}
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
+        if let Some(cache) = &self.cache {
+            if let Some(lints) = cache.lookup() {
+                for lint in lints {
+                    eprintln!("{}", lint);
+                }
+                self.replay = true;
+            } else {
+                config.diagnostic_output = cache.recorder();
+            }
+        }
+        if self.fix {
+            let output: Box<dyn Write + Send> =
+                match mem::replace(&mut config.diagnostic_output, DiagnosticOutput::Default) {
+                    DiagnosticOutput::Raw(output) => output,
+                    DiagnosticOutput::Default => Box::new(io::stderr()),
+                };
+            let output = fix_output::FixOutput::new(self.fix_lints.as_deref(), output);
+            config.diagnostic_output = DiagnosticOutput::Raw(Box::new(output));
+        }
+
+        // the lint levels of the configuration file come first, so the command line overrides them
+        let conf = self.conf.take().expect("the configuration is loaded");
+        config.opts.lint_opts.splice(0..0, conf.levels(config.opts.test));
+        // the last level given to a lint wins, so the lints are carved out of the groups given after them
+        // too
+        config
+            .opts
+            .lint_opts
+            .extend(self.except_lints.iter().map(|lint| (lint.clone(), Level::Allow)));
+
         let previous = config.register_lints.take();
+        let replay = self.replay;
+        // the lints are registered for their levels to be known, but none of the passes run
+        let only_lints = if replay {
+            Some(Vec::new())
+        } else {
+            self.only_lints.take()
+        };
+        let profile_lints = self.profile_lints;
+        let fix_lints = self.fix_lints.clone();
         config.register_lints = Some(Box::new(move |sess, mut lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
             // is there already. Certainly it can't hurt.
@@ -75,10 +306,23 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_lints::read_conf(&[], &sess);
-            clippy_lints::register_plugins(&mut lint_store, &sess, &conf);
-            clippy_lints::register_pre_expansion_lints(&mut lint_store);
+            conf.report_errors(&sess);
+            clippy_lints::register_plugins(&mut lint_store, &sess, conf.conf(), only_lints.as_deref());
+            clippy_lints::register_pre_expansion_lints(&mut lint_store, only_lints.as_deref());
             clippy_lints::register_renamed(&mut lint_store);
+            for lint in clippy_lints::unknown_only_lints(lint_store, only_lints.as_deref().unwrap_or_default()) {
+                sess.err(&format!("unknown lint given to `--only`: `{}`", lint));
+            }
+            for lint in fix_lints.iter().flatten() {
+                let lint = lint.replace('-', "_");
+                if lint_store.find_lints(&lint).is_err() && lint_store.find_lints(&format!("clippy::{}", lint)).is_err()
+                {
+                    sess.err(&format!("unknown lint given to `--lint`: `{}`", lint));
+                }
+            }
+            if profile_lints {
+                clippy_lints::profile_lint_passes(&mut lint_store);
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -87,6 +331,49 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
         // use for Clippy.
         config.opts.debugging_opts.mir_opt_level = 0;
     }
+
+    fn after_expansion<'tcx>(&mut self, compiler: &interface::Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        // the crate is linted when it is lowered, after its expansion
+        if let Ok(expansion) = queries.expansion() {
+            let (krate, _, lint_store) = &mut *expansion.peek_mut();
+            clippy_lints::allow_filtered_lints(compiler.session(), krate, lint_store);
+        }
+        Compilation::Continue
+    }
+
+    fn after_analysis<'tcx>(&mut self, compiler: &interface::Compiler, queries: &'tcx Queries<'tcx>) -> Compilation {
+        if let Some(cache) = self.cache.as_ref().filter(|_| !self.replay) {
+            cache.store(compiler.session());
+        }
+        // the lint passes are dropped once the crate is linted, adding up their times
+        if self.profile_lints && !self.replay {
+            if let Ok(crate_name) = queries.crate_name() {
+                eprint!("{}", clippy_lints::lint_pass_profile(&crate_name.peek(), 20));
+            }
+        }
+        Compilation::Continue
+    }
+}
+
+/// Registers the lints like `ClippyCallbacks` and prints their metadata, compiling an empty
+/// crate.
+struct LintMetadataCallbacks;
+impl rustc_driver::Callbacks for LintMetadataCallbacks {
+    fn config(&mut self, config: &mut interface::Config) {
+        config.input = Input::Str {
+            name: FileName::Custom("lint-metadata".into()),
+            input: String::new(),
+        };
+        config.register_lints = Some(Box::new(|sess, mut lint_store| {
+            let conf = clippy_lints::LoadedConf::load(None, Path::new(""));
+            conf.report_errors(&sess);
+            clippy_lints::register_plugins(&mut lint_store, &sess, conf.conf(), None);
+            clippy_lints::register_pre_expansion_lints(&mut lint_store, None);
+            println!("{}", clippy_lints::lint_metadata(lint_store));
+            // nothing else is needed from the compilation
+            exit(0);
+        }));
+    }
 }
 
 fn display_help() {
@@ -96,10 +383,12 @@ Checks a package to catch common mistakes and improve your Rust code.
 
 Usage:
     cargo clippy [options] [--] [<opts>...]
+    clippy-driver [<opts>...] <file.rs>
 
 Common options:
     -h, --help               Print this message
         --rustc              Pass all args to rustc
+        --explain <LINT>     Print the documentation of a lint
     -V, --version            Print version info and exit
 
 Other options are the same as `cargo check`.
@@ -111,10 +400,20 @@ with:
     -A --allow OPT      Set lint allowed
     -D --deny OPT       Set lint denied
     -F --forbid OPT     Set lint forbidden
+       --except LINTS   Allow the lints, separated by commas, whatever the groups
+                        given before or after them, eg. `-D clippy::pedantic --except
+                        clippy::module_name_repetitions`
+       --only LINTS     Only run the passes emitting the lints, separated by commas
+       --profile-lints  Print the lint passes taking the most time
 
 You can use tool lints to allow or deny lints from your code, eg.:
 
     #[allow(clippy::needless_lifetimes)]
+
+`clippy-driver <file.rs>` checks a single file without Cargo, taking the
+options of rustc and the ones above. The 2018 edition is used unless
+`--edition` is given, and nothing is written next to the file unless `--emit`
+is given.
 "
     );
 }
@@ -256,6 +555,34 @@ pub fn main() {
             exit(0);
         }
 
+        // hidden flag used by `cargo clippy -- --print-config-schema`
+        if orig_args.iter().any(|a| a == "--print-config-schema") {
+            println!("{}", clippy_lints::config_schema());
+            exit(0);
+        }
+
+        // `cargo clippy --explain <lint>`, the error codes being explained by rustc
+        if let Some(lint) = arg_value(&orig_args, "--explain", |lint| !is_error_code(lint)) {
+            if let Some(explanation) = clippy_lints::explain(lint) {
+                print!("{}", explanation);
+                exit(0);
+            }
+            eprintln!("error: `{}` is not a Clippy lint", lint);
+            exit(1);
+        }
+
+        // `cargo clippy -- --print-lints-json`, also used by `cargo clippy --message-format=sarif`
+        if orig_args.iter().any(|a| a == "--print-lints-json") {
+            // the input is replaced by `LintMetadataCallbacks`
+            let args = vec![
+                orig_args[0].clone(),
+                "lint-metadata.rs".into(),
+                "--sysroot".into(),
+                sys_root,
+            ];
+            return rustc_driver::RunCompiler::new(&args, &mut LintMetadataCallbacks).run();
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -278,36 +605,83 @@ pub fn main() {
             args.extend(vec!["--sysroot".into(), sys_root]);
         };
 
+        let clippy_tests_set = env::var("__CLIPPY_INTERNAL_TESTS").map_or(false, |val| val == "true");
+        // `clippy-driver <file>`, run without Cargo, which takes the options of Clippy as well
+        let mut clippy_options = Vec::new();
+        if !wrapper_mode && !clippy_tests_set {
+            clippy_options = take_clippy_options(&mut args);
+            add_standalone_defaults(&mut args);
+        }
+
         let mut no_deps = false;
-        let clippy_args = env::var("CLIPPY_ARGS")
-            .unwrap_or_default()
-            .split("__CLIPPY_HACKERY__")
-            .filter_map(|s| match s {
-                "" => None,
-                "--no-deps" => {
-                    no_deps = true;
-                    None
+        let mut only_lints = None;
+        let mut profile_lints = false;
+        let mut fix = false;
+        let mut fix_lints = None;
+        let mut except_lints = Vec::new();
+        let mut target_options = Vec::new();
+        let mut clippy_args = Vec::new();
+        let env_args = env::var("CLIPPY_ARGS").unwrap_or_default();
+        let mut env_args = clippy_options
+            .iter()
+            .map(String::as_str)
+            .chain(env_args.split("__CLIPPY_HACKERY__"))
+            .filter(|arg| !arg.is_empty());
+        while let Some(arg) = env_args.next() {
+            match arg {
+                "--no-deps" => no_deps = true,
+                "--profile-lints" => profile_lints = true,
+                "--fix" => fix = true,
+                "--only" => only_lints = env_args.next().map(split_lints),
+                _ if arg.starts_with("--only=") => only_lints = Some(split_lints(&arg["--only=".len()..])),
+                "--except" => except_lints.extend(env_args.next().map(split_lints).unwrap_or_default()),
+                _ if arg.starts_with("--except=") => except_lints.extend(split_lints(&arg["--except=".len()..])),
+                _ if arg.starts_with("--fix-lints=") => fix_lints = Some(split_lints(&arg["--fix-lints=".len()..])),
+                _ => match target_option(arg) {
+                    Some(option) => target_options.push(option),
+                    None => clippy_args.push(arg.to_string()),
                 },
-                _ => Some(s.to_string()),
-            })
-            .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
-            .collect::<Vec<String>>();
+            }
+        }
+        clippy_args.extend(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()]);
 
         // We enable Clippy if one of the following conditions is met
         // - IF Clippy is run on its test suite OR
         // - IF Clippy is run on the main crate, not on deps (`!cap_lints_allow`) THEN
         //    - IF `--no-deps` is not set (`!no_deps`) OR
         //    - IF `--no-deps` is set and Clippy is run on the specified primary package
-        let clippy_tests_set = env::var("__CLIPPY_INTERNAL_TESTS").map_or(false, |val| val == "true");
+        //    - AND IF the target isn't a test, an example or a bench whose kind isn't linted
+        // the relative paths of the configuration files are relative to the root file of the crate
+        let crate_dir = input_file(&args).and_then(|file| Path::new(file).parent());
+        let conf = clippy_lints::LoadedConf::load(None, crate_dir.unwrap_or_else(|| Path::new("")));
         let cap_lints_allow = arg_value(&orig_args, "--cap-lints", |val| val == "allow").is_some();
         let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
+        let target_linted = || {
+            target_kind(&args).map_or(true, |kind| {
+                let option = target_options
+                    .iter()
+                    .rev()
+                    .find(|(option_kind, _)| *option_kind == kind);
+                option.map_or_else(|| conf.is_target_linted(kind), |&(_, linted)| linted)
+            })
+        };
 
-        let clippy_enabled = clippy_tests_set || (!cap_lints_allow && (!no_deps || in_primary_package));
+        let clippy_enabled =
+            clippy_tests_set || (!cap_lints_allow && (!no_deps || in_primary_package) && target_linted());
         if clippy_enabled {
             args.extend(clippy_args);
         }
 
-        let mut clippy = ClippyCallbacks;
+        let mut clippy = ClippyCallbacks {
+            only_lints,
+            cache: cache::Cache::new(&args, conf.files()),
+            replay: false,
+            profile_lints,
+            fix: fix || fix_lints.is_some(),
+            fix_lints,
+            except_lints,
+            conf: Some(conf),
+        };
         let mut default = DefaultCallbacks;
         let callbacks: &mut (dyn rustc_driver::Callbacks + Send) =
             if clippy_enabled { &mut clippy } else { &mut default };
@@ -0,0 +1,111 @@
+//! Reports which lints `cargo clippy --fix` fixed and which are left to fix by hand, comparing the
+//! lints of `cargo check` before fixing them with the ones `cargo fix` leaves.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The options of `cargo fix` which `cargo check` doesn't accept.
+pub const FIX_OPTIONS: &[&str] = &[
+    "--allow-dirty",
+    "--allow-no-vcs",
+    "--allow-staged",
+    "--broken-code",
+    "--edition",
+    "--edition-idioms",
+];
+
+/// Counts the Clippy lints of the Cargo messages by name.
+fn lint_counts(messages: &[Value]) -> BTreeMap<&str, u64> {
+    let mut counts = BTreeMap::new();
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        if let Some(lint) = diagnostic["code"]["code"]
+            .as_str()
+            .filter(|code| code.starts_with("clippy::"))
+        {
+            *counts.entry(lint).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Formats the lints with their count, like `clippy::needless_return (2), clippy::len_zero (1)`.
+fn format_counts(counts: &BTreeMap<&str, u64>) -> String {
+    counts
+        .iter()
+        .map(|(lint, count)| format!("{} ({})", lint, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds the report of the lints fixed, the ones which are in the messages before fixing but not
+/// after, and of the lints left, which need manual attention.
+pub fn report(before: &[Value], after: &[Value]) -> String {
+    let left = lint_counts(after);
+    let fixed: BTreeMap<_, _> = lint_counts(before)
+        .into_iter()
+        .filter_map(|(lint, count)| {
+            let fixed = count.saturating_sub(left.get(lint).copied().unwrap_or(0));
+            if fixed > 0 {
+                Some((lint, fixed))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut report = String::new();
+    if fixed.is_empty() {
+        report.push_str("No lints were fixed automatically\n");
+    } else {
+        let count: u64 = fixed.values().sum();
+        report.push_str(&format!(
+            "Fixed {} lint{} automatically: {}\n",
+            count,
+            if count == 1 { "" } else { "s" },
+            format_counts(&fixed)
+        ));
+    }
+    if !left.is_empty() {
+        let count: u64 = left.values().sum();
+        report.push_str(&format!(
+            "{} {} manual attention: {}\n",
+            count,
+            if count == 1 { "lint needs" } else { "lints need" },
+            format_counts(&left)
+        ));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::report;
+    use serde_json::{json, Value};
+
+    fn lint(name: &str, line: u64) -> Value {
+        json!({
+            "reason": "compiler-message",
+            "message": { "message": "lint", "code": { "code": name }, "level": "warning", "line": line },
+        })
+    }
+
+    #[test]
+    fn reports_fixed_and_left_lints() {
+        let before = [
+            lint("clippy::needless_return", 1),
+            lint("clippy::needless_return", 2),
+            lint("clippy::too_many_arguments", 3),
+            lint("unused_variables", 4),
+        ];
+        let after = [lint("clippy::too_many_arguments", 3)];
+        assert_eq!(
+            report(&before, &after),
+            "Fixed 2 lints automatically: clippy::needless_return (2)\n\
+             1 lint needs manual attention: clippy::too_many_arguments (1)\n"
+        );
+        assert_eq!(
+            report(&after, &after).lines().next(),
+            Some("No lints were fixed automatically")
+        );
+    }
+}
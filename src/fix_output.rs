@@ -0,0 +1,265 @@
+//! Rewrites the diagnostics written by the driver for `cargo clippy --fix`.
+//!
+//! `cargo fix` applies the machine applicable suggestions of all the JSON diagnostics printed by
+//! the compiler, and fails to apply the ones replacing the same code. So the suggestions which
+//! replace code already replaced by the suggestion of another diagnostic are made `MaybeIncorrect`,
+//! the next run of `cargo fix` applying them if they still apply to the fixed code. With
+//! `cargo clippy --fix --lint <lints>`, the suggestions of the other diagnostics, of Clippy or of
+//! rustc, are made `MaybeIncorrect` too.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+
+/// The code of a file replaced by the machine applicable suggestions, which don't overlap, by the
+/// position of its start: its end and its replacement.
+type Replaced = BTreeMap<u64, (u64, String)>;
+
+/// Writes the diagnostics of the compiler to the output, the suggestions which overlap the ones
+/// written before or which aren't of the lints being fixed being made `MaybeIncorrect`.
+pub struct FixOutput {
+    /// The names of the lints whose suggestions are applied, like `clippy::manual_map` or
+    /// `unused_mut`, all of them if `None`
+    lints: Option<Vec<String>>,
+    /// The code replaced by the suggestions written so far, by file
+    replaced: HashMap<String, Replaced>,
+    output: Box<dyn Write + Send>,
+    /// The end of the output not written yet, which isn't a whole line
+    line: Vec<u8>,
+}
+
+impl FixOutput {
+    pub fn new(lints: Option<&[String]>, output: Box<dyn Write + Send>) -> Self {
+        let lints = lints.map(|lints| lints.iter().map(|lint| lint.replace('-', "_").to_lowercase()).collect());
+        Self {
+            lints,
+            replaced: HashMap::new(),
+            output,
+            line: Vec::new(),
+        }
+    }
+
+    /// Checks whether the suggestions of the diagnostic are applied, its lint being given with or
+    /// without the `clippy::` prefix.
+    fn is_fixed(&self, diagnostic: &Value) -> bool {
+        let lints = match &self.lints {
+            Some(lints) => lints,
+            None => return true,
+        };
+        diagnostic["code"]["code"].as_str().map_or(false, |code| {
+            let name = code.strip_prefix("clippy::").unwrap_or(code);
+            lints.iter().any(|lint| lint == code || lint == name)
+        })
+    }
+
+    /// Returns the line to write, which is changed if it is a diagnostic whose suggestions aren't
+    /// applied.
+    fn filter(&mut self, line: &[u8]) -> Vec<u8> {
+        match serde_json::from_slice::<Value>(line) {
+            Ok(mut diagnostic) if diagnostic["spans"].is_array() => {
+                let changed = if self.is_fixed(&diagnostic) {
+                    self.remove_overlaps(&mut diagnostic)
+                } else {
+                    make_maybe_incorrect(&mut diagnostic)
+                };
+                if !changed {
+                    return line.to_vec();
+                }
+                let mut line = diagnostic.to_string().into_bytes();
+                line.push(b'\n');
+                line
+            },
+            _ => line.to_vec(),
+        }
+    }
+
+    /// Makes the machine applicable suggestions of the diagnostic and of its children which
+    /// overlap the suggestions written before `MaybeIncorrect`, and records the code replaced by
+    /// the other ones. Returns whether a suggestion was changed.
+    fn remove_overlaps(&mut self, diagnostic: &mut Value) -> bool {
+        let mut changed = false;
+        if let Some(spans) = diagnostic["spans"].as_array_mut() {
+            // the spans of a diagnostic with machine applicable suggestions are the parts of a
+            // single suggestion
+            let parts: Option<Vec<_>> = spans
+                .iter()
+                .filter(|span| span["suggestion_applicability"] == "MachineApplicable")
+                .map(|span| {
+                    Some((
+                        span["file_name"].as_str()?.to_string(),
+                        span["byte_start"].as_u64()?,
+                        span["byte_end"].as_u64()?,
+                        span["suggested_replacement"].as_str()?.to_string(),
+                    ))
+                })
+                .collect();
+            let parts = parts.unwrap_or_default();
+            let overlaps = parts.iter().any(|(file, start, end, replacement)| {
+                self.replaced
+                    .get(file)
+                    .map_or(false, |replaced| overlaps(replaced, *start, *end, replacement))
+            });
+            if overlaps {
+                changed = make_maybe_incorrect(diagnostic);
+            } else {
+                for (file, start, end, replacement) in parts {
+                    self.replaced.entry(file).or_default().insert(start, (end, replacement));
+                }
+            }
+        }
+        if let Some(children) = diagnostic["children"].as_array_mut() {
+            for child in children {
+                changed |= self.remove_overlaps(child);
+            }
+        }
+        changed
+    }
+}
+
+/// Checks whether replacing the code from `start` to `end` overlaps the code already replaced. Two
+/// insertions at the same position overlap too, but not the same replacement of the same code,
+/// which `cargo fix` applies once.
+fn overlaps(replaced: &Replaced, start: u64, end: u64, replacement: &str) -> bool {
+    if let Some((&other_start, (other_end, other_replacement))) = replaced.range(..=start).next_back() {
+        if other_start == start {
+            return *other_end != end || other_replacement != replacement;
+        }
+        if *other_end > start {
+            return true;
+        }
+    }
+    replaced
+        .range(start + 1..)
+        .next()
+        .map_or(false, |(&other_start, _)| other_start < end)
+}
+
+/// Makes the machine applicable suggestions of the diagnostic and of its children `MaybeIncorrect`.
+/// Returns whether a suggestion was changed.
+fn make_maybe_incorrect(diagnostic: &mut Value) -> bool {
+    let mut changed = false;
+    if let Some(spans) = diagnostic["spans"].as_array_mut() {
+        for span in spans {
+            if span["suggestion_applicability"] == "MachineApplicable" {
+                span["suggestion_applicability"] = "MaybeIncorrect".into();
+                changed = true;
+            }
+        }
+    }
+    if let Some(children) = diagnostic["children"].as_array_mut() {
+        for child in children {
+            changed |= make_maybe_incorrect(child);
+        }
+    }
+    changed
+}
+
+impl Write for FixOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.line.extend_from_slice(buf);
+        // the diagnostics are written a line each, in several writes
+        while let Some(end) = self.line.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<_> = self.line.drain(..=end).collect();
+            let line = self.filter(&line);
+            self.output.write_all(&line)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let line = std::mem::take(&mut self.line);
+        self.output.write_all(&line)?;
+        self.output.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixOutput;
+    use serde_json::Value;
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    /// Records what is written.
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn diagnostic(code: &str) -> String {
+        format!(
+            r#"{{"message":"lint","code":{{"code":"{}","explanation":null}},"level":"warning","spans":[],"children":[{{"message":"try","code":null,"level":"help","spans":[{{"suggested_replacement":"x","suggestion_applicability":"MachineApplicable"}}],"children":[]}}]}}"#,
+            code
+        )
+    }
+
+    #[test]
+    fn only_the_lints_given_are_fixed() {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let mut fix_lints = FixOutput::new(Some(&["manual-map".into()]), Box::new(Output(Arc::clone(&written))));
+        let (fixed, not_fixed) = (diagnostic("clippy::manual_map"), diagnostic("unused_mut"));
+        let (start, end) = not_fixed.split_at(20);
+        write!(fix_lints, "{}\n{}", fixed, start).unwrap();
+        writeln!(fix_lints, "{}", end).unwrap();
+        fix_lints.write_all(b"not JSON\n").unwrap();
+
+        let written = String::from_utf8(written.lock().unwrap().clone()).unwrap();
+        let lines: Vec<_> = written.lines().collect();
+        assert_eq!(lines[0], fixed);
+        let not_fixed = not_fixed.replace("MachineApplicable", "MaybeIncorrect");
+        assert_eq!(
+            serde_json::from_str::<Value>(lines[1]).unwrap(),
+            serde_json::from_str::<Value>(&not_fixed).unwrap()
+        );
+        assert_eq!(lines[2], "not JSON");
+    }
+
+    fn suggestion(code: &str, start: u64, end: u64, replacement: &str) -> String {
+        format!(
+            r#"{{"message":"lint","code":{{"code":"{}","explanation":null}},"level":"warning","spans":[],"children":[{{"message":"try","code":null,"level":"help","spans":[{{"file_name":"src/lib.rs","byte_start":{},"byte_end":{},"suggested_replacement":"{}","suggestion_applicability":"MachineApplicable"}}],"children":[]}}]}}"#,
+            code, start, end, replacement
+        )
+    }
+
+    #[test]
+    fn overlapping_suggestions_are_not_applied() {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let mut output = FixOutput::new(None, Box::new(Output(Arc::clone(&written))));
+        let applied = [
+            suggestion("clippy::needless_return", 10, 20, "x"),
+            // the same replacement of the same code is applied once
+            suggestion("clippy::needless_return", 10, 20, "x"),
+            suggestion("clippy::len_zero", 20, 30, "y"),
+            suggestion("clippy::len_zero", 5, 10, "z"),
+        ];
+        let not_applied = [
+            suggestion("clippy::len_zero", 15, 25, "y"),
+            suggestion("clippy::len_zero", 0, 6, "y"),
+            suggestion("clippy::len_zero", 10, 20, "y"),
+            // an insertion at the start of replaced code
+            suggestion("clippy::len_zero", 20, 20, "y"),
+        ];
+        for line in applied.iter().chain(&not_applied) {
+            writeln!(output, "{}", line).unwrap();
+        }
+
+        let written = String::from_utf8(written.lock().unwrap().clone()).unwrap();
+        let lines: Vec<_> = written.lines().collect();
+        assert_eq!(lines[..applied.len()], applied);
+        for (line, not_applied) in lines[applied.len()..].iter().zip(&not_applied) {
+            let not_applied = not_applied.replace("MachineApplicable", "MaybeIncorrect");
+            assert_eq!(
+                serde_json::from_str::<Value>(line).unwrap(),
+                serde_json::from_str::<Value>(&not_applied).unwrap()
+            );
+        }
+    }
+}
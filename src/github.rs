@@ -0,0 +1,73 @@
+//! Prints the Clippy lints of the JSON messages of `cargo check --message-format=json` as the
+//! workflow commands of GitHub Actions, for `cargo clippy --output-format=github`, which show the
+//! lints inline on pull requests.
+
+use serde_json::Value;
+
+/// Escapes the data of a workflow command.
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a workflow command.
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Builds the workflow commands annotating the primary spans of the Clippy lints of the Cargo
+/// messages, with the rendered diagnostics as messages.
+pub fn annotations(messages: &[Value]) -> String {
+    let mut annotations = String::new();
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        let name = match diagnostic["code"]["code"].as_str() {
+            Some(code) if code.starts_with("clippy::") => code,
+            _ => continue,
+        };
+        let span = diagnostic["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        if let Some(span) = span {
+            let command = match diagnostic["level"].as_str() {
+                Some("error") => "error",
+                Some("warning") => "warning",
+                _ => "notice",
+            };
+            let message = diagnostic["rendered"]
+                .as_str()
+                .or_else(|| diagnostic["message"].as_str())
+                .unwrap_or_default();
+            annotations.push_str(&format!(
+                "::{} file={},line={},endLine={},col={},endColumn={},title={}::{}\n",
+                command,
+                escape_property(span["file_name"].as_str().unwrap_or_default()),
+                span["line_start"],
+                span["line_end"],
+                span["column_start"],
+                span["column_end"],
+                escape_property(name),
+                escape_data(message.trim_end())
+            ));
+        }
+    }
+    annotations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::annotations;
+    use crate::read_messages;
+
+    const MESSAGES: &str = r#"{"reason":"compiler-message","message":{"message":"unneeded `return` statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true}],"children":[],"rendered":"warning: unneeded `return` statement\n --> src/main.rs:2:5\n"}}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[]}}
+"#;
+
+    #[test]
+    fn annotates_the_lints() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        assert_eq!(
+            annotations(&messages),
+            "::warning file=src/main.rs,line=2,endLine=2,col=5,endColumn=14,title=clippy%3A%3Aneedless_return::\
+             warning: unneeded `return` statement%0A --> src/main.rs:2:5\n"
+        );
+    }
+}
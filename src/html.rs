@@ -0,0 +1,284 @@
+//! Renders the Clippy lints of the JSON messages of `cargo check --message-format=json` into a
+//! static HTML report, for `cargo clippy --report-html <dir>`.
+//!
+//! The report has an index of the lints and a page per file showing the code of its lints and the
+//! diffs of their suggestions. Both can be filtered by lint group.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+td, th { padding: 0.2em 0.8em; text-align: left; }
+pre { background: #f6f8fa; padding: 0.5em; overflow-x: auto; }
+mark { background: #ffd33d; }
+.lint { border-top: 1px solid #ddd; padding-top: 0.5em; }
+.error { color: #cb2431; }
+.warning { color: #b08800; }
+.line-number { color: #999; user-select: none; }
+.removed { background: #ffeef0; }
+.added { background: #e6ffed; }
+[hidden] { display: none !important; }";
+
+/// Hides the lints of the groups which are unchecked.
+const SCRIPT: &str = "for (const filter of document.querySelectorAll('input[data-filter]')) {
+    filter.addEventListener('change', () => {
+        for (const lint of document.querySelectorAll(`[data-group='${filter.dataset.filter}']`)) {
+            lint.hidden = !filter.checked;
+        }
+    });
+}";
+
+/// A Clippy lint of the report.
+struct Lint<'a> {
+    name: &'a str,
+    group: &'a str,
+    diagnostic: &'a Value,
+    /// The primary span of the lint
+    span: &'a Value,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The name of the page of a file.
+fn file_page(file: &str) -> String {
+    let name: String = file
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{}.html", name)
+}
+
+/// Splits a line of code of a span before and after its highlighted code, whose columns count the
+/// characters from 1.
+fn split_line(line: &Value) -> (String, String, String) {
+    let text = line["text"].as_str().unwrap_or_default();
+    let column = |key: &str| {
+        let column = line[key].as_u64().and_then(|column| usize::try_from(column).ok());
+        column.unwrap_or(1).saturating_sub(1)
+    };
+    let (start, end) = (column("highlight_start"), column("highlight_end"));
+    (
+        text.chars().take(start).collect(),
+        text.chars().skip(start).take(end.saturating_sub(start)).collect(),
+        text.chars().skip(end).collect(),
+    )
+}
+
+/// The lines of code of a span, as given by the compiler.
+fn span_lines(span: &Value) -> &[Value] {
+    span["text"].as_array().map_or(&[][..], Vec::as_slice)
+}
+
+/// Renders the lines of a span with their number, highlighting the code of the span.
+fn snippet(span: &Value) -> String {
+    let first_line = span["line_start"].as_u64().unwrap_or(1);
+    let mut html = String::from("<pre>");
+    for (line_number, line) in (first_line..).zip(span_lines(span)) {
+        let (before, highlight, after) = split_line(line);
+        html.push_str(&format!(
+            "<span class=\"line-number\">{:>4}</span>  {}<mark>{}</mark>{}\n",
+            line_number,
+            escape(&before),
+            escape(&highlight),
+            escape(&after)
+        ));
+    }
+    html.push_str("</pre>");
+    html
+}
+
+/// Renders the diff of the code of a span replaced by a suggestion.
+fn diff(span: &Value) -> Option<String> {
+    let replacement = span["suggested_replacement"].as_str()?;
+    let lines = span_lines(span);
+    let (prefix, _, _) = split_line(lines.first()?);
+    let (_, _, suffix) = split_line(lines.last()?);
+
+    let mut html = String::from("<pre>");
+    for line in lines {
+        let text = line["text"].as_str().unwrap_or_default();
+        html.push_str(&format!("<span class=\"removed\">- {}</span>\n", escape(text)));
+    }
+    for line in format!("{}{}{}", prefix, replacement, suffix).lines() {
+        html.push_str(&format!("<span class=\"added\">+ {}</span>\n", escape(line)));
+    }
+    html.push_str("</pre>");
+    Some(html)
+}
+
+/// Renders the suggestions of a lint as diffs, with the help messages giving them.
+fn suggestions(diagnostic: &Value) -> String {
+    let children = diagnostic["children"].as_array().map_or(&[][..], Vec::as_slice);
+    let mut html = String::new();
+    for child in children {
+        let diffs: Vec<_> = child["spans"]
+            .as_array()
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter_map(diff)
+            .collect();
+        if !diffs.is_empty() {
+            html.push_str(&format!(
+                "<p>{}: {}</p>{}",
+                escape(child["level"].as_str().unwrap_or("help")),
+                escape(child["message"].as_str().unwrap_or_default()),
+                diffs.concat()
+            ));
+        }
+    }
+    html
+}
+
+/// Renders a page with the filters of the lint groups.
+fn page(title: &str, groups: &[&str], body: &str) -> String {
+    let filters: String = groups
+        .iter()
+        .map(|group| {
+            format!(
+                "<label><input type=\"checkbox\" data-filter=\"{0}\" checked> {0}</label> ",
+                escape(group)
+            )
+        })
+        .collect();
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>\n{style}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n<p>Lint groups: {filters}</p>\n\
+         {body}\n<script>\n{script}\n</script>\n</body>\n</html>\n",
+        title = escape(title),
+        style = STYLE,
+        filters = filters,
+        body = body,
+        script = SCRIPT
+    )
+}
+
+/// Renders the pages of the report of the Clippy lints of the Cargo messages, by file name. The
+/// groups of the lints are found in their metadata.
+pub fn render(messages: &[Value], lints: &[Value]) -> Vec<(String, String)> {
+    let mut files: BTreeMap<&str, Vec<Lint<'_>>> = BTreeMap::new();
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        let name = match diagnostic["code"]["code"].as_str() {
+            Some(code) if code.starts_with("clippy::") => code,
+            _ => continue,
+        };
+        let span = diagnostic["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        if let Some(span) = span {
+            let short_name = name.trim_start_matches("clippy::");
+            let group = lints
+                .iter()
+                .find(|lint| lint["name"] == short_name)
+                .and_then(|lint| lint["group"].as_str())
+                .unwrap_or("unknown");
+            let file = span["file_name"].as_str().unwrap_or_default();
+            files.entry(file).or_default().push(Lint {
+                name,
+                group,
+                diagnostic,
+                span,
+            });
+        }
+    }
+    let mut groups: Vec<_> = files.values().flatten().map(|lint| lint.group).collect();
+    groups.sort_unstable();
+    groups.dedup();
+
+    let mut pages = Vec::new();
+    let mut index = String::from("<table>\n<tr><th>File</th><th>Line</th><th>Lint</th><th>Message</th></tr>\n");
+    for (file, file_lints) in &files {
+        let mut body = String::from("<p><a href=\"index.html\">All the lints</a></p>\n");
+        for lint in file_lints {
+            let level = lint.diagnostic["level"].as_str().unwrap_or_default();
+            let message = escape(lint.diagnostic["message"].as_str().unwrap_or_default());
+            let line = lint.span["line_start"].as_u64().unwrap_or_default();
+            let id = format!("{}-{}", line, lint.name.trim_start_matches("clippy::"));
+            index.push_str(&format!(
+                "<tr data-group=\"{group}\"><td><a href=\"{page}#{id}\">{file}</a></td><td>{line}</td>\
+                 <td class=\"{level}\">{name}</td><td>{message}</td></tr>\n",
+                group = escape(lint.group),
+                page = file_page(file),
+                id = id,
+                file = escape(file),
+                line = line,
+                level = escape(level),
+                name = escape(lint.name),
+                message = message
+            ));
+            body.push_str(&format!(
+                "<div class=\"lint\" id=\"{id}\" data-group=\"{group}\">\n<h3 class=\"{level}\">{level}: {message}</h3>\n\
+                 <p><a href=\"https://rust-lang.github.io/rust-clippy/master/index.html#{short_name}\">{name}</a> \
+                 ({group}), line {line}</p>\n{snippet}\n{suggestions}\n</div>\n",
+                id = id,
+                group = escape(lint.group),
+                level = escape(level),
+                message = message,
+                short_name = escape(lint.name.trim_start_matches("clippy::")),
+                name = escape(lint.name),
+                line = line,
+                snippet = snippet(lint.span),
+                suggestions = suggestions(lint.diagnostic)
+            ));
+        }
+        pages.push((file_page(file), page(file, &groups, &body)));
+    }
+    index.push_str("</table>");
+
+    let count: usize = files.values().map(Vec::len).sum();
+    let title = format!("Clippy report: {} lints in {} files", count, files.len());
+    pages.insert(0, ("index.html".to_string(), page(&title, &groups, &index)));
+    pages
+}
+
+/// Writes the HTML report of the Clippy lints of the Cargo messages in a directory, returning the
+/// path of its index.
+pub fn write(dir: &Path, messages: &[Value], lints: &[Value]) -> io::Result<String> {
+    fs::create_dir_all(dir)?;
+    for (name, content) in render(messages, lints) {
+        fs::write(dir.join(name), content)?;
+    }
+    Ok(dir.join("index.html").display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::read_messages;
+    use serde_json::json;
+
+    const MESSAGES: &str = r#"{"reason":"compiler-message","message":{"message":"unneeded `return` statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"text":[{"text":"    return 1;","highlight_start":5,"highlight_end":14}],"suggested_replacement":null}],"children":[{"message":"remove `return`","code":null,"level":"help","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"text":[{"text":"    return 1;","highlight_start":5,"highlight_end":14}],"suggested_replacement":"1"}],"children":[]}]}}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[]}}
+"#;
+
+    #[test]
+    fn renders_index_and_file_pages() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        let lints = [json!({ "name": "needless_return", "group": "style" })];
+        let pages = render(&messages, &lints);
+        assert_eq!(pages.len(), 2);
+
+        let (name, index) = &pages[0];
+        assert_eq!(name, "index.html");
+        assert!(index.contains("Clippy report: 1 lints in 1 files"));
+        assert!(index.contains(r#"<tr data-group="style"><td><a href="src_main_rs.html#2-needless_return">"#));
+        assert!(index.contains(r#"data-filter="style""#));
+        assert!(!index.contains("unused_variables"));
+
+        let (name, file) = &pages[1];
+        assert_eq!(name, "src_main_rs.html");
+        assert!(file.contains("    <mark>return 1;</mark>"));
+        assert!(file.contains("help: remove `return`"));
+        assert!(file.contains("<span class=\"removed\">-     return 1;</span>"));
+        assert!(file.contains("<span class=\"added\">+     1</span>"));
+    }
+}
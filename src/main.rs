@@ -3,10 +3,24 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 use rustc_tools_util::VersionInfo;
+use serde_json::Value;
 use std::env;
 use std::ffi::OsString;
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use std::process::{self, Command};
+use std::process::{self, Command, Stdio};
+
+mod baseline;
+mod diff;
+mod fix;
+mod github;
+mod html;
+mod parallel;
+mod sarif;
+mod short;
+mod stats;
+mod watch;
+mod xml;
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -16,6 +30,30 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+        --fix                Apply the machine applicable suggestions of the lints, see
+                             `cargo fix` for the other options like `--allow-dirty`
+        --lint <LINTS>       Only apply the suggestions of the lints with `--fix`, separated
+                             by commas, eg. `--fix --lint clippy::manual_map`
+        --message-format=sarif
+                             Print the lints as a SARIF 2.1 report
+        --message-format=short
+                             Print a line per lint, with the name of the lint
+        --output-format=<FORMAT>
+                             Print the lints as a `checkstyle`, `junit` or `sarif` report, or
+                             as `github` workflow commands annotating them
+        --explain <LINT>     Print the documentation of a lint
+        --baseline <FILE>    Only report the lints which aren't in the baseline file
+        --update-baseline    Record the lints in the baseline file instead
+        --diff-base <REV>    Only report the lints on the lines changed since the git revision
+        --fail-on <GROUPS>   Deny the lints of the groups, separated by commas, and only fail on
+                             them, eg. `--fail-on correctness,perf`
+        --parallel-members   Lint the members of the workspace concurrently, printing the lints
+                             member by member
+        --report-html <DIR>  Write an HTML report of the lints in the directory
+        --stats[=json]       Print the count of the lints by lint and by crate at the end, as
+                             a table or as JSON
+        --watch              Lint again whenever the sources change, only printing the new
+                             lints
 
 Other options are the same as `cargo check`.
 
@@ -26,6 +64,10 @@ with:
     -A --allow OPT      Set lint allowed
     -D --deny OPT       Set lint denied
     -F --forbid OPT     Set lint forbidden
+       --except LINTS   Allow the lints, separated by commas, whatever the groups
+                        given before or after them, eg. `-D clippy::pedantic --except
+                        clippy::module_name_repetitions`
+       --only LINTS     Only run the passes emitting the lints, separated by commas
 
 You can use tool lints to allow or deny lints from your code, eg.:
 
@@ -58,23 +100,88 @@ pub fn main() {
     }
 }
 
+/// How the messages are printed when they are read from the JSON messages of Cargo.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MessageFormat {
+    Rendered,
+    Json,
+    Sarif,
+    /// A line per diagnostic, with the name of its lint
+    Short,
+    Checkstyle,
+    Junit,
+    Github,
+}
+
+impl MessageFormat {
+    /// Parses the format of `--output-format`.
+    fn from_output_format(format: &str) -> Self {
+        match format {
+            "checkstyle" => Self::Checkstyle,
+            "junit" => Self::Junit,
+            "github" => Self::Github,
+            "sarif" => Self::Sarif,
+            _ => panic!(
+                "Unknown `--output-format` `{}`, expected `checkstyle`, `github`, `junit` or `sarif`",
+                format
+            ),
+        }
+    }
+
+    /// Checks whether the lints are printed as a report rather than as messages.
+    fn is_report(self) -> bool {
+        !matches!(self, Self::Rendered | Self::Json | Self::Short)
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
 struct ClippyCmd {
     unstable_options: bool,
+    verbose: bool,
+    message_format: MessageFormat,
+    baseline: Option<String>,
+    update_baseline: bool,
+    /// The git revision whose changes are linted
+    diff_base: Option<String>,
+    /// The lint groups failing the build, without the `clippy::` prefix
+    fail_on: Option<Vec<String>>,
+    /// Lints the members of the workspace concurrently
+    parallel_members: bool,
+    /// The directory of the HTML report
+    report_html: Option<String>,
+    /// Prints the counts of the lints at the end, as a table or as JSON
+    stats: Option<MessageFormat>,
+    /// Lints again whenever the sources change
+    watch: bool,
+    explain: Option<String>,
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
 }
 
 impl ClippyCmd {
+    #[allow(clippy::too_many_lines)]
     fn new<I>(mut old_args: I) -> Self
     where
         I: Iterator<Item = String>,
     {
         let mut cargo_subcommand = "check";
         let mut unstable_options = false;
+        let mut verbose = false;
+        let mut report_format = None;
+        let mut baseline = None;
+        let mut update_baseline = false;
+        let mut diff_base = None;
+        let mut fail_on = None;
+        let mut parallel_members = false;
+        let mut report_html = None;
+        let mut stats = None;
+        let mut watch = false;
+        let mut fix_lints = None;
+        let mut explain = None;
         let mut args = vec![];
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
@@ -83,16 +190,129 @@ impl ClippyCmd {
                 "--" => break,
                 // Cover -Zunstable-options and -Z unstable-options
                 s if s.ends_with("unstable-options") => unstable_options = true,
+                "-v" | "-vv" | "--verbose" => verbose = true,
+                // the SARIF report is built from the JSON messages
+                "--message-format=sarif" => {
+                    report_format = Some(MessageFormat::Sarif);
+                    args.push("--message-format=json".into());
+                    continue;
+                },
+                "sarif" if args.last().map(String::as_str) == Some("--message-format") => {
+                    report_format = Some(MessageFormat::Sarif);
+                    args.push("json".into());
+                    continue;
+                },
+                // the short format of Cargo doesn't give the names of the lints
+                "--message-format=short" => {
+                    report_format = Some(MessageFormat::Short);
+                    args.push("--message-format=json".into());
+                    continue;
+                },
+                "short" if args.last().map(String::as_str) == Some("--message-format") => {
+                    report_format = Some(MessageFormat::Short);
+                    args.push("json".into());
+                    continue;
+                },
+                "--output-format" => {
+                    report_format = old_args.next().as_deref().map(MessageFormat::from_output_format);
+                    continue;
+                },
+                s if s.starts_with("--output-format=") => {
+                    report_format = Some(MessageFormat::from_output_format(&s["--output-format=".len()..]));
+                    continue;
+                },
+                "--baseline" => {
+                    baseline = old_args.next();
+                    continue;
+                },
+                s if s.starts_with("--baseline=") => {
+                    baseline = Some(s["--baseline=".len()..].to_string());
+                    continue;
+                },
+                "--update-baseline" => {
+                    update_baseline = true;
+                    continue;
+                },
+                "--diff-base" => {
+                    diff_base = old_args.next();
+                    continue;
+                },
+                s if s.starts_with("--diff-base=") => {
+                    diff_base = Some(s["--diff-base=".len()..].to_string());
+                    continue;
+                },
+                "--fail-on" => {
+                    fail_on = old_args.next().map(|groups| split_groups(&groups));
+                    continue;
+                },
+                s if s.starts_with("--fail-on=") => {
+                    fail_on = Some(split_groups(&s["--fail-on=".len()..]));
+                    continue;
+                },
+                "--parallel-members" => {
+                    parallel_members = true;
+                    continue;
+                },
+                "--report-html" => {
+                    report_html = old_args.next();
+                    continue;
+                },
+                s if s.starts_with("--report-html=") => {
+                    report_html = Some(s["--report-html=".len()..].to_string());
+                    continue;
+                },
+                "--stats" => {
+                    stats = Some(MessageFormat::Rendered);
+                    continue;
+                },
+                "--stats=json" => {
+                    stats = Some(MessageFormat::Json);
+                    continue;
+                },
+                "--watch" => {
+                    watch = true;
+                    continue;
+                },
+                "--lint" => {
+                    fix_lints = old_args.next();
+                    continue;
+                },
+                s if s.starts_with("--lint=") => {
+                    fix_lints = Some(s["--lint=".len()..].to_string());
+                    continue;
+                },
+                "--explain" => {
+                    explain = old_args.next();
+                    continue;
+                },
+                s if s.starts_with("--explain=") => {
+                    explain = Some(s["--explain=".len()..].to_string());
+                    continue;
+                },
                 _ => {},
             }
 
             args.push(arg);
         }
 
-        if cargo_subcommand == "fix" && !unstable_options {
-            panic!("Usage of `--fix` requires `-Z unstable-options`");
+        if update_baseline && baseline.is_none() {
+            panic!("Usage of `--update-baseline` requires `--baseline <FILE>`");
         }
 
+        // the options need the JSON messages, which are printed in the format asked for
+        let json_option = baseline
+            .as_ref()
+            .map(|_| "--baseline")
+            .or_else(|| diff_base.as_ref().map(|_| "--diff-base"))
+            .or_else(|| fail_on.as_ref().map(|_| "--fail-on"))
+            .or_else(|| parallel_members.then(|| "--parallel-members"))
+            .or_else(|| report_html.as_ref().map(|_| "--report-html"))
+            .or_else(|| stats.map(|_| "--stats"))
+            .or_else(|| watch.then(|| "--watch"))
+            .or_else(|| (cargo_subcommand == "fix").then(|| "--fix"));
+        let json_option = json_option.or_else(|| report_format.map(|_| "--output-format"));
+        let message_format = message_format(&mut args, report_format, json_option);
+
         // Run the dogfood tests directly on nightly cargo. This is required due
         // to a bug in rustup.rs when running cargo on custom toolchains. See issue #3118.
         if env::var_os("CLIPPY_DOGFOOD").is_some() && cfg!(windows) {
@@ -100,12 +320,47 @@ impl ClippyCmd {
         }
 
         let mut clippy_args: Vec<String> = old_args.collect();
-        if cargo_subcommand == "fix" && !clippy_args.iter().any(|arg| arg == "--no-deps") {
+        // the lints of the groups can still be allowed by the arguments given after them
+        if let Some(groups) = &fail_on {
+            let deny = groups
+                .iter()
+                .flat_map(|group| vec!["-D".into(), format!("clippy::{}", group)]);
+            clippy_args.splice(0..0, deny);
+        }
+        if parallel_members && cargo_subcommand == "fix" {
+            panic!("Usage of `--parallel-members` requires not to use `--fix`");
+        }
+        if watch && cargo_subcommand == "fix" {
+            panic!("Usage of `--watch` requires not to use `--fix`");
+        }
+        // the driver makes the overlapping suggestions and the ones of the other lints not machine
+        // applicable
+        if let Some(lints) = fix_lints {
+            if cargo_subcommand != "fix" {
+                panic!("Usage of `--lint` requires `--fix`");
+            }
+            clippy_args.push(format!("--fix-lints={}", lints));
+        } else if cargo_subcommand == "fix" {
+            clippy_args.push("--fix".into());
+        }
+        // each member is linted without its dependencies
+        if (cargo_subcommand == "fix" || parallel_members) && !clippy_args.iter().any(|arg| arg == "--no-deps") {
             clippy_args.push("--no-deps".into());
         }
 
         ClippyCmd {
             unstable_options,
+            verbose,
+            message_format,
+            baseline,
+            update_baseline,
+            diff_base,
+            fail_on,
+            parallel_members,
+            report_html,
+            stats,
+            watch,
+            explain,
             cargo_subcommand,
             args,
             clippy_args,
@@ -113,7 +368,8 @@ impl ClippyCmd {
     }
 
     fn path_env(&self) -> &'static str {
-        if self.unstable_options {
+        // `cargo fix` wraps rustc with `RUSTC_WRAPPER` itself
+        if self.unstable_options || self.cargo_subcommand == "fix" {
             "RUSTC_WORKSPACE_WRAPPER"
         } else {
             "RUSTC_WRAPPER"
@@ -148,7 +404,22 @@ impl ClippyCmd {
             .map(|p| ("CARGO_TARGET_DIR", p))
     }
 
-    fn into_std_cmd(self) -> Command {
+    fn std_cmd(&self) -> Command {
+        self.cargo_cmd(self.cargo_subcommand, &self.args)
+    }
+
+    /// The `cargo check` command listing the lints before and after `--fix` fixes them.
+    fn check_fix_cmd(&self) -> Command {
+        let args: Vec<_> = self
+            .args
+            .iter()
+            .filter(|arg| !fix::FIX_OPTIONS.contains(&arg.as_str()))
+            .cloned()
+            .collect();
+        self.cargo_cmd("check", &args)
+    }
+
+    fn cargo_cmd(&self, cargo_subcommand: &str, args: &[String]) -> Command {
         let mut cmd = Command::new("cargo");
         let clippy_args: String = self
             .clippy_args
@@ -159,43 +430,403 @@ impl ClippyCmd {
         cmd.env(self.path_env(), Self::path())
             .envs(ClippyCmd::target_dir())
             .env("CLIPPY_ARGS", clippy_args)
-            .arg(self.cargo_subcommand)
-            .args(&self.args);
+            .arg(cargo_subcommand)
+            .args(args);
+
+        // Lets the driver show which configuration files are used
+        if self.verbose {
+            cmd.env("CLIPPY_VERBOSE", "true");
+        }
 
         cmd
     }
 }
 
+/// Splits the lint groups given to `--fail-on`, like `correctness,clippy::perf`.
+fn split_groups(groups: &str) -> Vec<String> {
+    groups
+        .split(',')
+        .map(|group| group.trim().trim_start_matches("clippy::").to_string())
+        .filter(|group| !group.is_empty())
+        .collect()
+}
+
+/// Checks whether an error of the compiler fails the build with `--fail-on`, which the lints of
+/// Clippy only do if they are in one of the groups.
+fn fails_on(message: &Value, groups: &[String], lints: &[Value]) -> bool {
+    let lint = diagnostic(message)
+        .and_then(|diagnostic| diagnostic["code"]["code"].as_str())
+        .and_then(|code| code.strip_prefix("clippy::"));
+    lint.map_or(true, |name| {
+        let group = lints
+            .iter()
+            .find(|lint| lint["name"] == name)
+            .map(|lint| &lint["group"]);
+        group.map_or(true, |group| groups.iter().any(|failing| group == failing))
+    })
+}
+
+/// Returns how the messages are printed with the arguments of Cargo, asking Cargo for JSON messages
+/// if they are printed as a report or if an option of `cargo clippy` needs them.
+fn message_format(
+    args: &mut Vec<String>,
+    report_format: Option<MessageFormat>,
+    json_option: Option<&str>,
+) -> MessageFormat {
+    let message_format = args
+        .iter()
+        .zip(args.iter().skip(1))
+        .find(|(arg, _)| *arg == "--message-format")
+        .map(|(_, format)| format.as_str())
+        .or_else(|| args.iter().find_map(|arg| arg.strip_prefix("--message-format=")));
+    if message_format.map_or(false, |format| format.starts_with("json")) {
+        report_format.unwrap_or(MessageFormat::Json)
+    } else {
+        if let Some(option) = json_option {
+            if message_format.is_some() {
+                panic!(
+                    "Usage of `{}` requires the default, `json`, `sarif` or `short` message format",
+                    option
+                );
+            }
+            args.push("--message-format=json".into());
+        }
+        report_format.unwrap_or(MessageFormat::Rendered)
+    }
+}
+
+/// Reads the JSON messages printed by Cargo, skipping the duplicated diagnostics emitted for
+/// several targets of a package.
+fn read_messages(reader: impl BufRead) -> Vec<Value> {
+    let mut messages: Vec<Value> = Vec::new();
+    for line in reader.lines().filter_map(Result::ok) {
+        if let Ok(message) = serde_json::from_str::<Value>(&line) {
+            let is_duplicate =
+                diagnostic(&message).map_or(false, |new| messages.iter().any(|other| diagnostic(other) == Some(new)));
+            if !is_duplicate {
+                messages.push(message);
+            }
+        }
+    }
+    messages
+}
+
+/// Returns the diagnostic of a Cargo message emitted by the compiler.
+fn diagnostic(message: &Value) -> Option<&Value> {
+    if message["reason"] == "compiler-message" {
+        Some(&message["message"])
+    } else {
+        None
+    }
+}
+
+/// Checks whether a Cargo message is an error of the compiler, not counting the summaries like
+/// `aborting due to previous error`, which have no span.
+fn is_error(message: &Value) -> bool {
+    diagnostic(message).map_or(false, |diagnostic| {
+        diagnostic["level"] == "error" && diagnostic["spans"].as_array().map_or(false, |spans| !spans.is_empty())
+    })
+}
+
+/// Checks whether a Cargo message is an error of the compiler which isn't a lint, like a type error
+/// or a failure to link, which fails the build whatever the lints filtered out, not counting the
+/// summaries like `aborting due to previous error`.
+fn is_hard_error(message: &Value) -> bool {
+    diagnostic(message).map_or(false, |diagnostic| {
+        // the lints have their name as code, the other errors have no code or one like `E0308`
+        let is_lint = diagnostic["code"]["code"].as_str().map_or(false, |code| {
+            let number = code.strip_prefix('E');
+            !number.map_or(false, |number| {
+                number.len() == 4 && number.bytes().all(|c| c.is_ascii_digit())
+            })
+        });
+        let is_summary = diagnostic["message"]
+            .as_str()
+            .map_or(false, |message| message.starts_with("aborting due to"));
+        diagnostic["level"] == "error" && !is_lint && !is_summary
+    })
+}
+
+/// Prints the Cargo messages as asked by `--message-format`, the diagnostics being rendered
+/// unless JSON is asked for.
+fn print_messages(messages: &[Value], json_output: bool) {
+    for message in messages {
+        if json_output {
+            println!("{}", message);
+        } else if let Some(rendered) = diagnostic(message).and_then(|diagnostic| diagnostic["rendered"].as_str()) {
+            // the summaries count the lints of the baseline too
+            if !rendered.starts_with("error: aborting due to") && !rendered.trim_end().ends_with("emitted") {
+                eprint!("{}", rendered);
+            }
+        }
+    }
+}
+
+/// Filters the lints of the Cargo messages with the baseline file, or records them in it. Returns
+/// whether an error is left which isn't in the baseline.
+fn apply_baseline(messages: &mut Vec<Value>, path: &str, update: bool) -> Result<bool, i32> {
+    if update {
+        match baseline::write(path.as_ref(), messages) {
+            Ok(count) => eprintln!("{} lints recorded in the baseline `{}`", count, path),
+            Err(error) => {
+                eprintln!("error: could not write the baseline `{}`: {}", path, error);
+                return Err(1);
+            },
+        }
+        messages.retain(|message| !baseline::is_recorded(message));
+    } else {
+        match baseline::read(path.as_ref()) {
+            Ok(known) => {
+                let count = baseline::remove_known(messages, known);
+                eprintln!("{} lints of the baseline `{}` not reported", count, path);
+            },
+            Err(error) => {
+                eprintln!("error: could not read the baseline `{}`: {}", path, error);
+                return Err(1);
+            },
+        }
+    }
+    Ok(messages.iter().any(is_error))
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let cmd = ClippyCmd::new(old_args);
+    if cmd.watch {
+        watch::run(|reported| lint(&cmd, Some(reported)));
+    }
+    lint(&cmd, None)
+}
+
+/// Runs Cargo as asked by the command, leaving out the lints reported by the previous run of
+/// `--watch`.
+#[allow(clippy::too_many_lines)]
+fn lint(cmd: &ClippyCmd, mut reported: Option<&mut watch::Reported>) -> Result<(), i32> {
+    let message_format = cmd.message_format;
+    let baseline = cmd.baseline.clone();
+    let update_baseline = cmd.update_baseline;
+    let stats_format = cmd.stats;
+    let fail_on = cmd.fail_on.clone();
+    let report_html = cmd.report_html.clone();
+    let changed_lines = match &cmd.diff_base {
+        Some(base) => match diff::changed_lines(base) {
+            Ok(changed_lines) => Some(changed_lines),
+            Err(error) => {
+                eprintln!("error: could not find the changes since `{}`: {}", base, error);
+                return Err(1);
+            },
+        },
+        None => None,
+    };
+    // the groups of the lints are found in their metadata
+    let lints = if message_format == MessageFormat::Sarif || fail_on.is_some() || report_html.is_some() {
+        sarif::lint_metadata(&ClippyCmd::path())
+    } else {
+        Vec::new()
+    };
+    for group in fail_on.iter().flatten() {
+        if !lints.iter().any(|lint| lint["group"] == *group) {
+            eprintln!("error: unknown lint group given to `--fail-on`: `{}`", group);
+            return Err(1);
+        }
+    }
+
+    // the driver prints the schema of the configuration, the list of the lints and their
+    // documentation without building anything
+    let print_flag = cmd
+        .clippy_args
+        .iter()
+        .find(|arg| *arg == "--print-config-schema" || *arg == "--print-lints-json");
+    let driver_args = if let Some(flag) = print_flag {
+        Some(vec![flag.clone()])
+    } else {
+        cmd.explain
+            .as_ref()
+            .map(|lint| vec!["--explain".to_string(), lint.clone()])
+    };
+    let fix = cmd.cargo_subcommand == "fix" && driver_args.is_none();
+    let parallel = if cmd.parallel_members && driver_args.is_none() {
+        match parallel::command(&cmd.args, |args| cmd.cargo_cmd("check", args)) {
+            Ok(parallel) => Some(parallel),
+            Err(error) => {
+                eprintln!("error: could not find the members of the workspace: {}", error);
+                return Err(1);
+            },
+        }
+    } else {
+        None
+    };
+    let mut before_fix = Vec::new();
+    let mut cmd = match driver_args {
+        Some(driver_args) => {
+            let mut driver = Command::new(ClippyCmd::path());
+            driver.args(driver_args);
+            driver
+        },
+        // the lints are listed before and after fixing them, to report which ones were fixed, the
+        // ones printed by `cargo fix` being the ones before its last fixes
+        None if fix => {
+            let output = cmd
+                .check_fix_cmd()
+                .stderr(Stdio::inherit())
+                .output()
+                .expect("could not run cargo");
+            before_fix = read_messages(&output.stdout[..]);
+
+            let status = cmd
+                .cargo_cmd(cmd.cargo_subcommand, &cmd.args)
+                .stdout(Stdio::null())
+                .status()
+                .expect("could not run cargo");
+            if !status.success() {
+                return Err(status.code().unwrap_or(-1));
+            }
+            cmd.check_fix_cmd()
+        },
+        None => cmd.std_cmd(),
+    };
+
+    let capture = message_format.is_report()
+        || message_format == MessageFormat::Short
+        || baseline.is_some()
+        || changed_lines.is_some()
+        || fail_on.is_some()
+        || report_html.is_some()
+        || stats_format.is_some()
+        || reported.is_some()
+        || fix;
+    let (messages, status) = if let Some((command, members)) = parallel {
+        let (messages, status) = parallel::run(command, &members);
+        (Some(messages), status)
+    } else {
+        if capture {
+            cmd.stdout(Stdio::piped());
+        }
+        let mut child = cmd.spawn().expect("could not run cargo");
+        let messages = child.stdout.take().map(|stdout| read_messages(BufReader::new(stdout)));
+        let exit_status = child.wait().expect("failed to wait for cargo?");
+        let status = if exit_status.success() {
+            Ok(())
+        } else {
+            Err(exit_status.code().unwrap_or(-1))
+        };
+        (messages, status)
+    };
+
+    // the errors which are all lints in the baseline, on unchanged lines or in the groups not given
+    // to `--fail-on` don't fail the build
+    let mut allowed_errors_only = false;
+    let mut fix_report = None;
+    let mut stats_report = None;
+    if let Some(mut messages) = messages {
+        if fix {
+            fix_report = Some(fix::report(&before_fix, &messages));
+        }
+        let has_errors = messages.iter().any(is_error);
+        let has_hard_errors = messages.iter().any(is_hard_error);
+        let mut has_failing_errors = has_errors;
+        if let Some(path) = &baseline {
+            has_failing_errors = apply_baseline(&mut messages, path, update_baseline)?;
+        }
+        if let Some(changed_lines) = &changed_lines {
+            let count = diff::remove_unchanged(&mut messages, changed_lines);
+            eprintln!("{} lints on unchanged lines not reported", count);
+            has_failing_errors = messages.iter().any(is_error);
+        }
+        if let Some(groups) = &fail_on {
+            has_failing_errors = messages
+                .iter()
+                .any(|message| is_error(message) && fails_on(message, groups, &lints));
+        }
+        allowed_errors_only = has_errors && !has_failing_errors && !has_hard_errors;
+        let watch_summary = reported
+            .as_mut()
+            .map(|reported| reported.remove_reported(&mut messages));
+
+        match message_format {
+            MessageFormat::Sarif => {
+                let report = sarif::report(&messages, &lints);
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&report).expect("the report can be serialized")
+                );
+            },
+            MessageFormat::Short => eprint!("{}", short::lines(&messages)),
+            MessageFormat::Checkstyle => print!("{}", xml::checkstyle(&messages)),
+            MessageFormat::Junit => print!("{}", xml::junit(&messages)),
+            // the annotations are shown in the log too
+            MessageFormat::Github => {
+                print_messages(&messages, false);
+                print!("{}", github::annotations(&messages));
+            },
+            MessageFormat::Rendered | MessageFormat::Json => {
+                print_messages(&messages, message_format == MessageFormat::Json)
+            },
+        }
+        if let Some(summary) = watch_summary {
+            eprintln!("{}", summary);
+        }
+
+        if let Some(dir) = &report_html {
+            match html::write(dir.as_ref(), &messages, &lints) {
+                Ok(index) => eprintln!("HTML report written to `{}`", index),
+                Err(error) => {
+                    eprintln!("error: could not write the HTML report in `{}`: {}", dir, error);
+                    return Err(1);
+                },
+            }
+        }
 
-    let mut cmd = cmd.into_std_cmd();
+        // the lints of the baseline aren't counted
+        stats_report = stats_format.map(|format| stats::report(&messages, format == MessageFormat::Json));
+    }
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    if let Some(report) = fix_report {
+        eprint!("{}", report);
+    }
+    if let Some(report) = stats_report {
+        // the report is the only output of `--output-format`
+        if stats_format == Some(MessageFormat::Json) && !message_format.is_report() {
+            print!("{}", report);
+        } else {
+            eprint!("{}", report);
+        }
+    }
 
-    if exit_status.success() {
+    if allowed_errors_only {
         Ok(())
     } else {
-        Err(exit_status.code().unwrap_or(-1))
+        status
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ClippyCmd;
+    use super::{is_hard_error, ClippyCmd, MessageFormat};
+    use serde_json::json;
 
     #[test]
-    #[should_panic]
     fn fix_without_unstable() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let _ = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert_eq!("RUSTC_WORKSPACE_WRAPPER", cmd.path_env());
+        assert_eq!(MessageFormat::Rendered, cmd.message_format);
+        assert!(cmd.args.iter().any(|arg| arg == "--message-format=json"));
+    }
+
+    #[test]
+    fn fix_allow_dirty() {
+        let args = "cargo clippy --fix --allow-dirty"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.args.iter().any(|arg| arg == "--allow-dirty"));
+        let check = format!("{:?}", cmd.check_fix_cmd());
+        assert!(check.contains(r#""check""#));
+        assert!(!check.contains("--allow-dirty"));
     }
 
     #[test]
@@ -216,6 +847,7 @@ mod tests {
             .map(ToString::to_string);
         let cmd = ClippyCmd::new(args);
         assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "--fix"));
     }
 
     #[test]
@@ -227,6 +859,243 @@ mod tests {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn fix_lint() {
+        let args = "cargo clippy --fix --lint clippy::manual_map,needless_return"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+        assert!(cmd
+            .clippy_args
+            .iter()
+            .any(|arg| arg == "--fix-lints=clippy::manual_map,needless_return"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn lint_without_fix() {
+        let args = "cargo clippy --lint=clippy::manual_map"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn message_format_short() {
+        let args = "cargo clippy --message-format short --baseline clippy-baseline.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Short, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format", "json"]);
+    }
+
+    #[test]
+    fn message_format_sarif() {
+        let args = "cargo clippy --message-format=sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Sarif, cmd.message_format);
+        assert!(cmd.args.iter().any(|arg| arg == "--message-format=json"));
+
+        let args = "cargo clippy --message-format sarif"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Sarif, cmd.message_format);
+        assert_eq!(cmd.args.last().map(String::as_str), Some("json"));
+    }
+
+    #[test]
+    fn output_format() {
+        let args = "cargo clippy --output-format=checkstyle"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Checkstyle, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+
+        let args = "cargo clippy --output-format junit --message-format=json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Junit, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+
+        let args = "cargo clippy --output-format=github"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(MessageFormat::Github, cmd.message_format);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_output_format() {
+        let args = "cargo clippy --output-format=xml"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let _ = ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn baseline() {
+        let args = "cargo clippy --baseline clippy-baseline.json --update-baseline"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.baseline.as_deref(), Some("clippy-baseline.json"));
+        assert!(cmd.update_baseline);
+        assert_eq!(MessageFormat::Rendered, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+
+        let args = "cargo clippy --baseline=clippy-baseline.json --message-format json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.baseline.as_deref(), Some("clippy-baseline.json"));
+        assert_eq!(MessageFormat::Json, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format", "json"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_baseline_without_baseline() {
+        let args = "cargo clippy --update-baseline"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let _ = ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn diff_base() {
+        let args = "cargo clippy --diff-base=origin/main"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.diff_base.as_deref(), Some("origin/main"));
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+    }
+
+    #[test]
+    fn fail_on() {
+        let args = "cargo clippy --fail-on correctness,clippy::perf -- -A clippy::absurd_extreme_comparisons"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.fail_on, Some(vec!["correctness".to_string(), "perf".to_string()]));
+        assert_eq!(
+            cmd.clippy_args,
+            [
+                "-D",
+                "clippy::correctness",
+                "-D",
+                "clippy::perf",
+                "-A",
+                "clippy::absurd_extreme_comparisons"
+            ]
+        );
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+    }
+
+    #[test]
+    fn hard_errors() {
+        let message = |level: &str, text: &str, code: Option<&str>| {
+            json!({
+                "reason": "compiler-message",
+                "message": {
+                    "level": level,
+                    "message": text,
+                    "code": code.map(|code| json!({ "code": code })),
+                    "spans": [],
+                },
+            })
+        };
+        assert!(is_hard_error(&message("error", "mismatched types", Some("E0308"))));
+        assert!(is_hard_error(&message(
+            "error",
+            "linking with `cc` failed: exit code: 1",
+            None
+        )));
+        assert!(!is_hard_error(&message(
+            "error",
+            "equal expressions",
+            Some("clippy::eq_op")
+        )));
+        assert!(!is_hard_error(&message(
+            "error",
+            "unused variable: `x`",
+            Some("unused_variables")
+        )));
+        assert!(!is_hard_error(&message(
+            "error",
+            "aborting due to previous error",
+            None
+        )));
+        assert!(!is_hard_error(&message("warning", "unused import", Some("E0000"))));
+    }
+
+    #[test]
+    fn parallel_members() {
+        let args = "cargo clippy --parallel-members -j 4"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.parallel_members);
+        assert_eq!(cmd.args, ["cargo", "clippy", "-j", "4", "--message-format=json"]);
+        assert_eq!(cmd.clippy_args, ["--no-deps"]);
+    }
+
+    #[test]
+    fn report_html() {
+        let args = "cargo clippy --report-html target/clippy-report --message-format=json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.report_html.as_deref(), Some("target/clippy-report"));
+        assert_eq!(MessageFormat::Json, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+    }
+
+    #[test]
+    fn stats() {
+        let args = "cargo clippy --stats=json".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.stats, Some(MessageFormat::Json));
+        assert_eq!(MessageFormat::Rendered, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+    }
+
+    #[test]
+    fn watch() {
+        let args = "cargo clippy --watch".split_whitespace().map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.watch);
+        assert_eq!(MessageFormat::Rendered, cmd.message_format);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn watch_with_fix() {
+        let args = "cargo clippy --fix -Zunstable-options --watch"
+            .split_whitespace()
+            .map(ToString::to_string);
+        ClippyCmd::new(args);
+    }
+
+    #[test]
+    fn explain() {
+        let args = "cargo clippy --explain needless_return"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.explain.as_deref(), Some("needless_return"));
+        assert!(!cmd.args.iter().any(|arg| arg == "needless_return"));
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
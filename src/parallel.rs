@@ -0,0 +1,134 @@
+//! Lints the members of a workspace concurrently for `cargo clippy --parallel-members`.
+//!
+//! The members are linted by a single `cargo check`, which builds their dependencies once in the
+//! target directory of the workspace and shares the jobs given with `-j` between the members
+//! through its jobserver. The messages of the members are merged in the order of the members,
+//! whatever the order they are emitted in.
+
+use serde_json::Value;
+use std::process::{Command, Stdio};
+
+use crate::read_messages;
+
+/// The arguments of Cargo split into the packages selected and the others.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    packages: Vec<String>,
+    others: Vec<String>,
+}
+
+fn split_args(args: &[String]) -> Args {
+    let mut split = Args::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--package" => split.packages.extend(args.next().cloned()),
+            // the members are selected below
+            "--workspace" | "--all" => {},
+            s if s.starts_with("--package=") => split.packages.push(s["--package=".len()..].to_string()),
+            s if s.starts_with("-p") => split.packages.push(s["-p".len()..].to_string()),
+            _ => split.others.push(arg.clone()),
+        }
+    }
+    split
+}
+
+/// Returns the names and the package IDs of the members of the workspace.
+fn workspace(args: &[String]) -> Result<Vec<(String, Value)>, String> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(&["metadata", "--no-deps", "--format-version", "1"]);
+    if let Some(index) = args.iter().position(|arg| arg == "--manifest-path") {
+        cmd.args(&args[index..(index + 2).min(args.len())]);
+    } else if let Some(arg) = args.iter().find(|arg| arg.starts_with("--manifest-path=")) {
+        cmd.arg(arg);
+    }
+    let output = cmd
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|error| format!("could not run cargo: {}", error))?;
+    if !output.status.success() {
+        return Err("`cargo metadata` failed".to_string());
+    }
+    let metadata: Value = serde_json::from_slice(&output.stdout).map_err(|error| error.to_string())?;
+
+    let member_ids = metadata["workspace_members"].as_array().map_or(&[][..], Vec::as_slice);
+    let packages = metadata["packages"].as_array().map_or(&[][..], Vec::as_slice);
+    Ok(packages
+        .iter()
+        .filter(|package| member_ids.contains(&package["id"]))
+        .filter_map(|package| Some((package["name"].as_str()?.to_string(), package["id"].clone())))
+        .collect())
+}
+
+/// Returns the command linting the members of the workspace selected by the arguments of Cargo,
+/// all of them if none is, and the package IDs of the members in the order their messages are
+/// merged in.
+pub fn command(args: &[String], check_cmd: impl Fn(&[String]) -> Command) -> Result<(Command, Vec<Value>), String> {
+    let Args { packages, mut others } = split_args(args);
+    let members = workspace(&others)?;
+    let packages = if packages.is_empty() {
+        members.iter().map(|(name, _)| name.clone()).collect()
+    } else {
+        packages
+    };
+
+    // the packages which aren't members, like `serde:1.0`, are left to Cargo
+    let ids = packages
+        .iter()
+        .filter_map(|package| Some(members.iter().find(|(name, _)| name == package)?.1.clone()))
+        .collect();
+    others.extend(packages.into_iter().flat_map(|package| vec!["-p".to_string(), package]));
+    Ok((check_cmd(&others), ids))
+}
+
+/// Runs the command, returning its messages in the order of the members, and its exit code if it
+/// failed.
+pub fn run(mut command: Command, members: &[Value]) -> (Vec<Value>, Result<(), i32>) {
+    let output = match command.stdout(Stdio::piped()).stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(error) => {
+            eprintln!("error: could not run cargo: {}", error);
+            return (Vec::new(), Err(1));
+        },
+    };
+    let mut messages = read_messages(&output.stdout[..]);
+    // the sort is stable, so the messages of each member keep their order, the ones of no member
+    // like `build-finished` coming last
+    messages.sort_by_key(|message| {
+        members
+            .iter()
+            .position(|member| *member == message["package_id"])
+            .unwrap_or(members.len())
+    });
+    let status = if output.status.success() {
+        Ok(())
+    } else {
+        Err(output.status.code().unwrap_or(-1))
+    };
+    (messages, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_args, Args};
+
+    #[test]
+    fn splits_packages() {
+        let args: Vec<_> = "--workspace -p foo --package=bar -pbaz -j 8 --all-targets --message-format=json"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            split_args(&args),
+            Args {
+                packages: vec!["foo".into(), "bar".into(), "baz".into()],
+                others: vec![
+                    "-j".into(),
+                    "8".into(),
+                    "--all-targets".into(),
+                    "--message-format=json".into()
+                ],
+            }
+        );
+    }
+}
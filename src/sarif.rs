@@ -0,0 +1,187 @@
+//! Converts the JSON messages of `cargo check --message-format=json` into a SARIF 2.1 report, for
+//! `cargo clippy --message-format=sarif`.
+
+use serde_json::{json, Value};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Asks the driver for the name, group, default level and description of the lints, see
+/// `clippy_lints::lint_metadata`. The lints are missing from the rules of the report if it fails.
+pub fn lint_metadata(driver: &Path) -> Vec<Value> {
+    Command::new(driver)
+        .arg("--print-lints-json")
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| serde_json::from_slice(&output.stdout).ok())
+        .unwrap_or_default()
+}
+
+/// The SARIF level of the lints of a group.
+fn group_level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "error",
+        "style" | "complexity" | "perf" => "warning",
+        _ => "note",
+    }
+}
+
+/// The SARIF level of a diagnostic, which depends on the level the lint is emitted at.
+fn diagnostic_level(level: &Value) -> &'static str {
+    match level.as_str() {
+        Some("error") => "error",
+        Some("warning") => "warning",
+        _ => "note",
+    }
+}
+
+/// Converts a span of a diagnostic into a SARIF region.
+fn region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
+
+/// Converts the suggestions of a diagnostic into SARIF fixes.
+fn fixes(diagnostic: &Value) -> Vec<Value> {
+    let children = diagnostic["children"].as_array().map_or(&[][..], Vec::as_slice);
+    children
+        .iter()
+        .filter_map(|child| {
+            let changes: Vec<_> = child["spans"]
+                .as_array()?
+                .iter()
+                .filter_map(|span| {
+                    let replacement = span["suggested_replacement"].as_str()?;
+                    Some(json!({
+                        "artifactLocation": { "uri": span["file_name"] },
+                        "replacements": [{
+                            "deletedRegion": region(span),
+                            "insertedContent": { "text": replacement },
+                        }],
+                    }))
+                })
+                .collect();
+            if changes.is_empty() {
+                return None;
+            }
+            Some(json!({
+                "description": { "text": child["message"] },
+                "artifactChanges": changes,
+            }))
+        })
+        .collect()
+}
+
+/// Builds the SARIF report of the Clippy lints of the Cargo messages. The rules are the lints
+/// emitted, described with the metadata of the lints.
+pub fn report(messages: &[Value], lints: &[Value]) -> Value {
+    let mut rules: Vec<Value> = Vec::new();
+    let mut rule_ids: Vec<String> = Vec::new();
+    let mut results = Vec::new();
+
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        let rule_id = match diagnostic["code"]["code"].as_str() {
+            Some(code) if code.starts_with("clippy::") => code.to_string(),
+            _ => continue,
+        };
+        let rule_index = if let Some(index) = rule_ids.iter().position(|id| *id == rule_id) {
+            index
+        } else {
+            let name = rule_id.trim_start_matches("clippy::");
+            let lint = lints.iter().find(|lint| lint["name"] == name);
+            let group = lint.and_then(|lint| lint["group"].as_str()).unwrap_or_default();
+            rules.push(json!({
+                "id": rule_id,
+                "name": name,
+                "shortDescription": { "text": lint.map_or(&Value::Null, |lint| &lint["description"]) },
+                "helpUri": format!("https://rust-lang.github.io/rust-clippy/master/index.html#{}", name),
+                "defaultConfiguration": { "level": group_level(group) },
+                "properties": { "tags": [group] },
+            }));
+            rule_ids.push(rule_id.clone());
+            rule_ids.len() - 1
+        };
+
+        let spans = diagnostic["spans"].as_array().map_or(&[][..], Vec::as_slice);
+        let locations: Vec<_> = spans
+            .iter()
+            .filter(|span| span["is_primary"] == true)
+            .map(|span| {
+                json!({
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": span["file_name"] },
+                        "region": region(span),
+                    },
+                })
+            })
+            .collect();
+
+        results.push(json!({
+            "ruleId": rule_id,
+            "ruleIndex": rule_index,
+            "level": diagnostic_level(&diagnostic["level"]),
+            "message": { "text": diagnostic["message"] },
+            "locations": locations,
+            "fixes": fixes(diagnostic),
+        }));
+    }
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "informationUri": "https://github.com/rust-lang/rust-clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::report;
+    use crate::read_messages;
+    use serde_json::json;
+
+    const MESSAGES: &str = r#"{"reason":"compiler-artifact","package_id":"foo"}
+{"reason":"compiler-message","message":{"message":"unneeded `return` statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"suggested_replacement":null}],"children":[{"message":"remove `return`","code":null,"level":"help","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true,"suggested_replacement":"1"}],"children":[]}]}}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[]}}
+"#;
+
+    #[test]
+    fn reports_rules_results_and_fixes() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        let lints = [json!({
+            "name": "needless_return",
+            "group": "style",
+            "level": "warn",
+            "description": "using a return statement like `return expr;` where an expression would suffice",
+        })];
+        let report = report(&messages, &lints);
+        let run = &report["runs"][0];
+
+        assert_eq!(run["results"].as_array().unwrap().len(), 1);
+
+        let rule = &run["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["id"], "clippy::needless_return");
+        assert_eq!(rule["defaultConfiguration"]["level"], "warning");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startColumn"], 5);
+        let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["insertedContent"]["text"], "1");
+    }
+}
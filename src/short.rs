@@ -0,0 +1,52 @@
+//! Prints the JSON messages of `cargo check --message-format=json` as one line per diagnostic, for
+//! `cargo clippy --message-format=short`, like the short format of rustc with the name of the lint.
+
+use serde_json::Value;
+
+/// Formats the diagnostics of the Cargo messages which have a primary span as lines like
+/// `src/main.rs:2:5: warning: clippy::needless_return: unneeded `return` statement`.
+pub fn lines(messages: &[Value]) -> String {
+    let mut lines = String::new();
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        let span = diagnostic["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        if let Some(span) = span {
+            lines.push_str(&format!(
+                "{}:{}:{}: {}: ",
+                span["file_name"].as_str().unwrap_or_default(),
+                span["line_start"],
+                span["column_start"],
+                diagnostic["level"].as_str().unwrap_or_default()
+            ));
+            if let Some(code) = diagnostic["code"]["code"].as_str() {
+                lines.push_str(&format!("{}: ", code));
+            }
+            let message = diagnostic["message"].as_str().unwrap_or_default();
+            lines.push_str(&message.lines().collect::<Vec<_>>().join(" "));
+            lines.push('\n');
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lines;
+    use crate::read_messages;
+
+    const MESSAGES: &str = r#"{"reason":"compiler-message","message":{"message":"unneeded `return` statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true}],"children":[],"rendered":"warning: unneeded `return` statement\n"}}
+{"reason":"compiler-message","message":{"message":"mismatched types","code":null,"level":"error","spans":[{"file_name":"src/lib.rs","line_start":7,"line_end":7,"column_start":13,"column_end":14,"is_primary":true}],"children":[]}}
+{"reason":"compiler-message","message":{"message":"1 warning emitted","code":null,"level":"warning","spans":[],"children":[]}}
+"#;
+
+    #[test]
+    fn prints_a_line_per_diagnostic() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        assert_eq!(
+            lines(&messages),
+            "src/main.rs:2:5: warning: clippy::needless_return: unneeded `return` statement\n\
+             src/lib.rs:7:13: error: mismatched types\n"
+        );
+    }
+}
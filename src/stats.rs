@@ -0,0 +1,111 @@
+//! Counts the Clippy lints of a run by lint and by crate, for `cargo clippy --stats`, to see which
+//! lints dominate a codebase.
+
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The counts of the Clippy lints emitted by a run.
+#[derive(Debug, Default)]
+pub struct Stats<'a> {
+    lints: BTreeMap<&'a str, u64>,
+    crates: BTreeMap<&'a str, u64>,
+}
+
+impl<'a> Stats<'a> {
+    /// Counts the Clippy lints of the Cargo messages, the crate of a lint being the target of
+    /// the package it is emitted on.
+    pub fn new(messages: &'a [Value]) -> Self {
+        let mut stats = Self::default();
+        for message in messages {
+            let lint = crate::diagnostic(message).and_then(|diagnostic| {
+                diagnostic["code"]["code"]
+                    .as_str()
+                    .filter(|code| code.starts_with("clippy::"))
+            });
+            if let Some(lint) = lint {
+                let krate = message["target"]["name"].as_str().unwrap_or("<unknown>");
+                *stats.lints.entry(lint).or_insert(0) += 1;
+                *stats.crates.entry(krate).or_insert(0) += 1;
+            }
+        }
+        stats
+    }
+
+    /// Formats the counts as tables, the most frequent lints and crates first.
+    pub fn table(&self) -> String {
+        let total: u64 = self.lints.values().sum();
+        let width = total.to_string().len().max(5);
+        let mut table = String::new();
+        for (title, counts) in &[("lint", &self.lints), ("crate", &self.crates)] {
+            let mut counts: Vec<_> = counts.iter().collect();
+            counts.sort_by(|(name, count), (other_name, other_count)| {
+                other_count.cmp(count).then_with(|| name.cmp(other_name))
+            });
+            table.push_str(&format!("{:>width$}  {}\n", "count", title, width = width));
+            for (name, count) in counts {
+                table.push_str(&format!("{:>width$}  {}\n", count, name, width = width));
+            }
+            table.push_str(&format!("{:>width$}  total\n\n", total, width = width));
+        }
+        table
+    }
+
+    /// Returns the counts as a JSON message, with a `reason` like the messages of Cargo.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "reason": "clippy-stats",
+            "total": self.lints.values().sum::<u64>(),
+            "lints": self.lints,
+            "crates": self.crates,
+        })
+    }
+}
+
+/// Formats the counts of the Clippy lints of the Cargo messages as tables, or as a JSON message.
+pub fn report(messages: &[Value], json: bool) -> String {
+    let stats = Stats::new(messages);
+    if json {
+        format!("{}\n", stats.to_json())
+    } else {
+        stats.table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use serde_json::{json, Value};
+
+    fn lint(name: &str, krate: &str) -> Value {
+        json!({
+            "reason": "compiler-message",
+            "target": { "name": krate },
+            "message": { "message": "lint", "code": { "code": name }, "level": "warning" },
+        })
+    }
+
+    #[test]
+    fn counts_lints_by_lint_and_crate() {
+        let messages = [
+            lint("clippy::len_zero", "foo"),
+            lint("clippy::needless_return", "bar"),
+            lint("clippy::needless_return", "foo"),
+            lint("unused_variables", "foo"),
+        ];
+        let stats = Stats::new(&messages);
+        assert_eq!(
+            stats.table(),
+            "count  lint\n    2  clippy::needless_return\n    1  clippy::len_zero\n    3  total\n\n\
+             count  crate\n    2  foo\n    1  bar\n    3  total\n\n"
+        );
+        assert_eq!(
+            stats.to_json(),
+            json!({
+                "reason": "clippy-stats",
+                "total": 3,
+                "lints": { "clippy::len_zero": 1, "clippy::needless_return": 2 },
+                "crates": { "bar": 1, "foo": 2 },
+            })
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Lints again whenever the sources change, for `cargo clippy --watch`, only printing the lints
+//! which the previous run didn't report.
+//!
+//! The sources of the current directory are polled for changes. Cargo only checks the crates which
+//! changed again, and the lint cache replays the lints of the ones whose sources were only touched.
+
+use crate::baseline::{self, Key};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the sources are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of the sources, by path.
+type Sources = BTreeMap<PathBuf, SystemTime>;
+
+/// Checks whether the lints depend on the file: the Rust files, the manifests and lock files of
+/// Cargo and the configuration files of Clippy.
+fn is_source(path: &Path) -> bool {
+    let name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+    path.extension().map_or(false, |extension| extension == "rs")
+        || ["Cargo.toml", "Cargo.lock", "clippy.toml", ".clippy.toml"].contains(&name)
+}
+
+/// Returns the sources under the directory, skipping the target directories and the hidden
+/// directories like `.git`.
+fn sources(dir: &Path) -> Sources {
+    let mut sources = Sources::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            // the directory was removed meanwhile
+            Err(_) => continue,
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let name = entry.file_name();
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() => {
+                    if name != "target" && !name.to_string_lossy().starts_with('.') {
+                        dirs.push(path);
+                    }
+                },
+                Ok(_) if is_source(&path) => {
+                    if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                        sources.insert(path, modified);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+    sources
+}
+
+/// Waits until the sources under the directory change, returning them once they stop changing,
+/// since editors and formatters often write several files in a row.
+fn wait_for_changes(dir: &Path, previous: &Sources) -> Sources {
+    let mut current = sources(dir);
+    while current == *previous {
+        thread::sleep(POLL_INTERVAL);
+        current = sources(dir);
+    }
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let next = sources(dir);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+/// The lints reported by the previous run, identified like in a baseline so that they are still
+/// found when the code around them moves.
+#[derive(Default)]
+pub struct Reported {
+    lints: BTreeMap<Key, u64>,
+}
+
+impl Reported {
+    /// Removes the lints which the previous run reported from the Cargo messages, remembering the
+    /// lints of this run. Returns a summary of the lints which are new, fixed and left out.
+    pub fn remove_reported(&mut self, messages: &mut Vec<Value>) -> String {
+        let lints = baseline::counts(messages);
+        let count: u64 = lints.values().sum();
+        let previous_count: u64 = self.lints.values().sum();
+        let removed = baseline::remove_known(messages, mem::replace(&mut self.lints, lints));
+        format!(
+            "{} new lints, {} lints fixed, {} lints already reported not shown",
+            count - removed,
+            previous_count - removed,
+            removed
+        )
+    }
+}
+
+/// Lints again whenever the sources of the current directory change, clearing the terminal
+/// before each run, until interrupted.
+pub fn run(mut lint: impl FnMut(&mut Reported) -> Result<(), i32>) -> ! {
+    let dir = Path::new(".");
+    let mut reported = Reported::default();
+    let mut current = sources(dir);
+    loop {
+        eprint!("\x1b[2J\x1b[H");
+        // the failures are shown by Cargo, and the watch goes on
+        let _ = lint(&mut reported);
+        eprintln!("Waiting for changes, press Ctrl-C to stop");
+        current = wait_for_changes(dir, &current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reported;
+    use serde_json::{json, Value};
+
+    fn lint(name: &str, code: &str) -> Value {
+        json!({
+            "reason": "compiler-message",
+            "message": {
+                "code": { "code": name },
+                "spans": [{
+                    "file_name": "src/main.rs",
+                    "is_primary": true,
+                    "text": [{ "text": code, "highlight_start": 1, "highlight_end": code.len() + 1 }],
+                }],
+            },
+        })
+    }
+
+    #[test]
+    fn only_new_lints_are_shown() {
+        let mut reported = Reported::default();
+        let mut messages = vec![
+            lint("clippy::len_zero", "v.len() == 0"),
+            lint("clippy::needless_return", "return 1;"),
+        ];
+        assert_eq!(
+            reported.remove_reported(&mut messages),
+            "2 new lints, 0 lints fixed, 0 lints already reported not shown"
+        );
+        assert_eq!(messages.len(), 2);
+
+        let mut messages = vec![
+            lint("clippy::len_zero", "v.len() == 0"),
+            lint("clippy::len_zero", "w.len() == 0"),
+        ];
+        assert_eq!(
+            reported.remove_reported(&mut messages),
+            "1 new lints, 1 lints fixed, 1 lints already reported not shown"
+        );
+        assert_eq!(messages, [lint("clippy::len_zero", "w.len() == 0")]);
+    }
+}
@@ -0,0 +1,155 @@
+//! Converts the JSON messages of `cargo check --message-format=json` into the Checkstyle and JUnit
+//! XML reports rendered by CI servers, for `cargo clippy --output-format=checkstyle|junit`.
+//!
+//! The rules of Checkstyle and the failures of JUnit are named after the lints, and each lint is
+//! located at the start of its primary span.
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A Clippy lint of the report.
+struct Lint<'a> {
+    name: &'a str,
+    level: &'a str,
+    message: &'a str,
+    rendered: &'a str,
+    line: u64,
+    column: u64,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Escapes the value of an attribute, keeping its line breaks.
+fn escape_attribute(text: &str) -> String {
+    escape(text).replace('\n', "&#10;")
+}
+
+/// Returns the Clippy lints of the Cargo messages by file.
+fn lints_by_file(messages: &[Value]) -> BTreeMap<&str, Vec<Lint<'_>>> {
+    let mut files: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for diagnostic in messages.iter().filter_map(crate::diagnostic) {
+        let name = match diagnostic["code"]["code"].as_str() {
+            Some(code) if code.starts_with("clippy::") => code,
+            _ => continue,
+        };
+        let span = diagnostic["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+        if let Some(span) = span {
+            files
+                .entry(span["file_name"].as_str().unwrap_or_default())
+                .or_default()
+                .push(Lint {
+                    name,
+                    level: diagnostic["level"].as_str().unwrap_or_default(),
+                    message: diagnostic["message"].as_str().unwrap_or_default(),
+                    rendered: diagnostic["rendered"].as_str().unwrap_or_default(),
+                    line: span["line_start"].as_u64().unwrap_or_default(),
+                    column: span["column_start"].as_u64().unwrap_or_default(),
+                });
+        }
+    }
+    files
+}
+
+/// Builds the Checkstyle report of the Clippy lints of the Cargo messages.
+pub fn checkstyle(messages: &[Value]) -> String {
+    let mut report = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for (file, lints) in lints_by_file(messages) {
+        report.push_str(&format!("  <file name=\"{}\">\n", escape_attribute(file)));
+        for lint in lints {
+            let severity = match lint.level {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "info",
+            };
+            report.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>\n",
+                lint.line,
+                lint.column,
+                severity,
+                escape_attribute(lint.message),
+                escape_attribute(lint.name)
+            ));
+        }
+        report.push_str("  </file>\n");
+    }
+    report.push_str("</checkstyle>\n");
+    report
+}
+
+/// Builds the JUnit report of the Clippy lints of the Cargo messages, with a test suite per file
+/// and a failed test case per lint.
+pub fn junit(messages: &[Value]) -> String {
+    let files = lints_by_file(messages);
+    let count: usize = files.values().map(Vec::len).sum();
+    let mut report = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"clippy\" tests=\"{0}\" failures=\"{0}\">\n",
+        count
+    );
+    for (file, lints) in files {
+        report.push_str(&format!(
+            "  <testsuite name=\"{0}\" tests=\"{1}\" failures=\"{1}\">\n",
+            escape_attribute(file),
+            lints.len()
+        ));
+        for lint in lints {
+            report.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}:{}:{}\">\n",
+                escape_attribute(lint.name),
+                escape_attribute(file),
+                lint.line,
+                lint.column
+            ));
+            report.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                escape_attribute(lint.name),
+                escape_attribute(lint.message),
+                escape(lint.rendered)
+            ));
+            report.push_str("    </testcase>\n");
+        }
+        report.push_str("  </testsuite>\n");
+    }
+    report.push_str("</testsuites>\n");
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checkstyle, junit};
+    use crate::read_messages;
+
+    const MESSAGES: &str = r#"{"reason":"compiler-message","message":{"message":"unneeded `return` statement","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":2,"line_end":2,"column_start":5,"column_end":14,"is_primary":true}],"children":[],"rendered":"warning: unneeded `return` statement\n"}}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[]}}
+"#;
+
+    #[test]
+    fn reports_checkstyle() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        assert_eq!(
+            checkstyle(&messages),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n  <file name=\"src/main.rs\">\n    \
+             <error line=\"2\" column=\"5\" severity=\"warning\" message=\"unneeded `return` statement\" \
+             source=\"clippy::needless_return\"/>\n  </file>\n</checkstyle>\n"
+        );
+    }
+
+    #[test]
+    fn reports_junit() {
+        let messages = read_messages(MESSAGES.as_bytes());
+        let report = junit(&messages);
+        assert!(report.contains(r#"<testsuites name="clippy" tests="1" failures="1">"#));
+        assert!(report.contains(r#"<testcase name="clippy::needless_return" classname="src/main.rs:2:5">"#));
+        assert!(report.contains(
+            "<failure type=\"clippy::needless_return\" message=\"unneeded `return` statement\">\
+             warning: unneeded `return` statement\n</failure>"
+        ));
+    }
+}
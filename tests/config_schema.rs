@@ -0,0 +1,22 @@
+#![feature(once_cell)]
+
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn config_schema_lists_the_options() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+        .arg("--print-config-schema")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let schema = String::from_utf8(output.stdout).unwrap();
+    assert!(schema.contains(r#""name": "cognitive-complexity-threshold""#));
+    assert!(schema.contains(r#""type": "BTreeMap<String,u64>""#));
+}
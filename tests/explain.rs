@@ -0,0 +1,29 @@
+#![feature(once_cell)]
+
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn explain_prints_the_documentation() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+        .args(&["--explain", "clippy::too_many_lines"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let explanation = String::from_utf8(output.stdout).unwrap();
+    assert!(explanation.starts_with("clippy::too_many_lines (pedantic)"));
+    assert!(explanation.contains("**What it does:** Checks for functions with a large amount of lines."));
+    assert!(explanation.contains("`thresholds.too_many_lines`"));
+
+    let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+        .args(&["--explain", "not_a_lint"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
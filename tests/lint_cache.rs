@@ -0,0 +1,52 @@
+#![feature(once_cell)]
+
+use std::fs;
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn lints_of_unchanged_crates_are_replayed() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    let dir = std::env::temp_dir().join("clippy-lint-cache");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("lib.rs");
+    fs::write(&file, "pub fn f() -> u8 {\n    return 1;\n}\n").unwrap();
+
+    let run = || {
+        let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+            .arg(&file)
+            .args(&[
+                "--crate-name=cached",
+                "--crate-type=lib",
+                "--emit=metadata",
+                "--error-format=json",
+                "-C",
+                "metadata=0123",
+                "--out-dir",
+            ])
+            .arg(&dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    assert!(run().contains("clippy::needless_return"));
+    let cache = dir.join("clippy-cache").join("cached-0123.json");
+    let entry = fs::read_to_string(&cache).unwrap();
+    assert!(entry.contains("clippy::needless_return"));
+
+    // the lints come from the cache while the crate is unchanged
+    fs::write(&cache, entry.replace("unneeded `return` statement", "replayed")).unwrap();
+    assert!(run().contains("replayed"));
+
+    fs::write(&file, "pub fn f() -> u8 {\n    1\n}\n").unwrap();
+    let stderr = run();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(!stderr.contains("clippy::needless_return"));
+}
@@ -0,0 +1,24 @@
+#![feature(once_cell)]
+
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn lint_metadata_lists_the_lints() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+        .arg("--print-lints-json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let metadata = String::from_utf8(output.stdout).unwrap();
+    assert!(metadata.contains(r#""name": "needless_return""#));
+    assert!(metadata.contains(r#""group": "correctness""#));
+    assert!(metadata.contains(r#""MachineApplicable""#));
+    assert!(metadata.contains("**What it does:**"));
+}
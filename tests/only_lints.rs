@@ -0,0 +1,45 @@
+#![feature(once_cell)]
+
+use std::fs;
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn only_the_passes_of_the_lints_run() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    let dir = std::env::temp_dir().join("clippy-only-lints");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("lib.rs");
+    fs::write(
+        &file,
+        "pub fn f(v: &[u8]) -> usize {\n    let s = String::new();\n    let _t = s.clone();\n    return v.len();\n}\n",
+    )
+    .unwrap();
+
+    let run = |only: &str| {
+        Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+            .arg(&file)
+            .args(&["--crate-type=lib", "--emit=metadata", "--out-dir"])
+            .arg(&dir)
+            .env(
+                "CLIPPY_ARGS",
+                format!("--only__CLIPPY_HACKERY__{}__CLIPPY_HACKERY__", only),
+            )
+            .output()
+            .unwrap()
+    };
+
+    let output = run("clippy::needless_return");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("clippy::needless_return"));
+    assert!(!stderr.contains("clippy::redundant_clone"));
+
+    let output = run("clippy::not_a_lint");
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(!output.status.success());
+}
@@ -0,0 +1,47 @@
+#![feature(once_cell)]
+
+use std::fs;
+use std::process::Command;
+
+mod cargo;
+
+#[test]
+fn overlapping_suggestions_are_not_machine_applicable_with_fix() {
+    if cargo::is_rustc_test_suite() {
+        return;
+    }
+
+    // both `needless_return` and `len_zero` replace `v.len() == 0`
+    let dir = std::env::temp_dir().join("clippy-overlapping-suggestions");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("lib.rs");
+    fs::write(&file, "pub fn f(v: &[u8]) -> bool {\n    return v.len() == 0;\n}\n").unwrap();
+
+    let lint = |clippy_args: &str| {
+        let output = Command::new(cargo::TARGET_LIB.join("clippy-driver"))
+            .env("CLIPPY_ARGS", clippy_args)
+            .arg(&file)
+            .args(&[
+                "--crate-type=lib",
+                "--emit=metadata",
+                "--error-format=json",
+                "--out-dir",
+            ])
+            .arg(&dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    };
+    let (fixed, checked) = (lint("--fix"), lint(""));
+    fs::remove_dir_all(&dir).unwrap();
+
+    let applicability = |stderr: &str, lint: &str| {
+        let diagnostic = stderr.lines().find(|line| line.contains(lint)).unwrap();
+        diagnostic.contains(r#""suggestion_applicability":"MachineApplicable""#)
+    };
+    assert!(applicability(&fixed, "clippy::needless_return"));
+    assert!(!applicability(&fixed, "clippy::len_zero"));
+    // the suggestions are left as they are when they aren't applied
+    assert!(applicability(&checked, "clippy::len_zero"));
+}
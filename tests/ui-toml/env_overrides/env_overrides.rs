@@ -0,0 +1,22 @@
+// rustc-env:CLIPPY_CONF_THRESHOLDS={too_many_arguments=2}
+// rustc-env:CLIPPY_CONF_BLACKLISTED_NAMES=["tata"]
+// rustc-env:CLIPPY_CONF_MSRV=1.40
+// rustc-env:CLIPPY_CONF_TOO_MANY_ARGUMENT_THRESHOLD=3
+
+#![allow(dead_code)]
+#![warn(clippy::blacklisted_name, clippy::manual_strip)]
+
+fn too_many_arguments(first: u8, second: u8, third: u8) {}
+
+fn blacklisted_names() {
+    let toto = 1;
+    let tata = 2;
+}
+
+fn manual_strip(s: &str) {
+    if s.starts_with("ab") {
+        println!("{}", &s[2..]);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::blacklisted_name)]
+
+mod generated;
+
+fn main() {
+    let foo = 1;
+}
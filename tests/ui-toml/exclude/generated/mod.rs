@@ -0,0 +1,14 @@
+pub fn generated() {
+    let foo = 1;
+    // an early lint
+    let _ = (1);
+}
+
+pub struct Generated;
+
+impl Generated {
+    pub fn new() -> Self {
+        let foo = (2);
+        Generated
+    }
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::blacklisted_name, clippy::too_many_arguments, clippy::too_many_lines)]
+
+fn three(_one: u32, _two: u32, _three: u32) {}
+
+fn long() {
+    println!("1");
+    println!("2");
+    println!("3");
+}
+
+fn main() {
+    let toto = 1;
+    three(toto, 2, 3);
+    long();
+}
@@ -0,0 +1,21 @@
+// compile-flags: --test
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::too_many_lines)]
+
+// the levels and the test overrides of both files apply
+fn main() {
+    let v = vec![1_u8];
+    let x = Some(v[0]).unwrap();
+    let _ = x + 1;
+    let _ = 1.5_f64 * f64::from(x);
+}
+
+#[cfg(test)]
+mod tests {
+    fn too_many_arguments(first: u8, second: u8) {}
+
+    fn too_many_lines() {
+        println!("1");
+        println!("2");
+    }
+}
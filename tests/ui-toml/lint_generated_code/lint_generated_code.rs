@@ -0,0 +1,12 @@
+// rustc-env:OUT_DIR=tests/ui-toml/lint_generated_code/out
+
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::blacklisted_name)]
+
+// like `include!(concat!(env!("OUT_DIR"), "/generated.rs"))`, only the correctness lints are
+// emitted
+include!("out/generated.rs");
+
+fn main() {
+    let foo = 1;
+}
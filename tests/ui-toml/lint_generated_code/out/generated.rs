@@ -0,0 +1,5 @@
+fn generated(x: u8) -> bool {
+    let foo = 1;
+    let _ = (1);
+    x == x
+}
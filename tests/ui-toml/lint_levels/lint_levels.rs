@@ -0,0 +1,15 @@
+fn needless_return() -> u8 {
+    return 1;
+}
+
+#[allow(clippy::cast_lossless)]
+fn allowed_by_attribute(x: u8) -> u64 {
+    x as u64
+}
+
+fn main() {
+    let x = Some(needless_return());
+    let y = x.unwrap();
+    let _ = y as u64;
+    let _ = allowed_by_attribute(y);
+}
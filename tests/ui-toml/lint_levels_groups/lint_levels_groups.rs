@@ -0,0 +1,20 @@
+fn cast_lossless(x: u8) -> u64 {
+    x as u64
+}
+
+fn cast_possible_truncation(x: u64) -> u8 {
+    x as u8
+}
+
+fn cast_sign_loss(x: i32) -> u32 {
+    x as u32
+}
+
+fn main() {
+    let x = Some(1);
+    let _ = x.unwrap();
+    let _ = x.expect("restriction lints stay allowed");
+    let _ = cast_lossless(1);
+    let _ = cast_possible_truncation(1);
+    let _ = cast_sign_loss(1);
+}
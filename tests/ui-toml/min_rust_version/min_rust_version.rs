@@ -1,4 +1,4 @@
-#![allow(clippy::redundant_clone)]
+#![allow(clippy::redundant_clone, clippy::incompatible_msrv)]
 #![warn(clippy::manual_non_exhaustive)]
 
 use std::ops::Deref;
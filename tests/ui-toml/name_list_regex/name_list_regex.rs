@@ -0,0 +1,10 @@
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::blacklisted_name, clippy::doc_markdown)]
+
+/// OpenGL and OpenCL are fine, but not FooBar.
+fn main() {
+    let a_tmp = 1;
+    let foo = 2;
+    let ab_tmp = 3;
+    let a_tmp_b = 4;
+}
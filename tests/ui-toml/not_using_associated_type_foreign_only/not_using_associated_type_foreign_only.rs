@@ -0,0 +1,39 @@
+#![warn(clippy::not_using_associated_type)]
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+trait Machine {
+    type State;
+    type Output;
+
+    fn start() -> Self::State;
+    fn step(state: Self::State) -> Self::Output;
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Off,
+    On,
+}
+
+struct Switch;
+
+impl Machine for Switch {
+    type State = State;
+    type Output = Ordering;
+
+    fn start() -> Self::State {
+        // `State` is defined in this crate
+        State::Off
+    }
+
+    fn step(state: Self::State) -> Self::Output {
+        match state {
+            State::Off => Ordering::Less,
+            State::On => Ordering::Greater,
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#![warn(clippy::not_using_associated_type)]
+#![allow(dead_code)]
+
+trait Machine {
+    type State;
+    type Output;
+
+    fn start() -> Self::State;
+    fn step(state: Self::State) -> Self::Output;
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Off,
+    On,
+}
+
+struct Switch;
+
+impl Machine for Switch {
+    type State = State;
+    type Output = Option<bool>;
+
+    fn start() -> Self::State {
+        State::Off
+    }
+
+    // `Option` isn't ignored with this configuration
+    fn step(state: Self::State) -> Option<bool> {
+        match state {
+            State::Off => None,
+            State::On => Some(true),
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![feature(lint_reasons)]
+
+#[allow(clippy::needless_return)]
+fn no_reason() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return, reason = "kept for symmetry with the other branches")]
+fn with_reason() -> u32 {
+    return 1;
+}
+
+// Kept for symmetry with the other branches.
+#[allow(clippy::needless_return)]
+fn comment_above() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return)] // Kept for symmetry with the other branches.
+fn comment_after() -> u32 {
+    return 1;
+}
+
+/// A doc comment isn't a justification.
+#[allow(clippy::needless_return)]
+fn doc_comment() -> u32 {
+    return 1;
+}
+
+#[allow(dead_code)]
+fn not_clippy() {}
+
+#[warn(clippy::needless_return)]
+fn not_allow() {}
+
+fn main() {
+    no_reason();
+    with_reason();
+    comment_above();
+    comment_after();
+    doc_comment();
+    not_allow();
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::result_large_err)]
+
+fn f() -> Result<(), [u8; 511]> {
+    todo!()
+}
+fn f2() -> Result<(), [u8; 512]> {
+    todo!()
+}
+fn f3() -> Result<(), [u8; 513]> {
+    todo!()
+}
+fn main() {}
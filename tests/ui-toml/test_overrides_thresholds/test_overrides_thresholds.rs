@@ -0,0 +1,32 @@
+// compile-flags: --test
+#![allow(dead_code, unused_variables)]
+#![warn(clippy::too_many_lines)]
+
+fn too_many_arguments(first: u8, second: u8, third: u8) {}
+
+fn too_many_lines() {
+    println!("1");
+    println!("2");
+}
+
+#[clippy::too_many_arguments(threshold = 1)]
+fn attribute_overrides_test_overrides(first: u8, second: u8) {}
+
+#[cfg(test)]
+mod tests {
+    fn helper(first: u8, second: u8, third: u8) {
+        println!("1");
+        println!("2");
+    }
+
+    fn too_many_arguments(first: u8, second: u8, third: u8, fourth: u8, fifth: u8) {}
+
+    #[clippy::too_many_arguments(threshold = 1)]
+    fn attribute_overrides_test_overrides(first: u8, second: u8) {}
+
+    #[test]
+    fn test() {
+        println!("1");
+        println!("2");
+    }
+}
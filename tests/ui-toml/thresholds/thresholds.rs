@@ -0,0 +1,17 @@
+#![warn(clippy::too_many_arguments, clippy::too_many_lines)]
+
+fn two(_one: u32, _two: u32) {}
+
+fn three(_one: u32, _two: u32, _three: u32) {}
+
+fn long() {
+    println!("1");
+    println!("2");
+    println!("3");
+}
+
+fn main() {
+    two(1, 2);
+    three(1, 2, 3);
+    long();
+}
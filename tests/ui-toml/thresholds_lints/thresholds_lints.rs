@@ -0,0 +1,19 @@
+// normalize-stderr-test "\(\d+ byte\)" -> "(N byte)"
+// normalize-stderr-test "\(limit: \d+ byte\)" -> "(limit: N byte)"
+
+#![deny(clippy::trivially_copy_pass_by_ref)]
+#![allow(dead_code)]
+
+fn small(x: &u16) {}
+fn large(x: &u32) {}
+
+struct SmallBoxes(Vec<Box<u32>>);
+struct LargeBoxes(Vec<Box<u64>>);
+
+fn simple(x: Vec<u8>) {}
+fn complex(x: Vec<Box<(u8, u16, Option<u32>)>>) {}
+
+fn main() {
+    small(&1);
+    large(&1);
+}
@@ -1,3 +1,5 @@
-// error-pattern: error reading Clippy's configuration file: unknown key `foobar`
+#![warn(clippy::blacklisted_name)]
 
-fn main() {}
+fn main() {
+    let toto = 42;
+}
@@ -0,0 +1,10 @@
+#![crate_type = "lib"]
+#![warn(clippy::unnecessary_literal_bound)]
+
+pub struct Struct;
+
+impl Struct {
+    pub fn exported(&self) -> &str {
+        "Hello"
+    }
+}
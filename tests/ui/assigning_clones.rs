@@ -0,0 +1,116 @@
+// run-rustfix
+#![feature(custom_inner_attributes, toowned_clone_into)]
+#![allow(unused)]
+#![allow(clippy::redundant_clone, clippy::ptr_arg, clippy::clone_on_copy)]
+#![warn(clippy::assigning_clones)]
+
+use std::borrow::ToOwned;
+use std::ops::{Add, Deref, DerefMut};
+
+// Clone
+pub struct HasCloneFrom;
+
+impl Clone for HasCloneFrom {
+    fn clone(&self) -> Self {
+        Self
+    }
+    fn clone_from(&mut self, source: &Self) {
+        *self = HasCloneFrom;
+    }
+}
+
+fn clone_method_rhs_val(mut_thing: &mut HasCloneFrom, value_thing: HasCloneFrom) {
+    *mut_thing = value_thing.clone();
+}
+
+fn clone_method_rhs_ref(mut_thing: &mut HasCloneFrom, ref_thing: &HasCloneFrom) {
+    *mut_thing = ref_thing.clone();
+}
+
+fn clone_method_lhs_val(mut mut_thing: HasCloneFrom, ref_thing: &HasCloneFrom) {
+    mut_thing = ref_thing.clone();
+}
+
+fn clone_function_lhs_mut_ref(mut_thing: &mut HasCloneFrom, ref_thing: &HasCloneFrom) {
+    *mut_thing = Clone::clone(ref_thing);
+}
+
+fn clone_function_lhs_val(mut mut_thing: HasCloneFrom, ref_thing: &HasCloneFrom) {
+    mut_thing = Clone::clone(ref_thing);
+}
+
+fn clone_method_field(holder: &mut (HasCloneFrom, u8), ref_thing: &HasCloneFrom) {
+    holder.0 = ref_thing.clone();
+}
+
+fn clone_method_vec(v: &mut Vec<Vec<u8>>, other: &Vec<u8>) {
+    v[0] = other.clone();
+}
+
+fn clone_method_string(mut s: String, other: &String) {
+    s = other.clone();
+}
+
+// Don't lint
+fn copy(mut a: u32, b: u32) {
+    a = b.clone();
+}
+
+fn uninitialized(b: &String) {
+    let a: String;
+    a = b.clone();
+}
+
+fn derived_clone(a: &mut DerivedClone, b: &DerivedClone) {
+    *a = b.clone();
+}
+
+fn borrows_from_lhs(mut a: (String, String)) {
+    a = a.clone();
+    a.0 = a.1.clone();
+}
+
+fn different_types(a: &mut String, b: &&str) {
+    *a = b.to_string();
+}
+
+#[derive(Clone)]
+pub struct DerivedClone;
+
+pub struct RecursiveCloneFrom;
+
+impl Clone for RecursiveCloneFrom {
+    fn clone(&self) -> Self {
+        Self
+    }
+    fn clone_from(&mut self, source: &Self) {
+        *self = source.clone();
+    }
+}
+
+// ToOwned
+fn owned_method_mut_ref(mut_string: &mut String, ref_str: &str) {
+    *mut_string = ref_str.to_owned();
+}
+
+fn owned_method_val(mut mut_string: String, ref_str: &str) {
+    mut_string = ref_str.to_owned();
+}
+
+fn owned_function_val(mut mut_string: String, ref_str: &str) {
+    mut_string = ToOwned::to_owned(ref_str);
+}
+
+fn owned_method_slice(v: &mut Vec<u8>, slice: &[u8]) {
+    *v = slice.to_owned();
+}
+
+mod msrv {
+    #![clippy::msrv = "1.62"]
+
+    fn owned_method_val(mut mut_string: String, ref_str: &str) {
+        mut_string = ref_str.to_owned();
+    }
+}
+
+fn main() {}
@@ -41,3 +41,22 @@ pub fn derive_foo(_input: TokenStream) -> TokenStream {
         }
     }
 }
+
+#[proc_macro_derive(ImplMachine)]
+pub fn derive_machine(_input: TokenStream) -> TokenStream {
+    // parsed rather than quoted, so that the names resolve at the call site
+    "impl Machine for Derived {
+        type State = State;
+        type Output = State;
+
+        fn start() -> State {
+            State::Off
+        }
+
+        fn step(state: State) -> State {
+            state
+        }
+    }"
+    .parse()
+    .unwrap()
+}
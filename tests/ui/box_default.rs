@@ -0,0 +1,36 @@
+// run-rustfix
+#![warn(clippy::box_default)]
+#![allow(clippy::default_trait_access)]
+
+#[derive(Default)]
+struct ImplementsDefault;
+
+struct OwnDefault;
+
+impl OwnDefault {
+    fn default() -> Self {
+        Self
+    }
+}
+
+macro_rules! outer {
+    ($e: expr) => {
+        $e
+    };
+}
+
+fn main() {
+    let _string: Box<String> = Box::new(Default::default());
+    let _byte = Box::new(u8::default());
+    let _vec = Box::new(Vec::<u8>::new());
+    let _impl = Box::new(ImplementsDefault::default());
+    let _impl2 = Box::new(<ImplementsDefault as Default>::default());
+    let _impl3: Box<ImplementsDefault> = Box::new(Default::default());
+    let _own = Box::new(OwnDefault::default()); // should not lint
+    let _in_macro = outer!(Box::new(String::new()));
+    let _string_new = Box::new(String::new());
+    let _typed: Box<Vec<u8>> = Box::new(Vec::new());
+    // Don't lint, the value is not the default one
+    let _with_capacity = Box::new(Vec::<u8>::with_capacity(4));
+    let _vec_macro: Box<Vec<u8>> = Box::new(vec![]);
+}
@@ -1,5 +1,5 @@
 #![warn(clippy::all)]
-#![allow(clippy::boxed_local, clippy::needless_pass_by_value)]
+#![allow(clippy::box_default, clippy::boxed_local, clippy::needless_pass_by_value)]
 #![allow(clippy::blacklisted_name)]
 
 macro_rules! boxit {
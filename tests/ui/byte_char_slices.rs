@@ -0,0 +1,17 @@
+// run-rustfix
+
+#![allow(unused)]
+#![warn(clippy::byte_char_slices)]
+
+fn main() {
+    let bad = &[b'a', b'b', b'c'];
+    let quotes = &[b'"', b'H', b'i'];
+    let quotes = &[b'\'', b'S', b'u', b'p'];
+    let escapes = &[b'\x42', b'E', b'\x0a', b'\\', b'\n'];
+
+    let good = &[b'a', 0x42];
+    let good = [b'a', b'a'];
+    let good: Vec<u8> = vec![b'a', b'a'];
+    let good: &[u8] = &[];
+    let good = &mut [b'a', b'b'];
+}
@@ -0,0 +1,43 @@
+// run-rustfix
+
+#![warn(clippy::deprecated_clippy_cfg_attr)]
+#![allow(clippy::deprecated_cfg_attr)]
+
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_return))]
+fn a() {}
+
+#[cfg_attr(clippy, allow(clippy::needless_return))]
+fn b() {}
+
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::needless_return), deny(clippy::single_match))]
+fn c() {}
+
+mod d {
+    #![cfg_attr(clippy, allow(clippy::needless_return))]
+}
+
+// don't lint, not a clippy lint
+#[cfg_attr(clippy, allow(dead_code))]
+fn e() {}
+
+// don't lint, not a lint attribute
+#[cfg_attr(clippy, inline)]
+fn f() {}
+
+// don't lint, not the clippy cfg
+#[cfg_attr(test, allow(clippy::needless_return))]
+fn g() {}
+
+#[cfg(feature = "cargo-clippy")]
+fn h() {}
+
+#[cfg(not(clippy))]
+fn i() {}
+
+fn main() {
+    if cfg!(clippy) {}
+    if cfg!(feature = "cargo-clippy") {}
+
+    // don't lint
+    if cfg!(test) {}
+}
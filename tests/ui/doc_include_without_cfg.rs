@@ -0,0 +1,13 @@
+// run-rustfix
+
+#![feature(extended_key_value_attributes)]
+#![warn(clippy::doc_include_without_cfg)]
+// large file
+#![doc = include_str!("../../CHANGELOG.md")]
+// ok, small file inside the package
+#![doc = include_str!("../../README.md")]
+// ok, only included for `cfg(doc)`
+#![cfg_attr(doc, doc = include_str!("../../CHANGELOG.md"))]
+
+#[doc = include_str!("../../CHANGELOG.md")]
+fn main() {}
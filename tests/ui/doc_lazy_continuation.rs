@@ -0,0 +1,38 @@
+// run-rustfix
+
+#![warn(clippy::doc_lazy_continuation)]
+
+/// 1. nest here
+/// lazy continuation
+fn one() {}
+
+/// 
+///   - nest here
+/// lazy continuation
+fn two() {}
+
+/// 
+///   - nest here indented continuation
+fn three() {}
+
+/// 
+/// - first item
+///   - nested item
+///   lazy continuation of the nested item
+fn four() {}
+
+/// > nest here
+/// lazy continuation
+fn five() {}
+
+/// > nest here
+/// > marked continuation
+fn six() {}
+
+/// 
+/// - item
+///
+/// paragraph after the list
+fn seven() {}
+
+fn main() {}
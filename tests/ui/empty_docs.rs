@@ -0,0 +1,51 @@
+#![allow(unused)]
+#![warn(clippy::empty_docs)]
+
+mod outer {
+    //!
+
+    /// dox
+    pub fn valid_doc() {}
+
+    ///
+    pub fn empty_doc() {}
+
+    ///
+    ///
+    pub fn empty_multiline_doc() {}
+
+    /**
+
+    */
+    pub fn empty_block_doc() {}
+
+    #[doc = ""]
+    pub fn empty_doc_attr() {}
+
+    #[doc = "   "]
+    #[doc = ""]
+    pub fn empty_doc_attrs() {}
+
+    /// This is ok
+    #[doc = ""]
+    pub fn partially_empty_doc() {}
+
+    #[doc(hidden)]
+    pub fn hidden_doc() {}
+
+    pub struct S {
+        ///
+        pub field: u8,
+        /// field
+        pub other: u8,
+    }
+
+    pub enum E {
+        ///
+        A,
+        /// variant
+        B,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#![feature(custom_inner_attributes)]
+#![warn(clippy::cognitive_complexity, clippy::too_many_arguments, clippy::too_many_lines)]
+#![allow(dead_code)]
+
+mod relaxed {
+    #![clippy::too_many_arguments(threshold = 10)]
+
+    fn nine(_: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8) {}
+
+    #[clippy::too_many_arguments(threshold = 2)]
+    fn three(_: u8, _: u8, _: u8) {}
+}
+
+fn eight(_: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8, _: u8) {}
+
+#[clippy::too_many_lines(threshold = 1)]
+fn two_lines() {
+    println!("one");
+    println!("two");
+}
+
+#[clippy::cognitive_complexity(threshold = 1)]
+fn two_branches(x: bool, y: bool) {
+    if x {
+        println!("x");
+    } else if y {
+        println!("y");
+    }
+}
+
+#[clippy::too_many_arguments(limit = 2)]
+fn unknown_argument(_: u8, _: u8, _: u8) {}
+
+#[clippy::too_many_arguments(threshold = "2")]
+fn not_a_number(_: u8, _: u8, _: u8) {}
+
+fn main() {}
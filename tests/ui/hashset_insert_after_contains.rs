@@ -0,0 +1,64 @@
+#![allow(unused)]
+#![allow(clippy::nonminimal_bool)]
+#![warn(clippy::hashset_insert_after_contains)]
+
+use std::collections::{BTreeSet, HashSet};
+
+fn main() {
+    should_warn_cases();
+
+    should_not_warn_cases();
+}
+
+fn should_warn_cases() {
+    let mut set = HashSet::new();
+    let value = 5;
+
+    if !set.contains(&value) {
+        set.insert(value);
+        println!("Just a comment");
+    }
+
+    if set.contains(&value) {
+        println!("Just a comment");
+    } else {
+        set.insert(value);
+    }
+
+    if !set.contains(&value) {
+        println!("Just a comment");
+        set.insert(value);
+    }
+
+    let mut btree = BTreeSet::new();
+    if !btree.contains(&value) {
+        btree.insert(value);
+    }
+}
+
+fn should_not_warn_cases() {
+    let mut set = HashSet::new();
+    let value = 5;
+    let another_value = 6;
+
+    if !set.contains(&value) {
+        set.insert(another_value);
+    }
+
+    if !set.contains(&value) {
+        println!("Just a comment");
+    }
+
+    if set.insert(value) {
+        println!("Just a comment");
+    }
+
+    if set.contains(&value) {
+        set.insert(value);
+    }
+
+    let mut other_set = HashSet::new();
+    if !set.contains(&value) {
+        other_set.insert(value);
+    }
+}
@@ -0,0 +1,43 @@
+// run-rustfix
+#![warn(clippy::implied_bounds_in_impls)]
+#![allow(dead_code)]
+
+use std::ops::{Deref, DerefMut};
+
+trait Trait1<T> {}
+trait Trait2<T>: Trait1<T> {}
+impl Trait1<i32> for () {}
+impl Trait1<i64> for () {}
+impl Trait2<i32> for () {}
+
+fn deref_derefmut<T>(x: T) -> impl Deref<Target = T> + DerefMut<Target = T> {
+    Box::new(x)
+}
+
+fn deref_derefmut_last<T>(x: T) -> impl DerefMut<Target = T> + Deref<Target = T> {
+    Box::new(x)
+}
+
+fn deref_binding_moved<T>(x: T) -> impl Deref<Target = T> + DerefMut {
+    Box::new(x)
+}
+
+fn generics_implied() -> impl Trait1<i32> + Trait2<i32> {}
+
+fn clone_copy() -> impl Clone + Copy {
+    1
+}
+
+fn partial_ord_partial_eq() -> impl PartialEq + PartialOrd + Copy {
+    1
+}
+
+// Don't lint: the arguments differ
+fn generics_different() -> impl Trait1<i64> + Trait2<i32> {}
+
+// Don't lint: unrelated traits
+fn unrelated() -> impl Clone + Send {
+    1
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+// run-rustfix
+#![warn(clippy::iter_on_empty_collections)]
+#![allow(
+    array_into_iter,
+    clippy::into_iter_on_ref,
+    clippy::iter_next_slice,
+    clippy::redundant_clone
+)]
+
+fn array() {
+    assert_eq!([].into_iter().next(), Option::<&i32>::None);
+    assert_eq!([].iter_mut().next(), Option::<&mut i32>::None);
+    assert_eq!([].iter().next(), Option::<&i32>::None);
+    assert_eq!(None.into_iter().next(), Option::<i32>::None);
+    assert_eq!(None.iter_mut().next(), Option::<&mut i32>::None);
+    assert_eq!(None.iter().next(), Option::<&i32>::None);
+    assert_eq!(vec![].into_iter().next(), Option::<i32>::None);
+    assert_eq!(vec![].iter_mut().next(), Option::<&mut i32>::None);
+    assert_eq!(vec![].iter().next(), Option::<&i32>::None);
+
+    // Don't trigger on non-iter methods
+    let _: Option<String> = None.clone();
+    let _: [String; 0] = [].clone();
+
+    // Don't trigger on collections which aren't empty
+    let _ = [1].iter();
+    let _ = Some(1).iter();
+}
+
+macro_rules! in_macros {
+    () => {
+        assert_eq!([].into_iter().next(), Option::<&i32>::None);
+        assert_eq!(None.iter().next(), Option::<&i32>::None);
+    };
+}
+
+fn main() {
+    array();
+    in_macros!();
+}
@@ -0,0 +1,40 @@
+// run-rustfix
+#![warn(clippy::iter_on_single_items)]
+#![allow(
+    array_into_iter,
+    clippy::into_iter_on_ref,
+    clippy::iter_next_slice,
+    clippy::redundant_clone
+)]
+
+fn array() {
+    assert_eq!([123].into_iter().next(), Some(&123));
+    assert_eq!([123].iter_mut().next(), Some(&mut 123));
+    assert_eq!([123].iter().next(), Some(&123));
+    assert_eq!(Some(123).into_iter().next(), Some(123));
+    assert_eq!(Some(123).iter_mut().next(), Some(&mut 123));
+    assert_eq!(Some(123).iter().next(), Some(&123));
+    assert_eq!(vec![123].into_iter().next(), Some(123));
+    assert_eq!(vec![123].iter_mut().next(), Some(&mut 123));
+    assert_eq!(vec![123].iter().next(), Some(&123));
+
+    // Don't trigger on non-iter methods
+    let _: Option<String> = Some("test".to_string()).clone();
+    let _: [String; 1] = ["test".to_string()].clone();
+
+    // Don't trigger on collections with more than one item
+    let _ = [1, 2].iter();
+    let _ = vec![1, 2].iter();
+}
+
+macro_rules! in_macros {
+    () => {
+        assert_eq!([123].into_iter().next(), Some(&123));
+        assert_eq!(Some(123).iter().next(), Some(&123));
+    };
+}
+
+fn main() {
+    array();
+    in_macros!();
+}
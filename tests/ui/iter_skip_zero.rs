@@ -0,0 +1,26 @@
+#![warn(clippy::iter_skip_zero)]
+#![allow(clippy::useless_vec)]
+
+use std::iter::once;
+
+struct HasSkip;
+
+impl HasSkip {
+    fn skip(self, _n: usize) -> Self {
+        self
+    }
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.iter().skip(0).count();
+    let _ = [1, 2, 3].iter().skip(0);
+    let _ = once(1).skip(0);
+    let _ = (0..10).skip(0).sum::<u32>();
+
+    // Don't lint
+    let _ = v.iter().skip(1);
+    let n = 1;
+    let _ = v.iter().skip(n);
+    let _ = HasSkip.skip(0);
+}
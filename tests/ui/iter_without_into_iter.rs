@@ -0,0 +1,71 @@
+#![warn(clippy::iter_without_into_iter)]
+
+pub struct S1;
+impl S1 {
+    pub fn iter(&self) -> std::slice::Iter<'_, u8> {
+        [].iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, u8> {
+        [].iter_mut()
+    }
+}
+
+pub struct S2<'a>(&'a [u8]);
+impl<'a> S2<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = &'a u8> {
+        self.0.iter()
+    }
+}
+
+// Do not lint
+
+// `IntoIterator` is already implemented
+pub struct S3<'a>(&'a mut [u8]);
+impl<'a> S3<'a> {
+    pub fn iter(&self) -> std::slice::Iter<'_, u8> {
+        self.0.iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, u8> {
+        self.0.iter_mut()
+    }
+}
+impl<'a, 'b> IntoIterator for &'b S3<'a> {
+    type IntoIter = std::slice::Iter<'b, u8>;
+    type Item = &'b u8;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, 'b> IntoIterator for &'b mut S3<'a> {
+    type IntoIter = std::slice::IterMut<'b, u8>;
+    type Item = &'b mut u8;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+// not exported
+struct S4;
+impl S4 {
+    pub fn iter(&self) -> std::slice::Iter<'_, u8> {
+        [].iter()
+    }
+}
+
+// does not return an iterator
+pub struct S5;
+impl S5 {
+    pub fn iter(&self) -> u32 {
+        0
+    }
+}
+
+// takes arguments other than `self`
+pub struct S6;
+impl S6 {
+    pub fn iter(&self, n: usize) -> std::slice::Iter<'_, u8> {
+        [].iter()
+    }
+}
+
+fn main() {}
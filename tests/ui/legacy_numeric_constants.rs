@@ -0,0 +1,55 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![allow(clippy::no_effect, unused)]
+#![warn(clippy::legacy_numeric_constants)]
+
+macro_rules! b {
+    () => {
+        std::u32::MAX
+    };
+}
+
+fn main() {
+    // Bad
+    std::f32::EPSILON;
+    std::u8::MIN;
+    std::usize::MIN;
+    std::u32::MAX;
+    core::u32::MAX;
+    std::f64::MAX;
+    i32::max_value();
+    u8::max_value();
+    u8::min_value();
+    ::std::u8::MIN;
+    // Good
+    f32::EPSILON;
+    u8::MIN;
+    usize::MIN;
+    u32::MAX;
+    f64::MAX;
+    std::f32::consts::E;
+    std::f64::consts::PI;
+    // Don't lint in macros
+    b!();
+}
+
+mod imports {
+    use std::f32::EPSILON;
+    use std::u32::MAX;
+    use std::u8;
+    // Good
+    use std::f32::consts::PI;
+}
+
+fn msrv_too_low() {
+    #![clippy::msrv = "1.42.0"]
+
+    std::u32::MAX;
+}
+
+fn msrv_just_right() {
+    #![clippy::msrv = "1.43.0"]
+
+    std::u32::MAX;
+}
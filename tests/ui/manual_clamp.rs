@@ -0,0 +1,106 @@
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_clamp)]
+#![allow(unused, clippy::if_same_then_else)]
+
+use std::cmp::{max as cmp_max, min as cmp_min};
+
+const CONST_MAX: i32 = 10;
+const CONST_MIN: i32 = 4;
+
+const CONST_F64_MAX: f64 = 10.0;
+const CONST_F64_MIN: f64 = 4.0;
+
+fn main() {
+    let (input, min, max) = (0, -2, 3);
+    let input_f64: f64 = 1.5;
+
+    // Lint
+    let x0 = if CONST_MAX < input {
+        CONST_MAX
+    } else if CONST_MIN > input {
+        CONST_MIN
+    } else {
+        input
+    };
+
+    let x1 = if input > CONST_MAX {
+        CONST_MAX
+    } else if input < CONST_MIN {
+        CONST_MIN
+    } else {
+        input
+    };
+
+    let x2 = if input < CONST_MIN {
+        CONST_MIN
+    } else if input > CONST_MAX {
+        CONST_MAX
+    } else {
+        input
+    };
+
+    let x3 = if input >= 10 {
+        10
+    } else if input <= -2 {
+        -2
+    } else {
+        input
+    };
+
+    let x4 = input.max(CONST_MIN).min(CONST_MAX);
+    let x5 = input.min(CONST_MAX).max(CONST_MIN);
+    let x6 = cmp_max(cmp_min(input, CONST_MAX), CONST_MIN);
+    let x7 = cmp_min(cmp_max(input, CONST_MIN), CONST_MAX);
+    let x8 = cmp_max(CONST_MIN, cmp_min(CONST_MAX, input));
+    let x9 = input_f64.max(CONST_F64_MIN).min(CONST_F64_MAX);
+    let x10 = if input_f64 > CONST_F64_MAX {
+        CONST_F64_MAX
+    } else if input_f64 < CONST_F64_MIN {
+        CONST_F64_MIN
+    } else {
+        input_f64
+    };
+
+    // Don't lint
+    // the bounds aren't constants
+    let x11 = input.max(min).min(max);
+    let x12 = if input > max {
+        max
+    } else if input < min {
+        min
+    } else {
+        input
+    };
+    // the bounds are in the wrong order, `clamp` would panic
+    let x13 = input.max(CONST_MAX).min(CONST_MIN);
+    // both are upper bounds
+    let x14 = if input > CONST_MAX {
+        CONST_MAX
+    } else if input > CONST_MIN {
+        CONST_MIN
+    } else {
+        input
+    };
+    // the value differs from the bound
+    let x15 = if input > CONST_MAX {
+        CONST_MIN
+    } else if input < CONST_MIN {
+        CONST_MAX
+    } else {
+        input
+    };
+    // `min` and `min`
+    let x16 = input.min(CONST_MAX).min(CONST_MIN);
+}
+
+fn msrv_1_49() {
+    #![clippy::msrv = "1.49"]
+    let input = 0;
+    let x = input.max(CONST_MIN).min(CONST_MAX);
+}
+
+fn msrv_1_50() {
+    #![clippy::msrv = "1.50"]
+    let input = 0;
+    let x = input.max(CONST_MIN).min(CONST_MAX);
+}
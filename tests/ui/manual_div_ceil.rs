@@ -0,0 +1,46 @@
+// run-rustfix
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_div_ceil)]
+
+// `div_ceil` is stabilized after the toolchain of the tests, so the fixed code calls this stand-in
+trait DivCeil {
+    fn div_ceil(self, rhs: Self) -> Self;
+}
+
+impl DivCeil for u32 {
+    #[allow(clippy::manual_div_ceil)]
+    fn div_ceil(self, rhs: u32) -> u32 {
+        (self + (rhs - 1)) / rhs
+    }
+}
+
+fn main() {
+    let x = 7_u32;
+    let y = 4_u32;
+    let z = 11_u32;
+
+    // Lint
+    let _ = (x + (y - 1)) / y;
+    let _ = ((y - 1) + x) / y;
+    let _ = (x + y - 1) / y;
+    let _ = (x + 3) / 4;
+    let _ = (7_u32 + (4 - 1)) / 4;
+    let _ = (7 + (4 - 1)) / 4_u32;
+
+    // No lint
+    let _ = (x + (y - 2)) / y;
+    let _ = (x + (z - 1)) / y;
+    let _ = (x + 4) / 4;
+
+    let x_i = 7_i32;
+    let y_i = 4_i32;
+    let _ = (x_i + (y_i - 1)) / y_i;
+}
+
+fn msrv_1_73() {
+    #![clippy::msrv = "1.73"]
+
+    let x = 7_u32;
+    let y = 4_u32;
+    let _ = (x + (y - 1)) / y;
+}
@@ -0,0 +1,11 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.72"]
+#![warn(clippy::manual_div_ceil)]
+
+// `div_ceil` is stabilized in Rust 1.73
+fn main() {
+    let x = 7_u32;
+    let y = 4_u32;
+    let _ = (x + (y - 1)) / y;
+    let _ = (x + 3) / 4;
+}
@@ -0,0 +1,8 @@
+#![warn(clippy::manual_div_ceil)]
+
+// the fixed code would call `div_ceil` as a `const fn`, which a stand-in can't
+const fn in_const(x: u32, y: u32) -> u32 {
+    (x + (y - 1)) / y
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.70"]
+#![warn(clippy::manual_hash_one)]
+
+use std::hash::{BuildHasher, Hash, Hasher};
+
+// `hash_one` is stabilized in Rust 1.71
+fn too_old(b: impl BuildHasher) -> u64 {
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+// run-rustfix
+
+#![allow(clippy::all)]
+#![deny(clippy::manual_ignore_case_cmp)]
+
+fn main() {}
+
+fn variants(a: &str, b: &str) {
+    if a.to_ascii_lowercase() == b.to_ascii_lowercase() {
+        return;
+    }
+    if a.to_ascii_uppercase() == b.to_ascii_uppercase() {
+        return;
+    }
+    let r = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+    let r = r || a.to_ascii_uppercase() == b.to_ascii_uppercase();
+    r && a.to_ascii_lowercase() == b.to_ascii_lowercase();
+    // !=
+    if a.to_ascii_lowercase() != b.to_ascii_lowercase() {
+        return;
+    }
+}
+
+fn owned(a: String, b: String) {
+    let _ = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+}
+
+fn chars_and_bytes(a: char, b: char, c: u8, d: u8) {
+    let _ = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+    let _ = c.to_ascii_uppercase() == d.to_ascii_uppercase();
+}
+
+fn byte_slices(a: &[u8], b: Vec<u8>) {
+    let _ = a.to_ascii_lowercase() == b.to_ascii_lowercase();
+}
+
+fn ok(a: &str, b: &str) {
+    // different conversions
+    let _ = a.to_ascii_lowercase() == b.to_ascii_uppercase();
+    let _ = a.to_ascii_lowercase() == b;
+    let _ = a.to_lowercase() == b.to_ascii_lowercase();
+}
@@ -0,0 +1,10 @@
+#![allow(clippy::all)]
+#![deny(clippy::manual_ignore_case_cmp)]
+
+fn main() {}
+
+fn unicode(a: &str, b: String) {
+    // also changes the case of non-ASCII characters
+    let _ = a.to_lowercase() == b.to_lowercase();
+    let _ = a.to_uppercase() != b.to_uppercase();
+}
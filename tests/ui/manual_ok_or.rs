@@ -2,6 +2,7 @@
 #![warn(clippy::manual_ok_or)]
 #![allow(clippy::blacklisted_name)]
 #![allow(clippy::redundant_closure)]
+#![allow(clippy::option_map_or_err_ok)]
 #![allow(dead_code)]
 #![allow(unused_must_use)]
 
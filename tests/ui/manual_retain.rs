@@ -0,0 +1,82 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_retain)]
+#![allow(unused)]
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+fn main() {
+    vec_retain();
+    vec_deque_retain();
+    hash_set_retain();
+    string_retain();
+    not_retain();
+}
+
+fn vec_retain() {
+    let mut vec = vec![0, 1, 2];
+    // Do lint.
+    vec = vec.iter().filter(|&x| x % 2 == 0).copied().collect();
+    vec = vec.iter().filter(|&x| x % 2 == 0).cloned().collect();
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.drain(..).filter(|x| x % 2 == 0).collect();
+    vec = vec.into_iter().filter(is_even).collect();
+
+    // Do not lint, because type conversion is performed
+    let mut tmp: Vec<i32> = vec.iter().filter(|&x| x % 2 == 0).copied().collect();
+    // Do not lint, because this is not the same binding
+    let other = vec![0, 1, 2];
+    vec = other.into_iter().filter(|x| x % 2 == 0).collect();
+    // Do not lint, because only a part of the vector is drained
+    vec = vec.drain(1..).filter(|x| x % 2 == 0).collect();
+    // Do not lint, because the predicate can't be adapted
+    vec = vec.iter().filter(|x| **x % 2 == 0).copied().collect();
+}
+
+fn is_even(x: &i32) -> bool {
+    x % 2 == 0
+}
+
+fn vec_deque_retain() {
+    let mut vec_deque = VecDeque::new();
+    vec_deque.push_back(1);
+    // Do lint.
+    vec_deque = vec_deque.iter().filter(|&x| x % 2 == 0).copied().collect();
+    vec_deque = vec_deque.into_iter().filter(|x| x % 2 == 0).collect();
+    vec_deque = vec_deque.drain(..).filter(|x| x % 2 == 0).collect();
+}
+
+fn hash_set_retain() {
+    let mut hash_set: HashSet<i32> = [1, 2, 3].iter().copied().collect();
+    // Do lint.
+    hash_set = hash_set.into_iter().filter(|x| x % 2 == 0).collect();
+    hash_set = hash_set.iter().filter(|&x| x % 2 == 0).copied().collect();
+    hash_set = hash_set.drain().filter(|x| x % 2 == 0).collect();
+}
+
+fn string_retain() {
+    let mut s = String::from("foobar");
+    // Do lint.
+    s = s.chars().filter(|&c| c != 'o').collect();
+    // Do not lint, because the predicate can't be adapted
+    s = s.chars().filter(|c| *c != 'o').collect();
+}
+
+fn not_retain() {
+    // Do not lint, `BTreeSet::retain` is not stable yet
+    let mut btree_set: BTreeSet<i8> = (0..8).collect();
+    btree_set = btree_set.into_iter().filter(|x| x % 2 == 0).collect();
+}
+
+fn _msrv_117() {
+    #![clippy::msrv = "1.17"]
+    let mut hash_set: HashSet<i32> = [1, 2, 3].iter().cloned().collect();
+    hash_set = hash_set.into_iter().filter(|x| x % 2 == 0).collect();
+}
+
+fn _msrv_125() {
+    #![clippy::msrv = "1.25"]
+    let mut s = String::from("foobar");
+    s = s.chars().filter(|&c| c != 'o').collect();
+}
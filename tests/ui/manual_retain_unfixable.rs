@@ -0,0 +1,11 @@
+#![warn(clippy::manual_retain)]
+#![allow(unused)]
+
+use std::collections::HashMap;
+
+fn main() {
+    let mut hash_map: HashMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    // The value is passed to the predicate of `retain` by mutable reference
+    hash_map = hash_map.into_iter().filter(|(k, _)| k % 2 == 0).collect();
+    hash_map = hash_map.drain().filter(|(_, v)| *v % 2 == 0).collect();
+}
@@ -0,0 +1,28 @@
+// run-rustfix
+
+#![warn(clippy::manual_rotate)]
+#![allow(unused)]
+
+fn main() {
+    let (x_u8, x_u16, x_u32, x_u64) = (1u8, 1u16, 1u32, 1u64);
+    let (x_i8, x_i16, x_i32, x_i64) = (1i8, 1i16, 1i32, 1i64);
+    let a_u32 = 1u32;
+    // True positives
+    let y_u8 = (x_u8 >> 3) | (x_u8 << 5);
+    let y_u16 = (x_u16 >> 7) | (x_u16 << 9);
+    let y_u32 = (x_u32 >> 8) | (x_u32 << 24);
+    let y_u64 = (x_u64 >> 9) | (x_u64 << 55);
+    // Plus parenthesis
+    let y_u32 = ((x_u32 + 1) >> 8) | ((x_u32 + 1) << 24);
+    // Symbolic shift amount
+    let n = 3u32;
+    let y_u32 = (x_u32 << n) | (x_u32 >> (32 - n));
+    let y_u32 = (x_u32 >> n) | (x_u32 << (32 - n));
+    // True negatives
+    let y_u32 = (x_u32 >> 8) | (x_u32 << 25);
+    let y_u32 = (x_u32 >> 8) ^ (x_u32 << 24);
+    let y_u32 = (x_u32 >> 8) | (a_u32 << 24);
+    let y_u32 = (x_u32 << 8) | (x_u32 << 24);
+    let y_u32 = (x_u32 << n) | (x_u32 >> (31 - n));
+    let y_i32 = (x_i32 >> 8) | (x_i32 << 24);
+}
@@ -1,6 +1,6 @@
 // run-rustfix
 
-#![allow(unused_imports)]
+#![allow(unused_imports, clippy::legacy_numeric_constants)]
 
 use std::{i128, i32, u128, u32};
 
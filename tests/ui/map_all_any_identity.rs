@@ -0,0 +1,20 @@
+// run-rustfix
+
+#![warn(clippy::map_all_any_identity)]
+
+fn main() {
+    let _ = ["foo"].iter().map(|s| *s == "foo").any(|a| a);
+    let _ = ["foo"].iter().map(|s| *s == "foo").all(std::convert::identity);
+
+    let v = vec![1, 2, 3];
+    let _ = v.iter().map(is_even).all(|b| b);
+    let _ = v.iter().map(is_even).any(std::convert::identity);
+
+    // ok, not the identity
+    let _ = ["foo"].iter().map(|s| *s == "foo").any(|a| !a);
+    let _ = v.iter().map(|x| *x > 1).all(|b| b && true);
+}
+
+fn is_even(x: &i32) -> bool {
+    x % 2 == 0
+}
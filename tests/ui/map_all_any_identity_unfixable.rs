@@ -0,0 +1,12 @@
+#![warn(clippy::map_all_any_identity)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    // the binding has to be made mutable
+    let iter = v.iter();
+    let _ = iter.map(is_even).any(std::convert::identity);
+}
+
+fn is_even(x: &i32) -> bool {
+    x % 2 == 0
+}
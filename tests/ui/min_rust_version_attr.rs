@@ -1,4 +1,4 @@
-#![allow(clippy::redundant_clone)]
+#![allow(clippy::redundant_clone, clippy::incompatible_msrv)]
 #![feature(custom_inner_attributes)]
 #![clippy::msrv = "1.0.0"]
 
@@ -123,6 +123,22 @@ fn missing_const_for_fn() -> i32 {
     1
 }
 
+fn manual_hash_one() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hash, Hasher};
+
+    let s = RandomState::new();
+    let mut hasher = s.build_hasher();
+    0u8.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn thread_local_initializer_can_be_made_const() {
+    thread_local! {
+        static BUF: String = String::new();
+    }
+}
+
 fn main() {
     filter_map_next();
     checked_conversion();
@@ -138,6 +154,8 @@ fn main() {
     replace_with_default();
     map_unwrap_or();
     missing_const_for_fn();
+    manual_hash_one();
+    thread_local_initializer_can_be_made_const();
 }
 
 mod meets_msrv {
@@ -1,4 +1,4 @@
-#![allow(clippy::redundant_clone)]
+#![allow(clippy::redundant_clone, clippy::incompatible_msrv)]
 #![feature(custom_inner_attributes)]
 #![clippy::msrv = "1.0"]
 
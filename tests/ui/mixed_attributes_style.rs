@@ -0,0 +1,34 @@
+#![warn(clippy::mixed_attributes_style)]
+
+#[allow(unused)]
+fn foo1() {
+    #![allow(unused)]
+}
+
+/// linux
+fn foo2() {
+    //! windows
+}
+
+#[allow(unused)]
+mod bar {
+    #![allow(unused)]
+}
+
+/// outer doc
+mod baz {
+    //! inner doc
+}
+
+// don't lint, different kinds of attributes
+#[allow(unused)]
+mod qux {
+    //! inner doc
+}
+
+#[inline]
+fn quux() {
+    #![allow(unused)]
+}
+
+fn main() {}
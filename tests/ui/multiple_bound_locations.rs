@@ -0,0 +1,54 @@
+#![warn(clippy::multiple_bound_locations)]
+
+fn ty<F: std::fmt::Debug>(a: F)
+where
+    F: Sized,
+{
+}
+
+fn lifetime<'a, 'b: 'a, 'c>(a: &'b str, b: &'a str, c: &'c str)
+where
+    'b: 'c,
+{
+}
+
+fn ty_pred<F: Sized>()
+where
+    for<'a> F: Send + 'a,
+{
+}
+
+struct B;
+
+impl B {
+    fn ty<F: std::fmt::Debug>(a: F)
+    where
+        F: Sized,
+    {
+    }
+
+    fn lifetime<'a, 'b: 'a, 'c>(a: &'b str, b: &'a str, c: &'c str)
+    where
+        'b: 'c,
+    {
+    }
+}
+
+struct C<T: Clone>(T)
+where
+    T: Default;
+
+// don't lint
+fn bounds_in_one_place<F>(a: F)
+where
+    F: Sized + std::fmt::Debug,
+{
+}
+
+fn other_bound<F: std::fmt::Debug, G>(a: F, b: G)
+where
+    G: Sized,
+{
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// run-rustfix
+
+#![warn(clippy::needless_as_bytes)]
+
+struct S;
+
+impl S {
+    fn as_bytes(&self) -> &[u8] {
+        &[]
+    }
+}
+
+fn main() {
+    if "some string".as_bytes().is_empty() {
+        println!("len = {}", "some string".as_bytes().len());
+    }
+
+    let s = String::from("yet another string");
+    if s.as_bytes().is_empty() {
+        println!("len = {}", s.as_bytes().len());
+    }
+
+    let r = &s;
+    let _ = r.as_bytes().len();
+
+    // ok, not a string
+    let s = S;
+    if s.as_bytes().is_empty() {
+        println!("len = {}", s.as_bytes().len());
+    }
+}
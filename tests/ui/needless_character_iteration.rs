@@ -0,0 +1,45 @@
+// run-rustfix
+
+#![warn(clippy::needless_character_iteration)]
+#![allow(clippy::map_identity, clippy::unnecessary_operation)]
+
+#[derive(Default)]
+struct S {
+    field: &'static str,
+}
+
+impl S {
+    fn field(&self) -> &str {
+        self.field
+    }
+}
+
+fn magic(_: char) {}
+
+fn main() {
+    "foo".chars().all(|c| c.is_ascii());
+    "foo".chars().any(|c| !c.is_ascii());
+    "foo".chars().all(|c| char::is_ascii(&c));
+    "foo".chars().any(|c| !char::is_ascii(&c));
+
+    let s = String::new();
+    s.chars().all(|c| c.is_ascii());
+    s.chars().any(|c| !c.is_ascii());
+
+    S::default().field().chars().all(|x| x.is_ascii());
+
+    "foo".chars().all(|c| c.is_ascii_digit());
+    "foo".chars().any(|c| c.is_ascii_uppercase());
+    "foo".chars().all(|c| !c.is_ascii_whitespace());
+    "foo".chars().any(|c| c.is_ascii());
+
+    // Should not lint!
+    "foo".chars().all(|c| {
+        let x = c;
+        magic(x);
+        x.is_ascii()
+    });
+    "foo".chars().all(|c| c.is_alphanumeric());
+    "foo".chars().map(|c| c).all(|c| !char::is_ascii(&c));
+    "foo".chars().all(|c| c.is_ascii() && c != 'x');
+}
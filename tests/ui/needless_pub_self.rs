@@ -0,0 +1,26 @@
+// run-rustfix
+#![warn(clippy::needless_pub_self)]
+#![allow(unused)]
+
+pub(self) fn a() {}
+#[rustfmt::skip]
+pub(in self) fn b() {}
+
+pub fn c() {}
+mod a {
+    pub(super) fn d() {}
+    pub(super) fn e() {}
+    pub(self) fn f() {}
+}
+
+macro_rules! define_private {
+    () => {
+        pub(self) fn g() {}
+    };
+}
+
+define_private!();
+
+fn main() {
+    // do not lint
+}
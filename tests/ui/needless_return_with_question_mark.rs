@@ -0,0 +1,56 @@
+// run-rustfix
+#![allow(
+    clippy::needless_question_mark,
+    clippy::needless_return,
+    clippy::no_effect,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    unreachable_code,
+    unused
+)]
+#![warn(clippy::needless_return_with_question_mark)]
+
+fn returns_result(x: u32) -> Result<(), String> {
+    if x == 0 {
+        return Err("zero".to_string())?;
+    }
+    if x == 1 {
+        return Err("one")?;
+    }
+    Ok(())
+}
+
+fn tail_position() -> Result<(), String> {
+    return Err("error".to_string())?;
+}
+
+fn boxed_error() -> Result<(), Box<dyn std::error::Error>> {
+    return Err(std::fmt::Error)?;
+}
+
+macro_rules! return_err {
+    ($e:expr) => {
+        return Err($e)?;
+    };
+}
+
+// Do not lint
+
+fn return_without_question_mark() -> Result<(), String> {
+    return Err("error".to_string());
+}
+
+fn question_mark_on_ok() -> Result<u32, String> {
+    let x: Result<u32, String> = Ok(0);
+    return Ok(x?);
+}
+
+fn from_macro() -> Result<(), String> {
+    return_err!("error".to_string());
+}
+
+fn returns_option() -> Option<()> {
+    return None?;
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// run-rustfix
+
+#![warn(clippy::non_zero_suggestions)]
+#![allow(clippy::many_single_char_names, unused)]
+
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+fn main() {
+    // Positive test cases (lint should trigger)
+    // U32 -> U64
+    let x: u64 = 100;
+    let y = NonZeroU32::new(10).unwrap();
+    let r1 = x / u64::from(y.get());
+    let r2 = x % u64::from(y.get());
+
+    // U16 -> U32
+    let a: u32 = 50;
+    let b = NonZeroU16::new(5).unwrap();
+    let r3 = a / u32::from(b.get());
+
+    // U8 -> U16
+    let d = NonZeroU8::new(3).unwrap();
+    let r4 = 9u16 / u16::from(d.get());
+
+    // Nested in another operation
+    let g = NonZeroU64::new(1000).unwrap();
+    let r7 = g.get() / u64::from(y.get()) - 1;
+
+    // Negative test cases (lint should not trigger)
+    // Left hand side expressions should not be triggered
+    let r5 = u32::from(b.get()) / a;
+
+    // Same size types
+    let e: u32 = 200;
+    let f = NonZeroU32::new(20).unwrap();
+    let r6 = e / f.get();
+
+    // Already using NonZero types
+    let r8 = NonZeroUsize::new(5).unwrap();
+
+    // Signed integers can't be divided by `NonZero` types
+    let r9 = -3i64 / i64::from(y.get());
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::non_zero_suggestions)]
+#![allow(unused)]
+
+use std::num::{NonZeroI16, NonZeroU32, NonZeroU64};
+
+fn main() {
+    let x = u64::from(NonZeroU32::new(5).unwrap().get());
+
+    let n = NonZeroI16::new(-5).unwrap();
+    let y = i64::from(n.get());
+
+    let z = u64::from(n.get() as u16) + 1;
+}
+
+fn return_non_zero(x: u64, y: NonZeroU32) -> u64 {
+    u64::from(y.get())
+}
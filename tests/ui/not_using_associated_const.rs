@@ -0,0 +1,51 @@
+// run-rustfix
+
+#![warn(clippy::not_using_associated_const)]
+#![allow(dead_code)]
+
+const MAX_STATES: usize = 4;
+const MIN_STATES: usize = 1;
+
+trait Machine {
+    const MAX: usize;
+    const MIN: usize;
+
+    fn is_full(count: usize) -> bool;
+}
+
+struct Switch;
+
+impl Machine for Switch {
+    const MAX: usize = MAX_STATES;
+    const MIN: usize = 2;
+
+    fn is_full(count: usize) -> bool {
+        match count {
+            MAX_STATES => true,
+            // `MIN` isn't set to `MIN_STATES`
+            MIN_STATES => false,
+            _ => count > MAX_STATES,
+        }
+    }
+}
+
+// both associated constants are set to `MAX_STATES`
+struct Dial;
+
+impl Machine for Dial {
+    const MAX: usize = MAX_STATES;
+    const MIN: usize = MAX_STATES;
+
+    fn is_full(count: usize) -> bool {
+        count == MAX_STATES
+    }
+}
+
+// inherent impls don't have associated constants
+impl Switch {
+    fn max() -> usize {
+        MAX_STATES
+    }
+}
+
+fn main() {}
@@ -0,0 +1,283 @@
+// run-rustfix
+// aux-build:proc_macro_derive.rs
+
+#![feature(generic_associated_types)]
+#![warn(clippy::not_using_associated_type)]
+#![allow(dead_code, incomplete_features, clippy::needless_lifetimes, clippy::use_self)]
+
+#[macro_use]
+extern crate proc_macro_derive;
+
+trait Machine {
+    type State;
+    type Output;
+
+    fn start() -> Self::State;
+    fn step(state: Self::State) -> Self::Output;
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Off,
+    On(u8),
+    Blinking { speed: u8 },
+}
+
+impl State {
+    fn new() -> Self {
+        Self::Off
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Off
+    }
+}
+
+struct Light(u32);
+
+impl Light {
+    const DEFAULT: Self = Self(0);
+}
+
+struct Switch;
+
+impl Machine for Switch {
+    type State = State;
+    type Output = Light;
+
+    fn start() -> Self::State {
+        let _ = State::new();
+        let _ = State::default();
+        let _ = <State as Default>::default();
+        let _: fn() -> State = <State>::new;
+        let _ = State::On(1);
+        let _ = State::Blinking { speed: 2 };
+        State::Off
+    }
+
+    fn step(state: State) -> Light {
+        let _: State = state;
+        let _ = Light::DEFAULT;
+        Light(1)
+    }
+}
+
+struct Value {
+    state: State,
+}
+
+trait Transition {
+    type Associated;
+
+    fn next(value: &Self::Associated) -> State;
+}
+
+impl Transition for Switch {
+    type Associated = Value;
+
+    fn next(Value { state }: &Self::Associated) -> State {
+        match state {
+            State::Off => State::On(1),
+            State::On(speed) | State::Blinking { speed } => State::Blinking { speed: *speed },
+        }
+    }
+}
+
+impl Transition for Light {
+    type Associated = State;
+
+    fn next(value: &Self::Associated) -> State {
+        if let State::On(_) | State::Blinking { .. } = value {
+            State::Off
+        } else {
+            *value
+        }
+    }
+}
+
+// both associated types are set to `State`
+struct Toggle;
+
+impl Machine for Toggle {
+    type State = State;
+    type Output = State;
+
+    fn start() -> Self::State {
+        State::Off
+    }
+
+    fn step(state: Self::State) -> Self::Output {
+        state
+    }
+}
+
+// no associated type is set to `State`
+struct Other;
+
+impl Machine for Other {
+    type State = u8;
+    type Output = Option<State>;
+
+    fn start() -> Self::State {
+        let _ = State::Off;
+        0
+    }
+
+    fn step(_: Self::State) -> Self::Output {
+        Some(State::new())
+    }
+}
+
+struct Wrapper<T>(T);
+
+type ByteWrapper = Wrapper<u8>;
+
+trait Wrap: Sized {
+    type Wrapped;
+
+    fn wrap(self) -> Self::Wrapped;
+    fn unwrap(wrapped: Self::Wrapped) -> Self;
+    fn convert(other: Wrapper<u16>) -> Option<Self>;
+}
+
+impl Wrap for u8 {
+    type Wrapped = Wrapper<u8>;
+
+    fn wrap(self) -> ByteWrapper {
+        Wrapper(self)
+    }
+
+    fn unwrap(wrapped: Wrapper<Self>) -> Self {
+        wrapped.0
+    }
+
+    // `Wrapper<u16>` isn't the associated type
+    fn convert(other: Wrapper<u16>) -> Option<Self> {
+        Some(other.0 as u8)
+    }
+}
+
+impl<T> Wrap for Option<T> {
+    type Wrapped = Wrapper<Option<T>>;
+
+    fn wrap(self) -> Wrapper<Option<T>> {
+        Wrapper(self)
+    }
+
+    fn unwrap(wrapped: Self::Wrapped) -> Self {
+        wrapped.0
+    }
+
+    fn convert(_: Wrapper<u16>) -> Option<Self> {
+        None
+    }
+}
+
+type Mode = State;
+
+// both the alias and the aliased type name the associated type
+struct Dimmer;
+
+impl Machine for Dimmer {
+    type State = Mode;
+    type Output = Light;
+
+    fn start() -> Mode {
+        Mode::On(2)
+    }
+
+    fn step(state: State) -> Self::Output {
+        match state {
+            Mode::Off => Light(0),
+            State::On(speed) | Mode::Blinking { speed } => Light(speed.into()),
+        }
+    }
+}
+
+macro_rules! off {
+    () => {
+        State::Off
+    };
+}
+
+// paths from macro expansions can't be rewritten
+struct Remote;
+
+impl Machine for Remote {
+    type State = State;
+    type Output = bool;
+
+    fn start() -> Self::State {
+        off!()
+    }
+
+    fn step(state: Self::State) -> Self::Output {
+        // the pattern is written in the macro call, so it can be rewritten
+        matches!(state, State::On(_))
+    }
+}
+
+#[derive(ImplMachine)]
+struct Derived;
+
+// associated types set to `Self` or common standard library types are ignored
+impl Machine for Light {
+    type State = Self;
+    type Output = String;
+
+    fn start() -> Self::State {
+        Light(0)
+    }
+
+    fn step(state: Light) -> String {
+        String::from(if state.0 == 0 { "off" } else { "on" })
+    }
+}
+
+// inherent impls don't have associated types
+impl Switch {
+    fn off() -> State {
+        State::Off
+    }
+}
+
+struct Borrowed<'a, T>(&'a T);
+
+trait Family {
+    type Member<T>;
+    type View<'a>;
+
+    fn member<U>(value: U) -> Self::Member<U>;
+    fn view<'a>(value: &'a u8) -> Self::View<'a>;
+    fn view_elided(value: &u8) -> Self::View<'_>;
+    fn other(value: u8) -> Wrapper<u8>;
+}
+
+// generic associated types are used with the arguments matching their parameters
+struct Bytes;
+
+impl Family for Bytes {
+    type Member<T> = Wrapper<Option<T>>;
+    type View<'a> = Borrowed<'a, u8>;
+
+    fn member<U>(value: U) -> Wrapper<Option<U>> {
+        Wrapper(Some(value))
+    }
+
+    fn view<'a>(value: &'a u8) -> Borrowed<'a, u8> {
+        Borrowed(value)
+    }
+
+    fn view_elided(value: &u8) -> Borrowed<u8> {
+        Borrowed(value)
+    }
+
+    // `Wrapper<u8>` isn't a `Wrapper<Option<T>>`
+    fn other(value: u8) -> Wrapper<u8> {
+        Wrapper(value)
+    }
+}
+
+fn main() {}
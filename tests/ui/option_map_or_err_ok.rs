@@ -0,0 +1,23 @@
+// run-rustfix
+
+#![warn(clippy::option_map_or_err_ok)]
+#![allow(clippy::manual_ok_or)]
+
+fn make_error() -> &'static str {
+    "error"
+}
+
+fn main() {
+    let x = Some("a");
+    let _ = x.map_or(Err("a"), Ok);
+    let _ = x.map_or(Err(make_error()), Ok);
+    let _ = x.map_or_else(|| Err("a"), Ok);
+    let _ = x.map_or_else(|| Err(make_error()), Ok);
+
+    // should not lint
+    let _ = x.map_or(Err("a"), |s| Ok(s.len()));
+    let _ = x.map_or(Ok::<_, &str>("b"), Ok);
+    let _ = x.map_or_else(|| Err("a"), |s| Ok(s.len()));
+    let y: Result<&str, &str> = Ok("a");
+    let _ = y.map_or(Err("a"), Ok);
+}
@@ -0,0 +1,93 @@
+// run-rustfix
+#![allow(clippy::float_cmp, clippy::many_single_char_names, clippy::no_effect, unused)]
+#![warn(clippy::redundant_guards)]
+
+const MAX: u32 = 10;
+
+struct A(u32);
+
+struct B {
+    e: u32,
+}
+
+struct C(u32, u32);
+
+fn main() {
+    let c = C(1, 2);
+    match c {
+        C(x, y) if x == 1 => {},
+        C(x, y) if 2 == y => {},
+        _ => {},
+    }
+
+    let x = Some(3u32);
+    match x {
+        Some(x) if x == MAX => {},
+        Some(x) if x == u32::MAX => {},
+        Some(x) if (3..=5).contains(&x) => {},
+        _ => {},
+    }
+
+    let x = Some(-1i32);
+    match x {
+        Some(x) if x == -1 => {},
+        _ => {},
+    }
+
+    let s = Some("a");
+    match s {
+        Some(s) if s == "a" => {},
+        _ => {},
+    }
+
+    let b = B { e: 1 };
+    match b {
+        B { e } if e == 1 => {},
+        B { e: x } if x == 2 => {},
+        _ => {},
+    }
+
+    match 'a' {
+        c if c == 'b' => {},
+        _ => {},
+    }
+
+    // Do not lint
+
+    let x = Some(3u32);
+    match x {
+        // binding is used in the body
+        Some(x) if x == 1 => println!("{}", x),
+        // binding is used in a closure
+        Some(x) if x == 2 => {
+            let _ = || x;
+        },
+        // not a literal
+        Some(x) if x == 1 + 1 => {},
+        // exclusive range
+        Some(x) if (3..5).contains(&x) => {},
+        // binding in or-pattern
+        Some(x) | Some(x) if x == 4 => {},
+        _ => {},
+    }
+
+    let x = Some(1.0f32);
+    match x {
+        // floats are not allowed in patterns
+        Some(x) if x == 1.0 => {},
+        _ => {},
+    }
+
+    let x = Some(String::new());
+    match &x {
+        // binding by reference
+        Some(x) if x == "a" => {},
+        _ => {},
+    }
+
+    let a = Some(A(1));
+    match a {
+        Some(ref a) if a.0 == 1 => {},
+        _ => {},
+    }
+}
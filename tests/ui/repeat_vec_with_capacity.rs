@@ -0,0 +1,42 @@
+// run-rustfix
+#![warn(clippy::repeat_vec_with_capacity)]
+
+fn main() {
+    {
+        let _ = vec![Vec::<()>::with_capacity(42); 123];
+    }
+
+    {
+        let n = 123;
+        let _ = vec![Vec::<()>::with_capacity(42); n];
+    }
+
+    {
+        macro_rules! from_macro {
+            ($x:expr) => {
+                vec![$x; 123];
+            };
+        }
+        // vec expansion is from another macro, don't lint
+        from_macro!(Vec::<()>::with_capacity(42));
+    }
+
+    {
+        let _ = std::iter::repeat(Vec::<()>::with_capacity(42));
+    }
+
+    {
+        macro_rules! from_macro {
+            ($x:expr) => {
+                std::iter::repeat($x)
+            };
+        }
+        from_macro!(Vec::<()>::with_capacity(42));
+    }
+
+    {
+        // not `with_capacity`
+        vec![Vec::<()>::new(); 123];
+        std::iter::repeat(Vec::<()>::new());
+    }
+}
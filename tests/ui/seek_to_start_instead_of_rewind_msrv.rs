@@ -0,0 +1,12 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.54"]
+#![warn(clippy::seek_to_start_instead_of_rewind)]
+
+use std::io::{Seek, SeekFrom};
+
+// `rewind` is stabilized in Rust 1.55
+fn too_old<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(0));
+}
+
+fn main() {}
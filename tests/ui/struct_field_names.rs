@@ -0,0 +1,81 @@
+#![warn(clippy::struct_field_names)]
+#![allow(unused)]
+
+#[derive(Default)]
+struct Data1 {
+    field_data1: u8,
+    another: u8,
+    yet_another: u8,
+}
+
+#[derive(Default)]
+struct Data2 {
+    another: u8,
+    yet_another: u8,
+    field_data2: u8,
+}
+
+struct StructData {
+    field: u8,
+    struct_data_field: u8,
+    other: u8,
+}
+
+struct DataStruct {
+    field: u8,
+    data_struct_field: u8,
+    other: u8,
+}
+
+struct DoublePrefix {
+    some_common_a: bool,
+    some_common_b: bool,
+    some_common_c: bool,
+}
+
+struct DoublePostfix {
+    a_common_postfix: bool,
+    b_common_postfix: bool,
+    c_common_postfix: bool,
+}
+
+struct Cake {
+    cake_sugar: u8,
+    cake_flour: u8,
+    cake_eggs: u8,
+}
+
+// Do not lint
+
+// not enough fields
+struct Vehicle {
+    vehicle_speed: u8,
+    wheels: u8,
+}
+
+// a common word that makes up a whole field name
+struct NotSamePrefix {
+    data: u8,
+    data_len: u8,
+    data_cap: u8,
+}
+
+// only part of the words are common
+struct NotAWord {
+    prefix_a: u8,
+    prefixes_b: u8,
+    prefix_c: u8,
+}
+
+macro_rules! mk_struct {
+    () => {
+        struct MacroStruct {
+            some_a: i32,
+            some_b: i32,
+            some_c: i32,
+        }
+    };
+}
+mk_struct!();
+
+fn main() {}
@@ -0,0 +1,61 @@
+#![warn(clippy::needless_return, clippy::len_zero, clippy::if_same_then_else)]
+#![allow(clippy::needless_bool)]
+
+fn same_line(v: &[u32]) -> bool {
+    return v.len() == 0; // clippy: allow(needless_return)
+}
+
+fn next_line(v: &[u32]) -> bool {
+    // clippy: allow-next-line(clippy::needless_return, clippy::len_zero)
+    return v.len() == 0;
+}
+
+fn other_line(v: &[u32]) -> bool {
+    // clippy: allow(needless_return)
+    return v.len() == 0;
+}
+
+fn match_arm(v: Option<&[u32]>) -> bool {
+    match v {
+        Some(v) => v.len() == 0, // clippy: allow(len-zero)
+        None => v.map_or(0, |v| v.len()) == 0,
+    }
+}
+
+macro_rules! statements {
+    ($($statement:stmt;)*) => {
+        $($statement;)*
+    };
+}
+
+fn in_macro(v: &[u32]) -> bool {
+    statements! {
+        let empty = v.len() == 0; // clippy: allow(len_zero)
+        let other = v.len() == 0;
+    }
+    empty && other
+}
+
+fn in_string(v: &[u32]) -> (bool, &'static str) {
+    (v.len() == 0, "// clippy: allow(len_zero)")
+}
+
+fn secondary_span(b: bool) -> u32 {
+    // the lint is emitted at the `else` block, with a note at the `if` block
+    // clippy: allow-next-line(if_same_then_else)
+    if b {
+        0
+    } else {
+        0
+    }
+}
+
+fn main() {
+    same_line(&[]);
+    next_line(&[]);
+    other_line(&[]);
+    match_arm(None);
+    in_macro(&[]);
+    in_string(&[]);
+    secondary_span(true);
+}
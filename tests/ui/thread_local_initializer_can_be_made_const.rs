@@ -0,0 +1,26 @@
+#![warn(clippy::thread_local_initializer_can_be_made_const)]
+
+use std::cell::RefCell;
+
+fn main() {
+    // lint and suggest const
+    thread_local! {
+        static BUF_1: RefCell<String> = RefCell::new(String::new());
+    }
+
+    thread_local! {
+        static SIMPLE: i32 = 1;
+    }
+
+    // don't lint
+    thread_local! {
+        static BUF_2: RefCell<String> = RefCell::new(String::from("hello"));
+    }
+
+    // lint and suggest const for all non const items
+    thread_local! {
+        static BUF_3_CAN_BE_MADE_CONST: RefCell<String> = RefCell::new(String::new());
+        static BUF_4_CAN_BE_MADE_CONST: RefCell<String> = RefCell::new(String::new());
+        static CONST_MIXED_WITH: RefCell<String> = RefCell::new(String::from("hello"));
+    }
+}
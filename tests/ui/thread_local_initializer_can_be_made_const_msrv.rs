@@ -0,0 +1,12 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.58"]
+#![warn(clippy::thread_local_initializer_can_be_made_const)]
+
+use std::cell::RefCell;
+
+// `const` initializers of `thread_local!` are stabilized in Rust 1.59
+thread_local! {
+    static BUF: RefCell<String> = RefCell::new(String::new());
+}
+
+fn main() {}
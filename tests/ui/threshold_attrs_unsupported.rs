@@ -0,0 +1,6 @@
+#![warn(clippy::type_complexity)]
+
+#[clippy::type_complexity(threshold = 10)]
+fn unsupported_lint() {}
+
+fn main() {}
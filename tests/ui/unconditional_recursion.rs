@@ -0,0 +1,91 @@
+#![warn(clippy::unconditional_recursion)]
+#![allow(clippy::partialeq_ne_impl, clippy::default_trait_access, clippy::new_without_default)]
+#![allow(unconditional_recursion)]
+
+enum Foo {
+    A,
+    B,
+}
+
+impl PartialEq for Foo {
+    fn ne(&self, other: &Self) -> bool {
+        self != other
+    }
+    fn eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+enum Foo2 {
+    A,
+    B,
+}
+
+impl PartialEq for Foo2 {
+    fn ne(&self, other: &Self) -> bool {
+        *self != *other
+    }
+    fn eq(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
+
+struct Bar<T: PartialEq>(T);
+
+impl<T: PartialEq> PartialEq for Bar<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+struct Baz;
+
+impl PartialEq<Bar<u8>> for Baz {
+    fn eq(&self, other: &Bar<u8>) -> bool {
+        // don't lint, compares other types
+        other == &Bar(0)
+    }
+}
+
+struct D {
+    field: u8,
+}
+
+impl Default for D {
+    fn default() -> Self {
+        Self { ..Default::default() }
+    }
+}
+
+struct D2;
+
+impl D2 {
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for D2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct D3 {
+    field: u8,
+}
+
+impl D3 {
+    fn new() -> Self {
+        Self { field: 0 }
+    }
+}
+
+impl Default for D3 {
+    fn default() -> Self {
+        // don't lint
+        Self::new()
+    }
+}
+
+fn main() {}
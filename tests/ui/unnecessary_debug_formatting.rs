@@ -0,0 +1,33 @@
+#![warn(clippy::unnecessary_debug_formatting)]
+
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("/a/b/c");
+    let path_buf = path.to_path_buf();
+    let os_str = OsStr::new("abc");
+    let os_string = os_str.to_os_string();
+
+    println!("{:?}", path);
+    println!("{:?}", path_buf);
+    println!("{:?}", os_str);
+    println!("{:?}", os_string);
+    println!("{:#?}", &path);
+    let _ = format!("{} is at {:?}", 1, path);
+    let mut s = String::new();
+    let _ = write!(s, "{:?}", path_buf);
+
+    // ok, `Display` formatting
+    println!("{}", path.display());
+    println!("{}", os_str.to_string_lossy());
+
+    // ok, not a path
+    println!("{:?}", "/a/b/c");
+    println!("{:?}", Some(path));
+}
+
+fn panics(path: &Path) {
+    panic!("couldn't read {:?}", path);
+}
@@ -0,0 +1,92 @@
+// run-rustfix
+
+#![warn(clippy::unnecessary_literal_bound)]
+#![allow(dead_code, clippy::needless_lifetimes)]
+
+struct Struct<'a> {
+    not_literal: &'a str,
+}
+
+impl Struct<'_> {
+    // Should warn
+    fn returns_lit(&self) -> &str {
+        "Hello"
+    }
+
+    // Should NOT warn
+    fn returns_non_lit(&self) -> &str {
+        self.not_literal
+    }
+
+    // Should warn
+    fn conditionally_returns_lit(&self, cond: bool) -> &str {
+        if cond {
+            "Literal"
+        } else {
+            "also a literal"
+        }
+    }
+
+    // Should NOT warn
+    fn conditionally_returns_not_lit(&self, cond: bool) -> &str {
+        if cond {
+            "Literal"
+        } else {
+            self.not_literal
+        }
+    }
+
+    // Should warn
+    fn contionally_returns_literals_explicit(&self, cond: bool) -> &str {
+        if cond {
+            return "Literal";
+        }
+
+        "also a literal"
+    }
+
+    // Should NOT warn
+    fn conditionally_returns_not_literals_explicit(&self, cond: bool) -> &str {
+        if cond {
+            return self.not_literal;
+        }
+
+        "Literal"
+    }
+
+    // Should NOT warn
+    fn returns_static(&self) -> &'static str {
+        "Hello"
+    }
+
+    // Should NOT warn
+    fn returns_named_lifetime<'a>(&'a self) -> &'a str {
+        "Hello"
+    }
+}
+
+// Should warn
+fn free_fn(_: &u8) -> &str {
+    match 0 {
+        0 => "zero",
+        _ => "other",
+    }
+}
+
+trait ReturnsStr {
+    fn trait_method(&self) -> &str;
+
+    // Should NOT warn, implementors may rely on the elided lifetime
+    fn provided(&self) -> &str {
+        "Hello"
+    }
+}
+
+impl ReturnsStr for u8 {
+    // Should NOT warn, the signature has to match the trait's
+    fn trait_method(&self) -> &str {
+        "Literal"
+    }
+}
+
+fn main() {}
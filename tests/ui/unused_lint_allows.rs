@@ -0,0 +1,45 @@
+#![warn(clippy::unused_lint_allows)]
+#![allow(clippy::needless_return, clippy::let_and_return)]
+
+#[allow(clippy::needless_return)]
+fn used() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return, clippy::len_zero)]
+fn partly_used() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return)]
+fn unused() -> u32 {
+    1
+}
+
+#[allow(clippy::style)]
+fn used_group() -> bool {
+    vec![1].len() == 0
+}
+
+#[allow(clippy::style, dead_code)]
+fn unused_group() {}
+
+fn nested() -> u32 {
+    #[allow(clippy::needless_return)]
+    {
+        let x = 1;
+        x
+    }
+}
+
+#[allow(clippy::unused_lint_allows, clippy::len_zero)]
+fn allowed() {}
+
+fn main() {
+    used();
+    partly_used();
+    unused();
+    used_group();
+    nested();
+    allowed();
+}
@@ -0,0 +1,61 @@
+#![warn(clippy::zombie_processes)]
+#![allow(clippy::if_same_then_else, clippy::ifs_same_cond)]
+
+use std::process::{Child, Command};
+
+fn main() {
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.kill();
+        x.id();
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.wait().unwrap(); // OK
+    }
+    {
+        let x = Command::new("").spawn().unwrap();
+        x.wait_with_output().unwrap(); // OK
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.try_wait().unwrap(); // OK
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let mut r = &mut x;
+        r.wait().unwrap(); // OK, not calling `.wait()` directly on `x` but through `r` -> `x`
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        process_child(x); // OK, other function might call `.wait()` so assume it does
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let v = &x;
+        // (allow shared refs is fine because one cannot call `.wait()` through that)
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let _ = x.stdin.take();
+    }
+    {
+        let _ = Command::new("").spawn().unwrap();
+    }
+    {
+        Command::new("").spawn().unwrap();
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let closure = move || {
+            x.wait().unwrap(); // OK
+        };
+    }
+    {
+        Command::new("").spawn().unwrap().wait().unwrap(); // OK
+    }
+}
+
+fn process_child(c: Child) {
+    todo!()
+}